@@ -0,0 +1,21 @@
+/* CARGO WORKSPACES */
+/* As a project develops, the library crate might keep getting bigger and
+ * we might want to split our package further into multiple library
+ * crates. Cargo offers a feature called 'workspaces' that can help manage
+ * multiple related packages that are developed in tandem.
+ *
+ * A 'workspace' is a set of packages that share the same 'Cargo.lock' and
+ * output directory. The workspace in this directory has a binary, 'adder',
+ * and two libraries: 'add_one' (this crate) and 'add_two', which is built
+ * on top of 'add_one'. The top-level 'Cargo.toml' doesn't have a
+ * '[package]' section; it only lists the members.
+ *
+ * Because all the members share one 'target' directory, they don't
+ * rebuild each other's artifacts, and because they share one 'Cargo.lock',
+ * they always agree on the versions of their external dependencies. */
+pub fn add_one(x: i32) -> i32 {
+    x + 1
+}
+
+#[cfg(test)]
+mod tests;
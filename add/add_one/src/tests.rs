@@ -0,0 +1,6 @@
+use crate::add_one;
+
+#[test]
+fn it_works() {
+    assert_eq!(3, add_one(2));
+}
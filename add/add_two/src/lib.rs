@@ -0,0 +1,11 @@
+/* Depending on Another Workspace Member */
+/* Cargo doesn't assume that crates in a workspace will depend on each
+ * other, so we need to be explicit about the dependency relationships:
+ * 'add_two' lists 'add_one' under '[dependencies]' with a 'path' pointing
+ * at the sibling directory. */
+pub fn add_two(x: i32) -> i32 {
+    add_one::add_one(add_one::add_one(x))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,6 @@
+use crate::add_two;
+
+#[test]
+fn it_works() {
+    assert_eq!(4, add_two(2));
+}
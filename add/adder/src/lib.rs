@@ -0,0 +1,14 @@
+/* A Re-export Facade */
+/* Users of the workspace shouldn't need to know which member crate each
+ * function lives in. 'adder' re-exports both libraries' public items with
+ * 'pub use', so 'adder::add_one' and 'adder::add_two' work no matter how
+ * the code is split up behind the scenes - and the split can change later
+ * without breaking anyone. The binary in 'main.rs' and the integration
+ * tests in 'tests/' both go through this facade. */
+pub use add_one::add_one;
+pub use add_two::add_two;
+
+/* Something that only makes sense with both libraries in hand. */
+pub fn add_three(x: i32) -> i32 {
+    add_one(add_two(x))
+}
@@ -0,0 +1,10 @@
+/* With the workspace in place, 'cargo build' from the top-level 'add'
+ * directory builds every member, and 'cargo run -p adder' picks which
+ * package in the workspace to run. 'cargo test' runs the tests of every
+ * member; 'cargo test -p add_one' runs only one crate's tests. */
+fn main() {
+    let num = 10;
+    println!("Hello, world! {num} plus one is {}!", adder::add_one(num));
+    println!("{num} plus two is {}!", adder::add_two(num));
+    println!("{num} plus three is {}!", adder::add_three(num));
+}
@@ -0,0 +1,19 @@
+/* Integration Tests */
+/* Integration tests are entirely external to the library: they use it the
+ * same way any other code would, so they can only call functions that are
+ * part of the public API. Each file in the 'tests' directory is compiled
+ * as its own separate crate. These tests check that the facade in
+ * 'adder' and the member crates it re-exports agree with each other. */
+#[test]
+fn facade_matches_member_crates() {
+    for x in [-5, 0, 2, 100] {
+        assert_eq!(adder::add_one(x), add_one::add_one(x));
+        assert_eq!(adder::add_two(x), add_two::add_two(x));
+    }
+}
+
+#[test]
+fn adding_composes() {
+    assert_eq!(adder::add_two(2), adder::add_one(adder::add_one(2)));
+    assert_eq!(adder::add_three(2), 5);
+}
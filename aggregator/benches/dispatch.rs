@@ -0,0 +1,39 @@
+/* Timing Static Against Dynamic Dispatch */
+/* Runs the same batch of tweets through 'notify_all_static' (a
+ * 'Vec<Tweet>') and 'notify_all_dyn' (a 'Vec<Box<dyn Summary>>') and
+ * prints how they compare, using the timing helpers from the benchmarks
+ * crate. Run it with 'cargo bench'; the numbers are only meaningful in an
+ * optimized build, which 'cargo bench' uses. The benchmark has its own
+ * 'main' ('harness = false' in Cargo.toml), so it works on stable Rust
+ * without the unstable '#[bench]' attribute. */
+use aggregator::dispatch::{notify_all_dyn, notify_all_static};
+use aggregator::{Summary, Tweet};
+use benchmarks::{measure, Comparison, Report};
+
+const TWEETS: usize = 10_000;
+const RUNS: u32 = 50;
+
+fn main() {
+    let tweets: Vec<Tweet> = (0..TWEETS)
+        .map(|i| Tweet::new("ferris", &format!("tweet number {i}")).unwrap())
+        .collect();
+    let boxed: Vec<Box<dyn Summary>> = tweets
+        .iter()
+        .map(|tweet| Box::new(tweet.clone()) as Box<dyn Summary>)
+        .collect();
+
+    let comparison = Comparison {
+        title: String::from("Notifying tweets"),
+        size: TWEETS,
+        measurements: vec![
+            measure("static (Vec<Tweet>)", RUNS, || notify_all_static(&tweets)),
+            measure("dynamic (Vec<Box<dyn Summary>>)", RUNS, || {
+                notify_all_dyn(&boxed)
+            }),
+        ],
+    };
+    let report = Report {
+        comparisons: vec![comparison],
+    };
+    print!("{report}");
+}
@@ -0,0 +1,94 @@
+/* Statistics over a batch of summaries. The functions take trait objects
+ * ('&dyn Summary') so that a single batch can mix tweets and articles. */
+use crate::{BlogPost, NewsArticle, Podcast, Summary, Tweet, VideoClip};
+
+#[derive(Debug, Default, PartialEq)]
+pub struct SummaryStats {
+    pub count: usize,
+    pub total_chars: usize,
+    pub shortest: usize,
+    pub longest: usize,
+}
+
+impl SummaryStats {
+    pub fn average_chars(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.total_chars as f64 / self.count as f64
+        }
+    }
+}
+
+/* Lengths are counted in 'char's rather than bytes so that non-ASCII text
+ * isn't counted as longer than it reads. */
+pub fn summary_stats(items: &[&dyn Summary]) -> SummaryStats {
+    let lengths: Vec<usize> = items
+        .iter()
+        .map(|item| item.summarize().chars().count())
+        .collect();
+
+    SummaryStats {
+        count: lengths.len(),
+        total_chars: lengths.iter().sum(),
+        shortest: lengths.iter().copied().min().unwrap_or(0),
+        longest: lengths.iter().copied().max().unwrap_or(0),
+    }
+}
+
+/* Reading Time */
+/* 'ContentStats' describes a single item instead of a batch. Every method
+ * has a default built on 'text', and 'text' itself defaults to the
+ * summary, so an implementation can be empty. Types with a body worth
+ * reading override 'text' to hand back the body instead, and everything
+ * else follows from that. Reading time assumes an average adult pace of
+ * 'WORDS_PER_MINUTE' and rounds up, so anything with words in it takes at
+ * least a minute. */
+pub const WORDS_PER_MINUTE: usize = 200;
+
+pub trait ContentStats: Summary {
+    fn text(&self) -> String {
+        self.summarize()
+    }
+
+    fn word_count(&self) -> usize {
+        self.text().split_whitespace().count()
+    }
+
+    fn char_count(&self) -> usize {
+        self.text().chars().count()
+    }
+
+    fn reading_minutes(&self) -> usize {
+        self.word_count().div_ceil(WORDS_PER_MINUTE)
+    }
+
+    /* "3 min read", the way dashboards usually show it. */
+    fn reading_time(&self) -> String {
+        format!("{} min read", self.reading_minutes())
+    }
+}
+
+impl ContentStats for NewsArticle {
+    fn text(&self) -> String {
+        self.content.clone()
+    }
+}
+
+impl ContentStats for Tweet {
+    fn text(&self) -> String {
+        self.content().to_string()
+    }
+}
+
+impl ContentStats for BlogPost {
+    fn text(&self) -> String {
+        self.body.clone()
+    }
+}
+
+/* Podcasts and clips are listened to or watched, not read, so there's no
+ * body to count; the summary is all there is. */
+impl ContentStats for Podcast {}
+
+impl ContentStats for VideoClip {}
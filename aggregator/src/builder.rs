@@ -0,0 +1,172 @@
+/* Builders */
+/* A struct literal has to name every field, so each time 'NewsArticle'
+ * grows a field every literal in every crate that uses it stops compiling.
+ * A builder turns construction into a chain of method calls, one per field
+ * we care about, finished off with 'build'. New fields get a setter and a
+ * sensible default, and existing code keeps working.
+ *
+ * Setters take 'self' by value and hand it back, which is what lets the
+ * calls chain. 'build' is where the checking happens: a required field
+ * that was never set is reported as a 'BuildError' instead of quietly
+ * becoming an empty string, and a tweet still has to pass 'Tweet::new'. */
+use std::fmt;
+use std::time::SystemTime;
+
+use crate::{NewsArticle, Tweet, TweetError};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuildError {
+    MissingField(&'static str),
+    InvalidTweet(TweetError),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::MissingField(field) => write!(f, "'{field}' is required"),
+            BuildError::InvalidTweet(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+impl From<TweetError> for BuildError {
+    fn from(e: TweetError) -> BuildError {
+        BuildError::InvalidTweet(e)
+    }
+}
+
+fn required(field: &'static str, value: Option<String>) -> Result<String, BuildError> {
+    value.ok_or(BuildError::MissingField(field))
+}
+
+/* 'headline', 'author', and 'content' are required; an article that
+ * wasn't filed from anywhere in particular can leave out 'location', and
+ * one with no known date can leave out 'published'. */
+#[derive(Debug, Default, Clone)]
+pub struct NewsArticleBuilder {
+    headline: Option<String>,
+    location: Option<String>,
+    author: Option<String>,
+    content: Option<String>,
+    published: Option<SystemTime>,
+}
+
+impl NewsArticleBuilder {
+    pub fn new() -> NewsArticleBuilder {
+        NewsArticleBuilder::default()
+    }
+
+    pub fn headline(mut self, headline: &str) -> NewsArticleBuilder {
+        self.headline = Some(headline.to_string());
+        self
+    }
+
+    pub fn location(mut self, location: &str) -> NewsArticleBuilder {
+        self.location = Some(location.to_string());
+        self
+    }
+
+    pub fn author(mut self, author: &str) -> NewsArticleBuilder {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    pub fn content(mut self, content: &str) -> NewsArticleBuilder {
+        self.content = Some(content.to_string());
+        self
+    }
+
+    pub fn published(mut self, published: SystemTime) -> NewsArticleBuilder {
+        self.published = Some(published);
+        self
+    }
+
+    pub fn build(self) -> Result<NewsArticle, BuildError> {
+        Ok(NewsArticle {
+            headline: required("headline", self.headline)?,
+            location: self.location.unwrap_or_default(),
+            author: required("author", self.author)?,
+            content: required("content", self.content)?,
+            published: self.published,
+        })
+    }
+}
+
+/* 'username' and 'content' are required. A tweet is an original tweet
+ * unless 'reply_to' or 'retweet_of' says otherwise; a tweet can only be
+ * one kind, so whichever of the two is called last wins. */
+#[derive(Debug, Default, Clone)]
+pub struct TweetBuilder {
+    username: Option<String>,
+    content: Option<String>,
+    reply_to: Option<String>,
+    retweet_of: Option<Tweet>,
+    published: Option<SystemTime>,
+}
+
+impl TweetBuilder {
+    pub fn new() -> TweetBuilder {
+        TweetBuilder::default()
+    }
+
+    pub fn username(mut self, username: &str) -> TweetBuilder {
+        self.username = Some(username.to_string());
+        self
+    }
+
+    pub fn content(mut self, content: &str) -> TweetBuilder {
+        self.content = Some(content.to_string());
+        self
+    }
+
+    pub fn reply_to(mut self, to: &str) -> TweetBuilder {
+        self.reply_to = Some(to.to_string());
+        self.retweet_of = None;
+        self
+    }
+
+    pub fn retweet_of(mut self, of: Tweet) -> TweetBuilder {
+        self.retweet_of = Some(of);
+        self.reply_to = None;
+        self
+    }
+
+    /* Left unset, the tweet is stamped with the time it's built. */
+    pub fn published(mut self, published: SystemTime) -> TweetBuilder {
+        self.published = Some(published);
+        self
+    }
+
+    pub fn build(self) -> Result<Tweet, BuildError> {
+        let username = required("username", self.username)?;
+        let content = required("content", self.content)?;
+        let mut tweet = Tweet::new(&username, &content)?;
+        if let Some(to) = self.reply_to {
+            tweet = tweet.as_reply_to(&to)?;
+        }
+        if let Some(of) = self.retweet_of {
+            tweet = tweet.as_retweet_of(of);
+        }
+        if let Some(published) = self.published {
+            tweet = tweet.published_at(published);
+        }
+        Ok(tweet)
+    }
+}
+
+/* 'NewsArticle::builder()' reads better at the call site than naming the
+ * builder type, and an 'impl' block doesn't have to live in the same
+ * module as the type it's for. */
+impl NewsArticle {
+    pub fn builder() -> NewsArticleBuilder {
+        NewsArticleBuilder::new()
+    }
+}
+
+impl Tweet {
+    pub fn builder() -> TweetBuilder {
+        TweetBuilder::new()
+    }
+}
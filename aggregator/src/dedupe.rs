@@ -0,0 +1,93 @@
+/* Deduplication */
+/* The same story often shows up in more than one feed, with a different
+ * location (we use the feed's title for that) and different whitespace.
+ * To collapse the copies, two items need to count as equal when their
+ * substance is the same, which is a different question from "are all the
+ * fields identical" - so instead of deriving 'PartialEq' we implement it
+ * by hand on top of a 'fingerprint'.
+ *
+ * A fingerprint is the parts of an item that identify it, lowercased and
+ * with every run of whitespace squeezed to a single space: the headline
+ * and text for an article (not the author or location, which vary between
+ * sources), and the username and text for a tweet.
+ *
+ * 'Hash' has to agree with 'Eq': values that are equal must hash the same,
+ * or a 'HashSet' would keep both. Hashing the fingerprint, the same thing
+ * 'eq' compares, guarantees that. */
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use crate::{NewsArticle, Tweet};
+
+pub trait Fingerprint {
+    fn fingerprint(&self) -> String;
+}
+
+pub fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl Fingerprint for NewsArticle {
+    fn fingerprint(&self) -> String {
+        format!(
+            "{}\n{}",
+            normalize(&self.headline),
+            normalize(&self.content)
+        )
+    }
+}
+
+impl Fingerprint for Tweet {
+    fn fingerprint(&self) -> String {
+        format!(
+            "{}\n{}",
+            normalize(self.username()),
+            normalize(self.content())
+        )
+    }
+}
+
+impl PartialEq for NewsArticle {
+    fn eq(&self, other: &Self) -> bool {
+        self.fingerprint() == other.fingerprint()
+    }
+}
+
+impl Eq for NewsArticle {}
+
+impl Hash for NewsArticle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.fingerprint().hash(state);
+    }
+}
+
+impl PartialEq for Tweet {
+    fn eq(&self, other: &Self) -> bool {
+        self.fingerprint() == other.fingerprint()
+    }
+}
+
+impl Eq for Tweet {}
+
+impl Hash for Tweet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.fingerprint().hash(state);
+    }
+}
+
+/* Keeps the first of each group of equal items, in their original order.
+ * We can't put the items in a 'HashSet' while also moving them into the
+ * result, so the first pass only borrows them to decide which to keep,
+ * and the second pass moves the keepers out. */
+pub fn dedupe<T: Eq + Hash>(items: Vec<T>) -> Vec<T> {
+    let mut seen = HashSet::new();
+    let keep: Vec<bool> = items.iter().map(|item| seen.insert(item)).collect();
+    items
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(item, keep)| keep.then_some(item))
+        .collect()
+}
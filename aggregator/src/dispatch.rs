@@ -0,0 +1,38 @@
+/* Static and Dynamic Dispatch */
+/* 'notify2<T: Summary>' and a 'Box<dyn Summary>' both call 'summarize',
+ * but they find it differently. For a generic function the compiler
+ * writes a separate copy for each concrete 'T' ('monomorphization'), so
+ * the call inside is an ordinary direct call that can be inlined. For a
+ * trait object, the call goes through the vtable: load the method's
+ * address, then jump to it, which the optimizer can't see through.
+ *
+ * The trade-off: static dispatch is faster per call but makes a copy of
+ * the code for every type it's used with, and a collection of it can only
+ * hold one type. Dynamic dispatch has one copy of the code and can mix
+ * types, at the cost of the indirect call. These two functions do exactly
+ * the same work through each path, so benches/dispatch.rs can time them
+ * against each other ('cargo bench'). Instead of printing they return the
+ * notification, which keeps stdout out of the measurement. */
+use crate::Summary;
+
+pub fn notify_static<T: Summary>(item: &T) -> String {
+    format!("Breaking news! {}", item.summarize())
+}
+
+pub fn notify_dyn(item: &dyn Summary) -> String {
+    format!("Breaking news! {}", item.summarize())
+}
+
+/* The same pair over a whole batch: a slice of one concrete type, and a
+ * slice of boxed trait objects. Each returns the total length of the
+ * notifications so the work can't be optimized away. */
+pub fn notify_all_static<T: Summary>(items: &[T]) -> usize {
+    items.iter().map(|item| notify_static(item).len()).sum()
+}
+
+pub fn notify_all_dyn(items: &[Box<dyn Summary>]) -> usize {
+    items
+        .iter()
+        .map(|item| notify_dyn(item.as_ref()).len())
+        .sum()
+}
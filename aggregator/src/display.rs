@@ -0,0 +1,63 @@
+/* Printing Anything Summarizable */
+/* 'println!("{}", item)' needs 'item' to implement 'Display'. The obvious
+ * fix would be to make 'Display' a supertrait ('trait Summary: Display'),
+ * but then every implementor would have to implement 'Display' as well,
+ * and some can't: 'Display' and 'Vec<T>' both come from the standard
+ * library, so the orphan rule stops us from implementing one for the
+ * other, and our 'Summary for Vec<T>' would have to go.
+ *
+ * An adapter avoids the problem. 'SummaryDisplay' borrows an item and
+ * implements 'Display' by writing its summary, so any 'Summary' can be
+ * formatted without implementing anything new. 'DisplaySummary' adds a
+ * 'display' method that builds the adapter, and its blanket
+ * implementation covers every 'Summary', trait objects included - the
+ * same pattern 'std::path::Path::display' uses.
+ *
+ * Types of our own can still implement 'Display' directly. 'NewsArticle'
+ * and 'Tweet' do in preview.rs, and the media types do at the bottom of
+ * this file, each by handing off to the adapter. */
+use std::fmt;
+
+use crate::{BlogPost, Podcast, Summary, VideoClip};
+
+pub struct SummaryDisplay<'a, T: ?Sized>(&'a T);
+
+impl<'a, T: Summary + ?Sized> SummaryDisplay<'a, T> {
+    pub fn new(item: &'a T) -> SummaryDisplay<'a, T> {
+        SummaryDisplay(item)
+    }
+}
+
+/* Writing through 'f.pad' instead of 'write!' means width, fill, and
+ * alignment flags like '{:>40}' work the way they do for a '&str'. */
+impl<T: Summary + ?Sized> fmt::Display for SummaryDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&self.0.summarize())
+    }
+}
+
+pub trait DisplaySummary: Summary {
+    fn display(&self) -> SummaryDisplay<'_, Self> {
+        SummaryDisplay(self)
+    }
+}
+
+impl<T: Summary + ?Sized> DisplaySummary for T {}
+
+impl fmt::Display for Podcast {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.display().fmt(f)
+    }
+}
+
+impl fmt::Display for BlogPost {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.display().fmt(f)
+    }
+}
+
+impl fmt::Display for VideoClip {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.display().fmt(f)
+    }
+}
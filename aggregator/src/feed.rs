@@ -0,0 +1,414 @@
+/* Feed Ingestion */
+/* Hand-built structs only go so far; real news arrives as RSS or Atom
+ * feeds. 'Feed::from_reader' reads either format and turns each item into
+ * a 'NewsArticle'. Feeds don't carry a location for each item the way our
+ * 'NewsArticle' does, so we use the feed's own title instead: an article
+ * from the "Pittsburgh Gazette" feed gets "Pittsburgh Gazette" as its
+ * location.
+ *
+ * Both formats are XML. We only need a small part of XML to read them
+ * (elements, text, CDATA sections, and entities), so rather than pull in
+ * a full XML parser, the bottom of this file has a minimal one that builds
+ * a tree of 'Element's. It skips comments, processing instructions, and
+ * the DOCTYPE, and ignores attributes. */
+use std::fmt;
+use std::io::{self, BufRead};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::NewsArticle;
+
+#[derive(Debug)]
+pub enum FeedError {
+    Io(io::Error),
+    Malformed(String),
+    UnsupportedFormat(String),
+}
+
+impl fmt::Display for FeedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FeedError::Io(e) => write!(f, "couldn't read feed: {e}"),
+            FeedError::Malformed(reason) => write!(f, "malformed feed: {reason}"),
+            FeedError::UnsupportedFormat(root) => {
+                write!(f, "expected an RSS or Atom feed, found <{root}>")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FeedError {}
+
+impl From<io::Error> for FeedError {
+    fn from(e: io::Error) -> FeedError {
+        FeedError::Io(e)
+    }
+}
+
+#[derive(Debug)]
+pub struct Feed {
+    pub title: String,
+    pub articles: Vec<NewsArticle>,
+}
+
+impl Feed {
+    pub fn from_reader(mut reader: impl BufRead) -> Result<Feed, FeedError> {
+        let mut xml = String::new();
+        reader.read_to_string(&mut xml)?;
+        Feed::parse(&xml)
+    }
+
+    pub fn parse(xml: &str) -> Result<Feed, FeedError> {
+        let root = Parser::new(xml).document()?;
+        match root.name.as_str() {
+            "rss" => {
+                let channel = root
+                    .child("channel")
+                    .ok_or_else(|| FeedError::Malformed(String::from("<rss> has no <channel>")))?;
+                Ok(Feed::from_rss_channel(channel))
+            }
+            "feed" => Ok(Feed::from_atom_feed(&root)),
+            other => Err(FeedError::UnsupportedFormat(other.to_string())),
+        }
+    }
+
+    /* RSS 2.0: '<channel>' holds the feed's '<title>' and one '<item>' per
+     * article. The author is in '<author>', or in '<dc:creator>' for feeds
+     * using the Dublin Core extension, the text is in '<description>', and
+     * the date is in '<pubDate>'. */
+    fn from_rss_channel(channel: &Element) -> Feed {
+        let title = channel.child_text("title");
+        let articles = channel
+            .children_named("item")
+            .map(|item| NewsArticle {
+                headline: item.child_text("title"),
+                location: title.clone(),
+                author: item
+                    .child("author")
+                    .or_else(|| item.child("dc:creator"))
+                    .map(|e| e.text.trim().to_string())
+                    .unwrap_or_default(),
+                content: item.child_text("description"),
+                published: item
+                    .child("pubDate")
+                    .and_then(|e| parse_rfc2822(e.text.trim())),
+            })
+            .collect();
+        Feed { title, articles }
+    }
+
+    /* Atom: '<feed>' holds the '<title>' and one '<entry>' per article.
+     * Authors are nested as '<author><name>...</name></author>', and an
+     * entry can have its text in '<content>', '<summary>', or both. An
+     * entry without an author inherits the feed's. The date is in
+     * '<published>', or failing that '<updated>'. */
+    fn from_atom_feed(feed: &Element) -> Feed {
+        let title = feed.child_text("title");
+        let author_of = |e: &Element| {
+            e.child("author")
+                .map(|author| author.child_text("name"))
+                .filter(|name| !name.is_empty())
+        };
+        let feed_author = author_of(feed).unwrap_or_default();
+
+        let articles = feed
+            .children_named("entry")
+            .map(|entry| NewsArticle {
+                headline: entry.child_text("title"),
+                location: title.clone(),
+                author: author_of(entry).unwrap_or_else(|| feed_author.clone()),
+                content: entry
+                    .child("content")
+                    .or_else(|| entry.child("summary"))
+                    .map(|e| e.text.trim().to_string())
+                    .unwrap_or_default(),
+                published: entry
+                    .child("published")
+                    .or_else(|| entry.child("updated"))
+                    .and_then(|e| parse_rfc3339(e.text.trim())),
+            })
+            .collect();
+        Feed { title, articles }
+    }
+}
+
+/* Feed Dates */
+/* RSS dates look like "Tue, 10 Jun 2003 04:00:00 GMT" (RFC 2822) and Atom
+ * dates like "2003-12-13T18:30:02Z" (RFC 3339). Both come down to a
+ * calendar date, a time of day, and an offset from UTC, which we turn
+ * into seconds since the Unix epoch. A date we can't read leaves the
+ * article undated rather than failing the whole feed. */
+fn parse_rfc2822(text: &str) -> Option<SystemTime> {
+    let mut parts: Vec<&str> = text.split_whitespace().collect();
+    if parts.first()?.ends_with(',') {
+        parts.remove(0);
+    }
+    let [day, month, year, time, zone] = parts[..] else {
+        return None;
+    };
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let month = MONTHS.iter().position(|m| *m == month)? as u32 + 1;
+    let offset = match zone {
+        "GMT" | "UT" | "UTC" | "Z" => 0,
+        _ => parse_offset(zone)?,
+    };
+    timestamp(year.parse().ok()?, month, day.parse().ok()?, time, offset)
+}
+
+fn parse_rfc3339(text: &str) -> Option<SystemTime> {
+    let (date, rest) = text.split_at_checked(10)?;
+    let rest = rest.strip_prefix(['T', 't', ' '])?;
+    let mut date = date.split('-');
+    let year = date.next()?.parse().ok()?;
+    let month = date.next()?.parse().ok()?;
+    let day = date.next()?.parse().ok()?;
+
+    let zone_at = rest.find(['Z', 'z', '+', '-'])?;
+    let (time, zone) = rest.split_at(zone_at);
+    /* Fractions of a second are dropped. */
+    let time = time.split('.').next()?;
+    let offset = match zone {
+        "Z" | "z" => 0,
+        _ => parse_offset(&zone.replace(':', ""))?,
+    };
+    timestamp(year, month, day, time, offset)
+}
+
+/* "+0100" or "-0500", as seconds east of UTC. */
+fn parse_offset(zone: &str) -> Option<i64> {
+    let (sign, digits) = match zone.split_at_checked(1)? {
+        ("+", digits) => (1, digits),
+        ("-", digits) => (-1, digits),
+        _ => return None,
+    };
+    if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i64 = digits[..2].parse().ok()?;
+    let minutes: i64 = digits[2..].parse().ok()?;
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+fn timestamp(year: i64, month: u32, day: u32, time: &str, offset: i64) -> Option<SystemTime> {
+    /* Feed dates have four-digit years. Turning down anything else also
+     * keeps 'days_from_civil' well clear of overflowing an 'i64'. */
+    if !(1..=9999).contains(&year) || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let mut time = time.split(':');
+    let hours: i64 = time.next()?.parse().ok()?;
+    let minutes: i64 = time.next()?.parse().ok()?;
+    let seconds: i64 = time.next().unwrap_or("0").parse().ok()?;
+    if hours > 23 || minutes > 59 || seconds > 60 {
+        return None;
+    }
+
+    let secs = days_from_civil(year, month, day)
+        .checked_mul(86_400)?
+        .checked_add(hours * 3600 + minutes * 60 + seconds)?
+        .checked_sub(offset)?;
+    UNIX_EPOCH.checked_add(Duration::from_secs(u64::try_from(secs).ok()?))
+}
+
+/* Days between 1970-01-01 and the given date in the proleptic Gregorian
+ * calendar, using Howard Hinnant's 'days_from_civil' algorithm: shifting
+ * the year to start in March puts the leap day at the end, where it
+ * doesn't disturb the day counts of the other months. */
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/* A Minimal XML Reader */
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    text: String,
+    children: Vec<Element>,
+}
+
+impl Element {
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+
+    fn child_text(&self, name: &str) -> String {
+        self.child(name)
+            .map(|e| e.text.trim().to_string())
+            .unwrap_or_default()
+    }
+}
+
+struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn new(xml: &'a str) -> Parser<'a> {
+        Parser { rest: xml }
+    }
+
+    fn error(&self, reason: &str) -> FeedError {
+        let near: String = self.rest.chars().take(20).collect();
+        FeedError::Malformed(format!("{reason} near '{near}'"))
+    }
+
+    /* Moves past everything up to and including 'end'. */
+    fn skip_past(&mut self, end: &str) -> Result<&'a str, FeedError> {
+        let i = self
+            .rest
+            .find(end)
+            .ok_or_else(|| self.error(&format!("missing '{end}'")))?;
+        let skipped = &self.rest[..i];
+        self.rest = &self.rest[i + end.len()..];
+        Ok(skipped)
+    }
+
+    /* Skips whitespace, comments, processing instructions ('<?xml ...?>'),
+     * and the DOCTYPE, which can all appear outside the root element. */
+    fn skip_misc(&mut self) -> Result<(), FeedError> {
+        loop {
+            self.rest = self.rest.trim_start();
+            if self.rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if self.rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest.starts_with("<!DOCTYPE") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn document(&mut self) -> Result<Element, FeedError> {
+        self.skip_misc()?;
+        if !self.rest.starts_with('<') {
+            return Err(self.error("expected a root element"));
+        }
+        let root = self.element()?;
+        self.skip_misc()?;
+        if !self.rest.is_empty() {
+            return Err(self.error("unexpected content after the root element"));
+        }
+        Ok(root)
+    }
+
+    /* Reads one element, starting at its '<'. Attribute values are quoted
+     * and may contain '>', so we step through the start tag quote by quote
+     * rather than searching for the first '>'. */
+    fn element(&mut self) -> Result<Element, FeedError> {
+        self.rest = &self.rest[1..];
+        let name_end = self
+            .rest
+            .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+            .ok_or_else(|| self.error("unterminated tag"))?;
+        let name = self.rest[..name_end].to_string();
+        if name.is_empty() {
+            return Err(self.error("empty tag name"));
+        }
+        self.rest = &self.rest[name_end..];
+
+        let mut quote = None;
+        let tag_end = self
+            .rest
+            .char_indices()
+            .find(|&(_, c)| match quote {
+                Some(q) if c == q => {
+                    quote = None;
+                    false
+                }
+                Some(_) => false,
+                None if c == '"' || c == '\'' => {
+                    quote = Some(c);
+                    false
+                }
+                None => c == '>',
+            })
+            .map(|(i, _)| i)
+            .ok_or_else(|| self.error(&format!("unterminated <{name}> tag")))?;
+        let self_closing = self.rest[..tag_end].ends_with('/');
+        self.rest = &self.rest[tag_end + 1..];
+
+        let mut element = Element {
+            name,
+            ..Element::default()
+        };
+        if self_closing {
+            return Ok(element);
+        }
+
+        loop {
+            if self.rest.starts_with("</") {
+                let closing = self.skip_past(">")?[2..].trim();
+                if closing != element.name {
+                    return Err(FeedError::Malformed(format!(
+                        "<{}> closed by </{closing}>",
+                        element.name
+                    )));
+                }
+                return Ok(element);
+            } else if self.rest.starts_with("<![CDATA[") {
+                self.rest = &self.rest["<![CDATA[".len()..];
+                element.text.push_str(self.skip_past("]]>")?);
+            } else if self.rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest.starts_with('<') {
+                element.children.push(self.element()?);
+            } else if self.rest.is_empty() {
+                return Err(FeedError::Malformed(format!(
+                    "<{}> is never closed",
+                    element.name
+                )));
+            } else {
+                let end = self.rest.find('<').unwrap_or(self.rest.len());
+                element.text.push_str(&decode_entities(&self.rest[..end])?);
+                self.rest = &self.rest[end..];
+            }
+        }
+    }
+}
+
+/* Replaces '&amp;', '&lt;', and friends, plus numeric references like
+ * '&#8217;' and '&#x2019;', with the characters they stand for. */
+fn decode_entities(text: &str) -> Result<String, FeedError> {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest
+            .find(';')
+            .ok_or_else(|| FeedError::Malformed(format!("unterminated entity in '{text}'")))?;
+        let entity = &rest[1..end];
+        let c = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(|dec| dec.parse()))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        let c = c.ok_or_else(|| FeedError::Malformed(format!("unknown entity '&{entity};'")))?;
+        decoded.push(c);
+        rest = &rest[end + 1..];
+    }
+    decoded.push_str(rest);
+    Ok(decoded)
+}
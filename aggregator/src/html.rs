@@ -0,0 +1,31 @@
+/* HTML output for summaries. Anything that came from a feed or a user is
+ * untrusted, so every piece of text is escaped before it's placed inside
+ * markup - otherwise a headline containing '<script>' would end up running
+ * in the reader's browser. */
+use crate::Summary;
+
+/* Replaces the five characters that have special meaning in HTML text and
+ * attribute values with their entity references. We walk the text one char
+ * at a time instead of chaining 'replace' calls, so the '&' we insert for an
+ * entity is never itself escaped again. */
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub fn summary_to_html(item: &(impl Summary + ?Sized)) -> String {
+    format!(
+        "<article class=\"summary\"><p>{}</p></article>",
+        escape(&item.summarize())
+    )
+}
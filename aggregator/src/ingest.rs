@@ -0,0 +1,62 @@
+/* Streaming Ingestion */
+/* 'Timeline::load' and 'Feed::from_reader' read their whole input before
+ * doing anything with it, which is fine for a feed but not for a dump of
+ * millions of tweets. 'lines_to_tweets' reads one line at a time and
+ * returns an iterator, so nothing happens until the caller asks for the
+ * next tweet and only one line is in memory at once. Each item is a
+ * 'Result': a bad line is reported where it is, with its line number, and
+ * the caller decides whether to skip it or stop.
+ *
+ * The format is one tweet per line, 'username: content'. The content is
+ * everything after the first colon, so it can contain colons of its own.
+ * Blank lines are skipped. */
+use std::fmt;
+use std::io::{self, BufRead};
+
+use crate::{Tweet, TweetError};
+
+#[derive(Debug)]
+pub enum ParseError {
+    Io(io::Error),
+    MissingSeparator { line: usize },
+    InvalidTweet { line: usize, error: TweetError },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Io(e) => write!(f, "couldn't read tweets: {e}"),
+            ParseError::MissingSeparator { line } => {
+                write!(f, "line {line}: expected 'username: content'")
+            }
+            ParseError::InvalidTweet { line, error } => write!(f, "line {line}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<io::Error> for ParseError {
+    fn from(e: io::Error) -> ParseError {
+        ParseError::Io(e)
+    }
+}
+
+pub fn lines_to_tweets(reader: impl BufRead) -> impl Iterator<Item = Result<Tweet, ParseError>> {
+    reader
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| match line {
+            Ok(line) if line.trim().is_empty() => None,
+            Ok(line) => Some(parse_line(i + 1, &line)),
+            Err(e) => Some(Err(ParseError::Io(e))),
+        })
+}
+
+fn parse_line(line: usize, text: &str) -> Result<Tweet, ParseError> {
+    let (username, content) = text
+        .split_once(':')
+        .ok_or(ParseError::MissingSeparator { line })?;
+    Tweet::new(username.trim(), content.trim())
+        .map_err(|error| ParseError::InvalidTweet { line, error })
+}
@@ -26,6 +26,39 @@
  */
 pub trait Summary {
     fn summarize(&self) -> String;
+
+    /* When the item was published, for putting a mix of items in order.
+     * Not everything knows, so the default is 'None'. */
+    fn published(&self) -> Option<std::time::SystemTime> {
+        None
+    }
+
+    /* The summary minus whoever wrote the item, for 'summarize_with'.
+     * Types whose summary doesn't name an author can keep the default. */
+    fn summarize_without_author(&self) -> String {
+        self.summarize()
+    }
+
+    /* The summary shaped by a 'SummaryConfig' (see preview.rs). Built only
+     * from the methods above, so no implementor has to write it. */
+    fn summarize_with(&self, cfg: &preview::SummaryConfig) -> String {
+        let summary = if cfg.show_author {
+            self.summarize()
+        } else {
+            self.summarize_without_author()
+        };
+        match cfg.max_len {
+            Some(max_len) => preview::truncate_with(&summary, max_len, &cfg.ellipsis),
+            None => summary,
+        }
+    }
+
+    /* The item as a line for 'Timeline::save', if its type knows how to
+     * write one (see persistence.rs). */
+    #[cfg(feature = "persistence")]
+    fn as_record(&self) -> Option<String> {
+        None
+    }
 }
 /* Here, we declare a trait using the 'trait' keyword and then the
  * trait's name, which is 'Summary' in this case. We also delcare the
@@ -54,29 +87,69 @@ pub trait Summary {
  * 'summarize'. For the 'Tweet' struct, we define 'summarize' as the
  * username followed by the entire text of the tweet, assuming that the
  * tweet content is already limited to 280 characters. */
+#[derive(Debug, Clone)]
 pub struct NewsArticle {
     pub headline: String,
     pub location: String,
     pub author: String,
     pub content: String,
+    pub published: Option<std::time::SystemTime>,
 }
 
 impl Summary for NewsArticle {
     fn summarize(&self) -> String {
         format!("{}, by {} ({})", self.headline, self.author, self.location)
     }
+
+    fn summarize_without_author(&self) -> String {
+        format!("{} ({})", self.headline, self.location)
+    }
+
+    fn published(&self) -> Option<std::time::SystemTime> {
+        self.published
+    }
+
+    #[cfg(feature = "persistence")]
+    fn as_record(&self) -> Option<String> {
+        Some(persistence::Persist::to_record(self))
+    }
 }
 
+#[derive(Debug, Clone)]
 pub struct Tweet {
-    pub username: String,
-    pub content: String,
-    pub reply: bool,
-    pub retweet: bool,
+    username: String,
+    content: String,
+    kind: TweetKind,
+    published: std::time::SystemTime,
 }
 
 impl Summary for Tweet {
     fn summarize(&self) -> String {
-        format!("{}: {}", self.username, self.content)
+        match &self.kind {
+            TweetKind::Original => format!("{}: {}", self.username, self.content),
+            TweetKind::Reply { to } => {
+                format!("{} (replying to @{to}): {}", self.username, self.content)
+            }
+            TweetKind::Retweet { of } => {
+                format!("{}: {} [RT {}]", self.username, self.content, of.summarize())
+            }
+        }
+    }
+
+    fn summarize_without_author(&self) -> String {
+        match &self.kind {
+            TweetKind::Retweet { of } => format!("{} [RT {}]", self.content, of.summarize()),
+            _ => self.content.clone(),
+        }
+    }
+
+    fn published(&self) -> Option<std::time::SystemTime> {
+        Some(self.published)
+    }
+
+    #[cfg(feature = "persistence")]
+    fn as_record(&self) -> Option<String> {
+        Some(persistence::Persist::to_record(self))
     }
 }
 /* Implementing a trait on a type is similar to implementing regular
@@ -118,6 +191,158 @@ impl Summary for Tweet {
  * same trait for the same type, and Rust wouldn't know which
  * implementation to use. */
 
+/* Here's that 'Vec<T>' implementation. It's a 'blanket implementation':
+ * rather than one type, it covers every vector whose elements implement
+ * 'Summary', so a whole collection can go anywhere a single item is
+ * expected (a 'Dispatcher', a 'Timeline', 'notify'). The summary is a
+ * count followed by each element's summary on its own line. We implement
+ * it for slices too, and the 'Vec<T>' version borrows the vector as a
+ * slice and reuses that. */
+impl<T: Summary> Summary for [T] {
+    fn summarize(&self) -> String {
+        let noun = if self.len() == 1 { "item" } else { "items" };
+        let mut summary = format!("{} {noun}", self.len());
+        for item in self {
+            summary.push_str("\n- ");
+            summary.push_str(&item.summarize());
+        }
+        summary
+    }
+}
+
+impl<T: Summary> Summary for Vec<T> {
+    fn summarize(&self) -> String {
+        self.as_slice().summarize()
+    }
+}
+
+/* Enforcing the 280-Character Limit */
+/* The 'Summary' implementation for 'Tweet' above assumes the content is
+ * already within 280 characters, but with public fields nothing stops
+ * anyone from building a 'Tweet' that isn't. So, like 'Guess' in the
+ * error handling chapter, the fields are private and 'Tweet::new' is the
+ * only way to make one from outside this crate. It checks the content and
+ * the username and reports what's wrong with a 'TweetError'. Getters give
+ * read access to the fields, and a new tweet is an original tweet until
+ * 'as_reply_to' or 'as_retweet_of' says otherwise. A tweet is stamped
+ * with the time it was created; 'published_at' sets a different time,
+ * for tweets that were posted earlier and are only now being loaded.
+ *
+ * The limit counts 'char's, not bytes, so an emoji counts as one
+ * character the way a reader would count it. Usernames follow Twitter's
+ * rules: 1 to 15 ASCII letters, digits, or underscores. */
+pub const MAX_TWEET_CHARS: usize = 280;
+pub const MAX_USERNAME_CHARS: usize = 15;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TweetError {
+    TooLong { length: usize },
+    EmptyContent,
+    InvalidUsername(String),
+}
+
+impl std::fmt::Display for TweetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TweetError::TooLong { length } => write!(
+                f,
+                "tweet is {length} characters long, the limit is {MAX_TWEET_CHARS}"
+            ),
+            TweetError::EmptyContent => write!(f, "tweet has no content"),
+            TweetError::InvalidUsername(name) => write!(
+                f,
+                "'{name}' isn't a valid username: use 1 to {MAX_USERNAME_CHARS} letters, digits, or underscores"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TweetError {}
+
+/* Replies and Retweets */
+/* A tweet used to carry two flags, 'reply' and 'retweet', which allowed a
+ * tweet that was somehow both, and neither flag said who was being
+ * replied to or what was being retweeted. An enum fixes both problems:
+ * a tweet is exactly one of these variants, and each variant carries the
+ * data that only makes sense for it. A retweet holds the tweet it shares,
+ * and because a 'Tweet' can contain a 'TweetKind' that contains a 'Tweet',
+ * the inner one has to be boxed so the type has a known size. */
+#[derive(Debug, Clone, PartialEq)]
+pub enum TweetKind {
+    Original,
+    Reply { to: String },
+    Retweet { of: Box<Tweet> },
+}
+
+fn check_username(username: &str) -> Result<(), TweetError> {
+    let valid = (1..=MAX_USERNAME_CHARS).contains(&username.len())
+        && username
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(TweetError::InvalidUsername(username.to_string()))
+    }
+}
+
+impl Tweet {
+    pub fn new(username: &str, content: &str) -> Result<Tweet, TweetError> {
+        check_username(username)?;
+        if content.trim().is_empty() {
+            return Err(TweetError::EmptyContent);
+        }
+        let length = content.chars().count();
+        if length > MAX_TWEET_CHARS {
+            return Err(TweetError::TooLong { length });
+        }
+
+        Ok(Tweet {
+            username: username.to_string(),
+            content: content.to_string(),
+            kind: TweetKind::Original,
+            published: std::time::SystemTime::now(),
+        })
+    }
+
+    pub fn published_at(mut self, published: std::time::SystemTime) -> Tweet {
+        self.published = published;
+        self
+    }
+
+    /* The name being replied to follows the same rules as our own. */
+    pub fn as_reply_to(mut self, to: &str) -> Result<Tweet, TweetError> {
+        check_username(to)?;
+        self.kind = TweetKind::Reply { to: to.to_string() };
+        Ok(self)
+    }
+
+    pub fn as_retweet_of(mut self, of: Tweet) -> Tweet {
+        self.kind = TweetKind::Retweet { of: Box::new(of) };
+        self
+    }
+
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn kind(&self) -> &TweetKind {
+        &self.kind
+    }
+
+    pub fn is_reply(&self) -> bool {
+        matches!(self.kind, TweetKind::Reply { .. })
+    }
+
+    pub fn is_retweet(&self) -> bool {
+        matches!(self.kind, TweetKind::Retweet { .. })
+    }
+}
+
 /* Default Implementations */
 /* Sometimes, it's useful to have default behavior for some or all of the
  * methods in a trait instead of requiring implementations for all methods
@@ -150,6 +375,7 @@ pub fn news_summary() {
             "The Pittsburgh Penguins once again are the best 
             hockey team in the NHL.",
         ),
+        published: None,
     };
 
     println!("New article available! {}", article.summarize2());
@@ -191,8 +417,8 @@ pub fn tweet_summary() {
     let tweet = Tweet {
         username: String::from("horse_ebooks"),
         content: String::from("of course, as you probably already know, people"),
-        reply: false,
-        retweet: false,
+        kind: TweetKind::Original,
+        published: std::time::SystemTime::now(),
     };
 
     println!("1 new tweet: {}", tweet.summarize3());
@@ -200,6 +426,144 @@ pub fn tweet_summary() {
 /* Note that it isn't possible to call the default implementation from an
  * overriding implementation of that same method. */
 
+/* One Function for Any Number of Items */
+/* Four 'notify' functions for one, two, or two-of-the-same items don't
+ * scale to three. 'notify_all' takes anything that can be iterated over -
+ * an array, a 'Vec', a slice's '.iter()', an iterator chain - as long as
+ * every item it yields implements 'Summary', and prints one "Breaking
+ * news!" line per item. The 'notify' functions in the next two sections
+ * are thin wrappers around these, kept so that existing callers still
+ * compile and print what they always have. 'notify3' and 'notify4' print
+ * their two items on one line, joined by " - ", with nothing in front;
+ * 'notify_joined_to' does that for any number of items.
+ *
+ * An iterator yields one type, so to mix types we pass trait objects
+ * instead: 'notify_each_dyn' takes a slice of '&dyn Summary'. (It isn't
+ * 'dispatch::notify_all_dyn', which takes boxes and counts what it
+ * printed.) 'notify_all_to' does the work for both, writing to any 'Write'
+ * so the output can go somewhere other than stdout (a file, a test's
+ * buffer). */
+pub fn notify_all<I, T>(items: I)
+where
+    I: IntoIterator<Item = T>,
+    T: Summary,
+{
+    notify_all_to(&mut std::io::stdout().lock(), items).expect("failed to write to stdout");
+}
+
+pub fn notify_each_dyn(items: &[&dyn Summary]) {
+    notify_all(items.iter().copied());
+}
+
+pub fn notify_all_to<W, I, T>(out: &mut W, items: I) -> std::io::Result<()>
+where
+    W: std::io::Write,
+    I: IntoIterator<Item = T>,
+    T: Summary,
+{
+    for item in items {
+        writeln!(out, "Breaking news! {}", item.summarize())?;
+    }
+    Ok(())
+}
+
+pub fn notify_joined_to<W: std::io::Write>(
+    out: &mut W,
+    items: &[&dyn Summary],
+) -> std::io::Result<()> {
+    let summaries: Vec<String> = items.iter().map(|item| item.summarize()).collect();
+    writeln!(out, "{}", summaries.join(" - "))
+}
+
+/* Iterating over a collection usually hands out references, so for
+ * 'notify_all(&tweets)' to work a '&Tweet' has to be a 'Summary' too.
+ * This blanket implementation makes a reference to anything summarizable
+ * summarizable itself, the same way 'Box<T>' is in timeline.rs. */
+impl<T: Summary + ?Sized> Summary for &T {
+    fn summarize(&self) -> String {
+        (**self).summarize()
+    }
+
+    fn published(&self) -> Option<std::time::SystemTime> {
+        (**self).published()
+    }
+
+    fn summarize_without_author(&self) -> String {
+        (**self).summarize_without_author()
+    }
+
+    #[cfg(feature = "persistence")]
+    fn as_record(&self) -> Option<String> {
+        (**self).as_record()
+    }
+}
+
+/* Beyond the Book */
+/* The book stops at a couple of hand-built structs. The modules below grow
+ * the aggregator into something that could be used for real: 'builder'
+ * constructs articles and tweets without struct literals, 'feed' reads
+ * articles out of RSS and Atom documents, 'display' lets any summary be
+ * used with '{}' in 'println!', 'dispatch' measures generic functions
+ * against trait objects, 'dedupe' collapses the copies of a story that
+ * arrive from several feeds, 'ingest' streams tweets out of a reader a
+ * line at a time, 'locale' frames summaries in English, Spanish, or
+ * German, 'media' adds podcasts, blog posts, and video clips alongside
+ * 'NewsArticle' and 'Tweet', 'timeline' keeps any mix of them together in
+ * one collection, 'pages' splits a collection into pages for display,
+ * 'preview' adds 'Display' and length-limited previews for articles and
+ * tweets, 'metadata' gives each type structured details to go with its
+ * summary, 'notify' turns the 'notify' functions above into a dispatcher
+ * with pluggable destinations (a module and a function can share a name;
+ * they live in different namespaces), 'render' formats summaries as
+ * Markdown or HTML, and 'search' finds items by their summaries. */
+pub mod builder;
+pub mod dedupe;
+pub mod display;
+pub mod dispatch;
+pub mod feed;
+pub mod ingest;
+pub mod locale;
+pub mod media;
+pub mod metadata;
+pub mod notify;
+pub mod pages;
+pub mod preview;
+pub mod render;
+pub mod search;
+pub mod timeline;
+
+pub use builder::{BuildError, NewsArticleBuilder, TweetBuilder};
+pub use media::{BlogPost, Podcast, VideoClip};
+
+/* Optional Subsystems */
+/* Everything above is the core of the crate. The modules below build on
+ * 'Summary' but not every consumer needs them, so each sits behind a
+ * cargo feature: 'html' for rendering summaries as escaped HTML,
+ * 'persistence' for writing items out as text records and reading them
+ * back, and 'analytics' for statistics over a batch of summaries, word
+ * counts and reading times for single items, and ranking feeds. All three
+ * are enabled by default; a consumer that only wants the trait and the
+ * content types can opt out with:
+ *
+ * aggregator = { path = "../aggregator", default-features = false }
+ *
+ * '#[cfg(feature = "...")]' removes the annotated item entirely when the
+ * feature is off, so the module's code isn't even compiled. */
+#[cfg(feature = "html")]
+pub mod html;
+
+#[cfg(feature = "persistence")]
+pub mod persistence;
+
+#[cfg(feature = "analytics")]
+pub mod analytics;
+
+#[cfg(feature = "analytics")]
+pub mod ranking;
+
+#[cfg(test)]
+mod tests;
+
 /* Traits as Parameters */
 /* Now that you know how to define and implement traits, we can explore how
  * to use traits to define functions that accept many different types.
@@ -209,7 +573,7 @@ pub fn tweet_summary() {
  * implements the 'Summary' trait. To do this, we use the 'impl Trait'
  * syntax, like this: */
 pub fn notify(item: &impl Summary) {
-    println!("Breaking news! {}", item.summarize());
+    notify_all([item]);
 }
 /* Instead of a concrete type for the 'item' parameter, we specify the
  * 'impl' keyword and the trait name. This parameter accepts any type that
@@ -225,7 +589,7 @@ pub fn notify(item: &impl Summary) {
  * syntax sugar for a longer formm known as a 'trait bound'; it looks like
  * this: */
 pub fn notify2<T: Summary>(item: &T) {
-    println!("Breaking news! {}", item.summarize());
+    notify_all([item]);
 }
 /* This longer form is equivalent to the example in the previous section
  * but is more verbose. We place trait bounds with the declaration of the
@@ -237,14 +601,16 @@ pub fn notify2<T: Summary>(item: &T) {
  * implement 'Summary'. Doing so with the 'impl Trait' syntax looks like
  * this: */
 pub fn notify3(item1: &impl Summary, item2: &impl Summary) {
-    println!("{} - {}", item1.summarize(), item2.summarize());
+    notify_joined_to(&mut std::io::stdout().lock(), &[item1, item2])
+        .expect("failed to write to stdout");
 }
 /* Using 'impl Trait' is appropriate if we want this function to allow
  * 'item1' and 'item2' to have different types (as long as both types
  * implements 'Summary'). If we want to force both parameters to have the
  * same type, however, we must use a trait bound, like this: */
 pub fn notify4<T: Summary>(item1: &T, item2: &T) {
-    println!("{} - {}", item1.summarize(), item2.summarize());
+    notify_joined_to(&mut std::io::stdout().lock(), &[item1, item2])
+        .expect("failed to write to stdout");
 }
 /* The generic type 'T' specified as the type of the 'item1' and 'item2'
  * parameters constrains the function such that the concrete type of the
@@ -0,0 +1,90 @@
+/* Localized Summaries */
+/* The 'notify' functions frame a summary with a bit of English: "Breaking
+ * news!" before an article, "1 new tweet" before a tweet. 'Localized'
+ * puts that framing in the reader's language. Each type says which
+ * 'Message' introduces it, and 'Locale::text' looks the message up in a
+ * table of translations; the summary itself is left as it is, since
+ * headlines and tweets are written in whatever language their author
+ * chose.
+ *
+ * Matching on the pair '(locale, message)' keeps the whole table in one
+ * place, and because the match has to be exhaustive, adding a 'Locale' or
+ * a 'Message' won't compile until every translation for it is written. */
+use crate::{BlogPost, NewsArticle, Podcast, Summary, Tweet, VideoClip};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    English,
+    Spanish,
+    German,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Message {
+    BreakingNews,
+    NewTweet,
+    NewEpisode,
+    NewPost,
+    NewVideo,
+}
+
+impl Locale {
+    pub fn text(self, message: Message) -> &'static str {
+        match (self, message) {
+            (Locale::English, Message::BreakingNews) => "Breaking news!",
+            (Locale::English, Message::NewTweet) => "1 new tweet:",
+            (Locale::English, Message::NewEpisode) => "New episode:",
+            (Locale::English, Message::NewPost) => "New post:",
+            (Locale::English, Message::NewVideo) => "New video:",
+            (Locale::Spanish, Message::BreakingNews) => "¡Última hora!",
+            (Locale::Spanish, Message::NewTweet) => "1 tuit nuevo:",
+            (Locale::Spanish, Message::NewEpisode) => "Nuevo episodio:",
+            (Locale::Spanish, Message::NewPost) => "Nueva entrada:",
+            (Locale::Spanish, Message::NewVideo) => "Nuevo vídeo:",
+            (Locale::German, Message::BreakingNews) => "Eilmeldung!",
+            (Locale::German, Message::NewTweet) => "1 neuer Tweet:",
+            (Locale::German, Message::NewEpisode) => "Neue Folge:",
+            (Locale::German, Message::NewPost) => "Neuer Beitrag:",
+            (Locale::German, Message::NewVideo) => "Neues Video:",
+        }
+    }
+}
+
+pub trait Localized: Summary {
+    fn message(&self) -> Message;
+
+    fn summarize_localized(&self, locale: Locale) -> String {
+        format!("{} {}", locale.text(self.message()), self.summarize())
+    }
+}
+
+impl Localized for NewsArticle {
+    fn message(&self) -> Message {
+        Message::BreakingNews
+    }
+}
+
+impl Localized for Tweet {
+    fn message(&self) -> Message {
+        Message::NewTweet
+    }
+}
+
+impl Localized for Podcast {
+    fn message(&self) -> Message {
+        Message::NewEpisode
+    }
+}
+
+impl Localized for BlogPost {
+    fn message(&self) -> Message {
+        Message::NewPost
+    }
+}
+
+impl Localized for VideoClip {
+    fn message(&self) -> Message {
+        Message::NewVideo
+    }
+}
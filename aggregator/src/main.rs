@@ -1,12 +1,14 @@
-use aggregator::{news_summary, tweet_summary, Summary, Tweet};
+use aggregator::notify::{ConsoleNotifier, Dispatcher};
+use aggregator::timeline::Timeline;
+use aggregator::{news_summary, tweet_summary, NewsArticle, Summary, Tweet};
 
 fn main() {
-    let tweet = Tweet {
-        username: String::from("horse_ebooks"),
-        content: String::from("of course, as you probably already know, people"),
-        reply: false,
-        retweet: false,
-    };
+    /* Outside the crate, 'Tweet::new' is the only way to build a tweet. */
+    let tweet = Tweet::new(
+        "horse_ebooks",
+        "of course, as you probably already know, people",
+    )
+    .expect("a valid tweet");
 
     println!("1 new tweet: {}", tweet.summarize());
     /* This code prints '1 new tweet: horse_ebooks: of course, as you probably
@@ -17,4 +19,26 @@ fn main() {
 
     tweet_summary();
     /* This code prints '1 new tweet: (Read more from @horse_ebooks...)'. */
+
+    /* A 'Timeline' holds tweets and articles side by side. */
+    let mut timeline = Timeline::new();
+    timeline.push(tweet);
+    let article = NewsArticle::builder()
+        .headline("Penguins win the Stanley Cup Championship!")
+        .location("Pittsburgh, PA, USA")
+        .author("Iceburgh")
+        .content("The Pittsburgh Penguins once again are the best hockey team in the NHL.")
+        .build()
+        .expect("every required field is set");
+    timeline.push(article);
+    println!("\n{}", timeline.render());
+
+    /* A 'Dispatcher' sends an item to every registered notifier. */
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.register(ConsoleNotifier::new("Breaking news!"));
+    for item in timeline.iter() {
+        if let Err(errors) = dispatcher.dispatch(item) {
+            eprintln!("{} notifier(s) failed", errors.len());
+        }
+    }
 }
@@ -0,0 +1,121 @@
+/* More Kinds of Content */
+/* Two implementors only hint at what a trait buys us. These three types
+ * have little in common - a podcast has an episode number, a blog post has
+ * tags, a video clip has a running time - but each one can say how it
+ * should be summarized, and everything written against 'Summary' (a
+ * 'Timeline', a 'Dispatcher', 'notify') works with them unchanged. */
+use std::time::{Duration, SystemTime};
+
+use crate::Summary;
+
+/* Running times read as 'm:ss', or 'h:mm:ss' once they pass an hour. */
+pub fn format_duration(duration: Duration) -> String {
+    let total = duration.as_secs();
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Podcast {
+    pub show: String,
+    pub title: String,
+    pub host: String,
+    pub episode: u32,
+    pub duration: Duration,
+    pub published: Option<SystemTime>,
+}
+
+impl Summary for Podcast {
+    fn summarize(&self) -> String {
+        format!(
+            "{} #{}: {}, hosted by {} ({})",
+            self.show,
+            self.episode,
+            self.title,
+            self.host,
+            format_duration(self.duration)
+        )
+    }
+
+    fn summarize_without_author(&self) -> String {
+        format!(
+            "{} #{}: {} ({})",
+            self.show,
+            self.episode,
+            self.title,
+            format_duration(self.duration)
+        )
+    }
+
+    fn published(&self) -> Option<SystemTime> {
+        self.published
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BlogPost {
+    pub title: String,
+    pub author: String,
+    pub tags: Vec<String>,
+    pub body: String,
+    pub published: Option<SystemTime>,
+}
+
+impl Summary for BlogPost {
+    fn summarize(&self) -> String {
+        if self.tags.is_empty() {
+            format!("{}, by {}", self.title, self.author)
+        } else {
+            format!(
+                "{}, by {} [{}]",
+                self.title,
+                self.author,
+                self.tags.join(", ")
+            )
+        }
+    }
+
+    fn summarize_without_author(&self) -> String {
+        if self.tags.is_empty() {
+            self.title.clone()
+        } else {
+            format!("{} [{}]", self.title, self.tags.join(", "))
+        }
+    }
+
+    fn published(&self) -> Option<SystemTime> {
+        self.published
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VideoClip {
+    pub title: String,
+    pub channel: String,
+    pub duration: Duration,
+    pub tags: Vec<String>,
+    pub published: Option<SystemTime>,
+}
+
+impl Summary for VideoClip {
+    fn summarize(&self) -> String {
+        format!(
+            "{} from {} ({})",
+            self.title,
+            self.channel,
+            format_duration(self.duration)
+        )
+    }
+
+    fn summarize_without_author(&self) -> String {
+        format!("{} ({})", self.title, format_duration(self.duration))
+    }
+
+    fn published(&self) -> Option<SystemTime> {
+        self.published
+    }
+}
@@ -0,0 +1,145 @@
+/* Structured Metadata with an Associated Type */
+/* 'summarize' hands back a finished 'String', which is fine for display
+ * but leaves generic code nothing to work with: to find a tweet's length
+ * or an article's author it would have to pick the string apart again.
+ * 'SummaryExt' adds a 'metadata' method that returns a struct instead.
+ *
+ * Each type has different things worth knowing, so the trait can't name
+ * one return type for all of them. An 'associated type' lets each
+ * implementation choose its own: 'type Meta' is a placeholder in the
+ * trait, and 'impl SummaryExt for Tweet' fills it in with 'TweetStats'.
+ * Unlike a generic parameter ('trait SummaryExt<M>'), there's exactly one
+ * 'Meta' per type, so callers never have to say which one they want.
+ *
+ * 'SummaryExt: Summary' makes 'Summary' a 'supertrait': only types that
+ * can already be summarized can implement 'SummaryExt', and code holding
+ * a 'T: SummaryExt' can call 'summarize' too. */
+use std::fmt::Debug;
+use std::time::Duration;
+
+use crate::{BlogPost, NewsArticle, Podcast, Summary, Tweet, VideoClip, MAX_TWEET_CHARS};
+
+pub trait SummaryExt: Summary {
+    type Meta;
+
+    fn metadata(&self) -> Self::Meta;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TweetStats {
+    pub chars: usize,
+    pub remaining: usize,
+    pub mentions: Vec<String>,
+    pub hashtags: Vec<String>,
+    pub reply: bool,
+    pub retweet: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Byline {
+    pub author: String,
+    pub location: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpisodeInfo {
+    pub show: String,
+    pub episode: u32,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostInfo {
+    pub author: String,
+    pub tags: Vec<String>,
+    pub word_count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClipInfo {
+    pub channel: String,
+    pub duration: Duration,
+}
+
+/* Words in 'text' that start with 'marker', without the marker and any
+ * trailing punctuation: "@ferris," becomes "ferris". */
+fn words_starting_with(text: &str, marker: char) -> Vec<String> {
+    text.split_whitespace()
+        .filter_map(|word| word.strip_prefix(marker))
+        .map(|word| word.trim_end_matches(|c: char| !c.is_alphanumeric() && c != '_'))
+        .filter(|word| !word.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+impl SummaryExt for Tweet {
+    type Meta = TweetStats;
+
+    fn metadata(&self) -> TweetStats {
+        let chars = self.content().chars().count();
+        TweetStats {
+            chars,
+            remaining: MAX_TWEET_CHARS.saturating_sub(chars),
+            mentions: words_starting_with(self.content(), '@'),
+            hashtags: words_starting_with(self.content(), '#'),
+            reply: self.is_reply(),
+            retweet: self.is_retweet(),
+        }
+    }
+}
+
+impl SummaryExt for NewsArticle {
+    type Meta = Byline;
+
+    fn metadata(&self) -> Byline {
+        Byline {
+            author: self.author.clone(),
+            location: self.location.clone(),
+        }
+    }
+}
+
+impl SummaryExt for Podcast {
+    type Meta = EpisodeInfo;
+
+    fn metadata(&self) -> EpisodeInfo {
+        EpisodeInfo {
+            show: self.show.clone(),
+            episode: self.episode,
+            duration: self.duration,
+        }
+    }
+}
+
+impl SummaryExt for BlogPost {
+    type Meta = PostInfo;
+
+    fn metadata(&self) -> PostInfo {
+        PostInfo {
+            author: self.author.clone(),
+            tags: self.tags.clone(),
+            word_count: self.body.split_whitespace().count(),
+        }
+    }
+}
+
+impl SummaryExt for VideoClip {
+    type Meta = ClipInfo;
+
+    fn metadata(&self) -> ClipInfo {
+        ClipInfo {
+            channel: self.channel.clone(),
+            duration: self.duration,
+        }
+    }
+}
+
+/* Generic code can put bounds on the associated type, too: this works for
+ * any 'T' whose metadata can be printed with '{:?}'. */
+pub fn describe<T>(item: &T) -> String
+where
+    T: SummaryExt,
+    T::Meta: Debug,
+{
+    format!("{} {:?}", item.summarize(), item.metadata())
+}
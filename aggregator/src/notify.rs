@@ -0,0 +1,127 @@
+/* Notification Dispatch */
+/* The 'notify' functions in lib.rs always print to stdout. Here that
+ * choice becomes pluggable: a 'Notifier' is anything that can deliver a
+ * summary somewhere, and a 'Dispatcher' holds any number of them as trait
+ * objects and sends each item to all of them. Adding a new destination
+ * means implementing one method, not touching the dispatcher.
+ *
+ * Delivery can fail (a file can't be opened, stdout is closed), so 'send'
+ * returns an 'io::Result'. One broken notifier shouldn't stop the others,
+ * so the dispatcher tries every notifier and then reports every error it
+ * collected along the way. */
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use crate::Summary;
+
+pub trait Notifier {
+    fn send(&self, summary: &str) -> io::Result<()>;
+}
+
+/* Prints each summary on its own line, after a prefix such as
+ * "Breaking news!". */
+pub struct ConsoleNotifier {
+    prefix: String,
+}
+
+impl ConsoleNotifier {
+    pub fn new(prefix: &str) -> ConsoleNotifier {
+        ConsoleNotifier {
+            prefix: prefix.to_string(),
+        }
+    }
+}
+
+impl Notifier for ConsoleNotifier {
+    fn send(&self, summary: &str) -> io::Result<()> {
+        writeln!(io::stdout(), "{} {summary}", self.prefix)
+    }
+}
+
+/* Appends each summary as a line at the end of a file, creating the file
+ * the first time. The file is opened again for every summary, so nothing
+ * is held open between notifications. */
+pub struct FileNotifier {
+    path: PathBuf,
+}
+
+impl FileNotifier {
+    pub fn new(path: impl Into<PathBuf>) -> FileNotifier {
+        FileNotifier { path: path.into() }
+    }
+}
+
+impl Notifier for FileNotifier {
+    fn send(&self, summary: &str) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{summary}")
+    }
+}
+
+/* Hands each summary to a closure, for destinations we haven't thought
+ * of. The closure is boxed, so each 'CallbackNotifier' can hold a
+ * different closure type and still be the same Rust type. */
+pub struct CallbackNotifier {
+    callback: Box<dyn Fn(&str)>,
+}
+
+impl CallbackNotifier {
+    pub fn new(callback: impl Fn(&str) + 'static) -> CallbackNotifier {
+        CallbackNotifier {
+            callback: Box::new(callback),
+        }
+    }
+}
+
+impl Notifier for CallbackNotifier {
+    fn send(&self, summary: &str) -> io::Result<()> {
+        (self.callback)(summary);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct Dispatcher {
+    notifiers: Vec<Box<dyn Notifier>>,
+}
+
+impl Dispatcher {
+    pub fn new() -> Dispatcher {
+        Dispatcher {
+            notifiers: Vec::new(),
+        }
+    }
+
+    pub fn register(&mut self, notifier: impl Notifier + 'static) {
+        self.notifiers.push(Box::new(notifier));
+    }
+
+    pub fn len(&self) -> usize {
+        self.notifiers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notifiers.is_empty()
+    }
+
+    /* Summarizes the item once and sends the result to every notifier, in
+     * the order they were registered. */
+    pub fn dispatch(&self, item: &(impl Summary + ?Sized)) -> Result<(), Vec<io::Error>> {
+        let summary = item.summarize();
+        let errors: Vec<io::Error> = self
+            .notifiers
+            .iter()
+            .filter_map(|notifier| notifier.send(&summary).err())
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
@@ -0,0 +1,80 @@
+/* Pagination */
+/* 'Pages' splits a slice of summarizable items into pages of a fixed size,
+ * like 'slice::chunks', but each 'Page' also knows its number and how many
+ * pages there are in total, which is what a UI needs to show "page 3 of
+ * 10". A page borrows its items from the slice rather than copying them,
+ * and summaries are only built for the page that's actually shown.
+ *
+ * Jumping straight to a page with 'nth' doesn't walk through the pages
+ * before it: we override the default 'nth' (which calls 'next' n times)
+ * with one that just moves the position. */
+use crate::Summary;
+
+#[derive(Debug)]
+pub struct Page<'a, T: Summary> {
+    pub number: usize,
+    pub page_count: usize,
+    pub items: &'a [T],
+}
+
+impl<T: Summary> Page<'_, T> {
+    pub fn summaries(&self) -> Vec<String> {
+        self.items.iter().map(|item| item.summarize()).collect()
+    }
+
+    pub fn is_last(&self) -> bool {
+        self.number == self.page_count
+    }
+}
+
+pub struct Pages<'a, T: Summary> {
+    items: &'a [T],
+    page_size: usize,
+    next_page: usize,
+}
+
+impl<'a, T: Summary> Pages<'a, T> {
+    /* Panics if 'page_size' is 0, like 'slice::chunks' does. */
+    pub fn new(items: &'a [T], page_size: usize) -> Pages<'a, T> {
+        assert!(page_size > 0, "page size must be at least 1");
+        Pages {
+            items,
+            page_size,
+            next_page: 0,
+        }
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.items.len().div_ceil(self.page_size)
+    }
+}
+
+impl<'a, T: Summary> Iterator for Pages<'a, T> {
+    type Item = Page<'a, T>;
+
+    fn next(&mut self) -> Option<Page<'a, T>> {
+        let start = self.next_page * self.page_size;
+        if start >= self.items.len() {
+            return None;
+        }
+        let end = (start + self.page_size).min(self.items.len());
+        self.next_page += 1;
+        Some(Page {
+            number: self.next_page,
+            page_count: self.page_count(),
+            items: &self.items[start..end],
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.page_count().saturating_sub(self.next_page);
+        (remaining, Some(remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Page<'a, T>> {
+        self.next_page = self.next_page.saturating_add(n).min(self.page_count());
+        self.next()
+    }
+}
+
+impl<T: Summary> ExactSizeIterator for Pages<'_, T> {}
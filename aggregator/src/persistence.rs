@@ -0,0 +1,269 @@
+/* Text records for the content types. Each item becomes one line: a kind
+ * tag followed by its fields, separated by tabs. Tabs, newlines, and
+ * backslashes inside a field are escaped so that a record always fits on
+ * exactly one line, which keeps files easy to read and to append to. */
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fmt, io};
+
+use crate::{NewsArticle, Summary, Tweet, TweetError, TweetKind};
+
+#[derive(Debug, PartialEq)]
+pub enum PersistError {
+    UnknownKind(String),
+    WrongFieldCount {
+        kind: String,
+        expected: usize,
+        found: usize,
+    },
+    InvalidField {
+        field: &'static str,
+        value: String,
+    },
+    BadEscape(String),
+}
+
+impl fmt::Display for PersistError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PersistError::UnknownKind(kind) => write!(f, "unknown record kind '{kind}'"),
+            PersistError::WrongFieldCount {
+                kind,
+                expected,
+                found,
+            } => write!(f, "'{kind}' record needs {expected} fields, found {found}"),
+            PersistError::InvalidField { field, value } => {
+                write!(f, "invalid value for '{field}': '{value}'")
+            }
+            PersistError::BadEscape(field) => write!(f, "bad escape sequence in '{field}'"),
+        }
+    }
+}
+
+impl std::error::Error for PersistError {}
+
+/* Saving or loading a whole timeline can also fail at the file level, and
+ * a bad record is much easier to fix when we know which line it was on.
+ * 'io::Error' doesn't implement 'PartialEq', so this gets its own type
+ * instead of another 'PersistError' variant. */
+#[derive(Debug)]
+pub enum TimelineError {
+    Io(io::Error),
+    Record { line: usize, error: PersistError },
+    NotPersistable { position: usize, summary: String },
+}
+
+impl fmt::Display for TimelineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimelineError::Io(e) => write!(f, "{e}"),
+            TimelineError::Record { line, error } => write!(f, "line {line}: {error}"),
+            TimelineError::NotPersistable { position, summary } => {
+                write!(f, "item {position} can't be saved: '{summary}'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TimelineError {}
+
+impl From<io::Error> for TimelineError {
+    fn from(e: io::Error) -> TimelineError {
+        TimelineError::Io(e)
+    }
+}
+
+/* A type that can be written to and read back from a record line. 'KIND'
+ * is the tag at the start of the line, which is how a reader knows which
+ * type to decode. */
+pub trait Persist: Sized {
+    const KIND: &'static str;
+
+    fn fields(&self) -> Vec<String>;
+    fn from_fields(fields: Vec<String>) -> Result<Self, PersistError>;
+
+    fn to_record(&self) -> String {
+        let mut parts = vec![Self::KIND.to_string()];
+        parts.extend(self.fields().iter().map(|f| escape_field(f)));
+        parts.join("\t")
+    }
+
+    fn from_record(line: &str) -> Result<Self, PersistError> {
+        let (kind, fields) = split_record(line)?;
+        if kind != Self::KIND {
+            return Err(PersistError::UnknownKind(kind));
+        }
+        Self::from_fields(fields)
+    }
+}
+
+/* Reads one line back into whichever type its kind tag names. The result
+ * is boxed because the caller can't know the type ahead of time. */
+pub fn load_record(line: &str) -> Result<Box<dyn Summary>, PersistError> {
+    let kind = line.split('\t').next().unwrap_or_default();
+    match kind {
+        NewsArticle::KIND => Ok(Box::new(NewsArticle::from_record(line)?)),
+        Tweet::KIND => Ok(Box::new(Tweet::from_record(line)?)),
+        _ => Err(PersistError::UnknownKind(kind.to_string())),
+    }
+}
+
+pub fn escape_field(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+    for c in field.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub fn unescape_field(field: &str) -> Result<String, PersistError> {
+    let mut unescaped = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => unescaped.push('\\'),
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            _ => return Err(PersistError::BadEscape(field.to_string())),
+        }
+    }
+    Ok(unescaped)
+}
+
+/* Splits a record line into its kind tag and its unescaped fields. */
+pub fn split_record(line: &str) -> Result<(String, Vec<String>), PersistError> {
+    let mut parts = line.split('\t');
+    let kind = parts.next().unwrap_or_default().to_string();
+    let fields = parts.map(unescape_field).collect::<Result<Vec<_>, _>>()?;
+    Ok((kind, fields))
+}
+
+fn expect_fields(kind: &str, fields: &[String], expected: usize) -> Result<(), PersistError> {
+    if fields.len() != expected {
+        return Err(PersistError::WrongFieldCount {
+            kind: kind.to_string(),
+            expected,
+            found: fields.len(),
+        });
+    }
+    Ok(())
+}
+
+/* Times are written as whole seconds since the Unix epoch, and an
+ * unknown time as an empty field. Anything finer than a second is
+ * dropped, which is plenty for putting a feed in order. */
+fn timestamp_field(time: Option<SystemTime>) -> String {
+    time.and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs().to_string())
+        .unwrap_or_default()
+}
+
+fn parse_timestamp(value: &str) -> Result<Option<SystemTime>, PersistError> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+    let invalid = || PersistError::InvalidField {
+        field: "published",
+        value: value.to_string(),
+    };
+    let secs: u64 = value.parse().map_err(|_| invalid())?;
+    /* A 'u64' of seconds can be further off than 'SystemTime' can
+     * reach, and '+' would panic on that. */
+    UNIX_EPOCH
+        .checked_add(Duration::from_secs(secs))
+        .map(Some)
+        .ok_or_else(invalid)
+}
+
+impl Persist for NewsArticle {
+    const KIND: &'static str = "article";
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.headline.clone(),
+            self.location.clone(),
+            self.author.clone(),
+            self.content.clone(),
+            timestamp_field(self.published),
+        ]
+    }
+
+    fn from_fields(fields: Vec<String>) -> Result<Self, PersistError> {
+        expect_fields(Self::KIND, &fields, 5)?;
+        let published = parse_timestamp(&fields[4])?;
+        let mut fields = fields.into_iter();
+        let mut next = || fields.next().unwrap_or_default();
+        Ok(NewsArticle {
+            headline: next(),
+            location: next(),
+            author: next(),
+            content: next(),
+            published,
+        })
+    }
+}
+
+impl Persist for Tweet {
+    const KIND: &'static str = "tweet";
+
+    /* The username, content, and time, then the kind: "original" on its own,
+     * "reply" followed by the name being replied to, or "retweet"
+     * followed by the retweeted tweet's whole record. That inner record is
+     * escaped like any other field, so it still fits in one column. */
+    fn fields(&self) -> Vec<String> {
+        let mut fields = vec![
+            self.username().to_string(),
+            self.content().to_string(),
+            timestamp_field(self.published()),
+        ];
+        match self.kind() {
+            TweetKind::Original => fields.push("original".to_string()),
+            TweetKind::Reply { to } => fields.extend(["reply".to_string(), to.clone()]),
+            TweetKind::Retweet { of } => fields.extend(["retweet".to_string(), of.to_record()]),
+        }
+        fields
+    }
+
+    /* Loading goes through 'Tweet::new' like everything else, so a record
+     * can't smuggle in a tweet that breaks its rules. */
+    fn from_fields(fields: Vec<String>) -> Result<Self, PersistError> {
+        let kind = fields.get(3).map(String::as_str).unwrap_or_default();
+        let expected = if kind == "original" { 4 } else { 5 };
+        expect_fields(Self::KIND, &fields, expected)?;
+
+        let mut tweet = Tweet::new(&fields[0], &fields[1]).map_err(invalid_tweet)?;
+        if let Some(published) = parse_timestamp(&fields[2])? {
+            tweet = tweet.published_at(published);
+        }
+        match kind {
+            "original" => Ok(tweet),
+            "reply" => tweet.as_reply_to(&fields[4]).map_err(invalid_tweet),
+            "retweet" => Ok(tweet.as_retweet_of(Tweet::from_record(&fields[4])?)),
+            _ => Err(PersistError::InvalidField {
+                field: "kind",
+                value: kind.to_string(),
+            }),
+        }
+    }
+}
+
+fn invalid_tweet(e: TweetError) -> PersistError {
+    let field = match e {
+        TweetError::InvalidUsername(_) => "username",
+        _ => "content",
+    };
+    PersistError::InvalidField {
+        field,
+        value: e.to_string(),
+    }
+}
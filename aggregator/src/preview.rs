@@ -0,0 +1,89 @@
+/* Headlines and Previews */
+/* 'summarize' gives a headline: short, and the same every time. Sometimes
+ * a reader wants a taste of the text itself, cut to fit the space there
+ * is. 'summarize_long' gives that preview, and 'Display' makes the
+ * headline available to 'println!("{article}")' and 'to_string()'.
+ *
+ * Cutting a 'String' at a byte index panics if the index falls inside a
+ * multi-byte character, so 'truncate' counts 'char's instead, the same
+ * way the tweet limit does. When text is cut, the last character that
+ * fits is replaced by an ellipsis, so the result is never longer than
+ * 'max_chars'.
+ *
+ * 'SummaryConfig' gathers those choices - how long, which ellipsis, and
+ * whether to name the author - for 'Summary::summarize_with', which every
+ * implementor gets for free. */
+use std::fmt;
+
+use crate::{NewsArticle, Summary, Tweet};
+
+pub const ELLIPSIS: char = '…';
+
+pub fn truncate(text: &str, max_chars: usize) -> String {
+    truncate_with(text, max_chars, &ELLIPSIS.to_string())
+}
+
+/* The ellipsis counts toward 'max_chars'. One that doesn't fit at all is
+ * cut short too. */
+pub fn truncate_with(text: &str, max_chars: usize, ellipsis: &str) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let room = max_chars.saturating_sub(ellipsis.chars().count());
+    let kept: String = text.chars().take(room).collect();
+    format!("{}{ellipsis}", kept.trim_end())
+        .chars()
+        .take(max_chars)
+        .collect()
+}
+
+/* 'max_len' of 'None' means no limit. The default is the plain summary:
+ * no limit, author shown, and '…' in case a limit is set later. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct SummaryConfig {
+    pub max_len: Option<usize>,
+    pub show_author: bool,
+    pub ellipsis: String,
+}
+
+impl Default for SummaryConfig {
+    fn default() -> SummaryConfig {
+        SummaryConfig {
+            max_len: None,
+            show_author: true,
+            ellipsis: ELLIPSIS.to_string(),
+        }
+    }
+}
+
+/* Feed text often arrives with line breaks and tabs in it, which would
+ * break a one-line preview. */
+fn one_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+impl fmt::Display for NewsArticle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.summarize())
+    }
+}
+
+impl fmt::Display for Tweet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.summarize())
+    }
+}
+
+impl NewsArticle {
+    pub fn summarize_long(&self, max_chars: usize) -> String {
+        let preview = format!("{}: {}", self.headline, one_line(&self.content));
+        truncate(&preview, max_chars)
+    }
+}
+
+impl Tweet {
+    pub fn summarize_long(&self, max_chars: usize) -> String {
+        let preview = format!("{}: {}", self.username(), one_line(self.content()));
+        truncate(&preview, max_chars)
+    }
+}
@@ -0,0 +1,149 @@
+/* Ranking */
+/* 'rank_feed' scores every item in a feed and sorts the feed by score,
+ * highest first. How an item is scored is up to the caller: a 'Scoring'
+ * is a list of 'Rule's, and an item's score is the sum of what each rule
+ * gives it. 'Scoring::default()' is a reasonable starting point that
+ * favors newer items and summaries of a comfortable length.
+ *
+ * Recency goes by 'published' when every item in the feed has a time:
+ * an item scores for each item published before it. If any item doesn't
+ * know when it was published, the items can't all be put in order by
+ * time, so recency falls back to position: feeds list items oldest first,
+ * so the further along an item is, the newer it is. Items published at
+ * the same moment keep their order from the feed either way.
+ *
+ * Each result is wrapped in a 'Ranked', which keeps the score next to the
+ * item and implements 'Ord' by comparing scores alone. That's what lets
+ * us sort with plain 'sort' and ask for 'max', but it also means two
+ * 'Ranked' values with the same score are "equal" even when their items
+ * are nothing alike. Scores are whole numbers because 'f64' can't
+ * implement 'Ord': 'NaN' isn't less than, equal to, or greater than
+ * anything. */
+use std::cmp::Ordering;
+use std::time::SystemTime;
+
+use crate::{search, Summary};
+
+#[derive(Debug, Clone)]
+pub enum Rule {
+    /* 'weight' points for every item older than this one. */
+    Recency { weight: i64 },
+    /* Loses 'weight' points for every 10 characters the summary is away
+     * from 'ideal_chars', in either direction. */
+    Length { ideal_chars: usize, weight: i64 },
+    /* 'boost' points if the summary contains 'keyword', ignoring case. A
+     * negative boost buries the item instead. */
+    Keyword { keyword: String, boost: i64 },
+}
+
+#[derive(Debug, Clone)]
+pub struct Scoring {
+    pub rules: Vec<Rule>,
+}
+
+impl Default for Scoring {
+    fn default() -> Scoring {
+        Scoring {
+            rules: vec![
+                Rule::Recency { weight: 10 },
+                Rule::Length {
+                    ideal_chars: 80,
+                    weight: 1,
+                },
+            ],
+        }
+    }
+}
+
+impl Scoring {
+    pub fn with_keyword(mut self, keyword: &str, boost: i64) -> Scoring {
+        self.rules.push(Rule::Keyword {
+            keyword: keyword.to_string(),
+            boost,
+        });
+        self
+    }
+
+    pub fn score(&self, summary: &str, position: usize) -> i64 {
+        let chars = summary.chars().count();
+        self.rules
+            .iter()
+            .map(|rule| match rule {
+                Rule::Recency { weight } => weight * position as i64,
+                Rule::Length {
+                    ideal_chars,
+                    weight,
+                } => -weight * (chars.abs_diff(*ideal_chars) / 10) as i64,
+                Rule::Keyword { keyword, boost } => {
+                    if search::matches(summary, keyword) {
+                        *boost
+                    } else {
+                        0
+                    }
+                }
+            })
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Ranked<T: Summary> {
+    pub score: i64,
+    pub item: T,
+}
+
+impl<T: Summary> PartialEq for Ranked<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.score == other.score
+    }
+}
+
+impl<T: Summary> Eq for Ranked<T> {}
+
+impl<T: Summary> PartialOrd for Ranked<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Summary> Ord for Ranked<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score.cmp(&other.score)
+    }
+}
+
+/* Highest score first. The sort is stable, so items with the same score
+ * keep their order from the feed. */
+pub fn rank_feed<T: Summary>(items: Vec<T>, scoring: &Scoring) -> Vec<Ranked<T>> {
+    let ages = ages(&items);
+    let mut ranked: Vec<Ranked<T>> = items
+        .into_iter()
+        .zip(ages)
+        .map(|(item, age)| Ranked {
+            score: scoring.score(&item.summarize(), age),
+            item,
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.cmp(a));
+    ranked
+}
+
+/* How many items are older than each one: its place in the feed sorted
+ * by 'published', or just its place in the feed if some item has no
+ * time. */
+fn ages<T: Summary>(items: &[T]) -> Vec<usize> {
+    let Some(published) = items
+        .iter()
+        .map(Summary::published)
+        .collect::<Option<Vec<SystemTime>>>()
+    else {
+        return (0..items.len()).collect();
+    };
+    let mut by_time: Vec<usize> = (0..items.len()).collect();
+    by_time.sort_by_key(|&i| published[i]);
+    let mut ages = vec![0; items.len()];
+    for (age, i) in by_time.into_iter().enumerate() {
+        ages[i] = age;
+    }
+    ages
+}
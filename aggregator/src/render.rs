@@ -0,0 +1,88 @@
+/* Rendering Backends */
+/* A 'Renderer' turns summaries into text in some markup language. Each
+ * backend decides how one item looks and how a whole digest page is laid
+ * out around the items; the default 'render_digest' just puts a title
+ * above the items, so a backend only overrides it when the format needs
+ * more structure (like HTML's '<head>' and '<body>').
+ *
+ * Renderers take '&dyn Summary' so that a timeline of mixed items can be
+ * rendered with one renderer, and so that renderers themselves can be
+ * stored as 'Box<dyn Renderer>' and picked at runtime. A '&Tweet' or
+ * '&NewsArticle' turns into a '&dyn Summary' automatically where one is
+ * expected.
+ *
+ * Summaries are text from outside sources, and each format has characters
+ * that would change the meaning of the markup, so every backend escapes
+ * the summary before inserting it. */
+use crate::Summary;
+
+pub trait Renderer {
+    fn render(&self, item: &dyn Summary) -> String;
+
+    fn render_digest(&self, title: &str, items: &[&dyn Summary]) -> String {
+        let mut page = format!("{title}\n\n");
+        for item in items {
+            page.push_str(&self.render(*item));
+            page.push('\n');
+        }
+        page
+    }
+}
+
+/* Markdown */
+/* Each item is a bullet point. A backslash in front of a punctuation
+ * character tells Markdown to print it literally, so a headline like
+ * "*Breaking* [update]" doesn't come out in italics with a broken link. */
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\`*_{}[]<>()#+-!|".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+pub struct MarkdownRenderer;
+
+impl Renderer for MarkdownRenderer {
+    fn render(&self, item: &dyn Summary) -> String {
+        format!("- {}", escape_markdown(&item.summarize()))
+    }
+
+    fn render_digest(&self, title: &str, items: &[&dyn Summary]) -> String {
+        let mut page = format!("# {}\n\n", escape_markdown(title));
+        for item in items {
+            page.push_str(&self.render(*item));
+            page.push('\n');
+        }
+        page
+    }
+}
+
+/* HTML */
+/* Only available with the 'html' feature, since it's built on the
+ * escaping in the 'html' module. */
+#[cfg(feature = "html")]
+pub struct HtmlRenderer;
+
+#[cfg(feature = "html")]
+impl Renderer for HtmlRenderer {
+    fn render(&self, item: &dyn Summary) -> String {
+        crate::html::summary_to_html(item)
+    }
+
+    fn render_digest(&self, title: &str, items: &[&dyn Summary]) -> String {
+        let title = crate::html::escape(title);
+        let mut page = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n</head>\n<body>\n<h1>{title}</h1>\n"
+        );
+        for item in items {
+            page.push_str(&self.render(*item));
+            page.push('\n');
+        }
+        page.push_str("</body>\n</html>\n");
+        page
+    }
+}
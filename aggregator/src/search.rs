@@ -0,0 +1,43 @@
+/* Searching and Filtering */
+/* Two ways to narrow down a collection of summaries. 'filter_by' takes
+ * any condition as a closure, the way 'Iterator::filter' does, and hands
+ * back references to the matching items, so nothing is copied. 'search'
+ * looks for text in each item's summary and returns the positions of the
+ * matches, which is handy when the caller wants to show the results next
+ * to the rest of the feed or look the items up again later.
+ *
+ * A search query is split into words, and an item matches when its
+ * summary contains every word, ignoring case - so "stanley penguins"
+ * finds "Penguins win the Stanley Cup". An empty query matches
+ * everything. */
+use crate::Summary;
+
+pub fn filter_by<T: Summary>(items: &[T], pred: impl Fn(&T) -> bool) -> Vec<&T> {
+    items.iter().filter(|item| pred(item)).collect()
+}
+
+pub fn matches(summary: &str, query: &str) -> bool {
+    let summary = summary.to_lowercase();
+    query
+        .split_whitespace()
+        .all(|word| summary.contains(&word.to_lowercase()))
+}
+
+pub fn search<T: Summary>(items: &[T], query: &str) -> Vec<usize> {
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| matches(&item.summarize(), query))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/* The same search over trait objects, for collections that mix types. */
+pub fn search_dyn(items: &[&dyn Summary], query: &str) -> Vec<usize> {
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| matches(&item.summarize(), query))
+        .map(|(i, _)| i)
+        .collect()
+}
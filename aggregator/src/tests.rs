@@ -0,0 +1,1162 @@
+use crate::*;
+use std::time::Duration;
+
+fn article() -> NewsArticle {
+    NewsArticle::builder()
+        .headline("Penguins win the Stanley Cup Championship!")
+        .location("Pittsburgh, PA, USA")
+        .author("Iceburgh")
+        .content("The Pittsburgh Penguins once again are the best\thockey team\nin the NHL.")
+        .build()
+        .unwrap()
+}
+
+fn tweet() -> Tweet {
+    Tweet::new(
+        "horse_ebooks",
+        "of course, as you probably already know, people",
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_summarize() {
+    assert_eq!(
+        article().summarize(),
+        "Penguins win the Stanley Cup Championship!, by Iceburgh (Pittsburgh, PA, USA)"
+    );
+    assert_eq!(
+        tweet().summarize(),
+        "horse_ebooks: of course, as you probably already know, people"
+    );
+}
+
+#[cfg(feature = "html")]
+#[test]
+fn test_html_escapes_summary() {
+    let tweet = Tweet::new("horse_ebooks", "<b>\"Tom\" & 'Jerry'</b>").unwrap();
+    assert_eq!(
+        html::summary_to_html(&tweet),
+        "<article class=\"summary\"><p>horse_ebooks: &lt;b&gt;&quot;Tom&quot; &amp; &#39;Jerry&#39;&lt;/b&gt;</p></article>"
+    );
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_records_round_trip() {
+    use persistence::Persist;
+
+    let record = article().to_record();
+    assert!(!record.contains('\n'));
+    let loaded = NewsArticle::from_record(&record).unwrap();
+    assert_eq!(loaded.content, article().content);
+    assert_eq!(loaded.summarize(), article().summarize());
+
+    let retweet = Tweet::new("ferris", "so true")
+        .unwrap()
+        .as_retweet_of(tweet());
+    let loaded = Tweet::from_record(&retweet.to_record()).unwrap();
+    assert_eq!(loaded.kind(), retweet.kind());
+    assert_eq!(loaded.summarize(), retweet.summarize());
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_random_tweets_round_trip() {
+    use persistence::Persist;
+    use test_support::{random_tweet, Rng};
+
+    let mut rng = Rng::from_env();
+    for _ in 0..200 {
+        let fixture = random_tweet(&mut rng);
+        let mut tweet = Tweet::new(&fixture.username, &fixture.content).unwrap();
+        if let Some(to) = &fixture.reply_to {
+            tweet = tweet.as_reply_to(to).unwrap();
+        }
+        if fixture.retweet {
+            tweet = Tweet::new("retweeter", "look")
+                .unwrap()
+                .as_retweet_of(tweet);
+        }
+        let loaded = Tweet::from_record(&tweet.to_record()).unwrap();
+        assert_eq!(loaded.summarize(), tweet.summarize());
+        assert_eq!(loaded.kind(), tweet.kind());
+    }
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_bad_records_are_rejected() {
+    use persistence::{Persist, PersistError};
+
+    assert_eq!(
+        Tweet::from_record(&article().to_record()).err(),
+        Some(PersistError::UnknownKind(String::from("article")))
+    );
+    assert!(matches!(
+        Tweet::from_record("tweet\tonly one"),
+        Err(PersistError::WrongFieldCount { found: 1, .. })
+    ));
+    assert!(matches!(
+        Tweet::from_record("tweet\ta\tb\t\tmaybe\tx"),
+        Err(PersistError::InvalidField { field: "kind", .. })
+    ));
+    assert!(matches!(
+        Tweet::from_record("tweet\ta\tb\tsoon\toriginal"),
+        Err(PersistError::InvalidField {
+            field: "published",
+            ..
+        })
+    ));
+    assert!(matches!(
+        Tweet::from_record("tweet\ta\\q\tb\toriginal"),
+        Err(PersistError::BadEscape(_))
+    ));
+}
+
+#[cfg(feature = "analytics")]
+#[test]
+fn test_summary_stats() {
+    let (a, t) = (article(), tweet());
+    let stats = analytics::summary_stats(&[&a, &t]);
+
+    assert_eq!(stats.count, 2);
+    assert_eq!(stats.shortest, t.summarize().len());
+    assert_eq!(stats.longest, a.summarize().len());
+    assert_eq!(
+        stats.average_chars(),
+        (stats.shortest + stats.longest) as f64 / 2.0
+    );
+    assert_eq!(analytics::summary_stats(&[]).average_chars(), 0.0);
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_any_fields_survive_a_record() {
+    use persistence::{escape_field, unescape_field, Persist};
+    use test_support::gen::{self, assert_property};
+
+    let field = gen::strings("ab\\\t\n\rtn", 12);
+    assert_property(&field, |f| {
+        let escaped = escape_field(f);
+        !escaped.contains(['\t', '\n']) && unescape_field(&escaped).as_ref() == Ok(f)
+    });
+
+    let username = gen::strings("ab_9", MAX_USERNAME_CHARS);
+    assert_property(&(username, field), |(username, content)| {
+        /* Pairs that 'Tweet::new' rejects can't be saved in the first
+         * place, so there's nothing to round-trip. */
+        let Ok(tweet) = Tweet::new(username, content) else {
+            return true;
+        };
+        let tweet = tweet.as_reply_to("bors").unwrap();
+        Tweet::from_record(&tweet.to_record()).map(|t| t.summarize()) == Ok(tweet.summarize())
+    });
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_arbitrary_lines_never_panic() {
+    use persistence::Persist;
+    use test_support::gen::{self, assert_property};
+
+    /* Whatever the line, parsing it returns a 'Result'; a panic here would
+     * fail the test. */
+    assert_property(&gen::strings("tweet\t\\noriginal0123456789", 40), |line| {
+        let _ = Tweet::from_record(line);
+        let _ = NewsArticle::from_record(line);
+        true
+    });
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_timestamp_past_the_end_of_time_is_invalid() {
+    use persistence::{Persist, PersistError};
+
+    let line = "tweet\ta\tb\t18446744073709551615\toriginal";
+    assert!(matches!(
+        Tweet::from_record(line),
+        Err(PersistError::InvalidField {
+            field: "published",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_rss_feed() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+  <channel>
+    <title>Pittsburgh Gazette</title>
+    <link>https://example.com/</link>
+    <item>
+      <title>Penguins win the Stanley Cup Championship!</title>
+      <dc:creator>Iceburgh</dc:creator>
+      <pubDate>Tue, 10 Jun 2003 04:00:00 GMT</pubDate>
+      <description><![CDATA[The <b>best</b> hockey team]]></description>
+    </item>
+    <!-- a comment between items -->
+    <item>
+      <title>Rock &amp; roll &#8217;til dawn</title>
+      <author>Iceburgh &lt;ice@example.com&gt;</author>
+      <description>Parade &gt; party</description>
+    </item>
+  </channel>
+</rss>"#;
+
+    let feed = feed::Feed::from_reader(xml.as_bytes()).unwrap();
+    assert_eq!(feed.title, "Pittsburgh Gazette");
+    assert_eq!(feed.articles.len(), 2);
+    assert_eq!(
+        feed.articles[0].summarize(),
+        "Penguins win the Stanley Cup Championship!, by Iceburgh (Pittsburgh Gazette)"
+    );
+    assert_eq!(feed.articles[0].content, "The <b>best</b> hockey team");
+    assert_eq!(feed.articles[1].headline, "Rock & roll \u{2019}til dawn");
+    assert_eq!(feed.articles[1].author, "Iceburgh <ice@example.com>");
+    assert_eq!(feed.articles[1].content, "Parade > party");
+    assert_eq!(feed.articles[0].published, Some(at(1_055_217_600)));
+    assert_eq!(feed.articles[1].published, None);
+}
+
+#[test]
+fn test_feed_date_past_year_9999_is_ignored() {
+    let xml = r#"<rss version="2.0">
+  <channel>
+    <title>Far Future Gazette</title>
+    <item>
+      <title>Still champions</title>
+      <pubDate>Tue, 10 Jun 999999999999999 04:00:00 GMT</pubDate>
+    </item>
+  </channel>
+</rss>"#;
+
+    let feed = feed::Feed::parse(xml).unwrap();
+    assert_eq!(feed.articles[0].headline, "Still champions");
+    assert_eq!(feed.articles[0].published, None);
+}
+
+#[test]
+fn test_atom_feed() {
+    let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Sports Desk</title>
+  <author><name>Desk Editor</name></author>
+  <link href="https://example.com/?a=1&amp;b=2" rel="self"/>
+  <entry>
+    <title type="text">Finals tonight</title>
+    <author><name>Iceburgh</name></author>
+    <updated>2003-12-13T18:30:02Z</updated>
+    <summary>Game seven.</summary>
+  </entry>
+  <entry>
+    <title>Unsigned</title>
+    <published>2003-12-13T19:30:02.25+01:00</published>
+    <content type="html">&lt;p&gt;Hi&lt;/p&gt;</content>
+  </entry>
+</feed>"#;
+
+    let feed = feed::Feed::parse(xml).unwrap();
+    assert_eq!(feed.title, "Sports Desk");
+    assert_eq!(feed.articles[0].author, "Iceburgh");
+    assert_eq!(feed.articles[0].content, "Game seven.");
+    assert_eq!(feed.articles[1].author, "Desk Editor");
+    assert_eq!(feed.articles[1].content, "<p>Hi</p>");
+    assert_eq!(feed.articles[1].location, "Sports Desk");
+    assert_eq!(feed.articles[0].published, Some(at(1_071_340_202)));
+    assert_eq!(feed.articles[1].published, feed.articles[0].published);
+}
+
+#[test]
+fn test_bad_feeds() {
+    use feed::{Feed, FeedError};
+
+    assert!(matches!(
+        Feed::parse("<html><body/></html>"),
+        Err(FeedError::UnsupportedFormat(root)) if root == "html"
+    ));
+    assert!(matches!(
+        Feed::parse("<rss><channel><title>x</channel></rss>"),
+        Err(FeedError::Malformed(_))
+    ));
+    assert!(matches!(Feed::parse("<rss>"), Err(FeedError::Malformed(_))));
+    assert!(matches!(
+        Feed::parse("<rss></rss>"),
+        Err(FeedError::Malformed(_))
+    ));
+    assert!(matches!(
+        Feed::parse("<feed><title>&nope;</title></feed>"),
+        Err(FeedError::Malformed(_))
+    ));
+    assert!(matches!(Feed::parse(""), Err(FeedError::Malformed(_))));
+}
+
+#[test]
+fn test_timeline_mixes_types() {
+    let mut timeline = timeline::Timeline::new();
+    assert!(timeline.is_empty());
+    assert_eq!(timeline.render(), "");
+
+    timeline.push(tweet());
+    timeline.push(article());
+    assert_eq!(timeline.len(), 2);
+
+    let summaries: Vec<String> = timeline.iter_summaries().collect();
+    assert_eq!(summaries, vec![tweet().summarize(), article().summarize()]);
+    assert_eq!(
+        timeline.render(),
+        format!("1. {}\n2. {}", tweet().summarize(), article().summarize())
+    );
+}
+
+#[test]
+fn test_dispatcher_fans_out() {
+    use notify::{CallbackNotifier, Dispatcher, FileNotifier};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use test_support::TempFile;
+
+    let log = TempFile::new("aggregator-notify");
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&received);
+
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.register(FileNotifier::new(log.path()));
+    dispatcher.register(CallbackNotifier::new(move |s| {
+        sink.borrow_mut().push(s.to_string())
+    }));
+    assert_eq!(dispatcher.len(), 2);
+
+    dispatcher.dispatch(&tweet()).unwrap();
+    dispatcher.dispatch(&article()).unwrap();
+
+    assert_eq!(
+        *received.borrow(),
+        vec![tweet().summarize(), article().summarize()]
+    );
+    assert_eq!(
+        log.read().unwrap(),
+        format!("{}\n{}\n", tweet().summarize(), article().summarize())
+    );
+}
+
+#[test]
+fn test_dispatcher_reports_every_failure() {
+    use notify::{CallbackNotifier, Dispatcher, FileNotifier};
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use test_support::TempDir;
+
+    /* A directory can't be opened for appending, so both file notifiers
+     * fail - but the callback registered between them still runs. */
+    let dir = TempDir::new("aggregator-notify").unwrap();
+    let calls = Rc::new(Cell::new(0));
+    let counter = Rc::clone(&calls);
+
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.register(FileNotifier::new(dir.path()));
+    dispatcher.register(CallbackNotifier::new(move |_| {
+        counter.set(counter.get() + 1)
+    }));
+    dispatcher.register(FileNotifier::new(dir.path()));
+
+    let errors = dispatcher.dispatch(&tweet()).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn test_tweet_validation() {
+    assert_eq!(
+        Tweet::new("horse_ebooks", "   ").err(),
+        Some(TweetError::EmptyContent)
+    );
+    assert_eq!(
+        Tweet::new("horse ebooks", "hi").err(),
+        Some(TweetError::InvalidUsername(String::from("horse ebooks")))
+    );
+    assert!(Tweet::new("", "hi").is_err());
+    assert!(Tweet::new("a_very_long_username", "hi").is_err());
+
+    /* The limit is in characters: 280 three-byte characters are fine. */
+    let at_limit = "\u{2603}".repeat(MAX_TWEET_CHARS);
+    assert!(Tweet::new("snow", &at_limit).is_ok());
+    assert_eq!(
+        Tweet::new("snow", &format!("{at_limit}!")).err(),
+        Some(TweetError::TooLong { length: 281 })
+    );
+
+    let tweet = Tweet::new("horse_ebooks", "hello").unwrap();
+    assert_eq!(
+        (tweet.username(), tweet.content()),
+        ("horse_ebooks", "hello")
+    );
+    assert_eq!(tweet.kind(), &TweetKind::Original);
+}
+
+fn podcast() -> Podcast {
+    Podcast {
+        show: String::from("Rustacean Station"),
+        title: String::from("Traits in depth"),
+        host: String::from("Ferris"),
+        episode: 42,
+        duration: Duration::from_secs(3 * 3600 + 5 * 60 + 9),
+        published: None,
+    }
+}
+
+fn blog_post() -> BlogPost {
+    BlogPost {
+        title: String::from("Why I like trait objects"),
+        author: String::from("Ferris"),
+        tags: vec![String::from("rust"), String::from("traits")],
+        body: String::from("Dynamic dispatch is underrated."),
+        published: None,
+    }
+}
+
+fn video_clip() -> VideoClip {
+    VideoClip {
+        title: String::from("Crab rave"),
+        channel: String::from("Crustacean TV"),
+        duration: Duration::from_secs(185),
+        tags: vec![String::from("music")],
+        published: None,
+    }
+}
+
+#[test]
+fn test_media_summaries() {
+    assert_eq!(
+        podcast().summarize(),
+        "Rustacean Station #42: Traits in depth, hosted by Ferris (3:05:09)"
+    );
+    assert_eq!(
+        blog_post().summarize(),
+        "Why I like trait objects, by Ferris [rust, traits]"
+    );
+    let untagged = BlogPost {
+        tags: Vec::new(),
+        ..blog_post()
+    };
+    assert_eq!(untagged.summarize(), "Why I like trait objects, by Ferris");
+    assert_eq!(
+        video_clip().summarize(),
+        "Crab rave from Crustacean TV (3:05)"
+    );
+    assert_eq!(media::format_duration(Duration::from_secs(9)), "0:09");
+}
+
+#[test]
+fn test_timeline_holds_every_kind() {
+    let mut timeline = timeline::Timeline::new();
+    timeline.push(tweet());
+    timeline.push(article());
+    timeline.push(podcast());
+    timeline.push(blog_post());
+    timeline.push(video_clip());
+
+    assert_eq!(timeline.len(), 5);
+    assert!(timeline
+        .render()
+        .ends_with("5. Crab rave from Crustacean TV (3:05)"));
+}
+
+#[test]
+fn test_metadata() {
+    use metadata::*;
+
+    let tweet = Tweet::new("ferris", "Hey @bors, ship it! #rustlang #traits.")
+        .unwrap()
+        .as_reply_to("bors")
+        .unwrap();
+    assert_eq!(
+        tweet.metadata(),
+        TweetStats {
+            chars: 38,
+            remaining: 242,
+            mentions: vec![String::from("bors")],
+            hashtags: vec![String::from("rustlang"), String::from("traits")],
+            reply: true,
+            retweet: false,
+        }
+    );
+    assert_eq!(article().metadata().author, "Iceburgh");
+    assert_eq!(podcast().metadata().episode, 42);
+    assert_eq!(blog_post().metadata().word_count, 4);
+    assert_eq!(video_clip().metadata().duration, Duration::from_secs(185));
+
+    assert_eq!(
+        describe(&article()),
+        format!(
+            "{} Byline {{ author: \"Iceburgh\", location: \"Pittsburgh, PA, USA\" }}",
+            article().summarize()
+        )
+    );
+}
+
+#[test]
+fn test_markdown_renderer() {
+    use render::{MarkdownRenderer, Renderer};
+
+    let tweet = Tweet::new("ferris", "*so* [cool] #1").unwrap();
+    assert_eq!(
+        MarkdownRenderer.render(&tweet),
+        "- ferris: \\*so\\* \\[cool\\] \\#1"
+    );
+    assert_eq!(
+        MarkdownRenderer.render_digest("Today's _news_", &[&tweet, &video_clip()]),
+        "# Today's \\_news\\_\n\n- ferris: \\*so\\* \\[cool\\] \\#1\n- Crab rave from Crustacean TV \\(3:05\\)\n"
+    );
+}
+
+#[cfg(feature = "html")]
+#[test]
+fn test_html_renderer() {
+    use render::{HtmlRenderer, Renderer};
+
+    let renderers: Vec<Box<dyn Renderer>> = vec![Box::new(HtmlRenderer)];
+    let tweet = Tweet::new("ferris", "<script>alert(1)</script>").unwrap();
+    let page = renderers[0].render_digest("Tom & Jerry", &[&tweet, &article()]);
+
+    assert!(page.starts_with("<!DOCTYPE html>"));
+    assert!(page.contains("<title>Tom &amp; Jerry</title>"));
+    assert!(page.contains("ferris: &lt;script&gt;alert(1)&lt;/script&gt;"));
+    assert!(!page.contains("<script>"));
+    assert_eq!(page.matches("<article").count(), 2);
+}
+
+#[test]
+fn test_search_and_filter() {
+    use search::{filter_by, search, search_dyn};
+
+    let tweets = vec![
+        Tweet::new("ferris", "Rust 2024 is out").unwrap(),
+        Tweet::new("bors", "merged a PR")
+            .unwrap()
+            .as_reply_to("ferris")
+            .unwrap(),
+        Tweet::new("ferris", "crabs love RUST").unwrap(),
+    ];
+    assert_eq!(search(&tweets, "rust"), vec![0, 2]);
+    assert_eq!(search(&tweets, "ferris crabs"), vec![2]);
+    assert_eq!(search(&tweets, ""), vec![0, 1, 2]);
+    assert!(search(&tweets, "python").is_empty());
+
+    let replies = filter_by(&tweets, |t| t.is_reply());
+    assert_eq!(replies.len(), 1);
+    assert_eq!(replies[0].username(), "bors");
+
+    let mixed: [&dyn Summary; 3] = [&tweets[0], &article(), &podcast()];
+    assert_eq!(search_dyn(&mixed, "stanley penguins"), vec![1]);
+    assert_eq!(search_dyn(&mixed, "ferris"), vec![0, 2]);
+
+    let mut timeline = timeline::Timeline::new();
+    timeline.push(article());
+    timeline.push(tweets[0].clone());
+    assert_eq!(timeline.search("RUST"), vec![1]);
+}
+
+#[cfg(feature = "analytics")]
+#[test]
+fn test_rank_feed() {
+    use ranking::{rank_feed, Ranked, Rule, Scoring};
+
+    let tweets = || {
+        vec![
+            Tweet::new("a", "first").unwrap(),
+            Tweet::new("b", "second, about rust").unwrap(),
+            Tweet::new("c", "third").unwrap(),
+        ]
+    };
+
+    /* By default, newer items rank higher. */
+    let ranked = rank_feed(tweets(), &Scoring::default());
+    let order: Vec<&str> = ranked.iter().map(|r| r.item.username()).collect();
+    assert_eq!(order, vec!["c", "b", "a"]);
+
+    /* When every item has a time, that's what decides which is newer. */
+    let backdated = vec![
+        Tweet::new("a", "first").unwrap().published_at(at(300)),
+        Tweet::new("b", "second").unwrap().published_at(at(100)),
+        Tweet::new("c", "third").unwrap().published_at(at(200)),
+    ];
+    let ranked = rank_feed(backdated, &Scoring::default());
+    let order: Vec<&str> = ranked.iter().map(|r| r.item.username()).collect();
+    assert_eq!(order, vec!["a", "c", "b"]);
+
+    let boosted = rank_feed(tweets(), &Scoring::default().with_keyword("RUST", 100));
+    assert_eq!(boosted[0].item.username(), "b");
+    assert_eq!(boosted[0].score, 100 + 10 - 5);
+
+    let only_length = Scoring {
+        rules: vec![Rule::Length {
+            ideal_chars: 5,
+            weight: 1,
+        }],
+    };
+    let ranked = rank_feed(tweets(), &only_length);
+    assert_eq!(ranked[0].score, 0);
+    assert_eq!(ranked[2].item.username(), "b");
+
+    /* Comparisons only look at the score. */
+    let low = Ranked {
+        score: 1,
+        item: tweet(),
+    };
+    let high = Ranked {
+        score: 2,
+        item: tweet(),
+    };
+    assert!(low < high);
+    let other = Tweet::new("someone_else", "nothing alike").unwrap();
+    assert_eq!(
+        low,
+        Ranked {
+            score: 1,
+            item: other
+        }
+    );
+}
+
+#[test]
+fn test_dedupe() {
+    use dedupe::dedupe;
+    use std::collections::HashSet;
+
+    let from_another_feed = NewsArticle {
+        location: String::from("Sports Desk"),
+        author: String::from("Staff"),
+        headline: String::from("PENGUINS   win the Stanley Cup championship!"),
+        ..article()
+    };
+    assert_eq!(article(), from_another_feed);
+    let different = NewsArticle {
+        headline: String::from("Penguins lose"),
+        ..article()
+    };
+    assert_ne!(article(), different);
+
+    let set: HashSet<NewsArticle> = [article(), from_another_feed.clone()].into();
+    assert_eq!(set.len(), 1);
+
+    let articles = dedupe(vec![
+        article(),
+        different.clone(),
+        from_another_feed,
+        different,
+    ]);
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].location, "Pittsburgh, PA, USA");
+    assert_eq!(articles[1].headline, "Penguins lose");
+
+    let tweets = dedupe(vec![
+        tweet(),
+        Tweet::new(
+            "horse_ebooks",
+            "Of course,  as you probably already know, people",
+        )
+        .unwrap(),
+        Tweet::new(
+            "someone_else",
+            "of course, as you probably already know, people",
+        )
+        .unwrap(),
+    ]);
+    assert_eq!(tweets.len(), 2);
+}
+
+#[test]
+fn test_pages() {
+    use pages::Pages;
+
+    let tweets: Vec<Tweet> = (1..=7)
+        .map(|i| Tweet::new("ferris", &format!("tweet {i}")).unwrap())
+        .collect();
+    let pages = Pages::new(&tweets, 3);
+    assert_eq!(pages.len(), 3);
+
+    let sizes: Vec<usize> = pages.map(|p| p.items.len()).collect();
+    assert_eq!(sizes, vec![3, 3, 1]);
+
+    let mut pages = Pages::new(&tweets, 3);
+    let third = pages.nth(2).unwrap();
+    assert_eq!((third.number, third.page_count), (3, 3));
+    assert!(third.is_last());
+    assert_eq!(third.summaries(), vec!["ferris: tweet 7"]);
+    assert!(pages.next().is_none());
+    assert!(Pages::new(&tweets, 3).nth(10).is_none());
+    assert_eq!(Pages::new(&tweets[..0], 3).count(), 0);
+
+    let mut timeline = timeline::Timeline::new();
+    timeline.push(article());
+    timeline.push(tweet());
+    timeline.push(podcast());
+    let second = timeline.pages(2).nth(1).unwrap();
+    assert_eq!(second.summaries(), vec![podcast().summarize()]);
+}
+
+#[test]
+fn test_collections_summarize() {
+    let tweets = vec![tweet(), Tweet::new("ferris", "hi").unwrap()];
+    assert_eq!(
+        tweets.summarize(),
+        format!("2 items\n- {}\n- ferris: hi", tweet().summarize())
+    );
+    assert_eq!(
+        tweets[..1].summarize(),
+        format!("1 item\n- {}", tweet().summarize())
+    );
+    assert_eq!(Vec::<Tweet>::new().summarize(), "0 items");
+
+    /* Nested collections and trait objects work too. */
+    let mixed: Vec<Box<dyn Summary>> = vec![Box::new(article()), Box::new(vec![podcast()])];
+    assert!(mixed.summarize().starts_with("2 items\n- Penguins"));
+
+    let mut timeline = timeline::Timeline::new();
+    timeline.push(tweets);
+    assert!(timeline.render().starts_with("1. 2 items\n- horse_ebooks"));
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_timeline_save_and_load() {
+    use test_support::TempFile;
+
+    let mut timeline = timeline::Timeline::new();
+    timeline.push(article());
+    timeline.push(tweet());
+    timeline.push(
+        Tweet::new("ferris", "line one\nline\ttwo")
+            .unwrap()
+            .as_reply_to("bors")
+            .unwrap(),
+    );
+
+    let file = TempFile::new("aggregator-timeline");
+    timeline.save(file.path()).unwrap();
+    assert_eq!(file.read().unwrap().lines().count(), 3);
+
+    let loaded = timeline::Timeline::load(file.path()).unwrap();
+    assert_eq!(loaded.render(), timeline.render());
+    assert!(loaded
+        .iter()
+        .nth(2)
+        .unwrap()
+        .as_record()
+        .unwrap()
+        .ends_with("reply\tbors"));
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_timeline_load_errors() {
+    use persistence::{PersistError, TimelineError};
+    use test_support::TempFile;
+
+    let file = TempFile::with_contents(
+        "aggregator-timeline",
+        "tweet\tferris\thi\t\toriginal\n\npodcast\tx\n",
+    )
+    .unwrap();
+    match timeline::Timeline::load(file.path()) {
+        Err(TimelineError::Record { line: 3, error }) => {
+            assert_eq!(error, PersistError::UnknownKind("podcast".to_string()))
+        }
+        other => panic!("expected a record error, got {:?}", other.map(|t| t.len())),
+    }
+
+    let mut timeline = timeline::Timeline::new();
+    timeline.push(podcast());
+    assert!(matches!(
+        timeline.save(file.path()),
+        Err(TimelineError::NotPersistable { position: 0, .. })
+    ));
+}
+
+#[test]
+fn test_article_builder() {
+    let built = NewsArticle::builder()
+        .headline("Penguins win")
+        .author("Iceburgh")
+        .content("Again.")
+        .build()
+        .unwrap();
+    assert_eq!(built.location, "");
+    assert_eq!(built.summarize(), "Penguins win, by Iceburgh ()");
+
+    let missing = NewsArticle::builder()
+        .headline("Penguins win")
+        .content("Again.")
+        .build();
+    assert_eq!(missing.unwrap_err(), BuildError::MissingField("author"));
+}
+
+#[test]
+fn test_tweet_builder() {
+    let built = Tweet::builder()
+        .username("horse_ebooks")
+        .content("of course, as you probably already know, people")
+        .reply_to("someone")
+        .retweet_of(article_tweet())
+        .build()
+        .unwrap();
+    assert_eq!(
+        built.kind(),
+        &TweetKind::Retweet {
+            of: Box::new(article_tweet())
+        }
+    );
+    assert!(built.is_retweet() && !built.is_reply());
+
+    assert_eq!(
+        Tweet::builder().content("hi").build().unwrap_err(),
+        BuildError::MissingField("username")
+    );
+    assert_eq!(
+        Tweet::builder()
+            .username("ferris")
+            .content(" ")
+            .build()
+            .unwrap_err(),
+        BuildError::InvalidTweet(TweetError::EmptyContent)
+    );
+}
+
+#[test]
+fn test_display_matches_summarize() {
+    assert_eq!(article().to_string(), article().summarize());
+    assert_eq!(format!("{}", tweet()), tweet().summarize());
+}
+
+#[test]
+fn test_summarize_long() {
+    assert_eq!(
+        article().summarize_long(200),
+        "Penguins win the Stanley Cup Championship!: \
+         The Pittsburgh Penguins once again are the best hockey team in the NHL."
+    );
+    assert_eq!(
+        article().summarize_long(50),
+        "Penguins win the Stanley Cup Championship!: The P…"
+    );
+    assert_eq!(tweet().summarize_long(20), "horse_ebooks: of co…");
+    assert_eq!(tweet().summarize_long(0), "");
+
+    /* Multi-byte characters are cut on a boundary, never through one. */
+    let emoji = Tweet::new("ferris", "🦀🦀🦀🦀").unwrap();
+    assert_eq!(emoji.summarize_long(10), "ferris: 🦀…");
+    assert_eq!(preview::truncate("🦀🦀", 2), "🦀🦀");
+}
+
+fn article_tweet() -> Tweet {
+    Tweet::new("Iceburgh", "Penguins win the Stanley Cup Championship!").unwrap()
+}
+
+#[test]
+fn test_tweet_kinds() {
+    let reply = Tweet::new("ferris", "congrats!")
+        .unwrap()
+        .as_reply_to("Iceburgh")
+        .unwrap();
+    assert_eq!(
+        reply.summarize(),
+        "ferris (replying to @Iceburgh): congrats!"
+    );
+    assert_eq!(
+        reply.kind(),
+        &TweetKind::Reply {
+            to: String::from("Iceburgh")
+        }
+    );
+
+    /* Retweets nest, and each level shows what it shares. */
+    let retweet = Tweet::new("bors", "this").unwrap().as_retweet_of(reply);
+    assert_eq!(
+        retweet.summarize(),
+        "bors: this [RT ferris (replying to @Iceburgh): congrats!]"
+    );
+    assert!(retweet.is_retweet() && !retweet.is_reply());
+
+    assert_eq!(
+        Tweet::new("ferris", "hi")
+            .unwrap()
+            .as_reply_to("not valid")
+            .err(),
+        Some(TweetError::InvalidUsername(String::from("not valid")))
+    );
+}
+
+#[cfg(feature = "analytics")]
+#[test]
+fn test_content_stats() {
+    use analytics::ContentStats;
+
+    assert_eq!(article().word_count(), 13);
+    assert_eq!(article().reading_time(), "1 min read");
+    assert_eq!(tweet().char_count(), tweet().content().chars().count());
+
+    let long_read = BlogPost {
+        body: "word ".repeat(450),
+        ..blog_post()
+    };
+    assert_eq!(long_read.word_count(), 450);
+    assert_eq!(long_read.reading_time(), "3 min read");
+
+    /* Without a body, the summary is what gets counted. */
+    assert_eq!(podcast().text(), podcast().summarize());
+    assert_eq!(
+        video_clip().char_count(),
+        video_clip().summarize().chars().count()
+    );
+}
+
+fn at(secs: u64) -> std::time::SystemTime {
+    std::time::UNIX_EPOCH + Duration::from_secs(secs)
+}
+
+#[test]
+fn test_timeline_merge() {
+    use timeline::Timeline;
+
+    let early = Tweet::new("ferris", "first").unwrap().published_at(at(100));
+    let late = Tweet::new("bors", "second").unwrap().published_at(at(300));
+    let middle = NewsArticle {
+        published: Some(at(200)),
+        ..article()
+    };
+
+    let mut feed_a = Timeline::new();
+    feed_a.push(late.clone());
+    feed_a.push(early.clone());
+    let mut feed_b = Timeline::new();
+    feed_b.push(middle);
+    feed_b.push(early);
+    feed_b.push(podcast());
+
+    /* The duplicate 'early' is dropped, the undated podcast comes first,
+     * and the rest are in order of publication. */
+    let merged = feed_a + feed_b;
+    assert_eq!(merged.len(), 4);
+    let summaries: Vec<String> = merged.iter_summaries().collect();
+    assert_eq!(summaries[0], podcast().summarize());
+    assert_eq!(summaries[1], "ferris: first");
+    assert_eq!(summaries[2], article().summarize());
+    assert_eq!(summaries[3], late.summarize());
+
+    let mut merged = merged;
+    let mut again = Timeline::new();
+    again.push(late);
+    merged += again;
+    assert_eq!(merged.len(), 4);
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_published_round_trips() {
+    use persistence::Persist;
+
+    let tweet = tweet().published_at(at(1_700_000_000));
+    let loaded = Tweet::from_record(&tweet.to_record()).unwrap();
+    assert_eq!(loaded.published(), Some(at(1_700_000_000)));
+
+    let undated = NewsArticle::from_record(&article().to_record()).unwrap();
+    assert_eq!(undated.published, None);
+}
+
+#[test]
+fn test_static_and_dynamic_dispatch_agree() {
+    use dispatch::*;
+
+    assert_eq!(notify_static(&tweet()), notify_dyn(&tweet()));
+    assert_eq!(
+        notify_static(&article()),
+        format!("Breaking news! {}", article().summarize())
+    );
+
+    let tweets = vec![tweet(), Tweet::new("ferris", "hi").unwrap()];
+    let boxed: Vec<Box<dyn Summary>> = tweets
+        .iter()
+        .map(|t| Box::new(t.clone()) as Box<dyn Summary>)
+        .collect();
+    assert_eq!(notify_all_static(&tweets), notify_all_dyn(&boxed));
+}
+
+#[test]
+fn test_localized_summaries() {
+    use locale::{Locale, Localized};
+
+    assert_eq!(
+        article().summarize_localized(Locale::English),
+        format!("Breaking news! {}", article().summarize())
+    );
+    assert_eq!(
+        tweet().summarize_localized(Locale::Spanish),
+        format!("1 tuit nuevo: {}", tweet().summarize())
+    );
+    assert_eq!(
+        podcast().summarize_localized(Locale::German),
+        format!("Neue Folge: {}", podcast().summarize())
+    );
+    assert_eq!(Locale::default(), Locale::English);
+
+    /* 'Localized' works through trait objects, too. */
+    let items: [&dyn Localized; 2] = [&blog_post(), &video_clip()];
+    assert!(items[0]
+        .summarize_localized(Locale::German)
+        .starts_with("Neuer Beitrag: "));
+    assert!(items[1]
+        .summarize_localized(Locale::Spanish)
+        .starts_with("Nuevo vídeo: "));
+}
+
+#[test]
+fn test_notify_all() {
+    use std::io::Cursor;
+
+    let tweets = vec![tweet(), Tweet::new("ferris", "hi").unwrap()];
+    let mut out = Cursor::new(Vec::new());
+    notify_all_to(&mut out, &tweets).unwrap();
+    notify_all_to(&mut out, [article()]).unwrap();
+    let mixed: [&dyn Summary; 2] = [&podcast(), &tweets[1]];
+    notify_all_to(&mut out, mixed).unwrap();
+
+    let printed = String::from_utf8(out.into_inner()).unwrap();
+    let lines: Vec<&str> = printed.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines[1], "Breaking news! ferris: hi");
+    assert_eq!(
+        lines[2],
+        format!("Breaking news! {}", article().summarize())
+    );
+    assert_eq!(
+        lines[3],
+        format!("Breaking news! {}", podcast().summarize())
+    );
+}
+
+#[test]
+fn test_notify_joined() {
+    /* What 'notify3' and 'notify4' print, and always have: the two
+     * summaries and nothing else. */
+    let mut out = Vec::new();
+    notify_joined_to(&mut out, &[&tweet(), &podcast()]).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        format!("{} - {}\n", tweet().summarize(), podcast().summarize())
+    );
+}
+
+#[test]
+fn test_display_adapter() {
+    use display::{DisplaySummary, SummaryDisplay};
+
+    assert_eq!(format!("{}", podcast()), podcast().summarize());
+    assert_eq!(blog_post().to_string(), blog_post().summarize());
+    assert_eq!(video_clip().to_string(), video_clip().summarize());
+
+    /* Collections and trait objects have no 'Display' of their own. */
+    let tweets = vec![tweet(), Tweet::new("ferris", "hi").unwrap()];
+    assert_eq!(format!("{}", tweets.display()), tweets.summarize());
+    let item: &dyn Summary = &article();
+    assert_eq!(item.display().to_string(), article().summarize());
+    assert_eq!(
+        SummaryDisplay::new(&tweets[..1]).to_string(),
+        tweets[..1].summarize()
+    );
+
+    /* Width and alignment flags apply to the summary as a whole. */
+    let hi = Tweet::new("ferris", "hi").unwrap();
+    assert_eq!(format!("[{:>12}]", hi.display()), "[  ferris: hi]");
+}
+
+#[test]
+fn test_lines_to_tweets() {
+    use ingest::{lines_to_tweets, ParseError};
+    use test_support::fake::Input;
+
+    let input = Input::from_lines(&[
+        "ferris: crabs: the best",
+        "",
+        "no separator here",
+        "not valid: hello",
+        "  bors :  merged  ",
+    ]);
+    let results: Vec<_> = lines_to_tweets(input).collect();
+    assert_eq!(results.len(), 4);
+    assert_eq!(
+        results[0].as_ref().unwrap().summarize(),
+        "ferris: crabs: the best"
+    );
+    assert!(matches!(
+        results[1],
+        Err(ParseError::MissingSeparator { line: 3 })
+    ));
+    assert!(matches!(
+        &results[2],
+        Err(ParseError::InvalidTweet {
+            line: 4,
+            error: TweetError::InvalidUsername(_)
+        })
+    ));
+    assert_eq!(results[3].as_ref().unwrap().summarize(), "bors: merged");
+}
+
+#[test]
+fn test_lines_to_tweets_is_lazy() {
+    use std::io::{self, BufReader, Read};
+
+    /* A reader that never runs out. If 'lines_to_tweets' tried to read
+     * everything first, this test would never finish. */
+    struct Endless;
+    impl Read for Endless {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let line = b"ferris: hi\n";
+            let n = buf.len().min(line.len());
+            buf[..n].copy_from_slice(&line[..n]);
+            Ok(n)
+        }
+    }
+
+    let tweets = ingest::lines_to_tweets(BufReader::new(Endless)).take(3);
+    assert_eq!(tweets.filter(Result::is_ok).count(), 3);
+}
+
+#[test]
+fn test_summarize_with() {
+    use preview::SummaryConfig;
+
+    let plain = SummaryConfig::default();
+    assert_eq!(article().summarize_with(&plain), article().summarize());
+
+    let anonymous = SummaryConfig {
+        show_author: false,
+        ..SummaryConfig::default()
+    };
+    assert_eq!(
+        article().summarize_with(&anonymous),
+        "Penguins win the Stanley Cup Championship! (Pittsburgh, PA, USA)"
+    );
+    assert_eq!(
+        tweet().summarize_with(&anonymous),
+        "of course, as you probably already know, people"
+    );
+    assert_eq!(video_clip().summarize_with(&anonymous), "Crab rave (3:05)");
+
+    let short = SummaryConfig {
+        max_len: Some(16),
+        show_author: true,
+        ellipsis: String::from("..."),
+    };
+    assert_eq!(tweet().summarize_with(&short), "horse_ebooks:...");
+    assert_eq!(podcast().summarize_with(&short).chars().count(), 16);
+
+    /* Collections and trait objects get it through the default, too. */
+    let item: Box<dyn Summary> = Box::new(blog_post());
+    assert_eq!(
+        item.summarize_with(&anonymous),
+        "Why I like trait objects [rust, traits]"
+    );
+    assert_eq!(preview::truncate_with("abcdef", 2, "..."), "..");
+}
@@ -0,0 +1,172 @@
+/* A Timeline of Mixed Content */
+/* Generic functions like 'notify2<T: Summary>' are monomorphized: the
+ * compiler writes a copy for each concrete type, so every 'T' has to be
+ * known at compile time and a 'Vec<T>' can only hold one type. To keep
+ * tweets and articles in the same collection we need 'trait objects'
+ * instead. A 'Box<dyn Summary>' is a pointer to some value that implements
+ * 'Summary' plus a table of its methods (the 'vtable'), and calls through
+ * it are looked up at runtime - 'dynamic dispatch'.
+ *
+ * 'push' is still generic so callers can hand over a plain 'Tweet' or
+ * 'NewsArticle' and we do the boxing. The ''static' bound says the item
+ * can't contain borrowed references, because the timeline owns it for as
+ * long as the timeline lives. */
+use std::collections::HashSet;
+use std::ops::{Add, AddAssign};
+use std::time::SystemTime;
+#[cfg(feature = "persistence")]
+use std::{fs, path::Path};
+
+use crate::pages::Pages;
+#[cfg(feature = "persistence")]
+use crate::persistence::{self, TimelineError};
+use crate::{dedupe, search, Summary};
+
+/* A 'Box<dyn Summary>' doesn't implement 'Summary' on its own, so code
+ * that's generic over 'T: Summary' (like 'Pages') couldn't accept the
+ * timeline's items. This blanket implementation fixes that for every box
+ * of something summarizable, trait objects included ('?Sized' allows
+ * 'dyn Summary', which has no size known at compile time). */
+impl<T: Summary + ?Sized> Summary for Box<T> {
+    fn summarize(&self) -> String {
+        (**self).summarize()
+    }
+
+    fn published(&self) -> Option<SystemTime> {
+        (**self).published()
+    }
+
+    fn summarize_without_author(&self) -> String {
+        (**self).summarize_without_author()
+    }
+
+    #[cfg(feature = "persistence")]
+    fn as_record(&self) -> Option<String> {
+        (**self).as_record()
+    }
+}
+
+#[derive(Default)]
+pub struct Timeline {
+    items: Vec<Box<dyn Summary>>,
+}
+
+impl Timeline {
+    pub fn new() -> Timeline {
+        Timeline { items: Vec::new() }
+    }
+
+    pub fn push(&mut self, item: impl Summary + 'static) {
+        self.items.push(Box::new(item));
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Summary> {
+        self.items.iter().map(|item| item.as_ref())
+    }
+
+    pub fn iter_summaries(&self) -> impl Iterator<Item = String> + '_ {
+        self.iter().map(|item| item.summarize())
+    }
+
+    /* Positions of the items whose summaries match 'query'; see the
+     * 'search' module for how matching works. */
+    pub fn search(&self, query: &str) -> Vec<usize> {
+        self.iter_summaries()
+            .enumerate()
+            .filter(|(_, summary)| search::matches(summary, query))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    pub fn pages(&self, page_size: usize) -> Pages<'_, Box<dyn Summary>> {
+        Pages::new(&self.items, page_size)
+    }
+
+    /* One numbered line per item, oldest first. */
+    pub fn render(&self) -> String {
+        self.iter_summaries()
+            .enumerate()
+            .map(|(i, summary)| format!("{}. {summary}", i + 1))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/* Merging Timelines */
+/* Overloading '+' is a matter of implementing 'std::ops::Add', and '+='
+ * is 'AddAssign'. 'feed_a + feed_b' takes both timelines by value - the
+ * boxes move into the result, nothing is copied - and 'a += b' moves 'b'
+ * into 'a'.
+ *
+ * Two timelines fetched a few minutes apart mostly overlap, so merging
+ * drops an item when one with the same summary (compared the way 'dedupe'
+ * compares text) and the same publication time is already there; the
+ * first copy wins. What's left is put in order of publication, oldest
+ * first. Undated items go at the front, and the sort is stable, so items
+ * with equal times keep the order they had. */
+impl AddAssign for Timeline {
+    fn add_assign(&mut self, other: Timeline) {
+        let mut seen: HashSet<(String, Option<SystemTime>)> = HashSet::new();
+        self.items.extend(other.items);
+        self.items
+            .retain(|item| seen.insert((dedupe::normalize(&item.summarize()), item.published())));
+        self.items.sort_by_key(|item| item.published());
+    }
+}
+
+impl Add for Timeline {
+    type Output = Timeline;
+
+    fn add(mut self, other: Timeline) -> Timeline {
+        self += other;
+        self
+    }
+}
+
+/* Saving and Loading */
+/* A saved timeline is just its items' records, one per line and oldest
+ * first, so the file can be read, diffed, or appended to by hand. Only
+ * types that override 'Summary::as_record' can be saved; anything else
+ * fails the whole save rather than silently going missing from the file.
+ * 'load' skips blank lines and reports the (1-based) line number of the
+ * first record it can't read. */
+#[cfg(feature = "persistence")]
+impl Timeline {
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), TimelineError> {
+        let mut contents = String::new();
+        for (position, item) in self.items.iter().enumerate() {
+            let record = item
+                .as_record()
+                .ok_or_else(|| TimelineError::NotPersistable {
+                    position,
+                    summary: item.summarize(),
+                })?;
+            contents.push_str(&record);
+            contents.push('\n');
+        }
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Timeline, TimelineError> {
+        let contents = fs::read_to_string(path)?;
+        let mut timeline = Timeline::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let item = persistence::load_record(line)
+                .map_err(|error| TimelineError::Record { line: i + 1, error })?;
+            timeline.items.push(item);
+        }
+        Ok(timeline)
+    }
+}
@@ -0,0 +1,203 @@
+/* A Minimal Executor */
+/* 'block_on' runs one future to completion on the current thread. It
+ * polls the future; if the future isn't ready, the thread parks itself
+ * until the future's waker unparks it, then polls again. The waker is
+ * built with the standard library's 'Wake' trait, which only needs a
+ * 'wake' method and gives us a 'Waker' from an 'Arc' of our type.
+ *
+ * Everything else in this file is a 'combinator': a future built out of
+ * other futures. None of them need their own threads; they simply poll
+ * their inner futures whenever they themselves are polled. */
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    /* 'pin!' fixes the future in place on this stack frame. Futures
+     * produced by 'async' blocks may hold references into themselves, so
+     * they must not move once they've been polled. */
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            /* A spurious wake-up only costs an extra poll, so there's no
+             * need to track whether 'wake' was really called. */
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/* A future that has finished keeps its output here until every future in
+ * the combinator is done. */
+enum MaybeDone<F: Future> {
+    Pending(F),
+    Done(F::Output),
+    Taken,
+}
+
+impl<F: Future> MaybeDone<F> {
+    /* Returns true once the inner future has produced its output. */
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool {
+        // SAFETY: we never move the inner future out of the pinned 'self'
+        let this = unsafe { self.get_unchecked_mut() };
+        match this {
+            MaybeDone::Pending(future) => {
+                let future = unsafe { Pin::new_unchecked(future) };
+                match future.poll(cx) {
+                    Poll::Ready(output) => {
+                        *this = MaybeDone::Done(output);
+                        true
+                    }
+                    Poll::Pending => false,
+                }
+            }
+            MaybeDone::Done(_) => true,
+            MaybeDone::Taken => panic!("MaybeDone polled after its output was taken"),
+        }
+    }
+
+    fn take(self: Pin<&mut Self>) -> F::Output {
+        // SAFETY: the future has completed, so there is nothing pinned left
+        let this = unsafe { self.get_unchecked_mut() };
+        match std::mem::replace(this, MaybeDone::Taken) {
+            MaybeDone::Done(output) => output,
+            _ => panic!("MaybeDone::take called before completion"),
+        }
+    }
+}
+
+/* 'join' waits for both futures and returns both outputs. */
+pub fn join<A: Future, B: Future>(a: A, b: B) -> Join<A, B> {
+    Join {
+        a: MaybeDone::Pending(a),
+        b: MaybeDone::Pending(b),
+    }
+}
+
+pub struct Join<A: Future, B: Future> {
+    a: MaybeDone<A>,
+    b: MaybeDone<B>,
+}
+
+impl<A: Future, B: Future> Future for Join<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: 'a' and 'b' are structurally pinned and never moved
+        let this = unsafe { self.get_unchecked_mut() };
+        let mut a = unsafe { Pin::new_unchecked(&mut this.a) };
+        let mut b = unsafe { Pin::new_unchecked(&mut this.b) };
+
+        let a_done = a.as_mut().poll(cx);
+        let b_done = b.as_mut().poll(cx);
+        if a_done && b_done {
+            Poll::Ready((a.take(), b.take()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/* 'join_all' is 'join' for any number of futures of the same type. The
+ * futures are boxed and pinned on the heap so that the vector can hold
+ * them without caring whether they move. */
+pub fn join_all<F: Future>(futures: Vec<F>) -> JoinAll<F> {
+    JoinAll {
+        futures: futures
+            .into_iter()
+            .map(|f| Box::pin(MaybeDone::Pending(f)))
+            .collect(),
+    }
+}
+
+pub struct JoinAll<F: Future> {
+    futures: Vec<Pin<Box<MaybeDone<F>>>>,
+}
+
+impl<F: Future> Future for JoinAll<F> {
+    type Output = Vec<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut all_done = true;
+        for future in self.futures.iter_mut() {
+            all_done &= future.as_mut().poll(cx);
+        }
+        if all_done {
+            Poll::Ready(self.futures.iter_mut().map(|f| f.as_mut().take()).collect())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/* 'select' races two futures and resolves with whichever finishes first.
+ * If both are ready on the same poll, the left one wins. */
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+pub fn select<A: Future, B: Future>(a: A, b: B) -> Select<A, B> {
+    Select { a, b }
+}
+
+pub struct Select<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: Future, B: Future> Future for Select<A, B> {
+    type Output = Either<A::Output, B::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: 'a' and 'b' are structurally pinned and never moved
+        let this = unsafe { self.get_unchecked_mut() };
+        let a = unsafe { Pin::new_unchecked(&mut this.a) };
+        if let Poll::Ready(output) = a.poll(cx) {
+            return Poll::Ready(Either::Left(output));
+        }
+        let b = unsafe { Pin::new_unchecked(&mut this.b) };
+        if let Poll::Ready(output) = b.poll(cx) {
+            return Poll::Ready(Either::Right(output));
+        }
+        Poll::Pending
+    }
+}
+
+/* 'yield_now' returns 'Pending' exactly once, waking itself straight
+ * away, so the executor gets a chance to poll other futures before this
+ * one continues. */
+pub fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+pub struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            self.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,73 @@
+/* ASYNC AND AWAIT */
+/* Threads let the operating system decide when each piece of work runs.
+ * 'async' Rust hands that job to our own code instead: an 'async fn'
+ * returns a 'future', a value that represents work which may not be
+ * finished yet, and nothing happens until something 'polls' it. Each call
+ * to 'poll' runs the future until it either finishes ('Poll::Ready') or has
+ * to wait for something ('Poll::Pending'). A future that returns 'Pending'
+ * promises to call the 'Waker' it was given once it's worth polling again.
+ *
+ * The thing that polls futures is called an 'executor' or 'runtime'. The
+ * standard library doesn't ship one - real programs usually pull in a
+ * crate like 'tokio' - but the pieces are small enough to write ourselves,
+ * which is what the 'executor' and 'time' modules do. With those in hand,
+ * this file shows what 'async' code looks like on top of them. */
+pub mod executor;
+pub mod time;
+
+use std::time::Duration;
+
+pub use executor::{block_on, join, join_all, select, yield_now, Either};
+pub use time::{sleep, timeout, Elapsed};
+
+/* Futures and the Async Syntax */
+/* Inside an 'async fn', '.await' on another future pauses this one until
+ * the other is ready, handing control back to the executor in the
+ * meantime. Written this way, code that waits on several things in order
+ * reads just like ordinary sequential code. */
+pub async fn fetch_title(url: &str, latency: Duration) -> String {
+    sleep(latency).await;
+    format!("<title of {url}>")
+}
+
+/* Concurrency with Async */
+/* Awaiting two futures one after the other takes as long as both together.
+ * 'join' polls both in turn, so their waiting overlaps and the total is
+ * roughly the longer of the two. Both results come back as a tuple in the
+ * order the futures were given, regardless of which finished first. */
+pub async fn fetch_both(first: Duration, second: Duration) -> (String, String) {
+    join(
+        fetch_title("https://rust-lang.org", first),
+        fetch_title("https://crates.io", second),
+    )
+    .await
+}
+
+/* Racing futures keeps only whichever finishes first and drops the other,
+ * which cancels it: a future that is never polled again never does any
+ * more work. */
+pub async fn fastest(first: Duration, second: Duration) -> String {
+    match select(
+        fetch_title("https://rust-lang.org", first),
+        fetch_title("https://crates.io", second),
+    )
+    .await
+    {
+        Either::Left(title) | Either::Right(title) => title,
+    }
+}
+
+/* Yielding Control to the Runtime */
+/* Futures only give the executor a chance to run something else at an
+ * 'await' point. A long-running loop with no 'await' in it hogs the
+ * executor, so 'count_to' yields after every step. Joining two of these
+ * interleaves their output, which 'log' records. */
+pub async fn count_to(name: &str, n: u32, log: &std::cell::RefCell<Vec<String>>) {
+    for i in 1..=n {
+        log.borrow_mut().push(format!("{name} {i}"));
+        yield_now().await;
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,26 @@
+use async_examples::{block_on, count_to, fastest, fetch_both, fetch_title, join, timeout};
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+fn main() {
+    /* 'main' itself can't be 'async': something has to run the outermost
+     * future, and that's the job of 'block_on'. */
+    block_on(async {
+        let start = Instant::now();
+        let (a, b) = fetch_both(Duration::from_millis(300), Duration::from_millis(200)).await;
+        println!("{a} and {b} in {:?}", start.elapsed());
+
+        let winner = fastest(Duration::from_millis(300), Duration::from_millis(100)).await;
+        println!("{winner} won the race");
+
+        let slow = fetch_title("https://example.com", Duration::from_secs(2));
+        match timeout(Duration::from_millis(250), slow).await {
+            Ok(title) => println!("Got {title}"),
+            Err(e) => println!("Gave up: {e}"),
+        }
+
+        let log = RefCell::new(Vec::new());
+        join(count_to("a", 3, &log), count_to("b", 3, &log)).await;
+        println!("Interleaved: {:?}", log.borrow());
+    });
+}
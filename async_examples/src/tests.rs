@@ -0,0 +1,88 @@
+use crate::*;
+use std::cell::RefCell;
+use std::time::Duration;
+
+#[test]
+fn test_block_on_ready_future() {
+    assert_eq!(block_on(async { 1 + 2 }), 3);
+}
+
+/* These tests check what happens in what order rather than how long it
+ * takes, since a busy machine can make anything take longer. */
+#[test]
+fn test_sleep_waits() {
+    let log = RefCell::new(Vec::new());
+    let sleeper = async {
+        sleep(Duration::from_millis(50)).await;
+        log.borrow_mut().push(String::from("slept"));
+    };
+    block_on(join(sleeper, count_to("b", 2, &log)));
+    assert_eq!(*log.borrow(), vec!["b 1", "b 2", "slept"]);
+}
+
+#[test]
+fn test_join_runs_concurrently() {
+    let log = RefCell::new(Vec::new());
+    let fetch = |url| {
+        let log = &log;
+        async move {
+            log.borrow_mut().push(format!("start {url}"));
+            let title = fetch_title(url, Duration::from_millis(20)).await;
+            log.borrow_mut().push(format!("done {url}"));
+            title
+        }
+    };
+    block_on(join(fetch("a"), fetch("b")));
+    assert_eq!(&log.borrow()[..2], ["start a", "start b"]);
+
+    let (a, b) = block_on(fetch_both(
+        Duration::from_millis(20),
+        Duration::from_millis(10),
+    ));
+    assert_eq!(a, "<title of https://rust-lang.org>");
+    assert_eq!(b, "<title of https://crates.io>");
+}
+
+#[test]
+fn test_join_all_keeps_order() {
+    let futures = vec![
+        fetch_title("c", Duration::from_millis(30)),
+        fetch_title("a", Duration::from_millis(10)),
+        fetch_title("b", Duration::from_millis(20)),
+    ];
+    assert_eq!(
+        block_on(join_all(futures)),
+        vec!["<title of c>", "<title of a>", "<title of b>"]
+    );
+}
+
+#[test]
+fn test_select_picks_fastest() {
+    let title = block_on(fastest(
+        Duration::from_millis(200),
+        Duration::from_millis(10),
+    ));
+    assert_eq!(title, "<title of https://crates.io>");
+}
+
+#[test]
+fn test_timeout() {
+    let ok = block_on(timeout(Duration::from_millis(200), async { 5 }));
+    assert_eq!(ok, Ok(5));
+
+    let finished = RefCell::new(false);
+    let slow = async {
+        sleep(Duration::from_secs(5)).await;
+        *finished.borrow_mut() = true;
+    };
+    let result = block_on(timeout(Duration::from_millis(20), slow));
+    assert_eq!(result, Err(Elapsed(Duration::from_millis(20))));
+    assert!(!*finished.borrow());
+}
+
+#[test]
+fn test_yield_interleaves() {
+    let log = RefCell::new(Vec::new());
+    block_on(join(count_to("a", 2, &log), count_to("b", 2, &log)));
+    assert_eq!(*log.borrow(), vec!["a 1", "b 1", "a 2", "b 2"]);
+}
@@ -0,0 +1,95 @@
+/* Timers */
+/* A runtime like 'tokio' keeps one timer thread or uses the operating
+ * system's event queue for all of its timers. Ours is simpler: the first
+ * time a 'Sleep' is polled it spawns a thread that sleeps for the
+ * remaining time, marks the timer as fired, and calls the most recent
+ * waker it was given. Later polls only check the flag and update the
+ * stored waker, because the future may have been moved to a different
+ * task since the last poll. */
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::executor::{select, Either};
+
+struct TimerState {
+    fired: bool,
+    waker: Option<Waker>,
+}
+
+pub struct Sleep {
+    deadline: Instant,
+    state: Option<Arc<Mutex<TimerState>>>,
+}
+
+pub fn sleep(duration: Duration) -> Sleep {
+    Sleep {
+        deadline: Instant::now() + duration,
+        state: None,
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+
+        match &self.state {
+            Some(state) => {
+                let mut state = state.lock().unwrap();
+                if state.fired {
+                    return Poll::Ready(());
+                }
+                state.waker = Some(cx.waker().clone());
+            }
+            None => {
+                let state = Arc::new(Mutex::new(TimerState {
+                    fired: false,
+                    waker: Some(cx.waker().clone()),
+                }));
+                let thread_state = Arc::clone(&state);
+                let remaining = self.deadline.saturating_duration_since(Instant::now());
+                thread::spawn(move || {
+                    thread::sleep(remaining);
+                    let mut state = thread_state.lock().unwrap();
+                    state.fired = true;
+                    if let Some(waker) = state.waker.take() {
+                        waker.wake();
+                    }
+                });
+                self.state = Some(state);
+            }
+        }
+        Poll::Pending
+    }
+}
+
+/* Building Our Own Async Abstractions */
+/* A timeout is just a race between the future we care about and a timer.
+ * If the timer wins, the other future is dropped - and with it all the
+ * work it hadn't done yet - and the caller gets an 'Elapsed' error saying
+ * how long it waited. */
+#[derive(Debug, PartialEq)]
+pub struct Elapsed(pub Duration);
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "timed out after {:?}", self.0)
+    }
+}
+
+impl std::error::Error for Elapsed {}
+
+pub async fn timeout<F: Future>(max: Duration, future: F) -> Result<F::Output, Elapsed> {
+    match select(future, sleep(max)).await {
+        Either::Left(output) => Ok(output),
+        Either::Right(()) => Err(Elapsed(max)),
+    }
+}
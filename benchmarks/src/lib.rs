@@ -0,0 +1,279 @@
+/* BENCHMARKING THE COMMON COLLECTIONS */
+/* The collections chapters make claims like "a 'Vec' stores its values
+ * next to each other in memory" and "a 'HashMap' looks values up by
+ * hashing the key", and leave the performance consequences to intuition.
+ * This crate puts numbers on them. Each comparison runs the same workload
+ * against two or more ways of doing it, times every version with
+ * 'std::time::Instant', and reports how much slower each one was than the
+ * fastest.
+ *
+ * These are rough measurements, not a replacement for a real benchmarking
+ * tool: there's no statistical analysis and the numbers move around with
+ * whatever else the machine is doing. They are still good enough to show
+ * the differences that matter, as long as the binary is built with
+ * optimizations ('cargo run --release'). */
+use std::collections::{BTreeMap, HashMap, LinkedList};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/* Timing a Closure */
+/* 'measure' calls the closure once to warm up (so the first run doesn't
+ * pay for cold caches on its own), then 'runs' more times under the clock.
+ * Every result goes through 'black_box', which stops the optimizer from
+ * noticing that we never use the value and deleting the work we're trying
+ * to time. */
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub name: String,
+    pub runs: u32,
+    pub total: Duration,
+}
+
+impl Measurement {
+    pub fn per_run(&self) -> Duration {
+        self.total / self.runs
+    }
+}
+
+pub fn measure<T, F: FnMut() -> T>(name: &str, runs: u32, mut f: F) -> Measurement {
+    assert!(runs > 0, "a measurement needs at least one run");
+
+    black_box(f());
+    let start = Instant::now();
+    for _ in 0..runs {
+        black_box(f());
+    }
+
+    Measurement {
+        name: name.to_string(),
+        runs,
+        total: start.elapsed(),
+    }
+}
+
+/* Comparisons and Reports */
+/* A 'Comparison' groups the measurements of one workload, and a 'Report'
+ * groups the comparisons. The report prints as a table for people and as
+ * CSV for anything that wants to keep the numbers around. */
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub title: String,
+    pub size: usize,
+    pub measurements: Vec<Measurement>,
+}
+
+impl Comparison {
+    pub fn fastest(&self) -> Option<&Measurement> {
+        self.measurements.iter().min_by_key(|m| m.per_run())
+    }
+
+    /* How many times slower 'm' was than the fastest measurement, so the
+     * fastest itself is always 1.0. */
+    pub fn relative(&self, m: &Measurement) -> f64 {
+        match self.fastest() {
+            Some(fastest) if !fastest.per_run().is_zero() => {
+                m.per_run().as_secs_f64() / fastest.per_run().as_secs_f64()
+            }
+            _ => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub comparisons: Vec<Comparison>,
+}
+
+impl Report {
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("comparison,name,size,runs,total_ns,per_run_ns,relative\n");
+        for comparison in &self.comparisons {
+            for m in &comparison.measurements {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{},{:.2}\n",
+                    comparison.title,
+                    m.name,
+                    comparison.size,
+                    m.runs,
+                    m.total.as_nanos(),
+                    m.per_run().as_nanos(),
+                    comparison.relative(m)
+                ));
+            }
+        }
+        csv
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, comparison) in self.comparisons.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{} (n = {})", comparison.title, comparison.size)?;
+            for m in &comparison.measurements {
+                writeln!(
+                    f,
+                    "  {:<28} {:>14} per run  {:>6.2}x",
+                    m.name,
+                    format!("{:?}", m.per_run()),
+                    comparison.relative(m)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* Vec vs LinkedList */
+/* Both push to the back in constant time, but a 'LinkedList' allocates a
+ * separate node for every element while a 'Vec' only reallocates when it
+ * runs out of room, doubling its capacity each time. Reserving the
+ * capacity up front with 'Vec::with_capacity' removes even those. */
+pub fn push_vec(n: usize) -> Vec<usize> {
+    let mut v = Vec::new();
+    for i in 0..n {
+        v.push(i);
+    }
+    v
+}
+
+pub fn push_vec_with_capacity(n: usize) -> Vec<usize> {
+    let mut v = Vec::with_capacity(n);
+    for i in 0..n {
+        v.push(i);
+    }
+    v
+}
+
+pub fn push_linked_list(n: usize) -> LinkedList<usize> {
+    let mut list = LinkedList::new();
+    for i in 0..n {
+        list.push_back(i);
+    }
+    list
+}
+
+pub fn vec_vs_linked_list(size: usize, runs: u32) -> Comparison {
+    Comparison {
+        title: String::from("push to the back"),
+        size,
+        measurements: vec![
+            measure("Vec::push", runs, || push_vec(size)),
+            measure("Vec::with_capacity + push", runs, || {
+                push_vec_with_capacity(size)
+            }),
+            measure("LinkedList::push_back", runs, || push_linked_list(size)),
+        ],
+    }
+}
+
+/* HashMap vs BTreeMap */
+/* A 'HashMap' finds a key by hashing it; a 'BTreeMap' keeps its keys
+ * sorted and searches for them. Hashing should win for lookups, but the
+ * default hasher (SipHash) is built to resist denial-of-service attacks
+ * rather than for speed, so the gap is smaller than you might expect. The
+ * maps are built once, outside the timed closures, so only the lookups
+ * are measured. */
+pub fn hash_map_of(n: usize) -> HashMap<usize, usize> {
+    (0..n).map(|i| (i, i * 2)).collect()
+}
+
+pub fn btree_map_of(n: usize) -> BTreeMap<usize, usize> {
+    (0..n).map(|i| (i, i * 2)).collect()
+}
+
+pub fn sum_hash_map_lookups(map: &HashMap<usize, usize>, n: usize) -> usize {
+    (0..n).filter_map(|k| map.get(&k)).sum()
+}
+
+pub fn sum_btree_map_lookups(map: &BTreeMap<usize, usize>, n: usize) -> usize {
+    (0..n).filter_map(|k| map.get(&k)).sum()
+}
+
+pub fn hashmap_vs_btreemap(size: usize, runs: u32) -> Comparison {
+    let hash_map = hash_map_of(size);
+    let btree_map = btree_map_of(size);
+
+    Comparison {
+        title: String::from("look up every key"),
+        size,
+        measurements: vec![
+            measure("HashMap::get", runs, || {
+                sum_hash_map_lookups(&hash_map, size)
+            }),
+            measure("BTreeMap::get", runs, || {
+                sum_btree_map_lookups(&btree_map, size)
+            }),
+        ],
+    }
+}
+
+/* String Concatenation */
+/* The strings chapter shows several ways to build a 'String' out of
+ * pieces. 'push_str' appends in place; '+' does too, but takes ownership
+ * of the left-hand side and hands it back; 'format!' builds a brand new
+ * 'String' every time it's called; and 'concat' on a slice works out the
+ * total length first, so it allocates exactly once. All four build the
+ * same string. */
+pub fn words(n: usize) -> Vec<String> {
+    (0..n).map(|i| format!("word{i} ")).collect()
+}
+
+pub fn concat_push_str(words: &[String]) -> String {
+    let mut s = String::new();
+    for word in words {
+        s.push_str(word);
+    }
+    s
+}
+
+pub fn concat_plus(words: &[String]) -> String {
+    let mut s = String::new();
+    for word in words {
+        s = s + word;
+    }
+    s
+}
+
+pub fn concat_format(words: &[String]) -> String {
+    let mut s = String::new();
+    for word in words {
+        s = format!("{s}{word}");
+    }
+    s
+}
+
+pub fn concat_slice(words: &[String]) -> String {
+    words.concat()
+}
+
+pub fn string_concatenation(size: usize, runs: u32) -> Comparison {
+    let words = words(size);
+
+    Comparison {
+        title: String::from("concatenate words"),
+        size,
+        measurements: vec![
+            measure("String::push_str", runs, || concat_push_str(&words)),
+            measure("+ operator", runs, || concat_plus(&words)),
+            measure("format!", runs, || concat_format(&words)),
+            measure("[String]::concat", runs, || concat_slice(&words)),
+        ],
+    }
+}
+
+pub fn run_all(size: usize, runs: u32) -> Report {
+    Report {
+        comparisons: vec![
+            vec_vs_linked_list(size, runs),
+            hashmap_vs_btreemap(size, runs),
+            string_concatenation(size, runs),
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests;
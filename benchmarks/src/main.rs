@@ -0,0 +1,43 @@
+use std::env;
+use std::process;
+
+use benchmarks::run_all;
+
+/* Usage: benchmarks [--csv] [size] [runs]
+ *
+ * Build with '--release' for meaningful numbers. 'format!' concatenation
+ * is quadratic, so very large sizes take a while. */
+fn main() {
+    let mut csv = false;
+    let mut numbers = Vec::new();
+    for arg in env::args().skip(1) {
+        if arg == "--csv" {
+            csv = true;
+        } else {
+            match arg.parse::<usize>() {
+                Ok(n) if n > 0 => numbers.push(n),
+                _ => {
+                    eprintln!("Expected a positive number, got '{arg}'");
+                    process::exit(1);
+                }
+            }
+        }
+    }
+
+    let size = numbers.first().copied().unwrap_or(10_000);
+    let runs = match u32::try_from(numbers.get(1).copied().unwrap_or(20)) {
+        Ok(runs) => runs,
+        Err(_) => {
+            eprintln!("Usage: benchmarks [--csv] [size] [runs]");
+            eprintln!("'runs' can be at most {}", u32::MAX);
+            process::exit(1);
+        }
+    };
+
+    let report = run_all(size, runs);
+    if csv {
+        print!("{}", report.to_csv());
+    } else {
+        print!("{report}");
+    }
+}
@@ -0,0 +1,72 @@
+use crate::*;
+
+#[test]
+fn test_workloads_agree() {
+    assert_eq!(push_vec(100), push_vec_with_capacity(100));
+    assert!(push_vec(100).iter().eq(push_linked_list(100).iter()));
+
+    let expected: usize = (0..100).map(|i| i * 2).sum();
+    assert_eq!(sum_hash_map_lookups(&hash_map_of(100), 100), expected);
+    assert_eq!(sum_btree_map_lookups(&btree_map_of(100), 100), expected);
+}
+
+#[test]
+fn test_concatenation_strategies_agree() {
+    let words = words(50);
+    let expected = concat_push_str(&words);
+    assert!(expected.starts_with("word0 word1 "));
+    assert_eq!(concat_plus(&words), expected);
+    assert_eq!(concat_format(&words), expected);
+    assert_eq!(concat_slice(&words), expected);
+}
+
+#[test]
+fn test_measure_counts_runs() {
+    let mut calls = 0;
+    let m = measure("count", 5, || calls += 1);
+    assert_eq!(m.runs, 5);
+    /* One warm-up call plus the timed runs. */
+    assert_eq!(calls, 6);
+}
+
+#[test]
+fn test_relative_to_fastest() {
+    let comparison = Comparison {
+        title: String::from("made up"),
+        size: 1,
+        measurements: vec![
+            Measurement {
+                name: String::from("slow"),
+                runs: 2,
+                total: Duration::from_millis(60),
+            },
+            Measurement {
+                name: String::from("fast"),
+                runs: 2,
+                total: Duration::from_millis(20),
+            },
+        ],
+    };
+    assert_eq!(comparison.fastest().unwrap().name, "fast");
+    assert_eq!(comparison.relative(&comparison.measurements[0]), 3.0);
+    assert_eq!(comparison.relative(&comparison.measurements[1]), 1.0);
+}
+
+#[test]
+fn test_report_formats() {
+    let report = run_all(20, 1);
+    assert_eq!(report.comparisons.len(), 3);
+
+    let table = report.to_string();
+    assert!(table.contains("push to the back (n = 20)"));
+    assert!(table.contains("BTreeMap::get"));
+
+    let csv = report.to_csv();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("comparison,name,size,runs,total_ns,per_run_ns,relative")
+    );
+    assert_eq!(lines.count(), 9);
+    assert!(csv.lines().all(|line| line.split(',').count() == 7));
+}
@@ -0,0 +1,200 @@
+/* IMPLEMENTING AN OBJECT-ORIENTED DESIGN PATTERN */
+/* The 'state pattern' is an object-oriented design pattern. The crux of the
+ * pattern is that we define a set of states a value can have internally.
+ * The states are represented by a set of 'state objects', and the value's
+ * behavior changes based on its state.
+ *
+ * We'll implement a blog post workflow:
+ *
+ *  1. A blog post starts as an empty draft.
+ *  2. When the draft is done, a review of the post is requested.
+ *  3. When the post is approved, it gets published.
+ *  4. Only published blog posts return content to print, so unapproved
+ *     posts can't accidentally be published.
+ *
+ * On top of the book's version, a reviewer can 'reject' a post under
+ * review, which sends it back to draft, and a post needs two separate
+ * approvals before it is published.
+ *
+ * This file uses trait objects for the states. 'typed.rs' encodes the same
+ * workflow as different types instead, so the compiler rejects invalid
+ * transitions. */
+pub mod typed;
+
+/* The number of 'approve' calls a post under review needs before it is
+ * published. */
+pub const APPROVALS_REQUIRED: u32 = 2;
+
+pub struct Post {
+    state: Option<Box<dyn State>>,
+    content: String,
+}
+
+impl Post {
+    pub fn new() -> Post {
+        Post {
+            state: Some(Box::new(Draft {})),
+            content: String::new(),
+        }
+    }
+
+    /* Adding text is only allowed while the post is a draft. The state
+     * decides, so 'Post' doesn't have to 'match' on anything. */
+    pub fn add_text(&mut self, text: &str) {
+        if self.state.as_ref().unwrap().can_edit() {
+            self.content.push_str(text);
+        }
+    }
+
+    /* We call 'as_ref' on the 'Option' because we want a reference to the
+     * value inside rather than ownership of it. The 'unwrap' can never
+     * panic: the methods on 'Post' always put a state back in 'state'
+     * before they return. */
+    pub fn content(&self) -> &str {
+        self.state.as_ref().unwrap().content(self)
+    }
+
+    pub fn status(&self) -> &'static str {
+        self.state.as_ref().unwrap().status()
+    }
+
+    /* To consume the old state, each transition takes ownership of the
+     * state value. This is where the 'Option' in the 'state' field comes
+     * in: 'take' moves the 'Some' value out and leaves a 'None' in its
+     * place, because Rust doesn't let us have unpopulated fields in
+     * structs. */
+    pub fn request_review(&mut self) {
+        if let Some(s) = self.state.take() {
+            self.state = Some(s.request_review())
+        }
+    }
+
+    pub fn approve(&mut self) {
+        if let Some(s) = self.state.take() {
+            self.state = Some(s.approve())
+        }
+    }
+
+    pub fn reject(&mut self) {
+        if let Some(s) = self.state.take() {
+            self.state = Some(s.reject())
+        }
+    }
+}
+
+impl Default for Post {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/* The 'State' trait defines the behavior shared by the different post
+ * states. 'self: Box<Self>' means the method is only valid when called on
+ * a 'Box' holding the type, and takes ownership of that box, invalidating
+ * the old state so the state value of the 'Post' can transform into a new
+ * state. */
+trait State {
+    fn request_review(self: Box<Self>) -> Box<dyn State>;
+    fn approve(self: Box<Self>) -> Box<dyn State>;
+    fn reject(self: Box<Self>) -> Box<dyn State>;
+    fn status(&self) -> &'static str;
+
+    /* Default implementations: most states have no content to show and
+     * can't be edited. */
+    fn content<'a>(&self, _post: &'a Post) -> &'a str {
+        ""
+    }
+
+    fn can_edit(&self) -> bool {
+        false
+    }
+}
+
+struct Draft {}
+
+impl State for Draft {
+    fn request_review(self: Box<Self>) -> Box<dyn State> {
+        Box::new(PendingReview { approvals: 0 })
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn status(&self) -> &'static str {
+        "draft"
+    }
+
+    fn can_edit(&self) -> bool {
+        true
+    }
+}
+
+/* 'PendingReview' counts the approvals it has received so far; only once
+ * it reaches 'APPROVALS_REQUIRED' does it turn into 'Published'. Rejecting
+ * throws the approvals away along with the review. */
+struct PendingReview {
+    approvals: u32,
+}
+
+impl State for PendingReview {
+    fn request_review(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn State> {
+        let approvals = self.approvals + 1;
+        if approvals >= APPROVALS_REQUIRED {
+            Box::new(Published {})
+        } else {
+            Box::new(PendingReview { approvals })
+        }
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn State> {
+        Box::new(Draft {})
+    }
+
+    fn status(&self) -> &'static str {
+        "pending review"
+    }
+}
+
+struct Published {}
+
+impl State for Published {
+    fn request_review(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn status(&self) -> &'static str {
+        "published"
+    }
+
+    fn content<'a>(&self, post: &'a Post) -> &'a str {
+        &post.content
+    }
+}
+/* Trade-offs of the State Pattern */
+/* The states are responsible for transitioning between each other; the
+ * states in 'Post' never need to be matched on, and adding a new state only
+ * means adding a new struct and implementing 'State' on it. The downside is
+ * that because the states implement the transitions, some of the states
+ * are coupled to each other, and there's a fair amount of duplicated logic:
+ * the 'request_review', 'approve', and 'reject' implementations that return
+ * 'self' are nearly identical. */
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,28 @@
+use blog::{typed, Post};
+
+fn main() {
+    let mut post = Post::new();
+
+    post.add_text("I ate a salad for lunch today");
+    println!("[{}] content: {:?}", post.status(), post.content());
+
+    post.request_review();
+    post.reject();
+    println!("[{}] after rejection", post.status());
+
+    post.request_review();
+    post.approve();
+    println!("[{}] content: {:?}", post.status(), post.content());
+
+    post.approve();
+    println!("[{}] content: {:?}", post.status(), post.content());
+
+    /* The same workflow with the states encoded as types. Each transition
+     * returns a new value, so 'post' is shadowed at every step. */
+    let mut post = typed::Post::new();
+    post.add_text("I ate a salad for lunch today");
+    let post = post.request_review();
+    let post = post.approve();
+    let post = post.approve();
+    println!("typed post content: {:?}", post.content());
+}
@@ -0,0 +1,71 @@
+use crate::{typed, Post};
+
+#[test]
+fn test_publishing_needs_two_approvals() {
+    let mut post = Post::new();
+
+    post.add_text("I ate a salad for lunch today");
+    assert_eq!("", post.content());
+    assert_eq!(post.status(), "draft");
+
+    post.request_review();
+    assert_eq!("", post.content());
+
+    post.approve();
+    assert_eq!("", post.content());
+    assert_eq!(post.status(), "pending review");
+
+    post.approve();
+    assert_eq!("I ate a salad for lunch today", post.content());
+    assert_eq!(post.status(), "published");
+}
+
+#[test]
+fn test_approve_before_review_does_nothing() {
+    let mut post = Post::new();
+    post.add_text("hi");
+    post.approve();
+    post.approve();
+    assert_eq!(post.status(), "draft");
+}
+
+#[test]
+fn test_reject_returns_to_draft_and_resets_approvals() {
+    let mut post = Post::new();
+    post.add_text("first");
+    post.request_review();
+    post.approve();
+    post.reject();
+    assert_eq!(post.status(), "draft");
+
+    post.add_text(" second");
+    post.request_review();
+    post.approve();
+    assert_eq!(post.status(), "pending review");
+    post.approve();
+    assert_eq!(post.content(), "first second");
+}
+
+#[test]
+fn test_text_only_added_in_draft() {
+    let mut post = Post::new();
+    post.add_text("draft");
+    post.request_review();
+    post.add_text(" too late");
+    post.approve();
+    post.approve();
+    assert_eq!(post.content(), "draft");
+}
+
+#[test]
+fn test_typed_workflow() {
+    let mut post = typed::Post::new();
+    post.add_text("I ate a salad for lunch today");
+
+    let post = post.request_review().approve();
+    let mut post = post.reject();
+    post.add_text(" and dessert");
+
+    let post = post.request_review().approve().approve();
+    assert_eq!(post.content(), "I ate a salad for lunch today and dessert");
+}
@@ -0,0 +1,84 @@
+/* Encoding States and Behavior as Types */
+/* Rather than encapsulating the states and transitions completely so
+ * outside code has no knowledge of them, we can encode the states into
+ * different types. Rust's type checking then prevents attempts to use
+ * draft posts where only published posts are allowed by issuing a
+ * compiler error.
+ *
+ * A 'DraftPost' has no 'content' method at all, so trying to read a
+ * draft's content doesn't compile. Each transition takes 'self' by value
+ * and returns a new type, so the old state can't be used afterwards; the
+ * caller has to rebind the result with 'let post = post.request_review();'.
+ *
+ * The extended review workflow maps onto types too: 'PendingReviewPost'
+ * becomes a 'PartiallyApprovedPost' on its first approval, and only a
+ * second approval produces a 'Post'. Either review state can be rejected
+ * back to a 'DraftPost'. */
+pub struct Post {
+    content: String,
+}
+
+pub struct DraftPost {
+    content: String,
+}
+
+impl Post {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> DraftPost {
+        DraftPost {
+            content: String::new(),
+        }
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+}
+
+impl DraftPost {
+    pub fn add_text(&mut self, text: &str) {
+        self.content.push_str(text);
+    }
+
+    pub fn request_review(self) -> PendingReviewPost {
+        PendingReviewPost {
+            content: self.content,
+        }
+    }
+}
+
+pub struct PendingReviewPost {
+    content: String,
+}
+
+impl PendingReviewPost {
+    pub fn approve(self) -> PartiallyApprovedPost {
+        PartiallyApprovedPost {
+            content: self.content,
+        }
+    }
+
+    pub fn reject(self) -> DraftPost {
+        DraftPost {
+            content: self.content,
+        }
+    }
+}
+
+pub struct PartiallyApprovedPost {
+    content: String,
+}
+
+impl PartiallyApprovedPost {
+    pub fn approve(self) -> Post {
+        Post {
+            content: self.content,
+        }
+    }
+
+    pub fn reject(self) -> DraftPost {
+        DraftPost {
+            content: self.content,
+        }
+    }
+}
@@ -0,0 +1,116 @@
+/* FEARLESS CONCURRENCY */
+/* 'Concurrent programming', where different parts of a program execute
+ * independently, and 'parallel programming', where different parts of a
+ * program execute at the same time, are becoming increasingly important as
+ * more computers take advantage of their multiple processors. By leveraging
+ * ownership and type checking, many concurrency errors are compile-time
+ * errors in Rust rather than runtime errors.
+ *
+ * The book's examples print from each thread, which makes the interleaving
+ * visible but leaves nothing to check. The functions below do the same
+ * work and hand back what each thread produced, so the ordering guarantees
+ * (and lack of them) can be asserted on. 'main.rs' prints the results. */
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/* Using Threads to Run Code Simultaneously */
+/* 'thread::spawn' takes a closure containing the code we want to run in
+ * the new thread and returns a 'JoinHandle'. Calling 'join' on the handle
+ * blocks the current thread until the spawned thread finishes, and gives
+ * back the closure's return value.
+ *
+ * The 'move' keyword forces the closure to take ownership of 'v'. Without
+ * it, Rust can't tell how long the spawned thread will run, so it can't
+ * know whether a borrowed reference to 'v' would stay valid. */
+pub fn spawn_and_join(v: Vec<i32>) -> i32 {
+    let handle = thread::spawn(move || v.iter().sum());
+
+    handle.join().unwrap()
+}
+
+/* Each spawned thread counts to 'count' and returns its labelled steps.
+ * Joining every handle before returning guarantees that none of the
+ * threads are cut off when the main thread finishes. */
+pub fn run_workers(workers: usize, count: usize) -> Vec<Vec<String>> {
+    let handles: Vec<_> = (0..workers)
+        .map(|id| {
+            thread::spawn(move || {
+                (1..=count)
+                    .map(|i| format!("worker {id}: step {i}"))
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    handles.into_iter().map(|h| h.join().unwrap()).collect()
+}
+
+/* Using Message Passing to Transfer Data Between Threads */
+/* "Do not communicate by sharing memory; instead, share memory by
+ * communicating." A 'channel' has two halves: a transmitter and a
+ * receiver. 'mpsc' stands for 'multiple producer, single consumer': we can
+ * clone the transmitter to have several threads send values to the same
+ * receiver.
+ *
+ * Using the receiver as an iterator ends the loop once every transmitter,
+ * including the clones, has been dropped - which happens when each
+ * producer thread finishes. 'send' takes ownership of the value, so a
+ * thread can't use a value after it has sent it. */
+pub fn multi_producer(producers: usize, messages: &[&str]) -> Vec<String> {
+    let (tx, rx) = mpsc::channel();
+    let messages: Vec<String> = messages.iter().map(|m| m.to_string()).collect();
+
+    for id in 0..producers {
+        let tx = tx.clone();
+        let messages = messages.clone();
+        thread::spawn(move || {
+            for message in messages {
+                tx.send(format!("{id}: {message}")).unwrap();
+                thread::sleep(Duration::from_millis(1));
+            }
+        });
+    }
+    /* The original 'tx' must be dropped too, or the receiver would wait
+     * forever for a message that nobody is going to send. */
+    drop(tx);
+
+    rx.iter().collect()
+}
+
+/* Shared-State Concurrency */
+/* A 'mutex' allows only one thread to access some data at any given time.
+ * To access the data, a thread calls 'lock', which returns a 'MutexGuard'
+ * smart pointer; the lock is released automatically when the guard goes
+ * out of scope.
+ *
+ * To give every thread ownership of the same 'Mutex' we need multiple
+ * ownership, but 'Rc<T>' isn't safe to share across threads because its
+ * reference count isn't updated atomically. 'Arc<T>' is the 'atomically
+ * reference counted' equivalent that is. */
+pub fn shared_counter(threads: usize, increments: usize) -> i32 {
+    let counter = Arc::new(Mutex::new(0));
+    let mut handles = vec![];
+
+    for _ in 0..threads {
+        let counter = Arc::clone(&counter);
+        let handle = thread::spawn(move || {
+            for _ in 0..increments {
+                let mut num = counter.lock().unwrap();
+
+                *num += 1;
+            }
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let total = *counter.lock().unwrap();
+    total
+}
+
+#[cfg(test)]
+mod tests;
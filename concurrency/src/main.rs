@@ -0,0 +1,17 @@
+use concurrency::{multi_producer, run_workers, shared_counter, spawn_and_join};
+
+fn main() {
+    println!("Sum computed in a spawned thread: {}", spawn_and_join(vec![1, 2, 3]));
+
+    for steps in run_workers(2, 3) {
+        println!("{steps:?}");
+    }
+
+    /* Run this a few times: the two producers' messages interleave
+     * differently on each run. */
+    for received in multi_producer(2, &["hi", "from", "the", "thread"]) {
+        println!("Got: {received}");
+    }
+
+    println!("Result: {}", shared_counter(10, 1));
+}
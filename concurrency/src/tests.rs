@@ -0,0 +1,39 @@
+use crate::*;
+
+#[test]
+fn test_spawn_and_join() {
+    assert_eq!(spawn_and_join(vec![1, 2, 3]), 6);
+}
+
+#[test]
+fn test_every_worker_finishes() {
+    let results = run_workers(3, 4);
+
+    assert_eq!(results.len(), 3);
+    for (id, steps) in results.iter().enumerate() {
+        assert_eq!(steps.len(), 4);
+        assert_eq!(steps[3], format!("worker {id}: step 4"));
+    }
+}
+
+#[test]
+fn test_multi_producer_receives_everything() {
+    let received = multi_producer(2, &["hi", "from", "the", "thread"]);
+
+    assert_eq!(received.len(), 8);
+    /* Messages from different producers interleave unpredictably, but each
+     * producer's own messages arrive in the order they were sent. */
+    for id in 0..2 {
+        let prefix = format!("{id}: ");
+        let own: Vec<&str> = received
+            .iter()
+            .filter_map(|m| m.strip_prefix(&prefix))
+            .collect();
+        assert_eq!(own, vec!["hi", "from", "the", "thread"]);
+    }
+}
+
+#[test]
+fn test_shared_counter() {
+    assert_eq!(shared_counter(10, 100), 1000);
+}
@@ -0,0 +1,183 @@
+/* Reading a Config File */
+/* A config file is one setting per line:
+ *
+ *     # lines starting with '#' are comments
+ *     name = Ferris
+ *     retries = 3
+ *
+ * Blank lines are skipped, and spaces around the key and the value don't
+ * matter. Every value is read as text; 'value' turns one into whatever
+ * type the caller asks for, using that type's 'FromStr'.
+ *
+ * Someone fixing a broken config file needs to know where to look, so every
+ * error about the file's contents says which line it's on and quotes what's
+ * there. A bad value is only found when it's asked for, which is after the
+ * file has been read, so each setting remembers the line it came from. */
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::{self, FromStr};
+
+#[derive(Debug)]
+pub enum ConfigError {
+    NotFound(PathBuf),
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    BadUtf8 {
+        line: usize,
+        text: String,
+    },
+    Malformed {
+        line: usize,
+        text: String,
+    },
+    DuplicateKey {
+        key: String,
+        line: usize,
+        first: usize,
+    },
+    MissingKey(String),
+    BadValue {
+        key: String,
+        line: usize,
+        text: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::NotFound(path) => {
+                write!(f, "there's no config file at {}", path.display())
+            }
+            ConfigError::Io { path, source } => {
+                write!(f, "couldn't read {}: {source}", path.display())
+            }
+            ConfigError::BadUtf8 { line, text } => {
+                write!(f, "line {line}: '{text}' isn't valid UTF-8")
+            }
+            ConfigError::Malformed { line, text } => {
+                write!(f, "line {line}: expected 'key = value', found '{text}'")
+            }
+            ConfigError::DuplicateKey { key, line, first } => {
+                write!(f, "line {line}: '{key}' was already set on line {first}")
+            }
+            ConfigError::MissingKey(key) => write!(f, "'{key}' isn't set"),
+            ConfigError::BadValue {
+                key,
+                line,
+                text,
+                reason,
+            } => write!(
+                f,
+                "line {line}: '{text}' isn't a valid value for '{key}' ({reason})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Setting {
+    value: String,
+    line: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    settings: HashMap<String, Setting>,
+}
+
+impl Config {
+    pub fn load(path: impl AsRef<Path>) -> Result<Config, ConfigError> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => ConfigError::NotFound(path.to_path_buf()),
+            _ => ConfigError::Io {
+                path: path.to_path_buf(),
+                source: e,
+            },
+        })?;
+        Config::parse(&bytes)
+    }
+
+    /* Takes bytes rather than a '&str' so that text that isn't UTF-8 is
+     * reported against the line it's on, instead of failing the whole file
+     * at once the way 'fs::read_to_string' would. */
+    pub fn parse(bytes: &[u8]) -> Result<Config, ConfigError> {
+        let mut config = Config::default();
+        for (index, raw) in bytes.split(|&b| b == b'\n').enumerate() {
+            let line = index + 1;
+            let text = str::from_utf8(raw).map_err(|_| ConfigError::BadUtf8 {
+                line,
+                text: String::from_utf8_lossy(raw).trim().to_string(),
+            })?;
+            let text = text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = match text.split_once('=') {
+                Some((key, value)) if !key.trim().is_empty() => (key.trim(), value.trim()),
+                _ => {
+                    return Err(ConfigError::Malformed {
+                        line,
+                        text: text.to_string(),
+                    })
+                }
+            };
+            if let Some(first) = config.settings.get(key) {
+                return Err(ConfigError::DuplicateKey {
+                    key: key.to_string(),
+                    line,
+                    first: first.line,
+                });
+            }
+            config.settings.insert(
+                key.to_string(),
+                Setting {
+                    value: value.to_string(),
+                    line,
+                },
+            );
+        }
+        Ok(config)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.settings.get(key).map(|setting| setting.value.as_str())
+    }
+
+    pub fn value<T>(&self, key: &str) -> Result<T, ConfigError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let setting = self
+            .settings
+            .get(key)
+            .ok_or_else(|| ConfigError::MissingKey(key.to_string()))?;
+        setting
+            .value
+            .parse()
+            .map_err(|e: T::Err| ConfigError::BadValue {
+                key: key.to_string(),
+                line: setting.line,
+                text: setting.value.clone(),
+                reason: e.to_string(),
+            })
+    }
+}
@@ -0,0 +1,77 @@
+/* Adding Context */
+/* "No such file or directory (os error 2)" is true, but it doesn't say
+ * which file, or what we were doing when we went looking for it. The
+ * function that got the error knows that, so before passing the error up
+ * with '?' it can wrap it with a sentence of its own:
+ *
+ *     fs::read_to_string(path).context("reading the greeting")?
+ *
+ * 'ContextError' keeps the original error as its 'source', so nothing is
+ * lost: code further up can still walk the chain and downcast to the
+ * 'io::Error' underneath. Its 'Display' prints the whole chain, outermost
+ * first:
+ *
+ *     starting up: reading the greeting: No such file or directory (os error 2)
+ *
+ * Errors in this crate (like 'AppError') already include their source's
+ * message in their own, so the chain is printed down to the first error
+ * that isn't a 'ContextError' and stops there. Carrying on would print the
+ * rest of the message twice.
+ *
+ * The wrapped error is boxed, so one 'ContextError' type can hold any
+ * error. 'Send' and 'Sync' keep it usable across threads. */
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct ContextError {
+    context: String,
+    source: Box<dyn Error + Send + Sync + 'static>,
+}
+
+impl ContextError {
+    pub fn new(
+        context: impl Into<String>,
+        source: impl Into<Box<dyn Error + Send + Sync + 'static>>,
+    ) -> ContextError {
+        ContextError {
+            context: context.into(),
+            source: source.into(),
+        }
+    }
+
+    pub fn context(&self) -> &str {
+        &self.context
+    }
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.context)?;
+        let mut next: &(dyn Error + 'static) = self.source.as_ref();
+        while let Some(inner) = next.downcast_ref::<ContextError>() {
+            write!(f, ": {}", inner.context())?;
+            next = inner.source.as_ref();
+        }
+        write!(f, ": {next}")
+    }
+}
+
+impl Error for ContextError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+pub trait Context<T> {
+    fn context(self, context: impl Into<String>) -> Result<T, ContextError>;
+}
+
+impl<T, E> Context<T> for Result<T, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn context(self, context: impl Into<String>) -> Result<T, ContextError> {
+        self.map_err(|e| ContextError::new(context, e))
+    }
+}
@@ -0,0 +1,62 @@
+/* Our Own Error Type */
+/* The '?' operator passes each error it returns through 'From::from', so a
+ * function can return one error type that covers every way it might fail,
+ * as long as each error its '?'s see can be turned into that type. This is
+ * the 'OurError' the prose in lib.rs describes: reading a file can fail
+ * with an 'io::Error', and turning what we read into a number can fail with
+ * a 'ParseIntError', so 'AppError' has a variant for each and a 'From' impl
+ * that builds it. Loading the settings file can fail with a 'ConfigError'
+ * (see config.rs), which gets a variant of its own too.
+ *
+ * 'Display' says what went wrong in a sentence a user could read. The
+ * wrapped error is still there for anyone who wants the details: 'source'
+ * hands it back. */
+use crate::config::ConfigError;
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+pub enum AppError {
+    Io(io::Error),
+    Parse(ParseIntError),
+    Config(ConfigError),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AppError::Io(e) => write!(f, "couldn't read the file: {e}"),
+            AppError::Parse(e) => write!(f, "couldn't read a number: {e}"),
+            AppError::Config(e) => write!(f, "couldn't load the settings: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for AppError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AppError::Io(e) => Some(e),
+            AppError::Parse(e) => Some(e),
+            AppError::Config(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for AppError {
+    fn from(e: io::Error) -> AppError {
+        AppError::Io(e)
+    }
+}
+
+impl From<ParseIntError> for AppError {
+    fn from(e: ParseIntError) -> AppError {
+        AppError::Parse(e)
+    }
+}
+
+impl From<ConfigError> for AppError {
+    fn from(e: ConfigError) -> AppError {
+        AppError::Config(e)
+    }
+}
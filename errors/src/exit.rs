@@ -0,0 +1,57 @@
+/* Exit Codes */
+/* A 'main' that returns 'Result<(), E>' exits with 1 for every error, so a
+ * shell script running us can tell that something failed but not what.
+ * 'AppResult' is what our 'main' returns instead. It implements
+ * 'Termination', the trait 'main's return type has to implement, and its
+ * 'report' prints the error and picks an exit code from the kind of error
+ * it is.
+ *
+ * The codes are the ones from BSD's 'sysexits.h', which other Unix tools
+ * use for the same failures, so a script may already know them:
+ *
+ *     65  the data was wrong (a number that isn't one)
+ *     66  an input file is missing
+ *     74  reading a file failed some other way
+ *     78  the config file is wrong
+ */
+use crate::config::ConfigError;
+use crate::error::AppError;
+use std::io;
+use std::process::{ExitCode, Termination};
+
+pub const DATA_ERROR: u8 = 65;
+pub const NO_INPUT: u8 = 66;
+pub const IO_ERROR: u8 = 74;
+pub const CONFIG_ERROR: u8 = 78;
+
+pub fn exit_code(error: &AppError) -> u8 {
+    match error {
+        AppError::Io(e) if e.kind() == io::ErrorKind::NotFound => NO_INPUT,
+        AppError::Io(_) => IO_ERROR,
+        AppError::Parse(_) => DATA_ERROR,
+        AppError::Config(ConfigError::NotFound(_)) => NO_INPUT,
+        AppError::Config(ConfigError::Io { .. }) => IO_ERROR,
+        AppError::Config(_) => CONFIG_ERROR,
+    }
+}
+
+#[derive(Debug)]
+pub struct AppResult(pub Result<(), AppError>);
+
+impl From<Result<(), AppError>> for AppResult {
+    fn from(result: Result<(), AppError>) -> AppResult {
+        AppResult(result)
+    }
+}
+
+impl Termination for AppResult {
+    fn report(self) -> ExitCode {
+        match self.0 {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("Error: {e}");
+                ExitCode::from(exit_code(&e))
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+/* Files for the Examples */
+/* Most of the examples in main.rs open 'hello.txt', and whether that works
+ * depends on there being one in whatever directory the program was run
+ * from. That's why the book's versions were commented out here: run from
+ * the wrong place, every one of them panicked. A 'TempFile' makes the file
+ * the example needs, so the example can run anywhere.
+ *
+ * Each 'TempFile' gets a directory of its own under the system temp
+ * directory, and the file inside keeps the name it was given. Error
+ * messages then still mention 'hello.txt', and two examples that both want
+ * a 'hello.txt' don't share one. The directory and everything in it is
+ * removed in 'Drop', so nothing is left behind, even if an example panics.
+ *
+ * 'missing' makes the directory but not the file, for the examples about
+ * what happens when a file isn't there. If the example goes on to create
+ * it, 'Drop' removes it along with the rest.
+ *
+ * The tests use 'test_support::TempFile', which works the same way, but a
+ * dev-dependency is only there for tests, so the program needs its own. */
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug)]
+pub struct TempFile {
+    dir: PathBuf,
+    path: PathBuf,
+}
+
+impl TempFile {
+    pub fn missing(name: &str) -> io::Result<TempFile> {
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let dir = env::temp_dir().join(format!("errors-{}-{id}", process::id()));
+        fs::create_dir_all(&dir)?;
+        let path = dir.join(name);
+        Ok(TempFile { dir, path })
+    }
+
+    pub fn with_contents(name: &str, contents: &str) -> io::Result<TempFile> {
+        let file = TempFile::missing(name)?;
+        fs::write(&file.path, contents)?;
+        Ok(file)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
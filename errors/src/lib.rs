@@ -0,0 +1,252 @@
+/* ERROR HANDLING */
+/* Rust groups errors into two major categories: 'recoverable' errors, like
+ * a file that isn't there, which are reported with 'Result<T, E>', and
+ * 'unrecoverable' errors, which are bugs, and stop the program with
+ * 'panic!'.
+ *
+ * The functions from the book's examples live here in the library, along
+ * with the prose that explains them, so that the tests in tests.rs can call
+ * them. main.rs runs them, and keeps the examples that are about 'main'
+ * itself: panicking, 'unwrap' and 'expect', and what 'main' may return.
+ * Each of the modules below builds on one of the chapter's ideas. */
+pub mod config;
+pub mod context;
+pub mod error;
+pub mod exit;
+pub mod fixtures;
+pub mod panic_reporting;
+pub mod recover;
+pub mod result_ext;
+pub mod retry;
+
+pub use config::{Config, ConfigError};
+pub use context::{Context, ContextError};
+pub use error::AppError;
+pub use exit::AppResult;
+pub use fixtures::TempFile;
+pub use recover::{recover_open, recover_open_with, Recovery};
+pub use result_ext::ResultExt;
+pub use retry::{retry, retry_if};
+
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+
+/* Propagating Errors */
+/* When a function's implementation calls something that might fall,
+ * instead of handling the error within the function itself, you can
+ * return the error to the calling code so that is can decide what to do.
+ * This is known as 'propagating' the error and gives more control to the
+ * calling code, where there might be more information or logic that
+ * dictates how the error should be handled than what you have available
+ * in the context of your code.
+ *
+ * The below example shows the process of propagating errors manually.
+ * The book calls every version of this function 'read_username_from_file';
+ * here each one gets a name of its own so they can all be public. Unlike
+ * the book's versions, they take the path of the file to read, so that
+ * main.rs can hand them a 'hello.txt' it made and the tests can hand them
+ * whatever file they like. */
+pub fn read_username_with_match(path: &Path) -> Result<String, AppError> {
+    let username_file_result = File::open(path);
+
+    let mut username_file = match username_file_result {
+        Ok(file) => file,
+        Err(e) => return Err(AppError::from(e)),
+    };
+
+    let mut username = String::new();
+
+    match username_file.read_to_string(&mut username) {
+        Ok(_) => Ok(username),
+        Err(e) => Err(AppError::from(e)),
+    }
+}
+/* This function can be written in a much shorter way, but we're going to
+ * start by doing a lot of it manually in order to explore error handling;
+ * at the end we'll use the shorter way. Let's look at the return type of
+ * the function first: 'Result<String, AppError>'. This means the
+ * function is returning a value of the type 'Result<T, E>' where the
+ * generic parameter 'T' has been filled in with the concrete type
+ * 'String', and the generic type 'E' has been filled in with the concrete
+ * type 'AppError', our own error type from error.rs.
+ *
+ * If this function succeeds without any problems, the code that calls
+ * this function will receive an 'Ok' value that holds a 'String' — the
+ * username that this function read from the file. If this function
+ * encounters any problems, the calling code will receive an 'Err' value
+ * that holds an instance of 'AppError' that contains more information
+ * about what the problems were. Both of the operations we’re calling in
+ * this function’s body that might fail, the 'File::open' function and the
+ * 'read_to_string' method, return an 'io::Error', so each 'Err' arm
+ * turns it into an 'AppError' with 'AppError::from' before returning it.
+ * The book returns the 'io::Error' as it is; we return 'AppError' so
+ * that every version of this function below has the same signature.
+ *
+ * The body of the function starts by calling the 'File::open' function.
+ * Then we handle the 'Result' value with a 'match' expression. If
+ * 'File::open' succeeds, the file handle in the pattern variable 'file'
+ * becomes the value in the mutable variable 'username_file' and the
+ * function continues. In the 'Err' case, instead of calling 'panic!', we
+ * use the 'return' keyword to return early out of the function entirely
+ * and pass the error value from 'File::open', now in the pattern variable
+ * 'e', back to the calling code as this function’s error value.
+ *
+ * So if we have a file handle in 'username_file', the function then
+ * creates a new 'String' in variable 'username' and calls the
+ * 'read_to_string' method on the file handle in 'username_file' to read
+ * the contents of the file into 'username'. The 'read_to_string' method
+ * also returns a 'Result' because it might fail, even though 'File::open'
+ * succeeded. So we need another 'match' to handle that 'Result': if
+ * 'read_to_string' succeeds, then our function has succeeded, and we
+ * return the username from the file that’s now in 'username' wrapped in
+ * an 'Ok'. If 'read_to_string' fails, we return the error value in the
+ * same way that we returned the error value in the 'match' that handled
+ * the return value of 'File::open'. However, we don’t need to explicitly
+ * say 'return', because this is the last expression in the function.
+ *
+ * The code that calls this code will then handle getting either an 'Ok'
+ * value that contains a username or an 'Err' value that contains an
+ * 'AppError'. It’s up to the calling code to decide what to do with those
+ * values. If the calling code gets an 'Err' value, it could call 'panic!'
+ * and crash the program, use a default username, or look up the username
+ * from somewhere other than a file, for example. We don’t have enough
+ * information on what the calling code is actually trying to do, so we
+ * propagate all the success or error information upward for it to handle
+ * appropriately.
+ *
+ * This pattern of propagating errors is so common in Rust that Rust
+ * provides the question mark operator '?' to make this easier. */
+
+/* A Shortcut for Propagating Errors: the ? Operator */
+/* The below example shows an implementation of 'read_username_with_match'
+ * that has the same functionality as the above example, but this
+ * implementation uses the '?' operator. */
+pub fn read_username_with_question_mark(path: &Path) -> Result<String, AppError> {
+    let mut username_file = File::open(path)?;
+    let mut username = String::new();
+    username_file.read_to_string(&mut username)?;
+    Ok(username)
+}
+/* The '?' placed after a 'Result' value is defined to work in almost the
+ * same way as the 'match' expressions we defined to handle the 'Result'
+ * in the first implementation of this function. If the value of the
+ * 'Result' is an 'Ok', the value inside the 'Ok' will get returned from
+ * this expression, and the program will continue. If the value is an
+ * 'Err', the 'Err' will be returned from the whole function as if we had
+ * used the 'return' keyword so the error value gets propagated to the
+ * calling code.
+ *
+ * There is a difference between what the 'match' expression from above
+ * does and what the '?' operator does: error values that have the '?'
+ * operator called on them go through the 'from' function, defined in the
+ * 'From' trait in the standard library, which is used to convert values
+ * from one type into another. When the '?' operator calls the 'from'
+ * function, the error type received is converted into the error type
+ * defined in the return type of the current function. This is useful
+ * when a function returns one error type to represent all the ways a
+ * function might fail, even if parts might fail for many different
+ * reasons.
+ *
+ * For example, we could change the 'read_username_with_question_mark' function
+ * in the above example to return a custom error type named 'OurError'
+ * that we define. If we also define 'impl From<io::Error>' for
+ * 'OurError' to construct an instance of 'OurError' from an 'io::Error',
+ * then the '?' operator calls in the body of 'read_username_with_question_mark'
+ * will call 'from' and convert the error types without needing to add
+ * any more code to the function. 'AppError' is exactly that, which is
+ * why 'read_username_with_question_mark' has no 'AppError::from' calls of its
+ * own.
+ *
+ * In the context of the above example, the '?' at the end of the
+ * 'File::open' call will return the value inside an 'Ok' to the variable
+ * 'username_file'. If an error occurs, the '?' operator will return
+ * early out of the whole function and give any 'Err' value to the
+ * calling code. The same thing applies to the '?' at the end of the
+ * 'read_to_string' call.
+ *
+ * The '?' operator eliminates a lot of boilerplate and makes this
+ * function's implementation simpler. We could even shorten this code
+ * further by chaining method calls immediately after the '?'. */
+pub fn read_username_chained(path: &Path) -> Result<String, AppError> {
+    let mut username = String::new();
+
+    File::open(path)?.read_to_string(&mut username)?;
+
+    Ok(username)
+}
+/* We've moved the creation of the new 'String' in 'username' to the
+ * beginning of the function; that part hasn't changed. Instead of
+ * creating a variable 'username_file', we've chained the call to
+ * 'read_to_string' directly onto the result of 'File::open(path)?'.
+ * We still have a '?' at the end of the 'read_to_string' call, and we
+ * still return an 'Ok' value containing 'username' when both 'File::open'
+ * and 'read_to_string' succeed rather than returning errors. The
+ * functionality is again the same as in the above two examples; this is
+ * just a different, more ergonomic way to write it.
+ *
+ * The below example shows a way to make this even shorter using
+ * 'fs::read_to_string'. */
+pub fn read_username_from_file(path: &Path) -> Result<String, AppError> {
+    Ok(fs::read_to_string(path)?)
+}
+/* Reading a file into a string is a fairly common operation, so the
+ * standard library provides the convenient 'fs::read_to_string' function
+ * that opens the file, creates a new 'String', reads the contentsof the
+ * file, puts the contents into that 'String', and returns it. We wrap
+ * its result in 'Ok(...?)' so that the 'io::Error' goes through 'From'.
+ *
+ * Because 'AppError' also converts from 'ParseIntError', one function
+ * can use '?' on both kinds of failure: reading the file and parsing
+ * what's in it. */
+pub fn read_number_from_file(path: &Path) -> Result<u32, AppError> {
+    let text = fs::read_to_string(path)?;
+    Ok(text.trim().parse()?)
+}
+
+/* Using '?' on Option */
+/* The compiler error in main.rs mentions that '?' can be used with
+ * 'Option<T>' values as well. As with using '?' on 'Result', you can only use '?' on
+ * 'Option' in a function that returns an 'Option'. The behavior of the '?'
+ * operator when called on an 'Option<T>' is similar to its behavior when
+ * called on a 'Result<T,E>': if the value is 'None', the 'None' will be
+ * returned early from the function at that point. If the value is 'Some',
+ * the value inside the 'Some' is the resulting value of the expression
+ * and the function continues. */
+pub fn last_char_of_first_line(text: &str) -> Option<char> {
+    text.lines().next()?.chars().last()
+}
+/* This function returns 'Option<char>' because it’s possible that there
+ * is a character there, but it’s also possible that there isn’t. This code
+ * takes the 'text' string slice argument and calls the 'lines' method on
+ * it, which returns an iterator over the lines in the string. Because this
+ * function wants to examine the first line, it calls 'next' on the
+ * iterator to get the first value from the iterator. If 'text' is an empty
+ * string, this call to 'next' will return 'None', in which case we use '?'
+ * to stop and return 'None' from 'last_char_of_first_line'. If 'text' is
+ * not an empty string, 'next' will return a 'Some' value containing a
+ * string slice of the first line in 'text'.
+ *
+ * The '?' extracts the string slice, and we can call 'chars' on that
+ * string slice to get an iterator of its characters. We’re interested in
+ * the last character in this first line, so we call 'last' to return the
+ * last item in the iterator. This is an 'Option' because it’s possible
+ * that the first line is an empty string, for example if 'text' starts
+ * with a blank line but has characters on other lines, as in "\nhi".
+ * However, if there is a last character on the first line, it will be
+ * returned in the 'Some' variant. The '?' operator in the middle gives us
+ * a concise way to express this logic, allowing us to implement the
+ * function in one line. If we couldn’t use the '?' operator on 'Option',
+ * we’d have to implement this logic using more method calls or a 'match'
+ * expression.
+ *
+ * Note that you can use the '?' operator on a 'Result' in a function that
+ * returns 'Result', and you can use the '?' operator on an 'Option' in a
+ * function that returns 'Option', but you can’t mix and match. The '?'
+ * operator won’t automatically convert a 'Result' to an 'Option' or vice
+ * versa; in those cases, you can use methods like the 'ok' method on
+ * 'Result' or the 'ok_or' method on 'Option' to do the conversion
+ * explicitly. */
+
+#[cfg(test)]
+mod tests;
@@ -1,4 +1,11 @@
-fn main() {
+use errors::{last_char_of_first_line, panic_reporting, read_number_from_file};
+use errors::{read_username_chained, read_username_from_file};
+use errors::{read_username_with_match, read_username_with_question_mark};
+use errors::{recover_open, recover_open_with, retry, retry_if, Recovery};
+use errors::{AppError, AppResult, Config, ConfigError, Context, ContextError};
+use errors::{ResultExt, TempFile};
+
+fn main() -> AppResult {
     /* Sometimes, bad things happen in your code, and there's nothing you can
      * do about it. In these cases, Rust has the panic! macro. There are two
      * ways to cause a panic in practice: by taking an action that causes our
@@ -30,6 +37,8 @@ fn main() {
      * our code calling the macro directly. The following example has some
      * code that atttempts to access and index in a vector beyond the range
      * of valid indexes. */
+    // An array would do here, but the example is about indexing a vector
+    #[allow(clippy::useless_vec)]
     let _v = vec![1, 2, 3];
     // When uncommented, the following line causes a panic
     //_v[99];
@@ -52,6 +61,36 @@ fn main() {
      * to read an element at an index that doesn't exist, Rust will stop
      * executing and refuse to continue. */
 
+    /* Keeping a Record of Panics */
+    /* What a panic prints goes to stderr and is gone once the terminal is
+     * closed. The 'panic_reporting' module (see panic_reporting.rs) installs
+     * a panic hook that also writes each panic, with a backtrace, to a log
+     * file. To see it work without stopping the program, we panic inside
+     * 'panic::catch_unwind', which stops the unwinding there and hands back
+     * an 'Err' instead. Catching panics like this isn't how Rust programs
+     * handle errors (that's what 'Result' is for, below), but it lets us
+     * carry on with the rest of the examples. The log goes in a 'TempFile'
+     * (see fixtures.rs), so running the examples doesn't leave one behind;
+     * we read the first line of the report back before it's removed. */
+    use std::fs;
+    use std::panic;
+
+    let panic_log = TempFile::missing("panic.log").expect("the temp directory should be writable");
+    match panic_reporting::install(panic_log.path()) {
+        Ok(()) => {
+            let caught = panic::catch_unwind(|| panic!("crash and burn"));
+            panic_reporting::uninstall();
+            let report = fs::read_to_string(panic_log.path()).unwrap_or_default();
+            println!(
+                "Caught a panic: {}; the report starts: {}",
+                caught.is_err(),
+                report.lines().next().unwrap_or("(nothing)")
+            );
+        }
+        Err(e) => println!("Couldn't open {}: {}", panic_log.path().display(), e),
+    }
+    drop(panic_log);
+
     /* Recovering from Errors with Result */
     /* Most errors aren't serious enough to require the program to stop
      * entirely. Sometimes, when a function fails, it's for a reason that you
@@ -65,7 +104,15 @@ fn main() {
      * function could fail. */
     use std::fs::File;
 
-    let _greeting_file_result = File::open("hello.txt");
+    /* The examples from here on open 'hello.txt'. So that they work
+     * wherever the program is run from, we make one in a temporary
+     * directory (see fixtures.rs), which is removed again when 'hello' goes
+     * out of scope at the end of 'main'. If even that fails, there's nothing
+     * for the examples to open, and panicking is the honest response. */
+    let hello = TempFile::with_contents("hello.txt", "Ferris\n")
+        .expect("the temp directory should be writable");
+
+    let greeting_file_result = File::open(hello.path());
     /* The return type of 'File::open' is a 'Result<T,E>'. The generic
      * parameter 'T' has been filled in by the implementation of 'File::open'
      * with the type of the success value, 'std::fs'File', which is a file
@@ -89,12 +136,12 @@ fn main() {
      * actions depending on the value 'File::open' returns. The following
      * example shows one way to handle the 'Result' using a basic tool, the
      * 'match' expression. */
-    //    let _greeting_file = match _greeting_file_result {
-    //        Ok(file) => file,
-    //        Err(error) => {
-    //            panic!("Problem opening the file: {:?}", error)
-    //        }
-    //    };
+    let _greeting_file = match greeting_file_result {
+        Ok(file) => file,
+        Err(error) => {
+            panic!("Problem opening the file: {:?}", error)
+        }
+    };
     /* Note that, like the 'Option' enum, the 'Result' enum and its variants
      * have been brought into scope by the prelude, so we don't need to
      * specify 'Result::' before the 'Ok' and 'Err' variants in the 'match'
@@ -120,22 +167,26 @@ fn main() {
      * example, because we don't have permission to open the file - we
      * still want the code to 'panic!' in the same way as it did in the
      * above example. For this, we can use an inner 'match' expression. */
-    //    use std::io::ErrorKind;
-    //
-    //    let greeting_file_result = File::open("hello.txt");
-    //
-    //    let _greeting_file = match greeting_file_result {
-    //        Ok(file) => file,
-    //        Err(error) => match error.kind() {
-    //            ErrorKind::NotFound => match File::create("hello.txt") {
-    //                Ok(fc) => fc,
-    //                Err(e) => panic!("Problem creating the file: {:?}", e),
-    //            },
-    //            other_error => {
-    //                panic!("Problem opening the file: {:?}", other_error);
-    //            }
-    //        },
-    //    };
+    /* That inner 'match' is in 'recover_open' (see recover.rs), which
+     * returns the error it can't recover from instead of panicking, so the
+     * panic is left to us. It also tries again if the open is interrupted,
+     * and 'recover_open_with' can turn off creating a missing file. This
+     * time the 'hello.txt' we open hasn't been written yet, so it gets
+     * created; told not to create it, 'recover_open_with' hands back the
+     * 'NotFound' error instead. All we want to do with that error is print
+     * it, and 'tap_err' (see result_ext.rs) does that without a 'match'. */
+    let new_hello = TempFile::missing("hello.txt").expect("the temp directory should be writable");
+    let no_create = Recovery {
+        create_if_missing: false,
+        ..Recovery::default()
+    };
+    let _ = recover_open_with(new_hello.path(), no_create)
+        .tap_err(|e| println!("Not creating hello.txt: {}", e));
+
+    let _greeting_file = match recover_open(new_hello.path()) {
+        Ok(file) => file,
+        Err(error) => panic!("Problem opening the file: {:?}", error),
+    };
     /* The type of the value that 'File::open' returns inside the 'Err' is
      * 'io::Error', which is a struct provided by the standard library.
      * This struct has a method 'kind' that we can call to get an
@@ -144,7 +195,7 @@ fn main() {
      * of errors that might result from an 'io' operation. The variant we
      * want to use is 'ErrorKind::NotFound', which indicates the file
      * we're trying to open doesn't exist yet. So we match on
-     * '_greeting_file_result', but we also have an inner match on
+     * 'greeting_file_result', but we also have an inner match on
      * 'error.kind()'.
      *
      * The condition we want to check in the inner match is whether the
@@ -164,13 +215,14 @@ fn main() {
      * expression we used above. If the 'Result' value is the 'Ok' variant,
      * 'unwrap' will return the value inside the 'Ok'. If the 'Result' is the
      * 'Err' variant, 'unwrap' will call the 'panic!' macro for us. */
-    // let _greeting_file = File::open("hello.txt").unwrap();
+    let _greeting_file = File::open(hello.path()).unwrap();
 
     /* Similarly, the 'expect' method lets us also choose the 'panic!' error
      * message. Using 'expect' instad of 'unwrap' and providing good error
      * messages can convey your intent and make tracking down the source of a
      * panic easier. */
-    // let _greeting_file = File::open("hello.txt").expect("hello.txt should be included in this project");
+    let _greeting_file =
+        File::open(hello.path()).expect("hello.txt should be included in this project");
     /* We use 'expect' in the same way as 'unwrap': to return the file handle
      * or call the 'panic!' macro. The error message used by 'expect' in its
      * call to 'panic!' will be the parameter that we pass to 'expect', rather
@@ -182,167 +234,42 @@ fn main() {
      * you have more information to use in debugging. */
 
     /* Propagating Errors */
-    /* When a function's implementation calls something that might fall,
-     * instead of handling the error within the function itself, you can
-     * return the error to the calling code so that is can decide what to do.
-     * This is known as 'propagating' the error and gives more control to the
-     * calling code, where there might be more information or logic that
-     * dictates how the error should be handled than what you have available
-     * in the context of your code.
-     *
-     * The below example shows the process of propagating errors manually. */
-    use std::io::{self, Read};
-
-    fn _read_username_from_file() -> Result<String, io::Error> {
-        let username_file_result = File::open("hello.txt");
-
-        let mut username_file = match username_file_result {
-            Ok(file) => file,
-            Err(e) => return Err(e),
-        };
+    /* Functions that pass their errors back to the caller with 'Result'
+     * are in lib.rs, along with the book's explanation of how each one
+     * works: four versions of 'read_username_from_file', from a 'match' on
+     * every 'Result' down to a single call to 'fs::read_to_string'. All four
+     * read the same name out of our 'hello.txt', and all four pass back the
+     * same error for a file that isn't there. 'log_err' prints that error
+     * to stderr, after a prefix saying what we were doing, and passes the
+     * 'Result' on, so only the name is left for us to deal with. */
+    use std::io;
+    use std::path::Path;
 
-        let mut username = String::new();
-
-        match username_file.read_to_string(&mut username) {
-            Ok(_) => Ok(username),
-            Err(e) => Err(e),
+    let no_hello = TempFile::missing("hello.txt").expect("the temp directory should be writable");
+    let readers = [
+        read_username_with_match,
+        read_username_with_question_mark,
+        read_username_chained,
+        read_username_from_file,
+    ];
+    for read in readers {
+        for path in [hello.path(), no_hello.path()] {
+            if let Ok(name) = read(path).log_err("reading the username") {
+                println!("{}", name.trim());
+            }
         }
     }
-    /* This function can be written in a much shorter way, but we're going to
-     * start by doing a lot of it manually in order to explore error handling;
-     * at the end we'll use the shorter way. Let's look at the return type of
-     * the function first: 'Result<String, io::Error>'. This means the
-     * function is returning a value of the type 'Result<T, E>' where the
-     * generic parameter 'T' has been filled in with the concrete type
-     * 'String', and the generic type 'E' has been filled in with the concrete
-     * type 'io::Error'.
-     *
-     * If this function succeeds without any problems, the code that calls
-     * this function will receive an 'Ok' value that holds a 'String' — the
-     * username that this function read from the file. If this function
-     * encounters any problems, the calling code will receive an 'Err' value
-     * that holds an instance of 'io::Error' that contains more information
-     * about what the problems were. We chose 'io::Error' as the return type
-     * of this function because that happens to be the type of the error value
-     * returned from both of the operations we’re calling in this function’s
-     * body that might fail: the 'File::open' function and the 'read_to_string'
-     * method.
-     *
-     * The body of the function starts by calling the 'File::open' function.
-     * Then we handle the 'Result' value with a 'match' expression. If
-     * 'File::open' succeeds, the file handle in the pattern variable 'file'
-     * becomes the value in the mutable variable 'username_file' and the
-     * function continues. In the 'Err' case, instead of calling 'panic!', we
-     * use the 'return' keyword to return early out of the function entirely
-     * and pass the error value from 'File::open', now in the pattern variable
-     * 'e', back to the calling code as this function’s error value.
-     *
-     * So if we have a file handle in 'username_file', the function then
-     * creates a new 'String' in variable 'username' and calls the
-     * 'read_to_string' method on the file handle in 'username_file' to read
-     * the contents of the file into 'username'. The 'read_to_string' method
-     * also returns a 'Result' because it might fail, even though 'File::open'
-     * succeeded. So we need another 'match' to handle that 'Result': if
-     * 'read_to_string' succeeds, then our function has succeeded, and we
-     * return the username from the file that’s now in 'username' wrapped in
-     * an 'Ok'. If 'read_to_string' fails, we return the error value in the
-     * same way that we returned the error value in the 'match' that handled
-     * the return value of 'File::open'. However, we don’t need to explicitly
-     * say 'return', because this is the last expression in the function.
-     *
-     * The code that calls this code will then handle getting either an 'Ok'
-     * value that contains a username or an 'Err' value that contains an
-     * 'io::Error'. It’s up to the calling code to decide what to do with those
-     * values. If the calling code gets an 'Err' value, it could call 'panic!'
-     * and crash the program, use a default username, or look up the username
-     * from somewhere other than a file, for example. We don’t have enough
-     * information on what the calling code is actually trying to do, so we
-     * propagate all the success or error information upward for it to handle
-     * appropriately.
-     *
-     * This pattern of propagating errors is so common in Rust that Rust
-     * provides the question mark operator '?' to make this easier. */
-
-    /* A Shortcut for Propagating Errors: the ? Operator */
-    /* The below example shows an implementation of '_read_username_from_file'
-     * that has the same functionality as the above example, but this
-     * implementation uses the '?' operator. */
-    fn _read_username_from_file1() -> Result<String, io::Error> {
-        let mut username_file = File::open("hello.txt")?;
-        let mut username = String::new();
-        username_file.read_to_string(&mut username)?;
-        Ok(username)
-    }
-    /* The '?' placed after a 'Result' value is defined to work in almost the
-     * same way as the 'match' expressions we defined to handle the 'Result'
-     * in the first implementation of this function. If the value of the
-     * 'Result' is an 'Ok', the value inside the 'Ok' will get returned from
-     * this expression, and the program will continue. If the value is an
-     * 'Err', the 'Err' will be returned from the whole function as if we had
-     * used the 'return' keyword so the error value gets propagated to the
-     * calling code.
-     *
-     * There is a difference between what the 'match' expression from above
-     * does and what the '?' operator does: error values that have the '?'
-     * operator called on them go through the 'from' function, defined in the
-     * 'From' trait in the standard library, which is used to convert values
-     * from one type into another. When the '?' operator calls the 'from'
-     * function, the error type received is converted into the error type
-     * defined in the return type of the current function. This is useful
-     * when a function returns one error type to represent all the ways a
-     * function might fail, even if parts might fail for many different
-     * reasons.
-     *
-     * For example, we could change the '_read_username_from_file1' function
-     * in the above example to return a custom error type named 'OurError'
-     * that we define. If we also define 'impl From<io::Error>' for
-     * 'OurError' to construct an instance of 'OurError' from an 'io::Error',
-     * then the '?' operator calls in the body of '_read_username_from_file1'
-     * will call 'from' and convert the error types without needing to add
-     * any more code to the function.
-     *
-     * In the context of the above example, the '?' at the end of the
-     * 'File::open' call will return the value inside an 'Ok' to the variable
-     * 'username_file'. If an error occurs, the '?' operator will return
-     * early out of the whole function and give any 'Err' value to the
-     * calling code. The same thing applies to the '?' at the end of the
-     * 'read_to_string' call.
-     *
-     * The '?' operator eliminates a lot of boilerplate and makes this
-     * function's implementation simpler. We could even shorten this code
-     * further by chaining method calls immediately after the '?'. */
-    fn _read_username_from_file2() -> Result<String, io::Error> {
-        let mut username = String::new();
-
-        File::open("hello.txt")?.read_to_string(&mut username)?;
-
-        Ok(username)
-    }
-    /* We've moved the creation of the new 'String' in 'username' to the
-     * beginning of the function; that part hasn't changed. Instead of
-     * creating a variable 'username_file', we've chained the call to
-     * 'read_to_string' directly onto the result of 'File::open("hello.txt")?'.
-     * We still have a '?' at the end of the 'read_to_string' call, and we
-     * still return an 'Ok' value containing 'username' when both 'File::open'
-     * and 'read_to_string' succeed rather than returning errors. The
-     * functionality is again the same as in the above two examples; this is
-     * just a different, more ergonomic way to write it.
-     *
-     * The below example shows a way to make this even shorter using
-     * 'fs::read_to_string'. */
-    use std::fs;
-
-    fn read_username_from_file() -> Result<String, io::Error> {
-        fs::read_to_string("hello.txt")
-    }
-    match read_username_from_file() {
-        Ok(s) => println!("{}", s),
-        Err(e) => println!("{}", e),
+    /* 'read_number_from_file' uses '?' on two kinds of error: one from
+     * reading the file, and one from parsing what's in it. Our 'hello.txt'
+     * has a name in it, not a number, so reading one from it fails at the
+     * second step. */
+    let number = TempFile::with_contents("number.txt", "42\n")
+        .expect("the temp directory should be writable");
+    for path in [number.path(), hello.path()] {
+        if let Ok(n) = read_number_from_file(path).log_err("reading the number") {
+            println!("The number is {}", n);
+        }
     }
-    /* Reading a file into a string is a fairly common operation, so the
-     * standard library provides the convenient 'fs::read_to_string' function
-     * that opens the file, creates a new 'String', reads the contentsof the
-     * file, puts the contents into that 'String', and returns it. */
 
     /* Where the '?' Operator Can Be Used */
     /* The '?' operator can only be used in functions whose return type is
@@ -374,50 +301,10 @@ fn main() {
      * 'Result<T,E>' methods to handle the 'Result<T,E>' in whatever way is
      * appropriate.
      *
-     * The error message also mentions that '?' can be used with 'Option<T>'
-     * values as well. As with using '?' on 'Result', you can only use '?' on
-     * 'Option' in a function that returns an 'Option'. The behavior of the '?'
-     * operator when called on an 'Option<T>' is similar to its behavior when
-     * called on a 'Result<T,E>': if the value is 'None', the 'None' will be
-     * returned early from the function at that point. If the value is 'Some',
-     * the value inside the 'Some' is the resulting value of the expression
-     * and the function continues. */
-    fn last_char_of_first_line(text: &str) -> Option<char> {
-        text.lines().next()?.chars().last()
-    }
+     * The error also mentions 'Option'. Using '?' on an 'Option' is shown
+     * by 'last_char_of_first_line' in lib.rs. */
     println!("{:?}", last_char_of_first_line("hello again!"));
-    /* This function returns 'Option<char>' because it’s possible that there
-     * is a character there, but it’s also possible that there isn’t. This code
-     * takes the 'text' string slice argument and calls the 'lines' method on
-     * it, which returns an iterator over the lines in the string. Because this
-     * function wants to examine the first line, it calls 'next' on the
-     * iterator to get the first value from the iterator. If 'text' is an empty
-     * string, this call to 'next' will return 'None', in which case we use '?'
-     * to stop and return 'None' from '_last_char_of_first_line'. If 'text' is
-     * not an empty string, 'next' will return a 'Some' value containing a
-     * string slice of the first line in 'text'.
-     *
-     * The '?' extracts the string slice, and we can call 'chars' on that
-     * string slice to get an iterator of its characters. We’re interested in
-     * the last character in this first line, so we call 'last' to return the
-     * last item in the iterator. This is an 'Option' because it’s possible
-     * that the first line is an empty string, for example if 'text' starts
-     * with a blank line but has characters on other lines, as in "\nhi".
-     * However, if there is a last character on the first line, it will be
-     * returned in the 'Some' variant. The '?' operator in the middle gives us
-     * a concise way to express this logic, allowing us to implement the
-     * function in one line. If we couldn’t use the '?' operator on 'Option',
-     * we’d have to implement this logic using more method calls or a 'match'
-     * expression.
-     *
-     * Note that you can use the '?' operator on a 'Result' in a function that
-     * returns 'Result', and you can use the '?' operator on an 'Option' in a
-     * function that returns 'Option', but you can’t mix and match. The '?'
-     * operator won’t automatically convert a 'Result' to an 'Option' or vice
-     * versa; in those cases, you can use methods like the 'ok' method on
-     * 'Result' or the 'ok_or' method on 'Option' to do the conversion
-     * explicitly.
-     * So far, all the 'main' functions we’ve used return '()'. The 'main'
+    /* So far, all the 'main' functions we’ve used return '()'. The 'main'
      * function is special because it’s the entry and exit point of executable
      * programs, and there are restrictions on what its return type can be for
      * the programs to behave as expected.
@@ -455,4 +342,111 @@ fn main() {
      * that returns an 'ExitCode'. Consult the standard library documentation
      * for more information on implementing the 'Termination' trait for your
      * own types. */
+
+    /* Trying Again */
+    /* Handling an error doesn't always mean giving up or panicking: some
+     * failures are worth another try. Below, a read of 'hello.txt' is
+     * interrupted twice before it gets through, the way a read can be when
+     * a signal arrives. 'retry_if' (see retry.rs) tries again after an
+     * 'Interrupted' error, waiting a little longer each time, but would stop
+     * at anything else: if the file weren't there, trying again wouldn't
+     * help. */
+    use std::io::ErrorKind;
+    use std::time::Duration;
+
+    let mut interruptions = 2;
+    let flaky_read = retry_if(
+        5,
+        Duration::from_millis(10),
+        |e: &io::Error| e.kind() == ErrorKind::Interrupted,
+        || {
+            if interruptions > 0 {
+                interruptions -= 1;
+                return Err(io::Error::from(ErrorKind::Interrupted));
+            }
+            fs::read_to_string(hello.path())
+        },
+    );
+    match flaky_read {
+        Ok(s) => println!("Read after retrying: {}", s.trim()),
+        Err(e) => println!("Gave up: {}", e),
+    }
+    /* When every error is worth another try, 'retry' leaves out the
+     * predicate. If some other program writes 'number.txt', it may not have
+     * finished when we first look, so here a missing file or a half-written
+     * number both get two more chances. Nothing writes one in this example,
+     * so all three tries fail, and 'or_default_with' counts from 0 instead:
+     * it hands the error to the closure and gives back 'u32::default()'. */
+    let visits = retry(3, Duration::from_millis(10), || {
+        read_number_from_file(Path::new("number.txt"))
+    })
+    .or_default_with(|e| println!("Gave up: {}", e));
+    println!("Counting visits from {}", visits);
+
+    /* Adding Context */
+    /* Each error above says what went wrong, but not what we were doing at
+     * the time. The 'Context' trait (see context.rs) adds a '.context'
+     * method to any 'Result' whose error is a real error type. It wraps the
+     * error in a 'ContextError' that says what we were trying to do and
+     * keeps the original as its 'source'. Contexts added at each level stack
+     * up, and printing the error prints them all, outermost first. The
+     * second time, the greeting file isn't there. */
+    fn read_greeting(path: &Path) -> Result<String, ContextError> {
+        fs::read_to_string(path).context(format!("reading the greeting from {}", path.display()))
+    }
+    fn start_up(greeting: &Path, count: &Path) -> Result<(), ContextError> {
+        let greeting = read_greeting(greeting).context("starting up")?;
+        let count = read_number_from_file(count).context("loading the visitor count")?;
+        println!("{} (visitor number {})", greeting.trim(), count);
+        Ok(())
+    }
+    for greeting in [hello.path(), no_hello.path()] {
+        let _ = start_up(greeting, number.path()).log_err("error");
+    }
+
+    /* Errors Worth Reading */
+    /* When the problem is in a file someone wrote by hand, the most useful
+     * thing an error can do is point at the line. The 'config' module (see
+     * config.rs) reads 'key = value' settings and, when something's wrong,
+     * says which line and quotes what it found there. First the file on
+     * disk, which isn't there unless you've made one. Without it we go on
+     * with an empty 'Config', its default: */
+    let settings = Config::load("settings.conf").or_default_with(|e| println!("{}", e));
+    println!("settings.conf sets name to {:?}", settings.get("name"));
+    /* Then a few files' worth of text that each go wrong in a different
+     * way. The last one is fine until we ask for 'retries' as a number. */
+    let files: [&[u8]; 4] = [
+        b"name = Ferris\nretries = 3\nname = Corro\n",
+        b"name = Ferris\nretries 3\n",
+        b"# caf\xe9 settings\nname = Ferris\n",
+        b"name = Ferris\nretries = three\n",
+    ];
+    for text in files {
+        let retries = Config::parse(text)
+            .and_then(|config| config.value::<u32>("retries"))
+            .log_err("reading the settings");
+        if let Ok(retries) = retries {
+            println!("retries = {}", retries);
+        }
+    }
+
+    /* Exit Codes */
+    /* Our 'main' returns an 'AppResult' (see exit.rs), which implements the
+     * 'Termination' trait described above. Every error so far was only
+     * printed, so the program carries on past it. The settings file is
+     * different: we don't need one, but if there is one and it's broken,
+     * we'd rather stop than quietly ignore it. That error goes back from
+     * 'main', and the exit code says what kind of problem it was: 78 for a
+     * mistake in the file, for example, and 74 if it couldn't be read at
+     * all. */
+    fn check_settings() -> Result<(), AppError> {
+        let config = match Config::load("settings.conf") {
+            Err(ConfigError::NotFound(_)) => return Ok(()),
+            result => result?,
+        };
+        let retries: u32 = config.value("retries")?;
+        println!("settings.conf asks for {} retries", retries);
+        Ok(())
+    }
+    AppResult::from(check_settings())
 }
@@ -0,0 +1,74 @@
+/* Reporting Panics */
+/* When a program panics, Rust calls a 'panic hook' before it starts
+ * unwinding. The default hook prints the message and where the panic
+ * happened to stderr, plus a backtrace if 'RUST_BACKTRACE' is set. That's
+ * fine when someone is watching the terminal. A program that runs
+ * unattended is better off keeping a record it can be asked about later.
+ *
+ * 'install' replaces the hook with one that appends each panic's message,
+ * location, and a backtrace to a log file, and then calls the hook that
+ * was there before, so the usual message still reaches stderr. The
+ * backtrace is captured whatever 'RUST_BACKTRACE' says, because by the time
+ * anyone reads the log it's too late to set it. 'uninstall' puts back
+ * whichever hook 'install' replaced, so a program (or a test) that had
+ * its own hook gets that one back rather than Rust's default. 'install'
+ * keeps the hook it replaced in 'PREVIOUS' for that, shared through an
+ * 'Arc' with the logging hook, which calls it too.
+ *
+ * The hook is shared by every thread in the program, so the log file sits
+ * behind a 'Mutex': two threads panicking at once still write their
+ * reports one after the other. A hook can't return an error, and
+ * panicking inside one aborts the program, so if the log can't be written
+ * the report is dropped. The file is opened by 'install', which can return
+ * an error, so a log that can't be written is noticed there instead. */
+use std::backtrace::Backtrace;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::panic::{self, PanicHookInfo};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+type Hook = Box<dyn Fn(&PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+static PREVIOUS: Mutex<Option<Arc<Hook>>> = Mutex::new(None);
+
+pub fn install(log_path: impl AsRef<Path>) -> io::Result<()> {
+    let log = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path)?;
+    let log = Mutex::new(log);
+    let previous = Arc::new(panic::take_hook());
+    *PREVIOUS.lock().unwrap_or_else(|e| e.into_inner()) = Some(Arc::clone(&previous));
+    panic::set_hook(Box::new(move |info| {
+        if let Ok(mut log) = log.lock() {
+            let _ = write_report(&mut log, info);
+        }
+        previous(info);
+    }));
+    Ok(())
+}
+
+pub fn uninstall() {
+    let previous = PREVIOUS.lock().unwrap_or_else(|e| e.into_inner()).take();
+    if let Some(previous) = previous {
+        panic::set_hook(Box::new(move |info| previous(info)));
+    }
+}
+
+fn write_report(log: &mut File, info: &PanicHookInfo) -> io::Result<()> {
+    let message = if let Some(s) = info.payload().downcast_ref::<&str>() {
+        s
+    } else if let Some(s) = info.payload().downcast_ref::<String>() {
+        s.as_str()
+    } else {
+        "Box<dyn Any>"
+    };
+    let location = match info.location() {
+        Some(location) => location.to_string(),
+        None => String::from("an unknown location"),
+    };
+    writeln!(log, "panicked at {location}: {message}")?;
+    writeln!(log, "backtrace:\n{}", Backtrace::force_capture())?;
+    log.flush()
+}
@@ -0,0 +1,66 @@
+/* Recovering from a Failed Open */
+/* The book's nested 'match' on 'error.kind()' decides what to do about a
+ * file that wouldn't open: create it if it wasn't there, and panic for
+ * anything else. 'recover_open' is that 'match' as a function. Instead of
+ * panicking it returns the error, so the caller decides how bad that is.
+ *
+ * What to do about each kind of error is set by a 'Recovery':
+ *
+ * - 'NotFound' creates the file, if 'create_if_missing' is set.
+ * - 'Interrupted' means a signal arrived before the open finished, and
+ *   nothing is wrong with the file, so we try again right away, up to
+ *   'interrupted_retries' more times.
+ * - Any other kind of error, like 'PermissionDenied', is returned as it
+ *   is: there's nothing we can do about it from here.
+ *
+ * Like 'File::create' in the book's version, a file we create is opened
+ * for writing, not reading. There's nothing in it to read yet. */
+use crate::retry::retry_if;
+use std::fs::File;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Recovery {
+    pub create_if_missing: bool,
+    pub interrupted_retries: u32,
+}
+
+impl Default for Recovery {
+    fn default() -> Recovery {
+        Recovery {
+            create_if_missing: true,
+            interrupted_retries: 3,
+        }
+    }
+}
+
+pub fn recover_open(path: impl AsRef<Path>) -> io::Result<File> {
+    recover_open_with(path, Recovery::default())
+}
+
+pub fn recover_open_with(path: impl AsRef<Path>, recovery: Recovery) -> io::Result<File> {
+    recover_open_using(path.as_ref(), recovery, |path| File::open(path))
+}
+
+/* 'open' stands in for 'File::open', so the tests can make it fail in
+ * ways that are hard to cause with a real file, like being interrupted. */
+pub(crate) fn recover_open_using(
+    path: &Path,
+    recovery: Recovery,
+    mut open: impl FnMut(&Path) -> io::Result<File>,
+) -> io::Result<File> {
+    retry_if(
+        recovery.interrupted_retries.saturating_add(1),
+        Duration::ZERO,
+        |e: &io::Error| e.kind() == ErrorKind::Interrupted,
+        || match open(path) {
+            Ok(file) => Ok(file),
+            Err(error) => match error.kind() {
+                ErrorKind::NotFound if recovery.create_if_missing => File::create(path),
+                _ => Err(error),
+            },
+        },
+    )
+}
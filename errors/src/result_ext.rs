@@ -0,0 +1,59 @@
+/* Doing Something with an Error on the Way Past */
+/* Often the only thing to do about an error is to let someone know and
+ * carry on, but a 'match' with an arm that prints the error and an arm
+ * that does the real work buries the real work. 'ResultExt' adds a few
+ * methods to every 'Result' for that:
+ *
+ * - 'tap_err' calls a function with the error, if there is one, and hands
+ *   back the 'Result' unchanged, so the chain carries on as before.
+ * - 'log_err' is 'tap_err' with the function already written: it prints
+ *   the error to stderr after a prefix saying what we were doing.
+ * - 'or_default_with' ends the chain. It gives back the 'Ok' value, or
+ *   hands the error to a function and gives back the type's default
+ *   value, for when there's a sensible value to fall back on.
+ *
+ * The trait is implemented once for every 'Result<T, E>', the way the
+ * standard library adds methods to types it didn't define: a trait of our
+ * own, and a generic 'impl' of it. */
+use std::fmt;
+
+pub trait ResultExt<T, E> {
+    fn tap_err(self, f: impl FnOnce(&E)) -> Result<T, E>;
+
+    fn log_err(self, prefix: &str) -> Result<T, E>
+    where
+        E: fmt::Display;
+
+    fn or_default_with(self, f: impl FnOnce(E)) -> T
+    where
+        T: Default;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    fn tap_err(self, f: impl FnOnce(&E)) -> Result<T, E> {
+        if let Err(e) = &self {
+            f(e);
+        }
+        self
+    }
+
+    fn log_err(self, prefix: &str) -> Result<T, E>
+    where
+        E: fmt::Display,
+    {
+        self.tap_err(|e| eprintln!("{prefix}: {e}"))
+    }
+
+    fn or_default_with(self, f: impl FnOnce(E)) -> T
+    where
+        T: Default,
+    {
+        match self {
+            Ok(value) => value,
+            Err(e) => {
+                f(e);
+                T::default()
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+/* Trying Again */
+/* Some errors are worth another try. A read that was interrupted by a
+ * signal, or a file another program is halfway through writing, may well
+ * work a moment later, and giving up on the first 'Err' would be giving up
+ * too soon. Other errors won't go away however often we ask: a file that
+ * doesn't exist now won't exist in ten milliseconds either.
+ *
+ * 'retry_if' calls 'op' until it returns 'Ok', it returns an error that
+ * 'is_retryable' says not to retry, or it has been called 'attempts' times.
+ * Between tries it sleeps, starting at 'backoff' and doubling the wait each
+ * time, so something that's busy gets longer and longer to recover. Either
+ * way, what comes back is the last thing 'op' returned, so the caller sees
+ * the real error and not one made up by the retrying.
+ *
+ * 'op' is an 'FnMut' because the whole point is to call it more than once,
+ * and it will usually want to count or change something as it goes. An
+ * 'attempts' of zero still calls 'op' once: there's no error to return
+ * without trying. */
+use std::thread;
+use std::time::Duration;
+
+pub fn retry_if<T, E>(
+    attempts: u32,
+    backoff: Duration,
+    is_retryable: impl Fn(&E) -> bool,
+    mut op: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let mut wait = backoff;
+    let mut tries = 1;
+    loop {
+        match op() {
+            Err(e) if tries < attempts && is_retryable(&e) => {
+                thread::sleep(wait);
+                wait = wait.saturating_mul(2);
+                tries += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/* Retries every error. */
+pub fn retry<T, E>(
+    attempts: u32,
+    backoff: Duration,
+    op: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    retry_if(attempts, backoff, |_| true, op)
+}
@@ -0,0 +1,557 @@
+use crate::config::ConfigError;
+use crate::exit::{self, exit_code};
+use crate::recover::recover_open_using;
+use crate::*;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::panic;
+use std::path::Path;
+use std::process::{ExitCode, Termination};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use test_support::{TempDir, TempFile};
+
+fn not_found() -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, "hello.txt is missing")
+}
+
+#[test]
+fn test_question_mark_converts_io_errors() {
+    fn open() -> Result<(), AppError> {
+        Err(not_found())?;
+        Ok(())
+    }
+    match open() {
+        Err(AppError::Io(e)) => assert_eq!(e.kind(), io::ErrorKind::NotFound),
+        other => panic!("expected an io error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_question_mark_converts_parse_errors() {
+    fn parse(text: &str) -> Result<u32, AppError> {
+        Ok(text.parse()?)
+    }
+    assert_eq!(parse("42").unwrap(), 42);
+    assert!(matches!(parse("forty-two"), Err(AppError::Parse(_))));
+}
+
+#[test]
+fn test_display_says_which_step_failed() {
+    let io = AppError::from(not_found());
+    assert_eq!(
+        io.to_string(),
+        "couldn't read the file: hello.txt is missing"
+    );
+
+    let parse = AppError::from("x".parse::<u32>().unwrap_err());
+    assert_eq!(
+        parse.to_string(),
+        "couldn't read a number: invalid digit found in string"
+    );
+}
+
+#[test]
+fn test_source_is_the_wrapped_error() {
+    let error = AppError::from(not_found());
+    let source = error.source().expect("a source");
+    assert_eq!(source.to_string(), "hello.txt is missing");
+    assert!(source.downcast_ref::<io::Error>().is_some());
+
+    let error = AppError::from("".parse::<u32>().unwrap_err());
+    let source = error.source().expect("a source");
+    assert!(source.downcast_ref::<std::num::ParseIntError>().is_some());
+}
+
+#[test]
+fn test_retry_stops_at_the_first_success() {
+    let mut calls = 0;
+    let result: Result<u32, &str> = retry(5, Duration::ZERO, || {
+        calls += 1;
+        if calls < 3 {
+            Err("not yet")
+        } else {
+            Ok(calls)
+        }
+    });
+    assert_eq!(result, Ok(3));
+    assert_eq!(calls, 3);
+}
+
+#[test]
+fn test_retry_gives_up_with_the_last_error() {
+    let mut calls = 0;
+    let result: Result<(), u32> = retry(4, Duration::ZERO, || {
+        calls += 1;
+        Err(calls)
+    });
+    assert_eq!(result, Err(4));
+    assert_eq!(calls, 4);
+}
+
+#[test]
+fn test_retry_tries_once_even_with_zero_attempts() {
+    let mut calls = 0;
+    let result: Result<(), &str> = retry(0, Duration::ZERO, || {
+        calls += 1;
+        Err("no")
+    });
+    assert_eq!(result, Err("no"));
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn test_retry_if_stops_at_an_error_that_isnt_retryable() {
+    let mut kinds = vec![io::ErrorKind::Interrupted, io::ErrorKind::NotFound].into_iter();
+    let mut calls = 0;
+    let result: Result<(), io::Error> = retry_if(
+        5,
+        Duration::ZERO,
+        |e: &io::Error| e.kind() == io::ErrorKind::Interrupted,
+        || {
+            calls += 1;
+            Err(io::Error::from(kinds.next().unwrap()))
+        },
+    );
+    assert_eq!(result.unwrap_err().kind(), io::ErrorKind::NotFound);
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn test_retry_waits_longer_each_time() {
+    let backoff = Duration::from_millis(5);
+    let start = Instant::now();
+    let _: Result<(), ()> = retry(3, backoff, || Err(()));
+    // Two waits between three tries: 5ms, then 10ms.
+    assert!(start.elapsed() >= backoff * 3);
+}
+
+fn missing_greeting() -> Result<String, ContextError> {
+    Err::<String, _>(not_found()).context("reading the greeting")
+}
+
+#[test]
+fn test_context_passes_ok_values_through() {
+    let result: Result<u32, io::Error> = Ok(7);
+    assert_eq!(result.context("counting").unwrap(), 7);
+}
+
+#[test]
+fn test_context_is_printed_before_the_error() {
+    let error = missing_greeting().unwrap_err();
+    assert_eq!(error.context(), "reading the greeting");
+    assert_eq!(
+        error.to_string(),
+        "reading the greeting: hello.txt is missing"
+    );
+}
+
+#[test]
+fn test_stacked_contexts_print_the_whole_chain_once() {
+    let error = missing_greeting().context("starting up").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "starting up: reading the greeting: hello.txt is missing"
+    );
+
+    let error = Err::<(), _>(AppError::from(not_found()))
+        .context("loading")
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "loading: couldn't read the file: hello.txt is missing"
+    );
+}
+
+#[test]
+fn test_context_keeps_the_source_chain() {
+    let error = missing_greeting().context("starting up").unwrap_err();
+
+    let inner = error.source().expect("the inner context");
+    let inner = inner
+        .downcast_ref::<ContextError>()
+        .expect("a ContextError");
+    assert_eq!(inner.context(), "reading the greeting");
+
+    let root = inner.source().expect("the io error");
+    let root = root.downcast_ref::<io::Error>().expect("an io::Error");
+    assert_eq!(root.kind(), io::ErrorKind::NotFound);
+    assert!(root.source().is_none());
+}
+
+#[test]
+fn test_config_reads_settings_and_skips_comments() {
+    let config = Config::parse(b"# the basics\n\nname = Ferris\r\n  retries=3  \n").unwrap();
+    assert_eq!(config.get("name"), Some("Ferris"));
+    assert_eq!(config.value::<u32>("retries").unwrap(), 3);
+    assert_eq!(config.get("missing"), None);
+}
+
+#[test]
+fn test_config_allows_an_equals_sign_in_the_value() {
+    let config = Config::parse(b"greeting = 1 + 1 = 2\n").unwrap();
+    assert_eq!(config.get("greeting"), Some("1 + 1 = 2"));
+}
+
+#[test]
+fn test_config_reports_a_duplicate_key_with_both_lines() {
+    let error = Config::parse(b"name = Ferris\n# again\nname = Corro\n").unwrap_err();
+    assert!(matches!(
+        error,
+        ConfigError::DuplicateKey { ref key, line: 3, first: 1 } if key == "name"
+    ));
+    assert_eq!(
+        error.to_string(),
+        "line 3: 'name' was already set on line 1"
+    );
+}
+
+#[test]
+fn test_config_reports_a_line_without_a_key_and_value() {
+    for (text, bad) in [
+        (&b"a = 1\nretries 3\n"[..], "retries 3"),
+        (b"a = 1\n= 3\n", "= 3"),
+    ] {
+        let error = Config::parse(text).unwrap_err();
+        assert!(matches!(error, ConfigError::Malformed { line: 2, .. }));
+        assert_eq!(
+            error.to_string(),
+            format!("line 2: expected 'key = value', found '{bad}'")
+        );
+    }
+}
+
+#[test]
+fn test_config_reports_the_line_that_isnt_utf8() {
+    let error = Config::parse(b"name = Ferris\ncity = M\xfcnchen\n").unwrap_err();
+    assert!(matches!(error, ConfigError::BadUtf8 { line: 2, .. }));
+    assert_eq!(
+        error.to_string(),
+        "line 2: 'city = M\u{fffd}nchen' isn't valid UTF-8"
+    );
+}
+
+#[test]
+fn test_config_reports_a_bad_value_where_it_was_set() {
+    let config = Config::parse(b"name = Ferris\n\nretries = three\n").unwrap();
+    let error = config.value::<u32>("retries").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 3: 'three' isn't a valid value for 'retries' (invalid digit found in string)"
+    );
+    assert!(matches!(
+        config.value::<u32>("timeout"),
+        Err(ConfigError::MissingKey(key)) if key == "timeout"
+    ));
+}
+
+#[test]
+fn test_config_loads_a_file() {
+    let file = TempFile::with_contents("errors-config", "name = Ferris\nretries = 2\n").unwrap();
+    let config = Config::load(file.path()).unwrap();
+    assert_eq!(config.value::<u32>("retries").unwrap(), 2);
+}
+
+#[test]
+fn test_config_says_when_the_file_is_missing() {
+    let file = TempFile::new("errors-config");
+    let error = Config::load(file.path()).unwrap_err();
+    assert!(matches!(&error, ConfigError::NotFound(path) if path == file.path()));
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn test_config_keeps_other_read_errors_as_the_source() {
+    let dir = TempDir::new("errors-config").unwrap();
+    let error = Config::load(dir.path()).unwrap_err();
+    assert!(matches!(error, ConfigError::Io { .. }));
+    assert!(error
+        .source()
+        .unwrap()
+        .downcast_ref::<io::Error>()
+        .is_some());
+}
+
+#[test]
+fn test_exit_codes_tell_the_kinds_of_error_apart() {
+    let missing = AppError::from(not_found());
+    let unreadable = AppError::from(io::Error::from(io::ErrorKind::PermissionDenied));
+    let not_a_number = AppError::from("x".parse::<u32>().unwrap_err());
+    let no_settings = AppError::from(ConfigError::NotFound("settings.conf".into()));
+    let bad_settings = AppError::from(ConfigError::MissingKey(String::from("retries")));
+
+    assert_eq!(exit_code(&missing), exit::NO_INPUT);
+    assert_eq!(exit_code(&unreadable), exit::IO_ERROR);
+    assert_eq!(exit_code(&not_a_number), exit::DATA_ERROR);
+    assert_eq!(exit_code(&no_settings), exit::NO_INPUT);
+    assert_eq!(exit_code(&bad_settings), exit::CONFIG_ERROR);
+}
+
+#[test]
+fn test_app_result_reports_success_and_failure_codes() {
+    assert_eq!(AppResult(Ok(())).report(), ExitCode::SUCCESS);
+    assert_eq!(
+        AppResult::from(Err(AppError::from(not_found()))).report(),
+        ExitCode::from(exit::NO_INPUT)
+    );
+}
+
+/* The panic hook is shared by the whole program, and tests run on several
+ * threads at once, so the tests that swap it take turns. */
+static HOOK: Mutex<()> = Mutex::new(());
+
+#[test]
+fn test_panic_reporting_logs_a_caught_panic() {
+    let _turn = HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    let log = TempFile::new("errors-panic");
+    panic_reporting::install(log.path()).unwrap();
+    let caught = panic::catch_unwind(|| panic!("crash and burn"));
+    panic_reporting::uninstall();
+    assert!(caught.is_err());
+
+    let report = log.read().unwrap();
+    let first = report.lines().next().unwrap();
+    assert!(first.starts_with("panicked at src/tests.rs:"), "{first}");
+    assert!(first.ends_with(": crash and burn"), "{first}");
+    assert!(report.contains("\nbacktrace:\n"));
+}
+
+#[test]
+fn test_panic_reporting_uninstall_restores_the_previous_hook() {
+    static CALLED: AtomicBool = AtomicBool::new(false);
+
+    let _turn = HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    let original = panic::take_hook();
+    panic::set_hook(Box::new(|_| CALLED.store(true, Ordering::SeqCst)));
+
+    let log = TempFile::new("errors-panic");
+    panic_reporting::install(log.path()).unwrap();
+    panic_reporting::uninstall();
+    let caught = panic::catch_unwind(|| panic!("after uninstall"));
+    panic::set_hook(original);
+
+    assert!(caught.is_err());
+    assert!(CALLED.load(Ordering::SeqCst));
+    assert_eq!(log.read().unwrap_or_default(), "");
+}
+
+#[test]
+fn test_panic_reporting_fails_to_install_without_a_log() {
+    let dir = TempDir::new("errors-panic").unwrap();
+    assert!(panic_reporting::install(dir.path()).is_err());
+}
+
+#[test]
+fn test_fixture_is_removed_when_dropped() {
+    let file = fixtures::TempFile::with_contents("hello.txt", "Ferris\n").unwrap();
+    let path = file.path().to_path_buf();
+    assert!(path.ends_with("hello.txt"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "Ferris\n");
+
+    drop(file);
+    assert!(!path.exists());
+    assert!(!path.parent().unwrap().exists());
+}
+
+#[test]
+fn test_missing_fixture_cleans_up_a_file_created_later() {
+    let file = fixtures::TempFile::missing("hello.txt").unwrap();
+    let path = file.path().to_path_buf();
+    assert_eq!(
+        File::open(&path).unwrap_err().kind(),
+        io::ErrorKind::NotFound
+    );
+
+    File::create(&path).unwrap();
+    drop(file);
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_fixtures_with_the_same_name_get_their_own_files() {
+    let first = fixtures::TempFile::with_contents("hello.txt", "first").unwrap();
+    let second = fixtures::TempFile::with_contents("hello.txt", "second").unwrap();
+    assert_ne!(first.path(), second.path());
+    assert_eq!(fs::read_to_string(first.path()).unwrap(), "first");
+}
+
+fn interrupted_then(
+    mut interruptions: u32,
+    then: io::ErrorKind,
+) -> impl FnMut(&Path) -> io::Result<File> {
+    move |_| {
+        if interruptions > 0 {
+            interruptions -= 1;
+            Err(io::Error::from(io::ErrorKind::Interrupted))
+        } else {
+            Err(io::Error::from(then))
+        }
+    }
+}
+
+#[test]
+fn test_recover_open_opens_a_file_that_is_there() {
+    let file = TempFile::with_contents("errors-recover", "Ferris").unwrap();
+    let mut greeting = String::new();
+    recover_open(file.path())
+        .unwrap()
+        .read_to_string(&mut greeting)
+        .unwrap();
+    assert_eq!(greeting, "Ferris");
+}
+
+#[test]
+fn test_recover_open_creates_a_missing_file() {
+    let file = TempFile::new("errors-recover");
+    assert!(!file.path().exists());
+    recover_open(file.path()).unwrap();
+    assert!(file.path().exists());
+}
+
+#[test]
+fn test_recover_open_with_can_leave_a_missing_file_missing() {
+    let file = TempFile::new("errors-recover");
+    let recovery = Recovery {
+        create_if_missing: false,
+        ..Recovery::default()
+    };
+    let error = recover_open_with(file.path(), recovery).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    assert!(!file.path().exists());
+}
+
+#[test]
+fn test_recover_open_returns_errors_it_cant_recover_from() {
+    let dir = TempDir::new("errors-recover").unwrap();
+    let path = dir.path().join("no-such-dir").join("hello.txt");
+    // Creating fails too: the directory it would go in isn't there.
+    let error = recover_open(&path).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::NotFound);
+
+    let opens = interrupted_then(0, io::ErrorKind::PermissionDenied);
+    let error = recover_open_using(&path, Recovery::default(), opens).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+}
+
+#[test]
+fn test_recover_open_tries_again_after_an_interruption() {
+    let file = TempFile::new("errors-recover");
+    let opens = interrupted_then(3, io::ErrorKind::NotFound);
+    recover_open_using(file.path(), Recovery::default(), opens).unwrap();
+    assert!(file.path().exists());
+}
+
+#[test]
+fn test_recover_open_stops_retrying_after_the_limit() {
+    let file = TempFile::new("errors-recover");
+    let recovery = Recovery {
+        interrupted_retries: 2,
+        ..Recovery::default()
+    };
+    let opens = interrupted_then(3, io::ErrorKind::NotFound);
+    let error = recover_open_using(file.path(), recovery, opens).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::Interrupted);
+    assert!(!file.path().exists());
+}
+
+#[test]
+fn test_every_username_reader_reads_the_same_name() {
+    let file = TempFile::with_contents("errors-username", "Ferris\n").unwrap();
+    let readers = [
+        read_username_with_match,
+        read_username_with_question_mark,
+        read_username_chained,
+        read_username_from_file,
+    ];
+    for read in readers {
+        assert_eq!(read(file.path()).unwrap(), "Ferris\n");
+    }
+}
+
+#[test]
+fn test_every_username_reader_passes_back_a_missing_file() {
+    let file = TempFile::new("errors-username");
+    let readers = [
+        read_username_with_match,
+        read_username_with_question_mark,
+        read_username_chained,
+        read_username_from_file,
+    ];
+    for read in readers {
+        match read(file.path()) {
+            Err(AppError::Io(e)) => assert_eq!(e.kind(), io::ErrorKind::NotFound),
+            other => panic!("expected a missing file, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_username_readers_reject_a_file_that_isnt_utf8() {
+    let dir = TempDir::new("errors-username").unwrap();
+    let path = dir.path().join("hello.txt");
+    fs::write(&path, b"Fe\xffris").unwrap();
+    let error = read_username_with_match(&path).unwrap_err();
+    assert!(matches!(error, AppError::Io(e) if e.kind() == io::ErrorKind::InvalidData));
+}
+
+#[test]
+fn test_read_number_from_file_trims_and_parses() {
+    let file = TempFile::with_contents("errors-number", "  42\n").unwrap();
+    assert_eq!(read_number_from_file(file.path()).unwrap(), 42);
+
+    let file = TempFile::with_contents("errors-number", "Ferris\n").unwrap();
+    assert!(matches!(
+        read_number_from_file(file.path()),
+        Err(AppError::Parse(_))
+    ));
+
+    let file = TempFile::new("errors-number");
+    assert!(matches!(
+        read_number_from_file(file.path()),
+        Err(AppError::Io(_))
+    ));
+}
+
+#[test]
+fn test_last_char_of_first_line_looks_only_at_the_first_line() {
+    assert_eq!(last_char_of_first_line("hello again!"), Some('!'));
+    assert_eq!(last_char_of_first_line("Hi\nthere"), Some('i'));
+    assert_eq!(last_char_of_first_line("\nhi"), None);
+    assert_eq!(last_char_of_first_line(""), None);
+}
+
+#[test]
+fn test_tap_err_sees_only_errors() {
+    let mut seen = Vec::new();
+    let ok: Result<u32, &str> = Ok(1);
+    assert_eq!(ok.tap_err(|e| seen.push(*e)), Ok(1));
+    let err: Result<u32, &str> = Err("no");
+    assert_eq!(err.tap_err(|e| seen.push(*e)), Err("no"));
+    assert_eq!(seen, ["no"]);
+}
+
+#[test]
+fn test_log_err_passes_the_result_on_unchanged() {
+    let ok: Result<u32, AppError> = Ok(7);
+    assert_eq!(ok.log_err("reading").unwrap(), 7);
+
+    let err: Result<u32, AppError> = Err(AppError::from(not_found()));
+    match err.log_err("reading") {
+        Err(AppError::Io(e)) => assert_eq!(e.kind(), io::ErrorKind::NotFound),
+        other => panic!("expected the io error back, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_or_default_with_falls_back_after_handing_over_the_error() {
+    let mut handed = None;
+    let ok: Result<u32, &str> = Ok(3);
+    assert_eq!(ok.or_default_with(|e| handed = Some(e)), 3);
+    assert_eq!(handed, None);
+
+    let err: Result<Vec<u32>, &str> = Err("no");
+    assert_eq!(err.or_default_with(|e| handed = Some(e)), Vec::<u32>::new());
+    assert_eq!(handed, Some("no"));
+}
@@ -0,0 +1,44 @@
+/* Running the built program in a directory of our choosing, the way a
+ * shell script would, and checking the exit code it gives back. */
+use std::process::Command;
+use test_support::TempDir;
+
+fn run_in(dir: &TempDir) -> Option<i32> {
+    let output = Command::new(env!("CARGO_BIN_EXE_errors"))
+        .current_dir(dir.path())
+        .output()
+        .expect("the errors binary runs");
+    output.status.code()
+}
+
+#[test]
+fn test_exits_cleanly_without_a_settings_file() {
+    let dir = TempDir::new("errors-exit").unwrap();
+    assert_eq!(run_in(&dir), Some(0));
+}
+
+#[test]
+fn test_exits_cleanly_with_good_settings() {
+    let dir = TempDir::new("errors-exit").unwrap();
+    dir.write("settings.conf", "retries = 3\n").unwrap();
+    assert_eq!(run_in(&dir), Some(0));
+}
+
+#[test]
+fn test_broken_settings_exit_with_the_config_code() {
+    let dir = TempDir::new("errors-exit").unwrap();
+    dir.write("settings.conf", "retries = 3\nretries = 4\n")
+        .unwrap();
+    assert_eq!(run_in(&dir), Some(78));
+
+    let dir = TempDir::new("errors-exit").unwrap();
+    dir.write("settings.conf", "retries = lots\n").unwrap();
+    assert_eq!(run_in(&dir), Some(78));
+}
+
+#[test]
+fn test_unreadable_settings_exit_with_the_io_code() {
+    let dir = TempDir::new("errors-exit").unwrap();
+    std::fs::create_dir(dir.path().join("settings.conf")).unwrap();
+    assert_eq!(run_in(&dir), Some(74));
+}
@@ -0,0 +1,46 @@
+use std::env;
+use std::panic;
+use std::process;
+
+use exercises::{check, find, reference_solutions, your_attempts, Outcome};
+
+/* Usage: check [name]
+ *
+ * Checks every attempt in 'attempts.rs', or just the named one, against
+ * the reference solutions. Exits with a non-zero status if anything
+ * didn't pass. */
+fn main() {
+    /* The default panic hook prints every caught 'todo!()' to stderr; the
+     * checker reports those itself. */
+    panic::set_hook(Box::new(|_| {}));
+
+    let solutions = reference_solutions();
+    let attempts = your_attempts();
+    let only = env::args().nth(1);
+
+    let mut passed = 0;
+    let mut total = 0;
+    for attempt in &attempts {
+        if only.as_deref().is_some_and(|name| name != attempt.name()) {
+            continue;
+        }
+        let reference = find(&solutions, attempt.name()).expect("every attempt has a solution");
+
+        for result in check(reference, attempt.as_ref()) {
+            total += 1;
+            if result.outcome == Outcome::Pass {
+                passed += 1;
+            }
+            println!("{result}");
+        }
+    }
+
+    if total == 0 {
+        eprintln!("No exercise named '{}'", only.unwrap_or_default());
+        process::exit(1);
+    }
+    println!("\n{passed}/{total} examples passed");
+    if passed < total {
+        process::exit(1);
+    }
+}
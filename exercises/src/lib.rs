@@ -0,0 +1,152 @@
+/* EXERCISES */
+/* A few chapters end with suggestions for programs to write on your own.
+ * This crate collects them behind one trait, 'Exercise', so they can all
+ * be run and checked the same way: each one takes its input as text and
+ * produces its output as text.
+ *
+ * 'solutions.rs' holds a reference solution for every exercise, and
+ * 'attempts.rs' holds an empty function for each one for you to fill in.
+ * 'cargo run --bin check' runs your functions on each exercise's examples
+ * and compares what they produce with the reference solutions. */
+pub mod attempts;
+pub mod solutions;
+
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+pub trait Exercise {
+    fn name(&self) -> &'static str;
+    fn description(&self) -> &'static str;
+    /* Inputs the checker feeds to both the reference solution and the
+     * attempt. */
+    fn examples(&self) -> &'static [&'static str];
+    fn run(&self, input: &str) -> String;
+}
+
+/* An attempt borrows everything except 'run' from the exercise it's an
+ * attempt at, so the checker uses the same examples for both. */
+pub struct Attempt<E: Exercise> {
+    exercise: E,
+    solve: fn(&str) -> String,
+}
+
+impl<E: Exercise> Attempt<E> {
+    pub fn new(exercise: E, solve: fn(&str) -> String) -> Attempt<E> {
+        Attempt { exercise, solve }
+    }
+}
+
+impl<E: Exercise> Exercise for Attempt<E> {
+    fn name(&self) -> &'static str {
+        self.exercise.name()
+    }
+
+    fn description(&self) -> &'static str {
+        self.exercise.description()
+    }
+
+    fn examples(&self) -> &'static [&'static str] {
+        self.exercise.examples()
+    }
+
+    fn run(&self, input: &str) -> String {
+        (self.solve)(input)
+    }
+}
+
+pub fn reference_solutions() -> Vec<Box<dyn Exercise>> {
+    vec![
+        Box::new(solutions::Temperature),
+        Box::new(solutions::Fibonacci),
+        Box::new(solutions::PigLatin),
+        Box::new(solutions::Departments),
+    ]
+}
+
+pub fn your_attempts() -> Vec<Box<dyn Exercise>> {
+    vec![
+        Box::new(Attempt::new(
+            solutions::Temperature,
+            attempts::convert_temperature,
+        )),
+        Box::new(Attempt::new(solutions::Fibonacci, attempts::fibonacci)),
+        Box::new(Attempt::new(solutions::PigLatin, attempts::pig_latin)),
+        Box::new(Attempt::new(solutions::Departments, attempts::departments)),
+    ]
+}
+
+pub fn find<'a>(exercises: &'a [Box<dyn Exercise>], name: &str) -> Option<&'a dyn Exercise> {
+    exercises
+        .iter()
+        .find(|e| e.name() == name)
+        .map(|e| e.as_ref())
+}
+
+/* Checking an Attempt */
+/* An unfinished attempt is still a 'todo!()', which panics. Rather than
+ * letting that take the whole checker down, 'check' catches the panic with
+ * 'panic::catch_unwind' and records it as an outcome of its own. */
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Panicked(String),
+}
+
+#[derive(Debug)]
+pub struct CheckResult {
+    pub exercise: &'static str,
+    pub input: &'static str,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for CheckResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let input = self.input.replace('\n', " / ");
+        match &self.outcome {
+            Outcome::Pass => write!(f, "PASS {} ({input})", self.exercise),
+            Outcome::Fail { expected, actual } => write!(
+                f,
+                "FAIL {} ({input})\n     expected: {expected:?}\n     got:      {actual:?}",
+                self.exercise
+            ),
+            Outcome::Panicked(message) => {
+                write!(f, "PANIC {} ({input}): {message}", self.exercise)
+            }
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        String::from("(no message)")
+    }
+}
+
+pub fn check(reference: &dyn Exercise, attempt: &dyn Exercise) -> Vec<CheckResult> {
+    reference
+        .examples()
+        .iter()
+        .map(|&input| {
+            let expected = reference.run(input);
+            let outcome = match panic::catch_unwind(AssertUnwindSafe(|| attempt.run(input))) {
+                Ok(actual) if actual == expected => Outcome::Pass,
+                Ok(actual) => Outcome::Fail { expected, actual },
+                Err(payload) => Outcome::Panicked(panic_message(payload)),
+            };
+            CheckResult {
+                exercise: reference.name(),
+                input,
+                outcome,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,34 @@
+use std::env;
+use std::io::{self, Read};
+use std::process;
+
+use exercises::{find, reference_solutions};
+
+/* Usage: exercises [name]
+ *
+ * With no arguments, lists the exercises. With a name, reads the input
+ * from stdin and prints the reference solution's answer, e.g.
+ *
+ * echo 212F | cargo run -- temperature */
+fn main() {
+    let solutions = reference_solutions();
+
+    let Some(name) = env::args().nth(1) else {
+        for exercise in &solutions {
+            println!("{:<12} {}", exercise.name(), exercise.description());
+        }
+        return;
+    };
+
+    let Some(exercise) = find(&solutions, &name) else {
+        eprintln!("No exercise named '{name}'");
+        process::exit(1);
+    };
+
+    let mut input = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut input) {
+        eprintln!("Couldn't read input: {e}");
+        process::exit(1);
+    }
+    println!("{}", exercise.run(&input));
+}
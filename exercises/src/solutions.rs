@@ -0,0 +1,200 @@
+/* Reference Solutions */
+/* Each solution answers with an 'error: ...' line instead of panicking
+ * when its input doesn't make sense, so that bad input is just another
+ * example to check. */
+use std::collections::HashMap;
+
+use crate::Exercise;
+
+/* Temperature Conversion */
+/* From the end of chapter 3: "Convert temperatures between Fahrenheit and
+ * Celsius." The input is a number followed by its unit, like '212F' or
+ * '-40C', and the output is the same temperature in the other unit,
+ * rounded to one decimal place. */
+pub struct Temperature;
+
+impl Exercise for Temperature {
+    fn name(&self) -> &'static str {
+        "temperature"
+    }
+
+    fn description(&self) -> &'static str {
+        "Convert between Fahrenheit and Celsius, e.g. 212F -> 100.0C"
+    }
+
+    fn examples(&self) -> &'static [&'static str] {
+        &["212F", "32F", "-40C", "37C", "98.6f", "hot", "12K"]
+    }
+
+    fn run(&self, input: &str) -> String {
+        let input = input.trim();
+        let Some(unit) = input.chars().last() else {
+            return String::from("error: expected a temperature like 212F");
+        };
+        let number = &input[..input.len() - unit.len_utf8()];
+        let Ok(degrees) = number.trim().parse::<f64>() else {
+            return format!("error: '{number}' is not a number");
+        };
+
+        match unit.to_ascii_uppercase() {
+            'F' => format!("{:.1}C", (degrees - 32.0) * 5.0 / 9.0),
+            'C' => format!("{:.1}F", degrees * 9.0 / 5.0 + 32.0),
+            _ => format!("error: unknown unit '{unit}', expected F or C"),
+        }
+    }
+}
+
+/* Fibonacci */
+/* Also from chapter 3: "Generate the nth Fibonacci number." We count from
+ * fib(0) = 0 and fib(1) = 1. A 'u128' holds every Fibonacci number up to
+ * fib(186); past that, 'checked_add' notices the overflow. */
+pub struct Fibonacci;
+
+impl Exercise for Fibonacci {
+    fn name(&self) -> &'static str {
+        "fibonacci"
+    }
+
+    fn description(&self) -> &'static str {
+        "Print the nth Fibonacci number, e.g. 10 -> 55"
+    }
+
+    fn examples(&self) -> &'static [&'static str] {
+        &["0", "1", "2", "10", "50", "186", "187", "-1", "ten"]
+    }
+
+    fn run(&self, input: &str) -> String {
+        let Ok(n) = input.trim().parse::<u32>() else {
+            return format!(
+                "error: '{}' is not a non-negative whole number",
+                input.trim()
+            );
+        };
+
+        /* 'next' is one step ahead of 'current', so it overflows first. It
+         * only becomes an error if we actually need that value. */
+        let (mut current, mut next): (u128, Option<u128>) = (0, Some(1));
+        for _ in 0..n {
+            let Some(following) = next else {
+                return format!("error: fib({n}) doesn't fit in a u128");
+            };
+            next = current.checked_add(following);
+            current = following;
+        }
+        current.to_string()
+    }
+}
+
+/* Pig Latin */
+/* From the end of chapter 8: "The first consonant of each word is moved to
+ * the end of the word and 'ay' is added, so 'first' becomes 'irst-fay'.
+ * Words that start with a vowel have 'hay' added to the end instead
+ * ('apple' becomes 'apple-hay')." We convert every word in the input and
+ * keep the words in order. Working with 'chars' rather than byte indices
+ * means a word starting with a multi-byte character can't be sliced in
+ * the middle of it. */
+pub struct PigLatin;
+
+fn pig_latin_word(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) if "aeiouAEIOU".contains(first) => format!("{word}-hay"),
+        Some(first) => format!("{}-{first}ay", chars.as_str()),
+        None => String::new(),
+    }
+}
+
+impl Exercise for PigLatin {
+    fn name(&self) -> &'static str {
+        "pig_latin"
+    }
+
+    fn description(&self) -> &'static str {
+        "Convert text to pig latin, e.g. first apple -> irst-fay apple-hay"
+    }
+
+    fn examples(&self) -> &'static [&'static str] {
+        &[
+            "first",
+            "apple",
+            "hello world",
+            "  Rust is   fun ",
+            "",
+            "ñandú",
+        ]
+    }
+
+    fn run(&self, input: &str) -> String {
+        input
+            .split_whitespace()
+            .map(pig_latin_word)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/* Department Directory */
+/* Also from chapter 8: "Using a hash map and vectors, create a text
+ * interface to allow a user to add employee names to a department in a
+ * company; for example, 'Add Sally to Engineering' or 'Add Amir to
+ * Sales'. Then let the user retrieve a list of all people in a department
+ * or all people in the company by department, sorted alphabetically."
+ *
+ * Each line of the input is one command: 'Add <name> to <department>',
+ * 'List <department>', or 'List' on its own for the whole company. Only
+ * the 'List' commands (and mistakes) produce output. */
+pub struct Departments;
+
+fn department_line(department: &str, names: &[String]) -> String {
+    let mut names = names.to_vec();
+    names.sort();
+    format!("{department}: {}", names.join(", "))
+}
+
+impl Exercise for Departments {
+    fn name(&self) -> &'static str {
+        "departments"
+    }
+
+    fn description(&self) -> &'static str {
+        "Add employees with 'Add Sally to Engineering', list them with 'List [department]'"
+    }
+
+    fn examples(&self) -> &'static [&'static str] {
+        &[
+            "Add Sally to Engineering\nAdd Amir to Sales\nAdd Bob to Engineering\nList Engineering",
+            "Add Sally to Engineering\nAdd Amir to Sales\nList",
+            "List Marketing",
+            "Add Sally\nFire Bob",
+        ]
+    }
+
+    fn run(&self, input: &str) -> String {
+        let mut directory: HashMap<String, Vec<String>> = HashMap::new();
+        let mut output = Vec::new();
+
+        for line in input.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words.as_slice() {
+                ["Add", name, "to", department] => directory
+                    .entry(department.to_string())
+                    .or_default()
+                    .push(name.to_string()),
+                ["List"] => {
+                    let mut departments: Vec<_> = directory.iter().collect();
+                    departments.sort();
+                    for (department, names) in departments {
+                        output.push(department_line(department, names));
+                    }
+                }
+                ["List", department] => match directory.get(*department) {
+                    Some(names) => output.push(department_line(department, names)),
+                    None => output.push(format!("error: no department named '{department}'")),
+                },
+                _ => output.push(format!("error: don't know how to '{line}'")),
+            }
+        }
+
+        output.join("\n")
+    }
+}
@@ -0,0 +1,77 @@
+use crate::solutions::*;
+use crate::*;
+
+#[test]
+fn test_temperature() {
+    assert_eq!(Temperature.run("212F"), "100.0C");
+    assert_eq!(Temperature.run("-40C\n"), "-40.0F");
+    assert_eq!(Temperature.run("98.6f"), "37.0C");
+    assert!(Temperature.run("12K").starts_with("error: unknown unit"));
+    assert!(Temperature.run("").starts_with("error:"));
+}
+
+#[test]
+fn test_fibonacci() {
+    let first: Vec<String> = (0..8).map(|n| Fibonacci.run(&n.to_string())).collect();
+    assert_eq!(first, vec!["0", "1", "1", "2", "3", "5", "8", "13"]);
+    assert_eq!(
+        Fibonacci.run("186"),
+        "332825110087067562321196029789634457848"
+    );
+    assert!(Fibonacci.run("187").starts_with("error:"));
+    assert!(Fibonacci.run("-1").starts_with("error:"));
+}
+
+#[test]
+fn test_pig_latin() {
+    assert_eq!(PigLatin.run("first apple"), "irst-fay apple-hay");
+    assert_eq!(PigLatin.run("  Rust is   fun "), "ust-Ray is-hay un-fay");
+    assert_eq!(PigLatin.run("ñandú"), "andú-ñay");
+}
+
+#[test]
+fn test_departments() {
+    assert_eq!(
+        Departments
+            .run("Add Sally to Engineering\nAdd Amir to Sales\nAdd Bob to Engineering\nList"),
+        "Engineering: Bob, Sally\nSales: Amir"
+    );
+    assert_eq!(
+        Departments.run("List Marketing\nFire Bob"),
+        "error: no department named 'Marketing'\nerror: don't know how to 'Fire Bob'"
+    );
+}
+
+#[test]
+fn test_check_outcomes() {
+    let results = check(&Fibonacci, &Fibonacci);
+    assert_eq!(results.len(), Fibonacci.examples().len());
+    assert!(results.iter().all(|r| r.outcome == Outcome::Pass));
+
+    let wrong = Attempt::new(Fibonacci, |_| String::from("1"));
+    let results = check(&Fibonacci, &wrong);
+    assert_eq!(
+        results[0].outcome,
+        Outcome::Fail {
+            expected: String::from("0"),
+            actual: String::from("1")
+        }
+    );
+    assert_eq!(results[1].outcome, Outcome::Pass);
+
+    let unfinished = Attempt::new(Fibonacci, |_| todo!("not yet"));
+    let results = check(&Fibonacci, &unfinished);
+    assert_eq!(
+        results[0].outcome,
+        Outcome::Panicked(String::from("not yet implemented: not yet"))
+    );
+}
+
+#[test]
+fn test_every_attempt_has_a_solution() {
+    let solutions = reference_solutions();
+    for attempt in your_attempts() {
+        let reference = find(&solutions, attempt.name()).unwrap();
+        assert_eq!(attempt.examples(), reference.examples());
+    }
+}
@@ -0,0 +1,243 @@
+/* FUNCTIONAL LANGUAGE FEATURES: Iterators and Closures */
+/* Rust's design has taken inspiration from many existing languages and
+ * techniques, and one significant influence is 'functional programming'.
+ * Programming in a functional style often includes using functions as
+ * values by passing them in arguments, returning them from other
+ * functions, assigning them to variables for later execution, and so
+ * forth. This crate covers 'closures', a function-like construct you can
+ * store in a variable, and 'iterators', a way of processing a series of
+ * elements. */
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/* Capturing the Environment with Closures */
+/* Every so often, our t-shirt company gives away an exclusive,
+ * limited-edition shirt to someone on our mailing list as a promotion.
+ * People on the mailing list can optionally add their favorite color to
+ * their profile. If the person chosen for a free shirt has their favorite
+ * color set, they get that color shirt. If the person hasn't specified a
+ * favorite color, they get whatever color the company currently has the
+ * most of. */
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ShirtColor {
+    Red,
+    Blue,
+}
+
+pub struct Inventory {
+    pub shirts: Vec<ShirtColor>,
+}
+
+impl Inventory {
+    pub fn giveaway(&self, user_preference: Option<ShirtColor>) -> ShirtColor {
+        user_preference.unwrap_or_else(|| self.most_stocked())
+    }
+    /* The 'unwrap_or_else' method on 'Option<T>' takes one argument: a
+     * closure without any arguments that returns a value 'T'. If the
+     * 'Option<T>' is the 'Some' variant, 'unwrap_or_else' returns the
+     * value from within the 'Some'. If it's 'None', it calls the closure
+     * and returns the value the closure returned.
+     *
+     * The closure expression '|| self.most_stocked()' captures an
+     * immutable reference to the 'self' 'Inventory' instance and passes it
+     * with the code we specify to 'unwrap_or_else'. Functions, on the other
+     * hand, are not able to capture their environment in this way. */
+
+    pub fn most_stocked(&self) -> ShirtColor {
+        let mut num_red = 0;
+        let mut num_blue = 0;
+
+        for color in &self.shirts {
+            match color {
+                ShirtColor::Red => num_red += 1,
+                ShirtColor::Blue => num_blue += 1,
+            }
+        }
+        if num_red > num_blue {
+            ShirtColor::Red
+        } else {
+            ShirtColor::Blue
+        }
+    }
+}
+
+/* Storing Closures in Structs: a Memoizer */
+/* Closures don't require type annotations, but when we want to store one
+ * in a struct field we need to name its type. Every closure has its own
+ * unique anonymous type, so we use generics and trait bounds: the 'Fn'
+ * traits. A 'Cacher' holds a closure and an optional result map. It only
+ * runs the expensive closure the first time a given argument is asked for
+ * and saves the result for every later call with the same argument. This
+ * pattern is known as 'memoization' or 'lazy evaluation'.
+ *
+ * 'Fn(K) -> V' is the bound here because we call the closure more than
+ * once and never need it to mutate or move out of its captured values. */
+pub struct Cacher<F, K, V>
+where
+    F: Fn(K) -> V,
+{
+    calculation: F,
+    values: HashMap<K, V>,
+}
+
+impl<F, K, V> Cacher<F, K, V>
+where
+    F: Fn(K) -> V,
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    pub fn new(calculation: F) -> Cacher<F, K, V> {
+        Cacher {
+            calculation,
+            values: HashMap::new(),
+        }
+    }
+
+    pub fn value(&mut self, arg: K) -> V {
+        if let Some(v) = self.values.get(&arg) {
+            return v.clone();
+        }
+        let v = (self.calculation)(arg.clone());
+        self.values.insert(arg, v.clone());
+        v
+    }
+
+    pub fn cached(&self) -> usize {
+        self.values.len()
+    }
+}
+/* An earlier version of 'Cacher' in the book stored a single 'Option<u32>'
+ * and returned the first computed value no matter which argument was
+ * passed afterwards. Keying the stored results by argument in a 'HashMap'
+ * fixes that, and making the argument and return types generic lets the
+ * same 'Cacher' memoize any function whose input is hashable. */
+
+/* Using Closures that Capture Their Environment */
+/* Many iterator adapters take closures as arguments, and commonly the
+ * closures we'll specify as arguments to iterator adapters will be
+ * closures that capture their environment. Here we use 'filter' with a
+ * closure that captures the 'shoe_size' variable from its environment to
+ * iterate over a collection of 'Shoe' struct instances. */
+#[derive(PartialEq, Debug)]
+pub struct Shoe {
+    pub size: u32,
+    pub style: String,
+}
+
+pub fn shoes_in_size(shoes: Vec<Shoe>, shoe_size: u32) -> Vec<Shoe> {
+    shoes.into_iter().filter(|s| s.size == shoe_size).collect()
+}
+
+/* Creating Our Own Iterators with the Iterator Trait */
+/* All iterators implement a trait named 'Iterator' that is defined in the
+ * standard library. The 'Iterator' trait only requires implementors to
+ * define one method: the 'next' method, which returns one item of the
+ * iterator at a time wrapped in 'Some' and, when iteration is over,
+ * returns 'None'. 'type Item' is an 'associated type'; the 'Item' type
+ * will be the type returned from the iterator.
+ *
+ * 'Counter' counts from 1 to 5. Once 'next' is implemented, every other
+ * 'Iterator' method (the adaptors 'zip', 'skip', 'map', 'filter', and the
+ * consuming 'sum') comes for free through default implementations. */
+pub struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    pub fn new() -> Counter {
+        Counter { count: 0 }
+    }
+}
+
+impl Default for Counter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for Counter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count < 5 {
+            self.count += 1;
+            Some(self.count)
+        } else {
+            None
+        }
+    }
+}
+
+/* Take the values produced by a 'Counter', pair them with values produced
+ * by another 'Counter' after skipping the first value, multiply each pair
+ * together, keep only those results that are divisible by 3, and add all
+ * the resulting values together. 'zip' produces only four pairs; the
+ * theoretical fifth pair '(5, None)' is never produced because 'zip'
+ * returns 'None' when either of its input iterators returns 'None'. */
+pub fn counter_chain_sum() -> u32 {
+    Counter::new()
+        .zip(Counter::new().skip(1))
+        .map(|(a, b)| a * b)
+        .filter(|x| x % 3 == 0)
+        .sum()
+}
+
+/* Improving Our I/O Project */
+/* The 'minigrep' search function was first written with an explicit loop
+ * that pushed matching lines into a mutable 'results' vector. The same
+ * logic reads more clearly as an iterator adaptor chain, and avoids the
+ * intermediate mutable state entirely; that makes it easier to make the
+ * search parallel later, since we would no longer have to manage
+ * concurrent access to 'results'. */
+pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    contents
+        .lines()
+        .filter(|line| line.contains(query))
+        .collect()
+}
+
+pub fn search_case_insensitive<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let query = query.to_lowercase();
+    contents
+        .lines()
+        .filter(|line| line.to_lowercase().contains(&query))
+        .collect()
+}
+
+/* 'Config::build' used to take a '&[String]' slice and clone the values
+ * it needed out of it. Taking ownership of an iterator instead ('env::args'
+ * returns one) lets us move the 'String' values out with 'next' rather
+ * than cloning them. */
+pub struct Config {
+    pub query: String,
+    pub file_path: String,
+    pub ignore_case: bool,
+}
+
+impl Config {
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, &'static str> {
+        // The first value is the name of the program
+        args.next();
+
+        let query = match args.next() {
+            Some(arg) => arg,
+            None => return Err("Didn't get a query string"),
+        };
+
+        let file_path = match args.next() {
+            Some(arg) => arg,
+            None => return Err("Didn't get a file path"),
+        };
+
+        let ignore_case = std::env::var("IGNORE_CASE").is_ok();
+
+        Ok(Config {
+            query,
+            file_path,
+            ignore_case,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
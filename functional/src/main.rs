@@ -0,0 +1,68 @@
+use functional::{counter_chain_sum, search, Cacher, Counter, Inventory, ShirtColor};
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    let store = Inventory {
+        shirts: vec![ShirtColor::Blue, ShirtColor::Red, ShirtColor::Blue],
+    };
+
+    let user_pref1 = Some(ShirtColor::Red);
+    let giveaway1 = store.giveaway(user_pref1);
+    println!("The user with preference {user_pref1:?} gets {giveaway1:?}");
+
+    let user_pref2 = None;
+    let giveaway2 = store.giveaway(user_pref2);
+    println!("The user with preference {user_pref2:?} gets {giveaway2:?}");
+
+    /* Closure Type Inference and Annotation */
+    /* Closures don't usually require annotating the types of the
+     * parameters or the return value like 'fn' functions do, because they
+     * are typically short and relevant only within a narrow context. We can
+     * still add annotations if we want to increase explicitness. */
+    let expensive_closure = |num: u32| -> u32 {
+        println!("calculating slowly...");
+        thread::sleep(Duration::from_millis(200));
+        num
+    };
+
+    /* The 'Cacher' only prints "calculating slowly..." once per distinct
+     * argument, no matter how many times we ask for the value. */
+    let mut cached = Cacher::new(expensive_closure);
+    println!("{}", cached.value(10));
+    println!("{}", cached.value(10));
+    println!("{}", cached.value(20));
+
+    /* Capturing References or Moving Ownership */
+    /* Closures can capture values from their environment in three ways,
+     * which directly map to the three ways a function can take a
+     * parameter: borrowing immutably, borrowing mutably, and taking
+     * ownership. The closure will decide which of these to use based on
+     * what the body of the function does with the captured values. */
+    let mut list = vec![1, 2, 3];
+    println!("Before defining closure: {list:?}");
+
+    let mut borrows_mutably = || list.push(7);
+
+    // No other borrow of 'list' may happen between here and the call
+    borrows_mutably();
+    println!("After calling closure: {list:?}");
+
+    /* If we want to force the closure to take ownership of the values it
+     * uses even though the body doesn't strictly need it, we use the
+     * 'move' keyword. This is mostly useful when passing a closure to a new
+     * thread, which might outlive the current function. */
+    thread::spawn(move || println!("From thread: {list:?}"))
+        .join()
+        .unwrap();
+
+    let v1: Vec<i32> = vec![1, 2, 3];
+    let v2: Vec<_> = v1.iter().map(|x| x + 1).collect();
+    println!("{v2:?}");
+
+    println!("Counter yields: {:?}", Counter::new().collect::<Vec<_>>());
+    println!("Counter adaptor chain sums to {}", counter_chain_sum());
+
+    let poem = "I'm nobody! Who are you?\nAre you nobody, too?";
+    println!("Lines containing 'nobody': {:?}", search("nobody", poem));
+}
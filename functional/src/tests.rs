@@ -0,0 +1,105 @@
+use crate::*;
+use std::cell::Cell;
+
+#[test]
+fn test_giveaway() {
+    let store = Inventory {
+        shirts: vec![ShirtColor::Blue, ShirtColor::Red, ShirtColor::Blue],
+    };
+
+    assert_eq!(store.giveaway(Some(ShirtColor::Red)), ShirtColor::Red);
+    assert_eq!(store.giveaway(None), ShirtColor::Blue);
+}
+
+#[test]
+fn test_cacher_calls_closure_once_per_argument() {
+    let calls = Cell::new(0);
+    let mut cacher = Cacher::new(|x: u32| {
+        calls.set(calls.get() + 1);
+        x * 2
+    });
+
+    assert_eq!(cacher.value(1), 2);
+    assert_eq!(cacher.value(1), 2);
+    assert_eq!(cacher.value(2), 4);
+    assert_eq!(calls.get(), 2);
+    assert_eq!(cacher.cached(), 2);
+}
+
+#[test]
+fn test_filters_by_size() {
+    let shoes = vec![
+        Shoe {
+            size: 10,
+            style: String::from("sneaker"),
+        },
+        Shoe {
+            size: 13,
+            style: String::from("sandal"),
+        },
+        Shoe {
+            size: 10,
+            style: String::from("boot"),
+        },
+    ];
+
+    let in_my_size = shoes_in_size(shoes, 10);
+
+    assert_eq!(
+        in_my_size,
+        vec![
+            Shoe {
+                size: 10,
+                style: String::from("sneaker")
+            },
+            Shoe {
+                size: 10,
+                style: String::from("boot")
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_counter() {
+    assert_eq!(Counter::new().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+    assert_eq!(counter_chain_sum(), 18);
+}
+
+#[test]
+fn test_case_sensitive() {
+    let query = "duct";
+    let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Duct tape.";
+
+    assert_eq!(vec!["safe, fast, productive."], search(query, contents));
+}
+
+#[test]
+fn test_case_insensitive() {
+    let query = "rUsT";
+    let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Trust me.";
+
+    assert_eq!(
+        vec!["Rust:", "Trust me."],
+        search_case_insensitive(query, contents)
+    );
+}
+
+#[test]
+fn test_config_build() {
+    let args = ["minigrep", "to", "poem.txt"].map(String::from);
+    let config = Config::build(args.into_iter()).unwrap();
+    assert_eq!(config.query, "to");
+    assert_eq!(config.file_path, "poem.txt");
+
+    let args = ["minigrep", "to"].map(String::from);
+    assert!(Config::build(args.into_iter()).is_err());
+}
@@ -0,0 +1,101 @@
+/* A Bounded Integer */
+/* 'Guess' only ever checked one thing: that a number falls in a range.
+ * With const generics (the same feature that lets an array's length be
+ * part of its type) the range itself can go in the type, so
+ * 'Bounded<1, 100>' and 'Bounded<1, 20>' are different types, and a
+ * function that takes a 'Bounded<1, 100>' knows its argument was checked
+ * against 1 and 100 without looking at anything at runtime.
+ *
+ * There are two ways in: 'new' (and 'TryFrom', which lets callers write
+ * 'value.try_into()?') rejects a value outside the range with the same
+ * 'GuessError' a bad guess gets, and 'clamp' never fails but moves the
+ * value to the nearest end of the range instead.
+ *
+ * A range whose 'MIN' is above its 'MAX' has no values at all, so using
+ * one is a compile error: the 'const' block in 'RANGE_IS_VALID' is
+ * evaluated when the type is used, not when the program runs.
+ *
+ * A range that's only known at runtime, like one from the command line,
+ * can't be part of a type. 'InRange' is the same idea checked the long
+ * way: its field is private too, so 'InRange::new' is the only way to get
+ * one, and holding one means the value was in the range it was given. */
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::GuessError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bounded<const MIN: i32, const MAX: i32> {
+    value: i32,
+}
+
+impl<const MIN: i32, const MAX: i32> Bounded<MIN, MAX> {
+    const RANGE_IS_VALID: () = assert!(MIN <= MAX, "Bounded needs MIN <= MAX");
+
+    pub fn new(value: i32) -> Result<Self, GuessError> {
+        let () = Self::RANGE_IS_VALID;
+        if !(MIN..=MAX).contains(&value) {
+            return Err(GuessError::OutOfRange {
+                value,
+                min: MIN,
+                max: MAX,
+            });
+        }
+        Ok(Bounded { value })
+    }
+
+    /* Nothing in the game clamps yet; it's here for other code that
+     * would rather adjust a value than reject it. */
+    pub fn clamp(value: i32) -> Self {
+        let () = Self::RANGE_IS_VALID;
+        Bounded {
+            value: value.clamp(MIN, MAX),
+        }
+    }
+
+    pub fn value(self) -> i32 {
+        self.value
+    }
+}
+
+impl<const MIN: i32, const MAX: i32> TryFrom<i32> for Bounded<MIN, MAX> {
+    type Error = GuessError;
+
+    fn try_from(value: i32) -> Result<Self, GuessError> {
+        Bounded::new(value)
+    }
+}
+
+impl<const MIN: i32, const MAX: i32> fmt::Display for Bounded<MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InRange {
+    value: i32,
+}
+
+impl InRange {
+    pub fn new(value: i32, range: &RangeInclusive<i32>) -> Result<InRange, GuessError> {
+        if !range.contains(&value) {
+            return Err(GuessError::OutOfRange {
+                value,
+                min: *range.start(),
+                max: *range.end(),
+            });
+        }
+        Ok(InRange { value })
+    }
+
+    pub fn value(self) -> i32 {
+        self.value
+    }
+}
+
+impl fmt::Display for InRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
@@ -0,0 +1,129 @@
+/* Command-Line Arguments */
+/* 'std::env::args' hands us the arguments as strings, the first being
+ * the program's name (as in the I/O project chapter). 'parse_args' takes
+ * any iterator of 'String's without that first one, so the tests can pass
+ * a 'vec!' instead of running the program.
+ *
+ * Every flag but the switches ('--timed', '--resume', and '--no-color')
+ * takes a value, written either as the next
+ * argument ('--min 5') or after an equals sign ('--min=5'). Anything that goes
+ * wrong comes back as an 'ArgsError' for 'main' to print next to the
+ * usage message; nothing here panics or exits. */
+use std::fmt;
+
+pub const USAGE: &str = "Usage: guessing_game_v2 [--min N] [--max N] [--attempts N] [--seed N] \
+                         [--timed] [--resume] [--no-color]";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Args {
+    pub min: Option<i32>,
+    pub max: Option<i32>,
+    pub attempts: Option<u32>,
+    pub seed: Option<u64>,
+    pub timed: bool,
+    pub resume: bool,
+    pub no_color: bool,
+}
+
+impl Args {
+    /* Each setting from 'self' if it was given, otherwise from 'fallback'.
+     * 'main' uses it to let the command line override the config file. */
+    pub fn or(self, fallback: Args) -> Args {
+        Args {
+            min: self.min.or(fallback.min),
+            max: self.max.or(fallback.max),
+            attempts: self.attempts.or(fallback.attempts),
+            seed: self.seed.or(fallback.seed),
+            timed: self.timed || fallback.timed,
+            resume: self.resume || fallback.resume,
+            no_color: self.no_color || fallback.no_color,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgsError {
+    UnknownArgument(String),
+    MissingValue(&'static str),
+    InvalidValue { flag: &'static str, value: String },
+    EmptyRange { min: i32, max: i32 },
+    NoAttempts,
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgsError::UnknownArgument(arg) => write!(f, "unknown argument '{arg}'"),
+            ArgsError::MissingValue(flag) => write!(f, "'{flag}' needs a value"),
+            ArgsError::InvalidValue { flag, value } => {
+                write!(f, "'{value}' isn't a valid value for '{flag}'")
+            }
+            ArgsError::EmptyRange { min, max } => {
+                write!(f, "there are no numbers from {min} to {max}")
+            }
+            ArgsError::NoAttempts => write!(f, "'--attempts' has to be at least 1"),
+        }
+    }
+}
+
+impl std::error::Error for ArgsError {}
+
+const FLAGS: [&str; 4] = ["--min", "--max", "--attempts", "--seed"];
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, ArgsError> {
+    let mut parsed = Args::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        /* The switches, which don't take a value. */
+        match arg.as_str() {
+            "--timed" => {
+                parsed.timed = true;
+                continue;
+            }
+            "--resume" => {
+                parsed.resume = true;
+                continue;
+            }
+            "--no-color" => {
+                parsed.no_color = true;
+                continue;
+            }
+            _ => {}
+        }
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => (arg.as_str(), None),
+        };
+        let Some(&flag) = FLAGS.iter().find(|&&flag| flag == name) else {
+            return Err(ArgsError::UnknownArgument(arg));
+        };
+        let value = inline
+            .or_else(|| args.next())
+            .ok_or(ArgsError::MissingValue(flag))?;
+
+        match flag {
+            "--min" => parsed.min = Some(number(flag, &value)?),
+            "--max" => parsed.max = Some(number(flag, &value)?),
+            "--attempts" => parsed.attempts = Some(number(flag, &value)?),
+            _ => parsed.seed = Some(number(flag, &value)?),
+        }
+    }
+
+    if let (Some(min), Some(max)) = (parsed.min, parsed.max) {
+        if min > max {
+            return Err(ArgsError::EmptyRange { min, max });
+        }
+    }
+    if parsed.attempts == Some(0) {
+        return Err(ArgsError::NoAttempts);
+    }
+    Ok(parsed)
+}
+
+fn number<T: std::str::FromStr>(flag: &'static str, value: &str) -> Result<T, ArgsError> {
+    value.parse().map_err(|_| ArgsError::InvalidValue {
+        flag,
+        value: value.to_string(),
+    })
+}
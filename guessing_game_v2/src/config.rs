@@ -0,0 +1,201 @@
+/* The Configuration File */
+/* Settings a player wants every time can go in 'guessing_game.toml' next
+ * to where the game is run, instead of being typed as arguments:
+ *
+ * # Everything is optional.
+ * min = 1
+ * max = 50
+ * attempts = 8
+ * name = "Ferris"
+ * color = false
+ *
+ * The format is a small part of TOML, the format Cargo.toml is written in:
+ * one 'key = value' per line, '#' starting a comment, strings in double
+ * quotes, and whole numbers and 'true'/'false' as they are. We only have
+ * five flat keys, so parsing it by hand is less work than a dependency.
+ *
+ * The file is optional: if it isn't there, every setting keeps its
+ * default. If it is there but something in it is wrong - an unknown key,
+ * a number that isn't one - we'd rather stop with the line number and
+ * what's wrong than guess what the player meant. Arguments given on the
+ * command line win over the file; see 'Args::or'. */
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::cli::Args;
+
+pub const CONFIG_FILE: &str = "guessing_game.toml";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub min: Option<i32>,
+    pub max: Option<i32>,
+    pub attempts: Option<u32>,
+    pub name: Option<String>,
+    pub color: Option<bool>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Syntax {
+        line: usize,
+        message: String,
+    },
+    UnknownKey {
+        line: usize,
+        key: String,
+    },
+    InvalidValue {
+        line: usize,
+        key: String,
+        expected: &'static str,
+    },
+    EmptyRange {
+        min: i32,
+        max: i32,
+    },
+    NoAttempts,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "couldn't read {CONFIG_FILE}: {e}"),
+            ConfigError::Syntax { line, message } => {
+                write!(f, "{CONFIG_FILE} line {line}: {message}")
+            }
+            ConfigError::UnknownKey { line, key } => {
+                write!(f, "{CONFIG_FILE} line {line}: unknown setting '{key}'")
+            }
+            ConfigError::InvalidValue {
+                line,
+                key,
+                expected,
+            } => write!(f, "{CONFIG_FILE} line {line}: '{key}' has to be {expected}"),
+            ConfigError::EmptyRange { min, max } => {
+                write!(f, "{CONFIG_FILE}: there are no numbers from {min} to {max}")
+            }
+            ConfigError::NoAttempts => write!(f, "{CONFIG_FILE}: 'attempts' has to be at least 1"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> ConfigError {
+        ConfigError::Io(e)
+    }
+}
+
+impl Config {
+    pub fn load(path: impl AsRef<Path>) -> Result<Config, ConfigError> {
+        match fs::read_to_string(path) {
+            Ok(text) => parse(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /* The file's settings in the same shape as the command line's, so the
+     * two can be merged. */
+    pub fn args(&self) -> Args {
+        Args {
+            min: self.min,
+            max: self.max,
+            attempts: self.attempts,
+            seed: None,
+            timed: false,
+            resume: false,
+            no_color: self.color == Some(false),
+        }
+    }
+}
+
+pub fn parse(text: &str) -> Result<Config, ConfigError> {
+    let mut config = Config::default();
+
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(ConfigError::Syntax {
+                line: line_number,
+                message: format!("expected 'key = value', found '{line}'"),
+            });
+        };
+        let (key, value) = (key.trim(), value.trim());
+        let invalid = |expected| ConfigError::InvalidValue {
+            line: line_number,
+            key: key.to_string(),
+            expected,
+        };
+
+        match key {
+            "min" => config.min = Some(value.parse().map_err(|_| invalid("a whole number"))?),
+            "max" => config.max = Some(value.parse().map_err(|_| invalid("a whole number"))?),
+            "attempts" => {
+                config.attempts = Some(value.parse().map_err(|_| invalid("a positive number"))?)
+            }
+            "name" => config.name = Some(string(value).ok_or_else(|| invalid("a quoted string"))?),
+            "color" => config.color = Some(value.parse().map_err(|_| invalid("true or false"))?),
+            _ => {
+                return Err(ConfigError::UnknownKey {
+                    line: line_number,
+                    key: key.to_string(),
+                })
+            }
+        }
+    }
+
+    if let (Some(min), Some(max)) = (config.min, config.max) {
+        if min > max {
+            return Err(ConfigError::EmptyRange { min, max });
+        }
+    }
+    if config.attempts == Some(0) {
+        return Err(ConfigError::NoAttempts);
+    }
+    Ok(config)
+}
+
+/* Everything from the first '#' that isn't inside a string. */
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/* A double-quoted string, with '\"' and '\\' for a quote and a backslash.
+ * 'None' if it isn't quoted, isn't closed, or has something after the
+ * closing quote. */
+fn string(value: &str) -> Option<String> {
+    let mut chars = value.strip_prefix('"')?.chars();
+    let mut text = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return chars.as_str().is_empty().then_some(text),
+            '\\' => match chars.next()? {
+                c @ ('"' | '\\') => text.push(c),
+                _ => return None,
+            },
+            c => text.push(c),
+        }
+    }
+    None
+}
@@ -0,0 +1,54 @@
+/* Input and Output */
+/* The game used to call 'io::stdin()' and 'println!' directly, which is
+ * fine for playing but means a test can't type guesses or see what the
+ * game said. Instead the game talks to an 'InputSource' and an
+ * 'OutputSink'. 'main' hands it the real terminal, and a test hands it
+ * canned lines and a buffer to read back afterwards.
+ *
+ * Rather than writing one implementation for stdin and another for each
+ * fake, both traits are implemented for every type that already knows how
+ * to do the job: anything 'BufRead' is an 'InputSource' (so
+ * 'io::stdin().lock()' is one), and anything 'Write' is an 'OutputSink'
+ * (so 'io::stdout()' is one). The in-memory fakes come for free the same
+ * way: 'test_support::Input' and 'test_support::Output' are 'BufRead' and
+ * 'Write', and so are a '&[u8]' and a 'Vec<u8>'. */
+use std::io::{BufRead, Write};
+
+use crate::style::Color;
+
+pub trait InputSource {
+    /* The next line without its line ending, or 'None' once there's no
+     * more input (the player pressed Ctrl-D, or a test ran out of lines).
+     * Callers have to handle 'None', otherwise a loop that keeps asking
+     * would spin forever. */
+    fn read_line(&mut self) -> Option<String>;
+}
+
+pub trait OutputSink {
+    fn write_line(&mut self, line: &str);
+
+    /* A line that should stand out; see style.rs. Sinks that don't do
+     * colors just write it. */
+    fn write_colored(&mut self, line: &str, _color: Color) {
+        self.write_line(line);
+    }
+}
+
+impl<R: BufRead> InputSource for R {
+    fn read_line(&mut self) -> Option<String> {
+        let mut line = String::new();
+        let read = BufRead::read_line(self, &mut line).expect("Failed to read line");
+        if read == 0 {
+            return None;
+        }
+        let trimmed = line.trim_end_matches(['\n', '\r']).len();
+        line.truncate(trimmed);
+        Some(line)
+    }
+}
+
+impl<W: Write> OutputSink for W {
+    fn write_line(&mut self, line: &str) {
+        writeln!(self, "{line}").expect("Failed to write output");
+    }
+}
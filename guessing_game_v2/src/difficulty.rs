@@ -0,0 +1,57 @@
+/* Difficulty Levels */
+/* A fixed range of 1 to 100 gets old. The difficulty decides how big the
+ * range is and how many guesses a good player should need. Halving the
+ * range with every guess finds any number from 1 to 100 in at most 7
+ * guesses (2^7 = 128), so each level's attempt count is what a perfect
+ * binary search needs, plus a little slack on the easy end. */
+use std::fmt;
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn range(self) -> RangeInclusive<i32> {
+        match self {
+            Difficulty::Easy => 1..=20,
+            Difficulty::Normal => 1..=100,
+            Difficulty::Hard => 1..=1000,
+        }
+    }
+
+    pub fn max_attempts(self) -> u32 {
+        match self {
+            Difficulty::Easy => 6,
+            Difficulty::Normal => 7,
+            Difficulty::Hard => 10,
+        }
+    }
+
+    /* Accepts the full name or its first letter, in any case, so "h",
+     * "Hard", and "HARD" all pick the hard level. */
+    pub fn parse(input: &str) -> Option<Difficulty> {
+        let input = input.trim().to_lowercase();
+        Difficulty::ALL.into_iter().find(|difficulty| {
+            let name = difficulty.to_string().to_lowercase();
+            input == name || (input.len() == 1 && name.starts_with(&input))
+        })
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        };
+        f.pad(name)
+    }
+}
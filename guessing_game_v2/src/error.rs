@@ -0,0 +1,36 @@
+/* Guess Errors */
+/* 'Guess::new' used to report a bad value as an 'io::Error', which it
+ * isn't: nothing went wrong with input or output, the player just picked
+ * a number we don't accept. Like the error chapter's advice for library
+ * code, we give the failures a type of their own, so the game loop can
+ * 'match' on what went wrong instead of only having a message to print.
+ *
+ * Implementing 'From<ParseIntError>' is what turns a failed 'parse' into
+ * a 'GuessError', in 'parse_guess' (see parse.rs). */
+use std::fmt;
+use std::num::ParseIntError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuessError {
+    OutOfRange { value: i32, min: i32, max: i32 },
+    NotANumber,
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GuessError::OutOfRange { min, max, .. } => {
+                write!(f, "Guess must be between {min} and {max}.")
+            }
+            GuessError::NotANumber => write!(f, "Please type a number."),
+        }
+    }
+}
+
+impl std::error::Error for GuessError {}
+
+impl From<ParseIntError> for GuessError {
+    fn from(_: ParseIntError) -> GuessError {
+        GuessError::NotANumber
+    }
+}
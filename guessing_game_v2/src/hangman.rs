@@ -0,0 +1,201 @@
+/* The Word Game */
+/* A hangman-style game: the program picks a word and the player guesses
+ * it a letter at a time, seeing which letters are in the word so far
+ * ("c _ _ _ _ e") and losing after 'MAX_MISSES' letters that aren't.
+ *
+ * The words come from words.txt, which 'include_str!' reads at compile
+ * time and puts into the program as one '&'static str', so the game
+ * doesn't need the file next to it when it runs. They're all lowercase
+ * ASCII, and the player's letters are lowercased before they're checked.
+ *
+ * 'Hangman' is a single round's state and knows nothing about input or
+ * output; 'play_word' is the loop that talks to the player, and
+ * 'WordGame' is the 'GameMode' that picks a word for each round. */
+use rand::Rng;
+
+use crate::mode::GameMode;
+use crate::picker::SecretPicker;
+use crate::style::Color;
+use crate::{InputSource, Outcome, OutputSink};
+
+const WORDS: &str = include_str!("words.txt");
+pub const MAX_MISSES: u32 = 6;
+
+pub fn words() -> Vec<&'static str> {
+    WORDS
+        .lines()
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Letter {
+    /* In the word this many times. */
+    Hit(usize),
+    Miss,
+    Repeated,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hangman {
+    word: String,
+    guessed: Vec<char>,
+}
+
+impl Hangman {
+    pub fn new(word: &str) -> Hangman {
+        Hangman {
+            word: word.to_lowercase(),
+            guessed: Vec::new(),
+        }
+    }
+
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    pub fn guess(&mut self, letter: char) -> Letter {
+        let letter = letter.to_ascii_lowercase();
+        if self.guessed.contains(&letter) {
+            return Letter::Repeated;
+        }
+        self.guessed.push(letter);
+        match self.word.matches(letter).count() {
+            0 => Letter::Miss,
+            n => Letter::Hit(n),
+        }
+    }
+
+    /* Every letter guessed so far, right or wrong. */
+    pub fn guesses(&self) -> u32 {
+        self.guessed.len() as u32
+    }
+
+    pub fn misses(&self) -> u32 {
+        self.guessed
+            .iter()
+            .filter(|&&letter| !self.word.contains(letter))
+            .count() as u32
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.word
+            .chars()
+            .all(|letter| self.guessed.contains(&letter))
+    }
+
+    /* The word with the letters not yet guessed as underscores, spaced out
+     * so that each one can be counted. */
+    pub fn masked(&self) -> String {
+        self.word
+            .chars()
+            .map(|letter| {
+                if self.guessed.contains(&letter) {
+                    letter
+                } else {
+                    '_'
+                }
+            })
+            .map(String::from)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/* One round against 'word'. A win's 'attempts' is every letter guessed,
+ * hits included, so fewer is better, as in the number game. */
+pub fn play_word(
+    word: &str,
+    input: &mut impl InputSource,
+    output: &mut impl OutputSink,
+) -> Outcome {
+    let mut game = Hangman::new(word);
+    output.write_line(&format!(
+        "Guess the word! It has {} letters, and you can miss {MAX_MISSES} times.",
+        word.chars().count()
+    ));
+
+    loop {
+        output.write_line(&format!(
+            "{}   (misses: {}/{MAX_MISSES})",
+            game.masked(),
+            game.misses()
+        ));
+        output.write_line("Please input a letter.");
+
+        let Some(line) = input.read_line() else {
+            output.write_line(&format!(
+                "No more guesses coming. The word was '{}'.\n",
+                game.word()
+            ));
+            return Outcome::Lost;
+        };
+        let mut letters = line.trim().chars();
+        let letter = match (letters.next(), letters.next()) {
+            (Some(letter), None) if letter.is_ascii_alphabetic() => letter,
+            _ => {
+                output.write_line("Please type a single letter.");
+                continue;
+            }
+        };
+
+        match game.guess(letter) {
+            Letter::Repeated => {
+                output.write_line(&format!("You already guessed '{letter}'."));
+                continue;
+            }
+            Letter::Hit(1) => {
+                output.write_colored(&format!("There's one '{letter}'."), Color::Blue)
+            }
+            Letter::Hit(n) => {
+                output.write_colored(&format!("There are {n} '{letter}'s."), Color::Blue)
+            }
+            Letter::Miss => output.write_colored(&format!("No '{letter}'."), Color::Red),
+        }
+
+        if game.is_solved() {
+            output.write_colored(
+                &format!("You win! The word was '{}'.", game.word()),
+                Color::Green,
+            );
+            output.write_line("");
+            return Outcome::Won {
+                attempts: game.guesses(),
+            };
+        }
+        if game.misses() == MAX_MISSES {
+            output.write_line(&format!(
+                "Out of guesses! The word was '{}'. You lose.\n",
+                game.word()
+            ));
+            return Outcome::Lost;
+        }
+    }
+}
+
+pub struct WordGame<'a, R> {
+    picker: SecretPicker<&'a mut R>,
+    words: Vec<&'static str>,
+}
+
+impl<'a, R: Rng> WordGame<'a, R> {
+    pub fn new(rng: &'a mut R) -> WordGame<'a, R> {
+        WordGame {
+            picker: SecretPicker::new(rng),
+            words: words(),
+        }
+    }
+}
+
+impl<R: Rng> GameMode for WordGame<'_, R> {
+    fn play_round<I: InputSource, O: OutputSink>(
+        &mut self,
+        input: &mut I,
+        output: &mut O,
+    ) -> Outcome {
+        let last = self.words.len() as i32 - 1;
+        let word = self.words[self.picker.pick(0..=last) as usize];
+        play_word(word, input, output)
+    }
+}
@@ -0,0 +1,103 @@
+/* Guess History */
+/* Every guess in a game is kept as a 'GuessRecord': the number, whether
+ * it was too small or too big, and what range the secret could still be
+ * in afterwards. Typing 'history' at the prompt prints them, which helps
+ * in a long game on hard where the earlier answers have scrolled away.
+ *
+ * 'RangeTracker' is what works out the range. It starts as the whole
+ * range of the game, and each answer moves one end in: "too small" after
+ * 40 means the secret is at least 41, "too big" after 60 means at most
+ * 59. It also notices a guess the earlier answers had already ruled out,
+ * like guessing 70 after being told 60 was too big - a wasted guess,
+ * which the history points out. */
+use std::fmt::Write as _;
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    TooSmall,
+    TooBig,
+    Correct,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuessRecord {
+    pub value: i32,
+    pub direction: Direction,
+    /* Whether the earlier answers had already ruled this guess out. */
+    pub wasted: bool,
+    /* Where the secret can be once this guess has been answered. */
+    pub range_after: RangeInclusive<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeTracker {
+    low: i32,
+    high: i32,
+}
+
+impl RangeTracker {
+    pub fn new(range: RangeInclusive<i32>) -> RangeTracker {
+        RangeTracker {
+            low: *range.start(),
+            high: *range.end(),
+        }
+    }
+
+    pub fn range(&self) -> RangeInclusive<i32> {
+        self.low..=self.high
+    }
+
+    /* Answers 'value' against 'secret' and narrows the range to match. A
+     * wasted guess can't narrow anything: the range already excludes it. */
+    pub fn record(&mut self, value: i32, secret: i32) -> GuessRecord {
+        let wasted = !self.range().contains(&value);
+        let direction = match value.cmp(&secret) {
+            std::cmp::Ordering::Less => Direction::TooSmall,
+            std::cmp::Ordering::Greater => Direction::TooBig,
+            std::cmp::Ordering::Equal => Direction::Correct,
+        };
+        match direction {
+            Direction::TooSmall => self.low = self.low.max(value + 1),
+            Direction::TooBig => self.high = self.high.min(value - 1),
+            Direction::Correct => {
+                self.low = value;
+                self.high = value;
+            }
+        }
+        GuessRecord {
+            value,
+            direction,
+            wasted,
+            range_after: self.range(),
+        }
+    }
+}
+
+pub fn history_table(records: &[GuessRecord]) -> String {
+    if records.is_empty() {
+        return String::from("No guesses yet.");
+    }
+    let mut table = String::new();
+    for (i, record) in records.iter().enumerate() {
+        let answer = match record.direction {
+            Direction::TooSmall => "too small",
+            Direction::TooBig => "too big",
+            Direction::Correct => "correct",
+        };
+        let _ = write!(
+            table,
+            "{:>3}. {:>6}  {answer:<9}  now {}-{}",
+            i + 1,
+            record.value,
+            record.range_after.start(),
+            record.range_after.end()
+        );
+        if record.wasted {
+            table.push_str("  (already ruled out)");
+        }
+        table.push('\n');
+    }
+    table.pop();
+    table
+}
@@ -0,0 +1,191 @@
+/* High Scores */
+/* Every win is saved to a CSV file so the best games outlast the program:
+ *
+ * name,difficulty,attempts,date
+ * Ferris,Normal,5,1718000000
+ *
+ * The date is stored as seconds since the Unix epoch and shown as
+ * YYYY-MM-DD. Attempts are only comparable between games on the same
+ * difficulty, so each difficulty has its own table, fewest attempts first
+ * and earlier games first on a tie.
+ *
+ * The file is plain text that anyone can open and edit, so 'parse' can't
+ * assume it's well formed. A line that doesn't make sense - a missing
+ * column, a word where a number should be, half a line left by a crash -
+ * is skipped and counted instead of throwing the whole leaderboard away. */
+use std::fmt::Write as _;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::difficulty::Difficulty;
+
+pub const LEADERBOARD_FILE: &str = "guessing_game_leaderboard.csv";
+const HEADER: &str = "name,difficulty,attempts,date";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub name: String,
+    pub difficulty: Difficulty,
+    pub attempts: u32,
+    pub date: u64,
+}
+
+impl Entry {
+    /* A win from just now. Commas and line breaks would break the CSV, so
+     * they're taken out of the name. */
+    pub fn new(name: &str, difficulty: Difficulty, attempts: u32) -> Entry {
+        let name: String = name
+            .chars()
+            .map(|c| if c == ',' || c.is_control() { ' ' } else { c })
+            .collect();
+        let date = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        Entry {
+            name: name.trim().to_string(),
+            difficulty,
+            attempts,
+            date,
+        }
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{},{},{},{}",
+            self.name, self.difficulty, self.attempts, self.date
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Entry> {
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [name, difficulty, attempts, date] = fields[..] else {
+            return None;
+        };
+        if name.is_empty() {
+            return None;
+        }
+        Some(Entry {
+            name: name.to_string(),
+            difficulty: Difficulty::parse(difficulty)?,
+            attempts: attempts.parse().ok().filter(|&a| a > 0)?,
+            date: date.parse().ok()?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Leaderboard {
+    path: PathBuf,
+    entries: Vec<Entry>,
+    skipped: usize,
+}
+
+impl Leaderboard {
+    /* A missing file is an empty leaderboard, not an error: it just means
+     * nobody has won yet. */
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Leaderboard> {
+        let path = path.as_ref().to_path_buf();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let (entries, skipped) = parse(&text);
+        Ok(Leaderboard {
+            path,
+            entries,
+            skipped,
+        })
+    }
+
+    /* How many lines of the file couldn't be read. */
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /* Appends the entry to the file, writing the header first if the file
+     * is new, and keeps it in memory too. */
+    pub fn record(&mut self, entry: Entry) -> io::Result<()> {
+        let is_new = fs::metadata(&self.path).map_or(true, |m| m.len() == 0);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        if is_new {
+            writeln!(file, "{HEADER}")?;
+        }
+        writeln!(file, "{}", entry.to_line())?;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    pub fn top(&self, difficulty: Difficulty, n: usize) -> Vec<&Entry> {
+        let mut entries: Vec<&Entry> = self
+            .entries
+            .iter()
+            .filter(|e| e.difficulty == difficulty)
+            .collect();
+        entries.sort_by_key(|e| (e.attempts, e.date));
+        entries.truncate(n);
+        entries
+    }
+
+    pub fn table(&self, difficulty: Difficulty) -> String {
+        let top = self.top(difficulty, 10);
+        let mut table = format!("Top scores ({difficulty})\n");
+        if top.is_empty() {
+            table.push_str("  No wins yet.\n");
+        }
+        for (i, entry) in top.iter().enumerate() {
+            let _ = writeln!(
+                table,
+                "{:>3}. {:<16} {:>3} guesses  {}",
+                i + 1,
+                entry.name,
+                entry.attempts,
+                format_date(entry.date)
+            );
+        }
+        table
+    }
+}
+
+/* The entries that could be read, and how many lines couldn't. The
+ * header and blank lines don't count as either. */
+pub fn parse(text: &str) -> (Vec<Entry>, usize) {
+    let mut entries = Vec::new();
+    let mut skipped = 0;
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line == HEADER {
+            continue;
+        }
+        match Entry::from_line(line) {
+            Some(entry) => entries.push(entry),
+            None => skipped += 1,
+        }
+    }
+    (entries, skipped)
+}
+
+/* Seconds since the epoch as a UTC date. This is Howard Hinnant's
+ * 'civil_from_days', which counts years from March so that February's
+ * leap day comes last. */
+pub fn format_date(secs: u64) -> String {
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
@@ -0,0 +1,427 @@
+/* The Guessing Game as a Library */
+/* Everything but 'main' lives here, so the game can be played by more than
+ * the binary: another crate, or a test in 'tests/', can build a 'Rules',
+ * hand 'play' a secret number and a scripted 'InputSource', and read back
+ * the 'Outcome' and everything the game printed. 'main.rs' is left with
+ * reading the command line and config file, the leaderboard, and the
+ * book's first version of the game.
+ *
+ * The modules are public for their types; the ones a caller needs most
+ * often are re-exported here too. */
+use rand::Rng;
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+pub mod bounded;
+pub mod cli;
+pub mod config;
+pub mod console;
+pub mod difficulty;
+pub mod error;
+pub mod hangman;
+pub mod history;
+pub mod leaderboard;
+pub mod mode;
+pub mod parse;
+pub mod picker;
+pub mod rules;
+pub mod save;
+pub mod solver;
+pub mod stats;
+pub mod style;
+pub mod timing;
+
+pub use bounded::{Bounded, InRange};
+pub use console::{InputSource, OutputSink};
+pub use difficulty::Difficulty;
+pub use error::GuessError;
+pub use mode::{session, GameMode, Mode};
+pub use picker::SecretPicker;
+pub use rules::Rules;
+pub use save::{GameState, SAVE_FILE};
+pub use solver::{Feedback, Guesser, Solver};
+pub use stats::{session_stats, Outcome};
+
+use history::{history_table, Direction, RangeTracker};
+use parse::parse_guess;
+use style::Color;
+use timing::Stopwatch;
+
+/* Instead, we can make a new type and put the validations in a
+ * function to create an instance of the type rather than repeating
+ * the validations everywhere. That way, it's safe for functions to
+ * use the new type in their signatures and confidently use the
+ * values they receive. The below code will show one way to define
+ * a 'Guess' type that will only create an instance of 'Guess' if
+ * the 'new' function receives a value between 1 and 100. */
+#[derive(Debug)]
+pub enum Guess {
+    Easy(Bounded<1, 20>),
+    Normal(Bounded<1, 100>),
+    Hard(Bounded<1, 1000>),
+    Custom(InRange),
+}
+impl Guess {
+    pub fn new(value: i32, difficulty: Difficulty) -> Result<Guess, GuessError> {
+        Ok(match difficulty {
+            Difficulty::Easy => Guess::Easy(value.try_into()?),
+            Difficulty::Normal => Guess::Normal(value.try_into()?),
+            Difficulty::Hard => Guess::Hard(value.try_into()?),
+        })
+    }
+
+    /* A line the player typed, surrounding whitespace and all; see
+     * parse.rs for what counts as a number. */
+    pub fn parse(line: &str, difficulty: Difficulty) -> Result<Guess, GuessError> {
+        Guess::new(parse_guess(line)?, difficulty)
+    }
+
+    /* For a range only known at runtime, checked the long way. */
+    pub fn within(value: i32, range: &RangeInclusive<i32>) -> Result<Guess, GuessError> {
+        Ok(Guess::Custom(InRange::new(value, range)?))
+    }
+
+    pub fn value(&self) -> i32 {
+        match self {
+            Guess::Easy(guess) => guess.value(),
+            Guess::Normal(guess) => guess.value(),
+            Guess::Hard(guess) => guess.value(),
+            Guess::Custom(guess) => guess.value(),
+        }
+    }
+}
+/* First we define a struct named 'Guess' that has a field named 'value'
+ * that holds an 'i32'. This is where the number will be stored.
+ *
+ * Then we implement an associated function named 'new' on 'Guess' that
+ * creates instances of 'Guess' values. The 'new' function is defined
+ * to have on parameter named 'value' of type 'i32' and to return a
+ * 'Guess'. The code in the body of the 'new' function tests 'value' to
+ * make sure it's between 1 and 100. If 'value' doesn't pass this test,
+ * we make a 'panic!' call, which will alert the programmer who is
+ * writing the calling code that they have a bug they need to fix,
+ * because creating a 'Guess' with a 'value' outside this range would
+ * violate the contract that 'Guess::new' is relying on. The conditions
+ * in which 'Guess::new' might panic should be discussed in its public-
+ * facing API documentation. The specifics of documentation conventions
+ * are covered later. If 'value' does pass the test, we create a new
+ * 'Guess' with its 'value' field set to the value parameter and return
+ * the 'Guess'.
+ *
+ * Next, we implement a method named 'value' that borrows 'self',
+ * doesn't have any other parameters, and returns an 'i32'. This kind
+ * of method is sometimes called a 'getter', because its purpose is to
+ * get some data from its fields and return it. This public method is
+ * necessary because the 'value' field of the 'Guess' struct is private.
+ * It's important that the 'value' field be private so code using the
+ * 'Guess' struct is not allowed to set value directly: code outside the
+ * module MUST use the 'Guess::new' function to create and instance of
+ * 'Guess', thereby ensuring there's no way for a 'Guess' to have a
+ * 'value' that hasn't been checked by the conditions in the
+ * 'Guess::new' function.
+ *
+ * A function that has a parameter or returns only numbers between 1 and
+ * 100 could then declare in its signature that it takes or returns a
+ * 'Guess' rather than an 'i32' and wouldn't need to do any additional
+ * checks in its body.
+ *
+ * Our 'Guess::new' goes one step further than the book's: the range isn't
+ * always 1 to 100 but comes from the 'Difficulty' the player picked, so
+ * 'new' takes that as a second parameter and checks against its range.
+ *
+ * The check itself now lives in 'Bounded' (see bounded.rs), a struct
+ * like the book's 'Guess' but with the range as part of its type. That
+ * makes 'Guess' an enum with one 'Bounded' per level: a normal-level
+ * guess holds a 'Bounded<1, 100>', exactly the book's 'Guess', and 'new'
+ * picks the variant from the difficulty. The ranges in 'Difficulty::range'
+ * have to agree with these types, which the tests check. A range set on
+ * the command line isn't a type, so 'Guess::within' checks against it at
+ * runtime and 'Guess::Custom' holds an 'InRange', whose field is private
+ * for the same reason: a 'Guess' of any kind can't be built around a
+ * value nobody checked.
+ *
+ * When the value is rejected, 'new' says why with a 'GuessError' (see
+ * error.rs) rather than panicking, since a bad guess is something the
+ * player can fix by trying again. 'Guess::parse' does the 'parse' step
+ * too (with 'parse_guess', which is more forgiving than 'str::parse'),
+ * so the game loop has a single 'Result' to look at.
+ *
+ * The difficulty also sets how many guesses the player gets. Only guesses
+ * that pass 'Guess::new' count against the limit: typing letters or a
+ * number outside the range is a mistake to correct, not a wasted turn. */
+pub fn guessing_game(
+    rules: &Rules,
+    picker: &mut SecretPicker<impl Rng>,
+    input: &mut impl InputSource,
+    output: &mut impl OutputSink,
+) -> Outcome {
+    let secret_number = picker.pick(rules.range.clone());
+    play(secret_number, rules, input, output)
+}
+
+/* The number game as a 'GameMode'. A game picked up with '--resume' is
+ * the first round; after that each round gets a new secret. */
+pub struct NumberGame<'a, R> {
+    rules: Rules,
+    picker: SecretPicker<&'a mut R>,
+    resumed: Option<GameState>,
+}
+
+impl<'a, R: Rng> NumberGame<'a, R> {
+    pub fn new(rules: Rules, rng: &'a mut R, resumed: Option<GameState>) -> Self {
+        NumberGame {
+            rules,
+            picker: SecretPicker::new(rng),
+            resumed,
+        }
+    }
+}
+
+impl<R: Rng> GameMode for NumberGame<'_, R> {
+    fn play_round<I: InputSource, O: OutputSink>(
+        &mut self,
+        input: &mut I,
+        output: &mut O,
+    ) -> Outcome {
+        match self.resumed.take() {
+            Some(state) => {
+                self.picker.remember(state.secret);
+                run(state, SAVE_FILE, input, output)
+            }
+            None => guessing_game(&self.rules, &mut self.picker, input, output),
+        }
+    }
+}
+
+/* One game against a secret number picked by the caller, so that a test
+ * can pick it too. */
+pub fn play(
+    secret_number: i32,
+    rules: &Rules,
+    input: &mut impl InputSource,
+    output: &mut impl OutputSink,
+) -> Outcome {
+    run(
+        GameState::new(rules.clone(), secret_number),
+        SAVE_FILE,
+        input,
+        output,
+    )
+}
+
+/* Plays a game from wherever 'state' left off, a new game being one with
+ * no guesses yet. Typing 'save' writes the state to 'save_to' and ends
+ * the game for now; running out of input counts as giving up. */
+pub fn run(
+    mut state: GameState,
+    save_to: impl AsRef<Path>,
+    input: &mut impl InputSource,
+    output: &mut impl OutputSink,
+) -> Outcome {
+    let rules = state.rules.clone();
+    let secret_number = state.secret;
+    output.write_line(&format!(
+        "{}: the secret number is between {} and {}. You have {} guesses.",
+        rules.name(),
+        rules.range.start(),
+        rules.range.end(),
+        rules.max_attempts
+    ));
+    if !state.guesses.is_empty() {
+        let guesses: Vec<String> = state.guesses.iter().map(i32::to_string).collect();
+        output.write_line(&format!(
+            "Picking up where you left off. So far you've guessed {}.",
+            guesses.join(", ")
+        ));
+    }
+    output.write_line("Type 'history' to see your guesses, or 'save' to finish later.");
+
+    let max_attempts = rules.max_attempts;
+    let mut stopwatch = rules.timed.then(Stopwatch::start);
+    let mut tracker = RangeTracker::new(rules.range.clone());
+    let mut history: Vec<_> = state
+        .guesses
+        .iter()
+        .map(|&guess| tracker.record(guess, secret_number))
+        .collect();
+
+    loop {
+        output.write_line("Please input your guess.");
+
+        let Some(guess) = input.read_line() else {
+            output.write_line(&format!(
+                "No more guesses coming. The secret number was {secret_number}.\n"
+            ));
+            return Outcome::Lost;
+        };
+
+        if guess.trim().eq_ignore_ascii_case("history") {
+            output.write_line(&history_table(&history));
+            continue;
+        }
+        if guess.trim().eq_ignore_ascii_case("save") {
+            match state.save(&save_to) {
+                Ok(()) => {
+                    output.write_line("Game saved. Run the game with --resume to carry on.\n");
+                    return Outcome::Saved;
+                }
+                Err(e) => output.write_line(&format!("Couldn't save the game: {e}")),
+            }
+            continue;
+        }
+
+        let guess = match rules.parse_guess(&guess) {
+            Ok(guess) => guess,
+            Err(e) => {
+                output.write_line(&e.to_string());
+                continue;
+            }
+        };
+
+        output.write_line(&format!("You guessed {}.", guess.value()));
+        state.guesses.push(guess.value());
+        let attempts = state.attempts();
+        if let Some(stopwatch) = stopwatch.as_mut() {
+            stopwatch.lap(guess.value());
+        }
+        let record = tracker.record(guess.value(), secret_number);
+        let direction = record.direction;
+        history.push(record);
+
+        match direction {
+            Direction::TooSmall => output.write_colored("Too small!", Color::Blue),
+            Direction::TooBig => output.write_colored("Too big!", Color::Red),
+            Direction::Correct => {
+                output.write_colored("You win!", Color::Green);
+                output.write_line("");
+                if let Some(stopwatch) = &stopwatch {
+                    output.write_line(&format!("{}\n", stopwatch.breakdown(true)));
+                }
+                return Outcome::Won { attempts };
+            }
+        }
+
+        let left = max_attempts - attempts;
+        if left == 0 {
+            output.write_line(&format!(
+                "Out of guesses! The secret number was {secret_number}. You lose.\n"
+            ));
+            if let Some(stopwatch) = &stopwatch {
+                output.write_line(&format!("{}\n", stopwatch.breakdown(false)));
+            }
+            return Outcome::Lost;
+        }
+        output.write_line(&format!(
+            "{left} {} left.",
+            if left == 1 { "guess" } else { "guesses" }
+        ));
+    }
+}
+
+/* The other way around: the player picks the number and a 'Solver' finds
+ * it. Returns how many guesses that took, or 'None' if the player's
+ * answers contradicted each other or the input ran out. Answers that
+ * aren't one of the three choices are asked again and don't use up a
+ * guess. */
+pub fn computer_guesses(
+    rules: &Rules,
+    input: &mut impl InputSource,
+    output: &mut impl OutputSink,
+) -> Option<u32> {
+    let range = &rules.range;
+    output.write_line(&format!(
+        "Think of a number between {} and {}, and I'll guess it.",
+        range.start(),
+        range.end()
+    ));
+
+    let mut solver = Solver::new(range.clone());
+    loop {
+        let Some(guess) = solver.guess() else {
+            output.write_line(&format!(
+                "Those answers don't add up: no number between {} and {} fits them.\n",
+                range.start(),
+                range.end()
+            ));
+            return None;
+        };
+        output.write_line(&format!("Is it {guess}? (h)igher, (l)ower, or (c)orrect"));
+
+        let answer = input.read_line()?;
+        let Some(feedback) = Feedback::parse(&answer) else {
+            output.write_line("Please answer h, l, or c.");
+            continue;
+        };
+        if solver.respond(feedback) {
+            let guesses = solver.guesses();
+            output.write_line(&format!(
+                "Got it in {guesses} {}!\n",
+                if guesses == 1 { "guess" } else { "guesses" }
+            ));
+            return Some(guesses);
+        }
+    }
+}
+
+/* The name that goes on the leaderboard. */
+pub fn ask_name(input: &mut impl InputSource, output: &mut impl OutputSink) -> String {
+    output.write_line("What's your name?");
+
+    match input.read_line().as_deref().map(str::trim) {
+        None | Some("") => String::from("Anonymous"),
+        Some(name) => name.to_string(),
+    }
+}
+
+/* Just pressing enter (or closing the input) means the player guesses,
+ * the way the game has always worked. */
+pub fn choose_guesser(input: &mut impl InputSource, output: &mut impl OutputSink) -> Guesser {
+    loop {
+        output.write_line("Who's guessing? (y)ou or the (c)omputer [you]");
+
+        let choice = input.read_line().unwrap_or_default();
+        if choice.trim().is_empty() {
+            return Guesser::Player;
+        }
+        match Guesser::parse(&choice) {
+            Some(guesser) => return guesser,
+            None => output.write_line(&format!("'{}' isn't a choice.", choice.trim())),
+        }
+    }
+}
+
+/* Just pressing enter (or closing the input) picks the number game. */
+pub fn choose_mode(input: &mut impl InputSource, output: &mut impl OutputSink) -> Mode {
+    loop {
+        output.write_line("Which game? (n)umbers or (w)ords [numbers]");
+
+        let choice = input.read_line().unwrap_or_default();
+        if choice.trim().is_empty() {
+            return Mode::default();
+        }
+        match Mode::parse(&choice) {
+            Some(mode) => return mode,
+            None => output.write_line(&format!("'{}' isn't a game.", choice.trim())),
+        }
+    }
+}
+
+/* Asks until the player picks a level. Just pressing enter (or closing
+ * the input) picks the default, 'Normal'. */
+pub fn choose_difficulty(input: &mut impl InputSource, output: &mut impl OutputSink) -> Difficulty {
+    loop {
+        output.write_line("Choose a difficulty: (e)asy, (n)ormal, or (h)ard [normal]");
+
+        let choice = input.read_line().unwrap_or_default();
+        if choice.trim().is_empty() {
+            return Difficulty::default();
+        }
+        match Difficulty::parse(&choice) {
+            Some(difficulty) => return difficulty,
+            None => output.write_line(&format!("'{}' isn't a difficulty.", choice.trim())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -1,10 +1,120 @@
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::cmp::Ordering;
-use std::io;
+use std::io::{self, IsTerminal};
+use std::{env, process};
+
+use guessing_game_v2::cli::{self, ArgsError, USAGE};
+use guessing_game_v2::config::{Config, CONFIG_FILE};
+use guessing_game_v2::hangman::WordGame;
+use guessing_game_v2::leaderboard::{Entry, Leaderboard, LEADERBOARD_FILE};
+use guessing_game_v2::style::Styled;
+use guessing_game_v2::{
+    ask_name, choose_difficulty, choose_guesser, choose_mode, computer_guesses, mode, session,
+    session_stats, GameState, Guesser, Mode, NumberGame, Outcome, OutputSink, Rules, SAVE_FILE,
+};
 
 /* Creating Custom Types for Validation */
 fn main() {
-    guessing_game();
+    let config = Config::load(CONFIG_FILE).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        process::exit(2);
+    });
+    let args = cli::parse_args(env::args().skip(1)).unwrap_or_else(|e| usage_error(e));
+    let args = args.or(config.args());
+    let mut rng = match args.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let mut leaderboard = match Leaderboard::load(LEADERBOARD_FILE) {
+        Ok(leaderboard) => Some(leaderboard),
+        Err(e) => {
+            println!("Couldn't read {LEADERBOARD_FILE}: {e}. High scores won't be saved.");
+            None
+        }
+    };
+    if let Some(skipped) = leaderboard.as_ref().map(Leaderboard::skipped) {
+        if skipped > 0 {
+            println!("Skipped {skipped} unreadable line(s) in {LEADERBOARD_FILE}.");
+        }
+    }
+
+    /* The lock has to be let go before the book's version of the game
+     * below calls 'io::stdin()' again, or that call would wait forever. */
+    let mut input = io::stdin().lock();
+    let color = !args.no_color && io::stdout().is_terminal();
+    let mut output = Styled::new(io::stdout(), color);
+    output.write_line("Guess the number! V2");
+    let name = match config.name.filter(|name| !name.trim().is_empty()) {
+        Some(name) => name,
+        None => ask_name(&mut input, &mut output),
+    };
+
+    /* A resumed game brings its own rules, so there's nothing to ask. */
+    let mut resumed = args.resume.then(|| {
+        GameState::take(SAVE_FILE).unwrap_or_else(|e| {
+            eprintln!("error: couldn't resume from {SAVE_FILE}: {e}");
+            process::exit(2);
+        })
+    });
+    let mode = match resumed {
+        Some(_) => Mode::Numbers,
+        None => choose_mode(&mut input, &mut output),
+    };
+
+    /* Only the player's own games count toward the leaderboard and the
+     * session summary. */
+    let mut outcomes = Vec::new();
+    match mode {
+        Mode::Words => {
+            let mut game = WordGame::new(&mut rng);
+            outcomes = session(&mut game, &mut input, &mut output, |_| {});
+        }
+        Mode::Numbers => {
+            let (rules, guesser) = match resumed.as_mut() {
+                Some(state) => {
+                    state.rules.timed = args.timed;
+                    (state.rules.clone(), Guesser::Player)
+                }
+                None => {
+                    let difficulty = choose_difficulty(&mut input, &mut output);
+                    let rules = Rules::new(difficulty, &args).unwrap_or_else(|e| usage_error(e));
+                    (rules, choose_guesser(&mut input, &mut output))
+                }
+            };
+            let difficulty = rules.difficulty;
+
+            if guesser == Guesser::Computer {
+                loop {
+                    computer_guesses(&rules, &mut input, &mut output);
+                    if !mode::play_again(&mut input, &mut output) {
+                        break;
+                    }
+                }
+            } else {
+                let ranked = rules.is_standard();
+                let record = |outcome| {
+                    let board = leaderboard.as_mut().filter(|_| ranked);
+                    if let (Some(board), Outcome::Won { attempts }) = (board, outcome) {
+                        if let Err(e) = board.record(Entry::new(&name, difficulty, attempts)) {
+                            println!("Couldn't save your score: {e}");
+                        }
+                    }
+                };
+                let mut game = NumberGame::new(rules, &mut rng, resumed);
+                outcomes = session(&mut game, &mut input, &mut output, record);
+            }
+            if let Some(leaderboard) = leaderboard.as_ref() {
+                println!("{}", leaderboard.table(difficulty));
+            }
+        }
+    }
+    drop(input);
+
+    if !outcomes.is_empty() {
+        println!("{}\n", session_stats(&outcomes));
+    }
     /* Let’s take the idea of using Rust’s type system to ensure we have a
      * valid value one step further and look at creating a custom type for
      * validation. Recall the guessing game built earlier in which our code
@@ -38,6 +148,7 @@ fn main() {
         /* One way to do this would be to parse the guess as an 'i32'
          * instead of only a 'u32' to allows potentially negative numbers,
          * and then add a check for the number being in range. */
+        #[allow(clippy::manual_range_contains)]
         if guess < 1 || guess > 100 {
             println!("The secret number will be between 1 and 100.");
             continue;
@@ -53,7 +164,7 @@ fn main() {
          * critical that the program only operated on values between 1 and
          * 100, and it had many functions with this requirement, having a
          * check like above in every function would be tedious (and might
-         * impact performance). - Jump to struct Guess definition */
+         * impact performance). - Jump to the 'Guess' definition in lib.rs */
 
         println!("You guessed: {guess}");
 
@@ -68,96 +179,11 @@ fn main() {
     }
 }
 
-/* Instead, we can make a new type and put the validations in a
- * function to create an instance of the type rather than repeating
- * the validations everywhere. That way, it's safe for functions to
- * use the new type in their signatures and confidently use the
- * values they receive. The below code will show one way to define
- * a 'Guess' type that will only create an instance of 'Guess' if
- * the 'new' function receives a value between 1 and 100. */
-pub struct Guess {
-    value: i32,
-}
-impl Guess {
-    pub fn new(value: i32) -> Result<Guess, io::Error> {
-        if value < 1 || value > 100 {
-            return Err(io::Error::other("Guess must be between 1 and 100."));
-        }
-        return Ok(Guess { value });
-    }
-
-    pub fn value(&self) -> i32 {
-        self.value
-    }
-}
-/* First we define a struct named 'Guess' that has a field named 'value'
- * that holds an 'i32'. This is where the number will be stored.
- *
- * Then we implement an associated function named 'new' on 'Guess' that
- * creates instances of 'Guess' values. The 'new' function is defined
- * to have on parameter named 'value' of type 'i32' and to return a
- * 'Guess'. The code in the body of the 'new' function tests 'value' to
- * make sure it's between 1 and 100. If 'value' doesn't pass this test,
- * we make a 'panic!' call, which will alert the programmer who is
- * writing the calling code that they have a bug they need to fix,
- * because creating a 'Guess' with a 'value' outside this range would
- * violate the contract that 'Guess::new' is relying on. The conditions
- * in which 'Guess::new' might panic should be discussed in its public-
- * facing API documentation. The specifics of documentation conventions
- * are covered later. If 'value' does pass the test, we create a new
- * 'Guess' with its 'value' field set to the value parameter and return
- * the 'Guess'.
- *
- * Next, we implement a method named 'value' that borrows 'self',
- * doesn't have any other parameters, and returns an 'i32'. This kind
- * of method is sometimes called a 'getter', because its purpose is to
- * get some data from its fields and return it. This public method is
- * necessary because the 'value' field of the 'Guess' struct is private.
- * It's important that the 'value' field be private so code using the
- * 'Guess' struct is not allowed to set value directly: code outside the
- * module MUST use the 'Guess::new' function to create and instance of
- * 'Guess', thereby ensuring there's no way for a 'Guess' to have a
- * 'value' that hasn't been checked by the conditions in the
- * 'Guess::new' function.
- *
- * A function that has a parameter or returns only numbers between 1 and
- * 100 could then declare in its signature that it takes or returns a
- * 'Guess' rather than an 'i32' and wouldn't need to do any additional
- * checks in its body. */
-fn guessing_game() {
-    println!("Guess the number! V2");
-
-    let secret_number = rand::thread_rng().gen_range(1..=100);
-
-    loop {
-        println!("Please input your guess.");
-
-        let mut guess = String::new();
-
-        io::stdin()
-            .read_line(&mut guess)
-            .expect("Failed to read line");
-
-        let guess: Guess = match guess.trim().parse() {
-            Ok(num) => match Guess::new(num) {
-                Ok(guess) => guess,
-                Err(e) => {
-                    println!("{}", e);
-                    continue;
-                }
-            },
-            Err(_) => continue,
-        };
-
-        println!("You guessed {}.", guess.value());
-
-        match guess.value().cmp(&secret_number) {
-            Ordering::Less => println!("Too small!"),
-            Ordering::Greater => println!("Too big!"),
-            Ordering::Equal => {
-                println!("You win!\n");
-                break;
-            }
-        }
-    }
+/* Bad arguments are the user's mistake, not a bug, so they get a message
+ * and the usage line on stderr and a nonzero exit code instead of a
+ * panic. */
+fn usage_error(e: ArgsError) -> ! {
+    eprintln!("error: {e}");
+    eprintln!("{USAGE}");
+    process::exit(2);
 }
@@ -0,0 +1,79 @@
+/* Game Modes */
+/* There are two games now, the number game and the word game, and both
+ * end up as an 'Outcome'. Everything around a single round - asking to
+ * play again, collecting the outcomes for 'session_stats' - doesn't care
+ * which game it is, so it's written once against the 'GameMode' trait.
+ *
+ * 'play_round' is generic over the input and output, like the functions
+ * it calls, which means 'GameMode' can't be used as a 'dyn GameMode'
+ * (a trait object can't have generic methods, the "object safety" rule
+ * from the trait objects chapter). We don't need it to be: 'main' knows
+ * which game it picked and calls 'session' with that type, and the
+ * compiler makes a copy of 'session' for each one. */
+use crate::{InputSource, Outcome, OutputSink};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    #[default]
+    Numbers,
+    Words,
+}
+
+impl Mode {
+    pub fn parse(input: &str) -> Option<Mode> {
+        match input.trim().to_lowercase().as_str() {
+            "n" | "number" | "numbers" => Some(Mode::Numbers),
+            "w" | "word" | "words" => Some(Mode::Words),
+            _ => None,
+        }
+    }
+}
+
+pub trait GameMode {
+    fn play_round<I: InputSource, O: OutputSink>(
+        &mut self,
+        input: &mut I,
+        output: &mut O,
+    ) -> Outcome;
+}
+
+/* Plays rounds until the player doesn't want another or saves a game,
+ * calling 'record' with each finished round's outcome as it happens (the
+ * number game uses it for the leaderboard). Returns every finished
+ * round's outcome, for the summary. */
+pub fn session<M: GameMode>(
+    mode: &mut M,
+    input: &mut impl InputSource,
+    output: &mut impl OutputSink,
+    mut record: impl FnMut(Outcome),
+) -> Vec<Outcome> {
+    let mut outcomes = Vec::new();
+    loop {
+        let outcome = mode.play_round(input, output);
+        if outcome == Outcome::Saved {
+            break;
+        }
+        outcomes.push(outcome);
+        record(outcome);
+
+        if !play_again(input, output) {
+            break;
+        }
+    }
+    outcomes
+}
+
+/* Anything but a yes ends the session, including just pressing enter;
+ * something that's neither a yes nor a no gets asked again. */
+pub fn play_again(input: &mut impl InputSource, output: &mut impl OutputSink) -> bool {
+    loop {
+        output.write_line("Play again? (y/n) [n]");
+
+        let answer = input.read_line().unwrap_or_default();
+        match answer.trim().to_lowercase().as_str() {
+            "y" | "yes" => return true,
+            "" | "n" | "no" => return false,
+            other => output.write_line(&format!("'{other}' isn't a yes or a no.")),
+        }
+    }
+}
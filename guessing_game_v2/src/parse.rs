@@ -0,0 +1,150 @@
+/* Reading a Guess */
+/* 'str::parse' only takes digits, but a player might reasonably type
+ * "fifty", or "1e2" for 100. 'parse_guess' tries, in order:
+ *
+ * - a plain integer, like "42" or " -7 " (surrounding whitespace is
+ *   ignored everywhere),
+ * - a number in scientific notation or with a decimal point, as long as
+ *   it's a whole number that fits in an 'i32': "1e2" and "2.5e1" are fine,
+ *   "1.5" isn't,
+ * - English number words from "zero" to "nine hundred ninety-nine
+ *   thousand nine hundred ninety-nine", with or without hyphens and
+ *   "and", optionally starting with "minus".
+ *
+ * Anything else is the 'GuessError' that the first attempt's
+ * 'ParseIntError' turns into. The words are checked for order, not just
+ * added up: "five five" and "hundred twenty ten" aren't numbers. */
+use crate::GuessError;
+
+pub fn parse_guess(input: &str) -> Result<i32, GuessError> {
+    let input = input.trim();
+    let error = match input.parse::<i32>() {
+        Ok(value) => return Ok(value),
+        Err(e) => GuessError::from(e),
+    };
+    parse_decimal(input)
+        .or_else(|| parse_words(input))
+        .ok_or(error)
+}
+
+/* 'f64' also parses "inf" and "NaN", which aren't guesses, so the input
+ * has to start like a number too. */
+fn parse_decimal(input: &str) -> Option<i32> {
+    let digits = input.trim_start_matches(['-', '+']);
+    if !digits.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+    let value: f64 = input.parse().ok()?;
+    let whole = value.fract() == 0.0 && value >= i32::MIN as f64 && value <= i32::MAX as f64;
+    whole.then_some(value as i32)
+}
+
+/* What the previous word was, which decides what may come next. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Last {
+    Start,
+    Unit,
+    Teen,
+    Tens,
+    Hundred,
+    Thousand,
+}
+
+fn parse_words(input: &str) -> Option<i32> {
+    let lowered = input.to_lowercase().replace('-', " ");
+    let mut words = lowered
+        .split_whitespace()
+        .filter(|&word| word != "and")
+        .peekable();
+
+    let sign = match words.peek() {
+        Some(&("minus" | "negative")) => {
+            words.next();
+            -1
+        }
+        _ => 1,
+    };
+    if words.peek() == Some(&"zero") {
+        words.next();
+        return words.next().is_none().then_some(0);
+    }
+
+    let (mut total, mut group, mut last) = (0, 0, Last::Start);
+    for word in words {
+        let group_start = matches!(last, Last::Start | Last::Hundred | Last::Thousand);
+        if let Some(n) = unit(word) {
+            if !(group_start || last == Last::Tens) {
+                return None;
+            }
+            group += n;
+            last = Last::Unit;
+        } else if let Some(n) = teen(word) {
+            if !group_start {
+                return None;
+            }
+            group += n;
+            last = Last::Teen;
+        } else if let Some(n) = tens(word) {
+            if !group_start {
+                return None;
+            }
+            group += n;
+            last = Last::Tens;
+        } else if word == "hundred" {
+            /* Only a single digit can be counted in hundreds, and only once
+             * per group: "two hundred", not "twelve hundred". */
+            if last != Last::Unit || group >= 10 {
+                return None;
+            }
+            group *= 100;
+            last = Last::Hundred;
+        } else if word == "thousand" {
+            if matches!(last, Last::Start | Last::Thousand) || total > 0 {
+                return None;
+            }
+            total = group * 1000;
+            group = 0;
+            last = Last::Thousand;
+        } else if word == "a" && last == Last::Start {
+            /* "a hundred", "a thousand" */
+            group = 1;
+            last = Last::Unit;
+        } else {
+            return None;
+        }
+    }
+
+    (last != Last::Start).then_some(sign * (total + group))
+}
+
+fn unit(word: &str) -> Option<i32> {
+    let units = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    units.iter().position(|&u| u == word).map(|i| i as i32 + 1)
+}
+
+fn teen(word: &str) -> Option<i32> {
+    let teens = [
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    teens.iter().position(|&t| t == word).map(|i| i as i32 + 10)
+}
+
+fn tens(word: &str) -> Option<i32> {
+    let tens = [
+        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+    tens.iter()
+        .position(|&t| t == word)
+        .map(|i| (i as i32 + 2) * 10)
+}
@@ -0,0 +1,78 @@
+/* Picking Secrets */
+/* A uniformly random secret will sometimes be the same number twice in a
+ * row, and on easy (20 numbers) that happens often enough to notice. A
+ * 'SecretPicker' wraps the random number generator and remembers the last
+ * few secrets it picked, then picks the next one from the rest of the
+ * range, so a session doesn't repeat itself.
+ *
+ * Always leaving out every remembered number would make the game
+ * predictable on a small range (with 20 numbers and 10 remembered, the
+ * secret would always be one of the other 10), so at most half of the
+ * range is ever left out, the most recent secrets first.
+ *
+ * It only remembers within a run. The leaderboard could carry secrets
+ * across runs, but it only has the games that were won and the file is
+ * there for people to read, so we leave secrets out of it.
+ *
+ * The word game uses the same picker to choose an index into its word
+ * list, so it doesn't repeat words either. */
+use std::collections::VecDeque;
+use std::ops::RangeInclusive;
+
+use rand::Rng;
+
+pub const REMEMBERED: usize = 10;
+
+#[derive(Debug)]
+pub struct SecretPicker<R> {
+    rng: R,
+    recent: VecDeque<i32>,
+}
+
+impl<R: Rng> SecretPicker<R> {
+    pub fn new(rng: R) -> SecretPicker<R> {
+        SecretPicker {
+            rng,
+            recent: VecDeque::new(),
+        }
+    }
+
+    /* For a secret that didn't come from 'pick', like a resumed game's. */
+    pub fn remember(&mut self, secret: i32) {
+        self.recent.retain(|&s| s != secret);
+        self.recent.push_front(secret);
+        self.recent.truncate(REMEMBERED);
+    }
+
+    /* The numbers 'pick' would leave out of 'range' right now, in
+     * increasing order. */
+    pub fn avoided(&self, range: &RangeInclusive<i32>) -> Vec<i32> {
+        let size = *range.end() as i64 - *range.start() as i64 + 1;
+        let mut avoided: Vec<i32> = self
+            .recent
+            .iter()
+            .copied()
+            .filter(|secret| range.contains(secret))
+            .take((size / 2) as usize)
+            .collect();
+        avoided.sort_unstable();
+        avoided
+    }
+
+    /* Picks uniformly among the numbers in 'range' that aren't avoided:
+     * a random position among them, then stepping over each avoided
+     * number at or before it. */
+    pub fn pick(&mut self, range: RangeInclusive<i32>) -> i32 {
+        let avoided = self.avoided(&range);
+        let size = *range.end() as i64 - *range.start() as i64 + 1;
+        let mut secret = *range.start() as i64 + self.rng.gen_range(0..size - avoided.len() as i64);
+        for &skip in &avoided {
+            if skip as i64 <= secret {
+                secret += 1;
+            }
+        }
+        let secret = secret as i32;
+        self.remember(secret);
+        secret
+    }
+}
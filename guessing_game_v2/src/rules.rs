@@ -0,0 +1,78 @@
+/* Rules */
+/* What one game is played by: the range the secret number comes from and
+ * how many guesses the player gets. A difficulty level is one set of
+ * rules, and the command-line arguments can override either part of it,
+ * so 'Rules::new' starts from the level and applies whatever 'Args' has.
+ * Giving only '--min' or only '--max' keeps the level's other end, which
+ * can leave nothing in between (say '--min 50' on easy), so that's checked
+ * here as well as in 'parse_args'.
+ *
+ * A range from the command line isn't known until the program runs, so it
+ * can't be one of 'Guess''s 'Bounded' types; those guesses are checked at
+ * runtime and end up as 'Guess::Custom'. Scores from changed rules
+ * wouldn't be comparable with everyone else's, so only games played by a
+ * level's own rules go on the leaderboard. */
+use std::ops::RangeInclusive;
+
+use crate::cli::{Args, ArgsError};
+use crate::parse::parse_guess;
+use crate::{Difficulty, Guess, GuessError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    pub difficulty: Difficulty,
+    pub range: RangeInclusive<i32>,
+    pub max_attempts: u32,
+    pub timed: bool,
+}
+
+impl From<Difficulty> for Rules {
+    fn from(difficulty: Difficulty) -> Rules {
+        Rules {
+            difficulty,
+            range: difficulty.range(),
+            max_attempts: difficulty.max_attempts(),
+            timed: false,
+        }
+    }
+}
+
+impl Rules {
+    pub fn new(difficulty: Difficulty, args: &Args) -> Result<Rules, ArgsError> {
+        let range = difficulty.range();
+        let min = args.min.unwrap_or(*range.start());
+        let max = args.max.unwrap_or(*range.end());
+        if min > max {
+            return Err(ArgsError::EmptyRange { min, max });
+        }
+        Ok(Rules {
+            difficulty,
+            range: min..=max,
+            max_attempts: args.attempts.unwrap_or(difficulty.max_attempts()),
+            timed: args.timed,
+        })
+    }
+
+    /* Whether the range and attempts are the difficulty level's own.
+     * Timing a game doesn't change how many guesses it takes, so a timed
+     * game still counts. */
+    pub fn is_standard(&self) -> bool {
+        self.range == self.difficulty.range() && self.max_attempts == self.difficulty.max_attempts()
+    }
+
+    pub fn name(&self) -> String {
+        if self.is_standard() {
+            self.difficulty.to_string()
+        } else {
+            String::from("Custom")
+        }
+    }
+
+    pub fn parse_guess(&self, line: &str) -> Result<Guess, GuessError> {
+        if self.range == self.difficulty.range() {
+            Guess::parse(line, self.difficulty)
+        } else {
+            Guess::within(parse_guess(line)?, &self.range)
+        }
+    }
+}
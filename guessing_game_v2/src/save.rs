@@ -0,0 +1,170 @@
+/* Saving a Game */
+/* Typing 'save' instead of a guess writes everything needed to carry on
+ * later to a small text file, one 'key=value' per line:
+ *
+ * difficulty=Normal
+ * min=1
+ * max=100
+ * attempts=7
+ * secret=42
+ * guesses=50,25,37
+ *
+ * and running the game with '--resume' reads it back and picks up where
+ * the player left off. Yes, the secret number is right there in the
+ * file; this is a game on the player's own computer, and anyone who wants
+ * to cheat could just as well guess with a debugger attached.
+ *
+ * Whether the game is timed isn't saved, since the clock can't be stopped
+ * while the program isn't running anyway. A resumed game is timed if
+ * '--timed' is given again, starting from the resume.
+ *
+ * As with the leaderboard, the file can be edited by hand, so 'from_text'
+ * checks that what it reads describes a game that could really be in
+ * progress: the secret and every guess inside the range, fewer guesses
+ * than allowed, and none of them already the right answer. */
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{Difficulty, Rules};
+
+pub const SAVE_FILE: &str = "guessing_game_save.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameState {
+    pub rules: Rules,
+    pub secret: i32,
+    pub guesses: Vec<i32>,
+}
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(io::Error),
+    Missing(&'static str),
+    Invalid { key: &'static str, value: String },
+    Impossible(&'static str),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveError::Io(e) => write!(f, "{e}"),
+            SaveError::Missing(key) => write!(f, "the save has no '{key}'"),
+            SaveError::Invalid { key, value } => {
+                write!(f, "'{value}' isn't a valid '{key}' in the save")
+            }
+            SaveError::Impossible(why) => write!(f, "the saved game can't be right: {why}"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<io::Error> for SaveError {
+    fn from(e: io::Error) -> SaveError {
+        SaveError::Io(e)
+    }
+}
+
+impl GameState {
+    pub fn new(rules: Rules, secret: i32) -> GameState {
+        GameState {
+            rules,
+            secret,
+            guesses: Vec::new(),
+        }
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.guesses.len() as u32
+    }
+
+    pub fn to_text(&self) -> String {
+        let guesses: Vec<String> = self.guesses.iter().map(i32::to_string).collect();
+        format!(
+            "difficulty={}\nmin={}\nmax={}\nattempts={}\nsecret={}\nguesses={}\n",
+            self.rules.difficulty,
+            self.rules.range.start(),
+            self.rules.range.end(),
+            self.rules.max_attempts,
+            self.secret,
+            guesses.join(",")
+        )
+    }
+
+    pub fn from_text(text: &str) -> Result<GameState, SaveError> {
+        let field = |key| field(text, key);
+        let number = |key| number(text, key);
+
+        let level = field("difficulty")?;
+        let difficulty = Difficulty::parse(level).ok_or_else(|| invalid("difficulty", level))?;
+        let (min, max, secret) = (number("min")?, number("max")?, number("secret")?);
+        let max_attempts = field("attempts")?;
+        let max_attempts = max_attempts
+            .parse()
+            .map_err(|_| invalid("attempts", max_attempts))?;
+        let guesses = match field("guesses")? {
+            "" => Vec::new(),
+            list => list
+                .split(',')
+                .map(|guess| guess.trim().parse().map_err(|_| invalid("guesses", list)))
+                .collect::<Result<Vec<i32>, _>>()?,
+        };
+
+        let range = min..=max;
+        if !range.contains(&secret) {
+            return Err(SaveError::Impossible("the secret is outside the range"));
+        }
+        if guesses.iter().any(|guess| !range.contains(guess)) {
+            return Err(SaveError::Impossible("a guess is outside the range"));
+        }
+        if guesses.len() as u32 >= max_attempts {
+            return Err(SaveError::Impossible("there are no guesses left"));
+        }
+        if guesses.contains(&secret) {
+            return Err(SaveError::Impossible("the number was already guessed"));
+        }
+
+        Ok(GameState {
+            rules: Rules {
+                difficulty,
+                range,
+                max_attempts,
+                timed: false,
+            },
+            secret,
+            guesses,
+        })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+
+    /* Loading a save also deletes it, so the same game can't be resumed
+     * over and over until it's won. */
+    pub fn take(path: impl AsRef<Path>) -> Result<GameState, SaveError> {
+        let state = GameState::from_text(&fs::read_to_string(&path)?)?;
+        fs::remove_file(path)?;
+        Ok(state)
+    }
+}
+
+fn field<'a>(text: &'a str, key: &'static str) -> Result<&'a str, SaveError> {
+    text.lines()
+        .find_map(|line| line.trim().strip_prefix(key)?.strip_prefix('='))
+        .ok_or(SaveError::Missing(key))
+}
+
+fn number(text: &str, key: &'static str) -> Result<i32, SaveError> {
+    let value = field(text, key)?;
+    value.parse().map_err(|_| invalid(key, value))
+}
+
+fn invalid(key: &'static str, value: &str) -> SaveError {
+    SaveError::Invalid {
+        key,
+        value: value.to_string(),
+    }
+}
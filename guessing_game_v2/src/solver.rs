@@ -0,0 +1,92 @@
+/* The Computer Guesses */
+/* Turning the game around: the player thinks of a number and the program
+ * has to find it. The best strategy is the one the difficulty comment
+ * talks about, a binary search. 'Solver' keeps the range the number can
+ * still be in, always guesses the middle of it, and throws away the half
+ * the player's answer rules out, so it needs at most 7 guesses for 1 to
+ * 100 and 10 for 1 to 1000.
+ *
+ * 'Solver' only does the searching. It doesn't know about prompts or
+ * input, which is 'computer_guesses' in lib.rs, so the tests can play
+ * it against a number without typing anything.
+ *
+ * If the player's answers contradict each other (say "higher" than 50
+ * and then "lower" than 51), the range ends up empty and 'guess' returns
+ * 'None' rather than guessing outside it. */
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Guesser {
+    Player,
+    Computer,
+}
+
+impl Guesser {
+    pub fn parse(input: &str) -> Option<Guesser> {
+        match input.trim().to_lowercase().as_str() {
+            "y" | "you" | "me" => Some(Guesser::Player),
+            "c" | "computer" => Some(Guesser::Computer),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    Higher,
+    Lower,
+    Correct,
+}
+
+impl Feedback {
+    pub fn parse(input: &str) -> Option<Feedback> {
+        match input.trim().to_lowercase().as_str() {
+            "h" | "higher" => Some(Feedback::Higher),
+            "l" | "lower" => Some(Feedback::Lower),
+            "c" | "correct" => Some(Feedback::Correct),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solver {
+    low: i64,
+    high: i64,
+    guesses: u32,
+}
+
+impl Solver {
+    pub fn new(range: RangeInclusive<i32>) -> Solver {
+        Solver {
+            low: *range.start() as i64,
+            high: *range.end() as i64,
+            guesses: 0,
+        }
+    }
+
+    /* The middle of what's left. The bounds are kept as 'i64' so that
+     * adding them up, or stepping one past 'i32::MAX', can't overflow. */
+    pub fn guess(&self) -> Option<i32> {
+        (self.low <= self.high).then(|| (self.low + self.high).div_euclid(2) as i32)
+    }
+
+    /* Takes the player's answer to the current guess. Returns 'true' once
+     * the answer is 'Correct'. */
+    pub fn respond(&mut self, feedback: Feedback) -> bool {
+        let Some(guess) = self.guess() else {
+            return false;
+        };
+        self.guesses += 1;
+        match feedback {
+            Feedback::Higher => self.low = guess as i64 + 1,
+            Feedback::Lower => self.high = guess as i64 - 1,
+            Feedback::Correct => return true,
+        }
+        false
+    }
+
+    pub fn guesses(&self) -> u32 {
+        self.guesses
+    }
+}
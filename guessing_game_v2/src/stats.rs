@@ -0,0 +1,88 @@
+/* Wins and Losses */
+/* With a limited number of attempts a game can end two ways, so
+ * 'guessing_game' reports which one as an 'Outcome' instead of just
+ * returning when it's over. 'main' keeps every outcome of the session,
+ * one per round, and when the player quits 'session_stats' boils them
+ * down to a 'SessionStats' for the summary.
+ *
+ * 'session_stats' is a pure function: it only looks at the slice it's
+ * given and doesn't print, read input, or keep state between calls. That
+ * makes it the easy part of the game to test, so the arithmetic lives
+ * here and 'main' just prints the result. */
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Won { attempts: u32 },
+    Lost,
+    /* Put away with 'save' to finish later. */
+    Saved,
+}
+
+/* A saved game isn't over, so it doesn't count at all.
+ * 'average_guesses' and 'best_game' only count games that were won (a
+ * lost game always used up every guess, so it says nothing about how
+ * well the player searched), and are 'None' until there's a win. A streak
+ * is a run of wins in a row; 'current_streak' is the one the session
+ * ended on, which is 0 if the last game was lost. */
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SessionStats {
+    pub games: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub average_guesses: Option<f64>,
+    pub best_game: Option<u32>,
+    pub longest_streak: u32,
+    pub current_streak: u32,
+}
+
+pub fn session_stats(outcomes: &[Outcome]) -> SessionStats {
+    let mut stats = SessionStats::default();
+    let mut total_guesses = 0;
+
+    for outcome in outcomes {
+        match *outcome {
+            Outcome::Won { attempts } => {
+                stats.games += 1;
+                stats.wins += 1;
+                total_guesses += attempts;
+                stats.best_game = Some(stats.best_game.map_or(attempts, |b| b.min(attempts)));
+                stats.current_streak += 1;
+                stats.longest_streak = stats.longest_streak.max(stats.current_streak);
+            }
+            Outcome::Lost => {
+                stats.games += 1;
+                stats.losses += 1;
+                stats.current_streak = 0;
+            }
+            Outcome::Saved => {}
+        }
+    }
+
+    if stats.wins > 0 {
+        stats.average_guesses = Some(total_guesses as f64 / stats.wins as f64);
+    }
+    stats
+}
+
+impl fmt::Display for SessionStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Games: {}, won: {}, lost: {}",
+            self.games, self.wins, self.losses
+        )?;
+        if let (Some(average), Some(best)) = (self.average_guesses, self.best_game) {
+            write!(
+                f,
+                "\nAverage guesses per win: {average:.1}\nBest game: {best} {}",
+                if best == 1 { "guess" } else { "guesses" }
+            )?;
+        }
+        write!(
+            f,
+            "\nLongest winning streak: {} (current: {})",
+            self.longest_streak, self.current_streak
+        )
+    }
+}
@@ -0,0 +1,67 @@
+/* Colors */
+/* Most terminals understand ANSI escape codes: printing "\x1b[31m" turns
+ * the text after it red, and "\x1b[0m" turns it back to normal. The codes
+ * are unreadable in the middle of a 'format!', and they're noise when the
+ * output goes to a file or to something that doesn't understand them, so
+ * they live here and nowhere else.
+ *
+ * The game says which lines deserve a color with
+ * 'OutputSink::write_colored'. A plain writer ignores the color, which is
+ * why the tests' 'Output' sees "Too big!" as is. 'Styled' wraps a writer
+ * and adds the codes, but only if it was told to: 'main' turns colors off
+ * for '--no-color', for 'color = false' in the config file, and when
+ * stdout isn't a terminal. */
+use std::io::Write;
+
+use crate::OutputSink;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Color {
+    fn code(self) -> &'static str {
+        match self {
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Blue => "34",
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+pub fn paint(text: &str, color: Color) -> String {
+    format!("\x1b[{}m{text}{RESET}", color.code())
+}
+
+#[derive(Debug)]
+pub struct Styled<W> {
+    inner: W,
+    color: bool,
+}
+
+impl<W: Write> Styled<W> {
+    pub fn new(inner: W, color: bool) -> Styled<W> {
+        Styled { inner, color }
+    }
+}
+
+/* 'Styled' isn't a 'Write' itself, which is what lets it have its own
+ * 'OutputSink' impl next to the one for every 'Write'. */
+impl<W: Write> OutputSink for Styled<W> {
+    fn write_line(&mut self, line: &str) {
+        self.inner.write_line(line);
+    }
+
+    fn write_colored(&mut self, line: &str, color: Color) {
+        if self.color {
+            self.inner.write_line(&paint(line, color));
+        } else {
+            self.inner.write_line(line);
+        }
+    }
+}
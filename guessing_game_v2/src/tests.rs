@@ -0,0 +1,966 @@
+use crate::cli::ArgsError;
+use crate::config::{Config, CONFIG_FILE};
+use crate::hangman::WordGame;
+use crate::leaderboard::Leaderboard;
+use crate::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::cmp::Ordering;
+use test_support::gen::{self, assert_property};
+use test_support::{Input, Output};
+
+#[test]
+fn test_guess_accepts_exactly_one_to_one_hundred() {
+    assert_property(&gen::ints(i32::MIN as i64, i32::MAX as i64), |&n| {
+        let n = n as i32;
+        Guess::new(n, Difficulty::Normal).is_ok() == (1..=100).contains(&n)
+    });
+}
+
+#[test]
+fn test_guess_keeps_its_value() {
+    assert_property(&gen::ints(1, 100), |&n| {
+        Guess::new(n as i32, Difficulty::Normal)
+            .map(|g| g.value())
+            .ok()
+            == Some(n as i32)
+    });
+}
+
+#[test]
+fn test_parsed_guesses() {
+    /* What the game loop does with a line of input: parse it, then
+     * validate it. Neither step may panic, whatever was typed. */
+    assert_property(&gen::strings("0123456789-+ x", 6), |line| {
+        match line.trim().parse::<i32>() {
+            Ok(n) => Guess::new(n, Difficulty::Normal).is_ok() == (1..=100).contains(&n),
+            Err(_) => true,
+        }
+    });
+}
+
+#[test]
+fn test_guess_bounds_follow_difficulty() {
+    for difficulty in Difficulty::ALL {
+        let range = difficulty.range();
+        assert!(Guess::new(*range.start(), difficulty).is_ok());
+        assert!(Guess::new(*range.end(), difficulty).is_ok());
+        assert!(Guess::new(range.start() - 1, difficulty).is_err());
+        assert!(Guess::new(range.end() + 1, difficulty).is_err());
+    }
+    assert_eq!(
+        Guess::new(500, Difficulty::Normal).unwrap_err().to_string(),
+        "Guess must be between 1 and 100."
+    );
+    assert_eq!(Guess::new(500, Difficulty::Hard).unwrap().value(), 500);
+}
+
+#[test]
+fn test_parse_difficulty() {
+    assert_eq!(Difficulty::parse("e"), Some(Difficulty::Easy));
+    assert_eq!(Difficulty::parse(" Normal\n"), Some(Difficulty::Normal));
+    assert_eq!(Difficulty::parse("HARD"), Some(Difficulty::Hard));
+    assert_eq!(Difficulty::parse("ha"), None);
+    assert_eq!(Difficulty::parse(""), None);
+}
+
+#[test]
+fn test_session_stats_count_wins_and_losses() {
+    let stats = session_stats(&[
+        Outcome::Won { attempts: 3 },
+        Outcome::Lost,
+        Outcome::Won { attempts: 7 },
+    ]);
+    assert_eq!((stats.wins, stats.losses, stats.games), (2, 1, 3));
+    assert_eq!(
+        stats.to_string().lines().next(),
+        Some("Games: 3, won: 2, lost: 1")
+    );
+}
+
+#[test]
+fn test_session_stats_averages_and_streaks() {
+    let empty = session_stats(&[]);
+    assert_eq!(empty, stats::SessionStats::default());
+    assert_eq!(
+        empty.to_string(),
+        "Games: 0, won: 0, lost: 0\nLongest winning streak: 0 (current: 0)"
+    );
+
+    let won = |attempts| Outcome::Won { attempts };
+    let lost = Outcome::Lost;
+    let stats = session_stats(&[won(4), won(6), lost, won(2), won(5), won(6), lost, won(1)]);
+    assert_eq!(stats.games, 8);
+    assert_eq!(stats.average_guesses, Some(4.0));
+    assert_eq!(stats.best_game, Some(1));
+    assert_eq!((stats.longest_streak, stats.current_streak), (3, 1));
+    assert_eq!(
+        stats.to_string(),
+        "Games: 8, won: 6, lost: 2\n\
+         Average guesses per win: 4.0\n\
+         Best game: 1 guess\n\
+         Longest winning streak: 3 (current: 1)"
+    );
+
+    let stats = session_stats(&[lost, lost]);
+    assert_eq!((stats.average_guesses, stats.best_game), (None, None));
+}
+
+#[test]
+fn test_play_again() {
+    let mut input = Input::from_lines(&["Y", "maybe", "no", "yes", ""]);
+    let output = Output::new();
+    assert!(mode::play_again(&mut input, &mut output.clone()));
+    assert!(!mode::play_again(&mut input, &mut output.clone()));
+    assert!(mode::play_again(&mut input, &mut output.clone()));
+    assert!(!mode::play_again(&mut input, &mut output.clone()));
+    assert!(!mode::play_again(&mut input, &mut output.clone()));
+    assert!(output.contents().contains("'maybe' isn't a yes or a no."));
+}
+
+#[test]
+fn test_leaderboard_skips_corrupt_lines() {
+    let text = "name,difficulty,attempts,date\n\
+                Ferris,Normal,5,1718000000\n\
+                garbage\n\
+                Bors,Hard,x,1718000000\n\
+                \n\
+                Corro,Normal,0,1718000000\n\
+                Crab,Easy,3,1718000001\n\
+                Half,Nor";
+    let (entries, skipped) = leaderboard::parse(text);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(skipped, 4);
+    assert_eq!(entries[0].name, "Ferris");
+    assert_eq!(entries[1].difficulty, Difficulty::Easy);
+}
+
+#[test]
+fn test_leaderboard_round_trip_and_ranking() {
+    use leaderboard::Entry;
+    use test_support::TempFile;
+
+    let file = TempFile::new("guessing-game-leaderboard");
+    let mut board = Leaderboard::load(file.path()).unwrap();
+    assert!(board.top(Difficulty::Normal, 10).is_empty());
+
+    for (name, attempts) in [("Slow", 7), ("Fast", 2), ("Mid, Jr.", 4)] {
+        board
+            .record(Entry::new(name, Difficulty::Normal, attempts))
+            .unwrap();
+    }
+    board
+        .record(Entry::new("Other", Difficulty::Hard, 1))
+        .unwrap();
+
+    let reloaded = Leaderboard::load(file.path()).unwrap();
+    assert_eq!(reloaded.skipped(), 0);
+    for difficulty in Difficulty::ALL {
+        assert_eq!(reloaded.top(difficulty, 10), board.top(difficulty, 10));
+    }
+
+    let top: Vec<&str> = reloaded
+        .top(Difficulty::Normal, 10)
+        .iter()
+        .map(|e| e.name.as_str())
+        .collect();
+    assert_eq!(top, ["Fast", "Mid  Jr.", "Slow"]);
+    assert_eq!(reloaded.top(Difficulty::Normal, 1).len(), 1);
+    assert!(reloaded.table(Difficulty::Easy).contains("No wins yet."));
+    assert!(reloaded
+        .table(Difficulty::Normal)
+        .contains("  1. Fast               2 guesses"));
+}
+
+#[test]
+fn test_format_date() {
+    assert_eq!(leaderboard::format_date(0), "1970-01-01");
+    assert_eq!(leaderboard::format_date(951_782_400), "2000-02-29");
+    assert_eq!(leaderboard::format_date(1_718_000_000), "2024-06-10");
+}
+
+#[test]
+fn test_play_a_winning_game() {
+    let mut input = Input::from_lines(&["50", "abc", "25", "500", "37"]);
+    let output = Output::new();
+    let outcome = play(
+        37,
+        &Rules::from(Difficulty::Normal),
+        &mut input,
+        &mut output.clone(),
+    );
+    assert_eq!(outcome, Outcome::Won { attempts: 3 });
+
+    let lines = output.lines();
+    assert_eq!(
+        lines[0],
+        "Normal: the secret number is between 1 and 100. You have 7 guesses."
+    );
+    assert!(lines.contains(&String::from("Too big!")));
+    assert!(lines.contains(&String::from("Too small!")));
+    assert!(lines.contains(&String::from("Guess must be between 1 and 100.")));
+    assert!(lines.contains(&String::from("You win!")));
+}
+
+#[test]
+fn test_play_a_losing_game() {
+    let guesses = ["1", "2", "3", "4", "5", "6", "7", "8"];
+    let mut input = Input::from_lines(&guesses);
+    let output = Output::new();
+    let outcome = play(
+        20,
+        &Rules::from(Difficulty::Easy),
+        &mut input,
+        &mut output.clone(),
+    );
+    assert_eq!(outcome, Outcome::Lost);
+    assert!(output.contents().contains("1 guess left."));
+    assert!(output
+        .contents()
+        .contains("Out of guesses! The secret number was 20. You lose."));
+    /* The game stopped after six guesses, so two lines were never read. */
+    assert_eq!(input.read_line().as_deref(), Some("7"));
+}
+
+#[test]
+fn test_running_out_of_input_ends_the_game() {
+    let output = Output::new();
+    let outcome = play(
+        5,
+        &Rules::from(Difficulty::Normal),
+        &mut Input::default(),
+        &mut output.clone(),
+    );
+    assert_eq!(outcome, Outcome::Lost);
+    assert!(output.contents().contains("The secret number was 5."));
+
+    let mut empty = Input::default();
+    assert_eq!(ask_name(&mut empty, &mut Output::new()), "Anonymous");
+    assert_eq!(
+        choose_difficulty(&mut empty, &mut Output::new()),
+        Difficulty::Normal
+    );
+}
+
+#[test]
+fn test_setup_prompts() {
+    let mut input = Input::from_lines(&["  Ferris  ", "medium", "h"]);
+    let output = Output::new();
+    assert_eq!(ask_name(&mut input, &mut output.clone()), "Ferris");
+    assert_eq!(
+        choose_difficulty(&mut input, &mut output.clone()),
+        Difficulty::Hard
+    );
+    assert!(output
+        .lines()
+        .contains(&String::from("'medium' isn't a difficulty.")));
+}
+
+#[test]
+fn test_guess_errors() {
+    assert_eq!(
+        Guess::new(0, Difficulty::Easy).unwrap_err(),
+        GuessError::OutOfRange {
+            value: 0,
+            min: 1,
+            max: 20
+        }
+    );
+    assert_eq!(
+        Guess::parse("a dozen", Difficulty::Easy).unwrap_err(),
+        GuessError::NotANumber
+    );
+    assert_eq!(Guess::parse(" 12\n", Difficulty::Easy).unwrap().value(), 12);
+    let parse_error = "".parse::<i32>().unwrap_err();
+    assert_eq!(GuessError::from(parse_error), GuessError::NotANumber);
+    assert_eq!(GuessError::NotANumber.to_string(), "Please type a number.");
+}
+
+#[test]
+fn test_bounded_new_and_try_from_agree() {
+    assert_property(&gen::ints(-200, 200), |&n| {
+        let n = n as i32;
+        let checked = Bounded::<-50, 50>::new(n).ok();
+        checked == Bounded::<-50, 50>::try_from(n).ok()
+            && checked.map(Bounded::value) == (-50..=50).contains(&n).then_some(n)
+    });
+    let error: Result<Bounded<1, 100>, _> = 101.try_into();
+    assert_eq!(
+        error.unwrap_err(),
+        GuessError::OutOfRange {
+            value: 101,
+            min: 1,
+            max: 100
+        }
+    );
+}
+
+#[test]
+fn test_bounded_clamp() {
+    assert_eq!(Bounded::<1, 100>::clamp(-7).value(), 1);
+    assert_eq!(Bounded::<1, 100>::clamp(42).value(), 42);
+    assert_eq!(Bounded::<1, 100>::clamp(i32::MAX).value(), 100);
+    assert_eq!(Bounded::<5, 5>::clamp(0).to_string(), "5");
+}
+
+/* 'InRange' agrees with 'Bounded' when the range is the same. */
+#[test]
+fn test_in_range_checks_like_bounded() {
+    assert_property(&gen::ints(-200, 200), |&n| {
+        let n = n as i32;
+        InRange::new(n, &(-50..=50)).ok().map(InRange::value)
+            == Bounded::<-50, 50>::new(n).ok().map(Bounded::value)
+    });
+    assert_eq!(
+        Guess::within(31, &(1..=30)).unwrap_err(),
+        GuessError::OutOfRange {
+            value: 31,
+            min: 1,
+            max: 30
+        }
+    );
+}
+
+#[test]
+fn test_normal_guesses_are_one_to_one_hundred() {
+    let guess = Guess::new(64, Difficulty::Normal).unwrap();
+    assert!(matches!(guess, Guess::Normal(b) if b == Bounded::<1, 100>::new(64).unwrap()));
+}
+
+/* Answers the solver's guesses truthfully until it finds 'secret'. */
+fn solve(range: std::ops::RangeInclusive<i32>, secret: i32) -> Option<u32> {
+    let mut solver = Solver::new(range);
+    loop {
+        let feedback = match secret.cmp(&solver.guess()?) {
+            Ordering::Greater => Feedback::Higher,
+            Ordering::Less => Feedback::Lower,
+            Ordering::Equal => Feedback::Correct,
+        };
+        if solver.respond(feedback) {
+            return Some(solver.guesses());
+        }
+    }
+}
+
+#[test]
+fn test_solver_finds_any_number_within_the_attempt_limit() {
+    for difficulty in Difficulty::ALL {
+        for secret in difficulty.range() {
+            let guesses = solve(difficulty.range(), secret).unwrap();
+            assert!(guesses <= difficulty.max_attempts(), "{secret}: {guesses}");
+        }
+    }
+    /* 2^32 numbers take at most 33 guesses, and the ends mustn't overflow. */
+    for secret in [i32::MIN, -1, 0, i32::MAX] {
+        assert!(solve(i32::MIN..=i32::MAX, secret).unwrap() <= 33);
+    }
+}
+
+#[test]
+fn test_solver_notices_contradictions() {
+    let mut solver = Solver::new(1..=100);
+    assert_eq!(solver.guess(), Some(50));
+    assert!(!solver.respond(Feedback::Higher));
+    assert_eq!(solver.guess(), Some(75));
+    for _ in 0..5 {
+        solver.respond(Feedback::Lower);
+    }
+    assert_eq!(solver.guess(), None);
+    assert!(!solver.respond(Feedback::Correct));
+    assert_eq!(solver.guesses(), 6);
+}
+
+#[test]
+fn test_computer_guesses_a_number() {
+    let mut input = Input::from_lines(&["h", "what", "l", "c"]);
+    let output = Output::new();
+    let guesses = computer_guesses(
+        &Rules::from(Difficulty::Easy),
+        &mut input,
+        &mut output.clone(),
+    );
+    assert_eq!(guesses, Some(3));
+    assert_eq!(
+        output.lines(),
+        [
+            "Think of a number between 1 and 20, and I'll guess it.",
+            "Is it 10? (h)igher, (l)ower, or (c)orrect",
+            "Is it 15? (h)igher, (l)ower, or (c)orrect",
+            "Please answer h, l, or c.",
+            "Is it 15? (h)igher, (l)ower, or (c)orrect",
+            "Is it 12? (h)igher, (l)ower, or (c)orrect",
+            "Got it in 3 guesses!",
+            "",
+        ]
+    );
+    assert_eq!(Guesser::parse(" C "), Some(Guesser::Computer));
+    assert_eq!(Feedback::parse("Lower"), Some(Feedback::Lower));
+}
+
+fn args(line: &str) -> Result<cli::Args, ArgsError> {
+    cli::parse_args(line.split_whitespace().map(String::from))
+}
+
+#[test]
+fn test_parse_args() {
+    assert_eq!(args(""), Ok(cli::Args::default()));
+    let parsed = args("--min -5 --max=5 --attempts 3 --seed 42").unwrap();
+    assert_eq!(
+        (parsed.min, parsed.max, parsed.attempts, parsed.seed),
+        (Some(-5), Some(5), Some(3), Some(42))
+    );
+
+    assert_eq!(
+        args("--verbose"),
+        Err(ArgsError::UnknownArgument(String::from("--verbose")))
+    );
+    assert_eq!(args("--min 1 --max"), Err(ArgsError::MissingValue("--max")));
+    assert_eq!(
+        args("--attempts -1").unwrap_err().to_string(),
+        "'-1' isn't a valid value for '--attempts'"
+    );
+    assert_eq!(
+        args("--min 10 --max 9"),
+        Err(ArgsError::EmptyRange { min: 10, max: 9 })
+    );
+    assert_eq!(args("--attempts=0"), Err(ArgsError::NoAttempts));
+}
+
+#[test]
+fn test_rules_from_args() {
+    let normal = Rules::new(Difficulty::Normal, &args("").unwrap()).unwrap();
+    assert_eq!(normal, Rules::from(Difficulty::Normal));
+    assert!(normal.is_standard());
+    assert_eq!(normal.name(), "Normal");
+
+    let rules = Rules::new(Difficulty::Easy, &args("--max 30 --attempts 2").unwrap()).unwrap();
+    assert_eq!((rules.range.clone(), rules.max_attempts), (1..=30, 2));
+    assert!(!rules.is_standard());
+    assert_eq!(rules.name(), "Custom");
+    assert_eq!(rules.parse_guess("25").unwrap().value(), 25);
+    assert!(matches!(rules.parse_guess("25"), Ok(Guess::Custom(g)) if g.value() == 25));
+    assert_eq!(
+        rules.parse_guess("31").unwrap_err().to_string(),
+        "Guess must be between 1 and 30."
+    );
+
+    assert_eq!(
+        Rules::new(Difficulty::Easy, &args("--min 50").unwrap()),
+        Err(ArgsError::EmptyRange { min: 50, max: 20 })
+    );
+}
+
+#[test]
+fn test_seeded_games_pick_the_same_secret() {
+    let rules = Rules::new(Difficulty::Hard, &args("--min 900 --attempts 1").unwrap()).unwrap();
+    /* With one attempt and a wrong guess, the game says what the secret
+     * was; a right guess is a win. */
+    let secret = |seed| {
+        let mut picker = SecretPicker::new(StdRng::seed_from_u64(seed));
+        let output = Output::new();
+        let mut input = Input::from_lines(&["1000"]);
+        match guessing_game(&rules, &mut picker, &mut input, &mut output.clone()) {
+            Outcome::Won { .. } => 1000,
+            _ => {
+                let contents = output.contents();
+                let (_, rest) = contents.split_once("The secret number was ").unwrap();
+                rest.split('.').next().unwrap().parse().unwrap()
+            }
+        }
+    };
+    assert_eq!(secret(7), secret(7));
+    for seed in 0..20 {
+        assert!(rules.range.contains(&secret(seed)));
+    }
+}
+
+#[test]
+fn test_parse_config() {
+    assert_eq!(config::parse("").unwrap(), Config::default());
+    let parsed = config::parse(
+        "# My settings\n\
+         min = -10\n\
+         \n\
+         max = 1_0   # not TOML's underscores, though\n",
+    );
+    assert_eq!(
+        parsed.unwrap_err().to_string(),
+        "guessing_game.toml line 4: 'max' has to be a whole number"
+    );
+
+    let parsed = config::parse(
+        "min = -10 # inclusive\n\
+         attempts=4\n\
+         name = \"Ferris \\\"#1\\\" Crab\"\n\
+         color = false\n",
+    )
+    .unwrap();
+    assert_eq!(parsed.min, Some(-10));
+    assert_eq!(parsed.attempts, Some(4));
+    assert_eq!(parsed.name.as_deref(), Some("Ferris \"#1\" Crab"));
+    assert_eq!(parsed.color, Some(false));
+}
+
+#[test]
+fn test_config_errors() {
+    let error = |text| config::parse(text).unwrap_err().to_string();
+    assert_eq!(
+        error("min 5"),
+        "guessing_game.toml line 1: expected 'key = value', found 'min 5'"
+    );
+    assert_eq!(
+        error("\nlevel = 3"),
+        "guessing_game.toml line 2: unknown setting 'level'"
+    );
+    assert_eq!(
+        error("name = Ferris"),
+        "guessing_game.toml line 1: 'name' has to be a quoted string"
+    );
+    assert_eq!(
+        error("name = \"Ferris"),
+        "guessing_game.toml line 1: 'name' has to be a quoted string"
+    );
+    assert_eq!(
+        error("color = yes"),
+        "guessing_game.toml line 1: 'color' has to be true or false"
+    );
+    assert_eq!(
+        error("attempts = -2"),
+        "guessing_game.toml line 1: 'attempts' has to be a positive number"
+    );
+    assert_eq!(
+        error("min = 5\nmax = 4"),
+        "guessing_game.toml: there are no numbers from 5 to 4"
+    );
+    assert_eq!(
+        error("attempts = 0"),
+        "guessing_game.toml: 'attempts' has to be at least 1"
+    );
+}
+
+#[test]
+fn test_command_line_overrides_config() {
+    use test_support::TempFile;
+
+    let missing = test_support::TempDir::new("guessing-game-config").unwrap();
+    let config = Config::load(missing.path().join(CONFIG_FILE)).unwrap();
+    assert_eq!(config, Config::default());
+
+    let file = TempFile::with_contents("guessing-game-config", "min = 5\nmax = 50\n").unwrap();
+    let config = Config::load(file.path()).unwrap();
+    let merged = args("--max 60 --attempts 3").unwrap().or(config.args());
+    assert_eq!(
+        (merged.min, merged.max, merged.attempts),
+        (Some(5), Some(60), Some(3))
+    );
+}
+
+#[test]
+fn test_score() {
+    use std::time::Duration;
+    use timing::{score, MAX_SCORE};
+
+    assert_eq!(score(1, Duration::ZERO), MAX_SCORE);
+    assert_eq!(score(5, Duration::from_secs(20)), 142);
+    assert_eq!(score(0, Duration::from_secs(1)), 0);
+    /* A guess costs as much as ten seconds. */
+    assert_eq!(
+        score(2, Duration::from_secs(5)),
+        score(1, Duration::from_secs(15))
+    );
+    assert_property(&gen::ints(1, 1000), |&attempts| {
+        let attempts = attempts as u32;
+        let quick = score(attempts, Duration::from_millis(500));
+        quick <= MAX_SCORE
+            && score(attempts + 1, Duration::from_millis(500)) <= quick
+            && score(attempts, Duration::from_secs(60)) <= quick
+    });
+}
+
+#[test]
+fn test_timed_game_shows_a_breakdown() {
+    assert!(args("--timed").unwrap().timed);
+    let rules = Rules::new(Difficulty::Normal, &args("--timed").unwrap()).unwrap();
+    assert!(rules.is_standard());
+
+    let mut input = Input::from_lines(&["50", "oops", "25"]);
+    let output = Output::new();
+    let outcome = play(25, &rules, &mut input, &mut output.clone());
+    assert_eq!(outcome, Outcome::Won { attempts: 2 });
+
+    let lines = output.lines();
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("Guess 1: 50 after ")));
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("Guess 2: 25 after ")));
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("Total: 2 guesses in ")));
+    assert!(lines.iter().any(|line| line.starts_with("Score: ")));
+
+    let output = Output::new();
+    let untimed = Rules::from(Difficulty::Normal);
+    play(
+        25,
+        &untimed,
+        &mut Input::from_lines(&["25"]),
+        &mut output.clone(),
+    );
+    assert!(!output.contents().contains("Score: "));
+}
+
+#[test]
+fn test_game_state_round_trip() {
+    let mut state = GameState::new(Rules::from(Difficulty::Hard), 512);
+    assert_eq!(GameState::from_text(&state.to_text()).unwrap(), state);
+    state.guesses = vec![500, 750, 625];
+    let text = state.to_text();
+    assert!(text.contains("secret=512\n"));
+    assert!(text.contains("guesses=500,750,625\n"));
+    assert_eq!(GameState::from_text(&text).unwrap(), state);
+}
+
+#[test]
+fn test_impossible_saves_are_rejected() {
+    let error = |text: &str| GameState::from_text(text).unwrap_err().to_string();
+    let save = "difficulty=Easy\nmin=1\nmax=20\nattempts=3\nsecret=7\n";
+    assert_eq!(error(save), "the save has no 'guesses'");
+    assert_eq!(
+        error(&format!("{save}guesses=1,x")),
+        "'1,x' isn't a valid 'guesses' in the save"
+    );
+    assert_eq!(
+        error(&format!(
+            "{}guesses=",
+            save.replace("secret=7", "secret=70")
+        )),
+        "the saved game can't be right: the secret is outside the range"
+    );
+    assert_eq!(
+        error(&format!("{save}guesses=1,2,3")),
+        "the saved game can't be right: there are no guesses left"
+    );
+    assert_eq!(
+        error(&format!("{save}guesses=7")),
+        "the saved game can't be right: the number was already guessed"
+    );
+    assert!(GameState::from_text(&format!("{save}guesses=1,2")).is_ok());
+}
+
+#[test]
+fn test_save_and_resume_a_game() {
+    use test_support::TempDir;
+
+    let dir = TempDir::new("guessing-game-save").unwrap();
+    let save_to = dir.path().join(SAVE_FILE);
+    let rules = Rules::from(Difficulty::Normal);
+
+    let output = Output::new();
+    let mut input = Input::from_lines(&["50", "25", "SAVE", "never read"]);
+    let state = GameState::new(rules, 30);
+    let outcome = run(state, &save_to, &mut input, &mut output.clone());
+    assert_eq!(outcome, Outcome::Saved);
+    assert!(output
+        .contents()
+        .contains("Game saved. Run the game with --resume to carry on."));
+
+    let resumed = GameState::take(&save_to).unwrap();
+    assert_eq!(resumed.guesses, [50, 25]);
+    assert!(!save_to.exists());
+    assert!(GameState::take(&save_to).is_err());
+
+    let output = Output::new();
+    let mut input = Input::from_lines(&["30"]);
+    let outcome = run(resumed, &save_to, &mut input, &mut output.clone());
+    assert_eq!(outcome, Outcome::Won { attempts: 3 });
+    assert!(output.contents().contains("So far you've guessed 50, 25."));
+
+    assert!(args("--resume --timed").unwrap().resume);
+    let stats = session_stats(&[Outcome::Saved, Outcome::Won { attempts: 2 }]);
+    assert_eq!((stats.games, stats.wins), (1, 1));
+}
+
+#[test]
+fn test_colored_feedback() {
+    use style::{paint, Styled};
+
+    assert_eq!(paint("Too big!", Color::Red), "\x1b[31mToo big!\x1b[0m");
+
+    let output = Output::new();
+    let mut styled = Styled::new(output.clone(), true);
+    let mut input = Input::from_lines(&["60", "40", "50"]);
+    play(
+        50,
+        &Rules::from(Difficulty::Normal),
+        &mut input,
+        &mut styled,
+    );
+    let lines = output.lines();
+    assert!(lines.contains(&paint("Too big!", Color::Red)));
+    assert!(lines.contains(&paint("Too small!", Color::Blue)));
+    assert!(lines.contains(&paint("You win!", Color::Green)));
+    assert!(lines.contains(&String::from("You guessed 50.")));
+
+    let output = Output::new();
+    let mut plain = Styled::new(output.clone(), false);
+    play(
+        50,
+        &Rules::from(Difficulty::Normal),
+        &mut Input::from_lines(&["60"]),
+        &mut plain,
+    );
+    assert!(!output.contents().contains('\x1b'));
+    assert!(output.lines().contains(&String::from("Too big!")));
+
+    assert!(args("--no-color").unwrap().no_color);
+    let config = config::parse("color = false").unwrap();
+    assert!(args("").unwrap().or(config.args()).no_color);
+}
+
+#[test]
+fn test_hangman() {
+    use hangman::{Hangman, Letter};
+
+    let mut game = Hangman::new("Ferris");
+    assert_eq!(game.masked(), "_ _ _ _ _ _");
+    assert_eq!(game.guess('R'), Letter::Hit(2));
+    assert_eq!(game.guess('r'), Letter::Repeated);
+    assert_eq!(game.guess('x'), Letter::Miss);
+    assert_eq!(game.masked(), "_ _ r r _ _");
+    assert_eq!((game.guesses(), game.misses()), (2, 1));
+    for letter in "fesi".chars() {
+        assert!(!game.is_solved());
+        game.guess(letter);
+    }
+    assert!(game.is_solved());
+    assert_eq!(game.masked(), "f e r r i s");
+
+    let words = hangman::words();
+    assert!(words.len() >= 20);
+    assert!(words
+        .iter()
+        .all(|word| !word.is_empty() && word.chars().all(|c| c.is_ascii_lowercase())));
+}
+
+#[test]
+fn test_play_word() {
+    let mut input = Input::from_lines(&["c", "ab", "7", "a", "C", "r", "g", "o"]);
+    let output = Output::new();
+    let outcome = hangman::play_word("cargo", &mut input, &mut output.clone());
+    assert_eq!(outcome, Outcome::Won { attempts: 5 });
+    let lines = output.lines();
+    assert_eq!(
+        lines[0],
+        "Guess the word! It has 5 letters, and you can miss 6 times."
+    );
+    assert!(lines.contains(&String::from("c _ _ _ _   (misses: 0/6)")));
+    assert_eq!(
+        lines
+            .iter()
+            .filter(|line| *line == "Please type a single letter.")
+            .count(),
+        2
+    );
+    assert!(lines.contains(&String::from("You already guessed 'C'.")));
+    assert!(lines.contains(&String::from("You win! The word was 'cargo'.")));
+
+    let mut input = Input::from_lines(&["q", "w", "e", "z", "x", "v", "b"]);
+    let output = Output::new();
+    let outcome = hangman::play_word("cargo", &mut input, &mut output.clone());
+    assert_eq!(outcome, Outcome::Lost);
+    assert!(output
+        .contents()
+        .contains("Out of guesses! The word was 'cargo'. You lose."));
+    assert_eq!(input.read_line().as_deref(), Some("b"));
+}
+
+#[test]
+fn test_sessions_work_the_same_for_both_games() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut words = WordGame::new(&mut rng);
+    /* Closing the input loses the round and ends the session. */
+    let outcomes = session(
+        &mut words,
+        &mut Input::default(),
+        &mut Output::new(),
+        |_| {},
+    );
+    assert_eq!(outcomes, [Outcome::Lost]);
+
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut numbers = NumberGame {
+        rules: Rules::new(Difficulty::Easy, &args("--attempts 1").unwrap()).unwrap(),
+        picker: SecretPicker::new(&mut rng),
+        resumed: Some(GameState::new(Rules::from(Difficulty::Easy), 4)),
+    };
+    let mut recorded = Vec::new();
+    let mut input = Input::from_lines(&["4", "yes", "0", "21", "x"]);
+    let outcomes = session(&mut numbers, &mut input, &mut Output::new(), |outcome| {
+        recorded.push(outcome)
+    });
+    /* The resumed game first, then a new one: "0" and "21" are out of
+     * range and don't count, and "x" isn't a number, so the input runs
+     * out before the one guess is used. */
+    assert_eq!(outcomes, [Outcome::Won { attempts: 1 }, Outcome::Lost]);
+    assert_eq!(recorded, outcomes);
+
+    assert_eq!(Mode::parse("W"), Some(Mode::Words));
+    assert_eq!(Mode::parse("letters"), None);
+}
+
+#[test]
+fn test_range_tracker() {
+    use history::{Direction, RangeTracker};
+
+    let mut tracker = RangeTracker::new(1..=100);
+    let record = tracker.record(60, 42);
+    assert_eq!(record.direction, Direction::TooBig);
+    assert_eq!(record.range_after, 1..=59);
+    assert!(!record.wasted);
+
+    assert_eq!(tracker.record(30, 42).range_after, 31..=59);
+    let wasted = tracker.record(70, 42);
+    assert!(wasted.wasted);
+    assert_eq!(wasted.range_after, 31..=59);
+    assert_eq!(tracker.record(42, 42).direction, Direction::Correct);
+    assert_eq!(tracker.range(), 42..=42);
+
+    /* Truthful answers never rule the secret out. */
+    assert_property(&gen::ints(1, 100), |&secret| {
+        let secret = secret as i32;
+        let mut tracker = RangeTracker::new(1..=100);
+        [50, 25, 75, 12, 88, 1, 100]
+            .iter()
+            .all(|&guess| tracker.record(guess, secret).range_after.contains(&secret))
+    });
+}
+
+#[test]
+fn test_history_command() {
+    let mut input = Input::from_lines(&["history", "80", "20", "90", "History", "50"]);
+    let output = Output::new();
+    let outcome = play(
+        50,
+        &Rules::from(Difficulty::Normal),
+        &mut input,
+        &mut output.clone(),
+    );
+    assert_eq!(outcome, Outcome::Won { attempts: 4 });
+
+    let lines = output.lines();
+    assert!(lines.contains(&String::from("No guesses yet.")));
+    let table = lines
+        .iter()
+        .skip_while(|line| !line.starts_with("  1."))
+        .take(3)
+        .cloned()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        table,
+        [
+            "  1.     80  too big    now 1-79",
+            "  2.     20  too small  now 21-79",
+            "  3.     90  too big    now 21-79  (already ruled out)",
+        ]
+    );
+}
+
+#[test]
+fn test_parse_guess() {
+    for (input, value) in [
+        ("42", 42),
+        ("  -7\t", -7),
+        ("1e2", 100),
+        ("2.5e1", 25),
+        ("50.0", 50),
+        ("zero", 0),
+        ("fifty", 50),
+        ("Twenty-One", 21),
+        ("thirty one", 31),
+        ("a hundred", 100),
+        ("one hundred and five", 105),
+        ("nine hundred ninety-nine", 999),
+        ("twelve thousand three hundred forty five", 12_345),
+        ("minus seven", -7),
+    ] {
+        assert_eq!(parse_guess(input), Ok(value), "{input:?}");
+    }
+    for input in [
+        "",
+        "1.5",
+        "inf",
+        "NaN",
+        "1e20",
+        "five five",
+        "twenty ten",
+        "twelve hundred",
+        "hundred",
+        "thousand thousand",
+        "zero one",
+        "fifty-ish",
+    ] {
+        assert_eq!(parse_guess(input), Err(GuessError::NotANumber), "{input:?}");
+    }
+    assert_property(&gen::ints(i32::MIN as i64, i32::MAX as i64), |&n| {
+        parse_guess(&format!(" {n} ")) == Ok(n as i32)
+    });
+}
+
+#[test]
+fn test_number_words_in_a_game() {
+    let mut input = Input::from_lines(&["fifty", "1e1", "twenty five"]);
+    let outcome = play(
+        25,
+        &Rules::from(Difficulty::Normal),
+        &mut input,
+        &mut Output::new(),
+    );
+    assert_eq!(outcome, Outcome::Won { attempts: 3 });
+}
+
+#[test]
+fn test_secret_picker_avoids_recent_secrets() {
+    use picker::REMEMBERED;
+
+    let mut picker = SecretPicker::new(StdRng::seed_from_u64(5));
+    let mut last = Vec::new();
+    for _ in 0..200 {
+        let secret = picker.pick(1..=20);
+        assert!((1..=20).contains(&secret));
+        /* Never one of the last ten on a range of twenty. */
+        assert!(!last.contains(&secret), "{secret} repeated in {last:?}");
+        last.insert(0, secret);
+        last.truncate(REMEMBERED);
+    }
+
+    /* On a tiny range at most half is left out, so it still varies. */
+    let mut picker = SecretPicker::new(StdRng::seed_from_u64(5));
+    picker.remember(1);
+    picker.remember(2);
+    assert_eq!(picker.avoided(&(1..=3)), [2]);
+    assert_ne!(picker.pick(1..=3), 2);
+    assert_eq!(picker.pick(5..=5), 5);
+
+    let mut picker = SecretPicker::new(StdRng::seed_from_u64(5));
+    for _ in 0..20 {
+        assert!((i32::MAX - 3..=i32::MAX).contains(&picker.pick(i32::MAX - 3..=i32::MAX)));
+        assert!((i32::MIN..=i32::MIN + 3).contains(&picker.pick(i32::MIN..=i32::MIN + 3)));
+    }
+}
+
+#[test]
+fn test_secret_picker_stays_uniform_over_the_rest() {
+    let mut picker = SecretPicker::new(StdRng::seed_from_u64(9));
+    let mut counts = [0; 4];
+    for _ in 0..4000 {
+        picker.remember(0);
+        counts[picker.pick(0..=3) as usize] += 1;
+    }
+    assert_eq!(counts[0], 0);
+    assert!(
+        counts[1..].iter().all(|&n| (1100..1600).contains(&n)),
+        "{counts:?}"
+    );
+}
@@ -0,0 +1,77 @@
+/* Timed Mode */
+/* With '--timed', the game keeps a 'Stopwatch' running and every guess
+ * is a lap: the time from the prompt before it to the moment a valid
+ * guess came in. 'Instant' is the clock for this, not 'SystemTime':
+ * an 'Instant' only ever moves forward, so subtracting two of them can't
+ * go negative when the system clock gets adjusted.
+ *
+ * A win's score weighs both how many guesses it took and how long:
+ * 'score' treats each guess as worth ten seconds and divides 10,000 by
+ * the total, so a first-guess win in no time scores the maximum of
+ * 1000, and a win that took 5 guesses and 20 seconds (70 "seconds" in
+ * all) scores 142. It's a pure function of its two arguments, which
+ * is what makes it testable without a real clock. */
+use std::fmt::Write as _;
+use std::time::{Duration, Instant};
+
+pub const MAX_SCORE: u32 = 1000;
+const MILLIS_PER_GUESS: u128 = 10_000;
+
+pub fn score(attempts: u32, duration: Duration) -> u32 {
+    if attempts == 0 {
+        return 0;
+    }
+    let cost = attempts as u128 * MILLIS_PER_GUESS + duration.as_millis();
+    (MAX_SCORE as u128 * MILLIS_PER_GUESS / cost) as u32
+}
+
+#[derive(Debug, Clone)]
+pub struct Stopwatch {
+    started: Instant,
+    last: Instant,
+    laps: Vec<(i32, Duration)>,
+}
+
+impl Stopwatch {
+    pub fn start() -> Stopwatch {
+        let now = Instant::now();
+        Stopwatch {
+            started: now,
+            last: now,
+            laps: Vec::new(),
+        }
+    }
+
+    pub fn lap(&mut self, guess: i32) {
+        let now = Instant::now();
+        self.laps.push((guess, now - self.last));
+        self.last = now;
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.last - self.started
+    }
+
+    /* One line per guess, then the totals; the score only for a win. */
+    pub fn breakdown(&self, won: bool) -> String {
+        let mut text = String::new();
+        for (i, (guess, time)) in self.laps.iter().enumerate() {
+            let _ = writeln!(text, "Guess {}: {guess} after {}", i + 1, seconds(*time));
+        }
+        let attempts = self.laps.len() as u32;
+        let _ = write!(
+            text,
+            "Total: {attempts} {} in {}",
+            if attempts == 1 { "guess" } else { "guesses" },
+            seconds(self.elapsed())
+        );
+        if won {
+            let _ = write!(text, "\nScore: {}", score(attempts, self.elapsed()));
+        }
+        text
+    }
+}
+
+fn seconds(time: Duration) -> String {
+    format!("{:.1}s", time.as_secs_f64())
+}
@@ -0,0 +1,45 @@
+/* Playing through the library's public API only, the way another crate
+ * would. */
+use guessing_game_v2::{play, Difficulty, Guess, GuessError, NumberGame, Outcome, Rules};
+use guessing_game_v2::{session, SecretPicker};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use test_support::{Input, Output};
+
+#[test]
+fn test_play_a_scripted_game() {
+    let rules = Rules::from(Difficulty::Normal);
+    let mut input = Input::from_lines(&["50", "twenty", "30"]);
+    let mut output = Output::new();
+
+    let outcome = play(30, &rules, &mut input, &mut output);
+
+    assert_eq!(outcome, Outcome::Won { attempts: 3 });
+    let text = output.contents();
+    assert!(text.contains("Too big!"));
+    assert!(text.contains("Too small!"));
+    assert!(text.contains("You win!"));
+}
+
+#[test]
+fn test_session_of_number_games() {
+    let rules = Rules::from(Difficulty::Easy);
+    let mut rng = StdRng::seed_from_u64(7);
+    let secret = SecretPicker::new(&mut StdRng::seed_from_u64(7)).pick(rules.range.clone());
+    let mut game = NumberGame::new(rules, &mut rng, None);
+    let guess = secret.to_string();
+    let mut input = Input::from_lines(&[&guess, "n"]);
+
+    let outcomes = session(&mut game, &mut input, &mut Output::new(), |_| {});
+
+    assert_eq!(outcomes, vec![Outcome::Won { attempts: 1 }]);
+}
+
+#[test]
+fn test_guess_is_checked() {
+    assert_eq!(Guess::new(20, Difficulty::Easy).unwrap().value(), 20);
+    assert!(matches!(
+        Guess::parse("21", Difficulty::Easy),
+        Err(GuessError::OutOfRange { value: 21, .. })
+    ));
+}
@@ -0,0 +1,156 @@
+/* USING TRAIT OBJECTS THAT ALLOW FOR VALUES OF DIFFERENT TYPES */
+/* A vector can only store elements of one type. One workaround is an enum
+ * with a variant for each type we want to store, but that only works when
+ * the set of types is known when our code is compiled. Sometimes we want
+ * users of our library to be able to extend the set of types that are
+ * valid in a particular situation.
+ *
+ * This crate sketches a graphical user interface (GUI) tool that iterates
+ * through a list of items, calling a 'draw' method on each one to draw it
+ * to the screen. The 'gui' library provides some types, like 'Button' and
+ * 'SelectBox', and other crates will want to create their own - an 'Image'
+ * or a 'TextField', say. At the time of writing the library, we can't know
+ * and define all the types other programmers might want to create.
+ *
+ * Instead of drawing pixels, every component renders itself to a few
+ * lines of text. That keeps the example runnable in a terminal and lets
+ * the tests check exactly what was drawn. */
+
+/* Defining a Trait for Common Behavior */
+/* A 'trait object' points to both an instance of a type implementing our
+ * specified trait and a table used to look up trait methods on that type
+ * at runtime. We create a trait object by specifying some sort of pointer,
+ * such as a '&' reference or a 'Box<T>' smart pointer, then the 'dyn'
+ * keyword, and then the relevant trait. */
+pub trait Draw {
+    fn draw(&self) -> String;
+}
+
+/* 'Screen' holds a vector named 'components' of type 'Box<dyn Draw>'. It's
+ * a stand-in for any type inside a 'Box' that implements 'Draw'.
+ *
+ * This works differently from defining a struct that uses a generic type
+ * parameter with trait bounds. A generic type parameter can only be
+ * substituted with one concrete type at a time, whereas trait objects allow
+ * for multiple concrete types to fill in for the trait object at runtime.
+ * A 'Screen<T: Draw>' with 'components: Vec<T>' would limit us to a screen
+ * that has a list of components all of type 'Button' or all of type
+ * 'TextField'. */
+pub struct Screen {
+    pub components: Vec<Box<dyn Draw>>,
+}
+
+impl Screen {
+    pub fn new() -> Screen {
+        Screen {
+            components: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, component: Box<dyn Draw>) {
+        self.components.push(component);
+    }
+
+    /* 'run' doesn't need to know the concrete type of each component. It
+     * just calls 'draw' on each one, and the right implementation is found
+     * at runtime. The output of every component is separated by a blank
+     * line. */
+    pub fn run(&self) -> String {
+        self.components
+            .iter()
+            .map(|component| component.draw())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+impl Default for Screen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/* Implementing the Trait */
+/* A 'Button' draws a box 'width' characters wide and 'height' rows tall
+ * with its label centered in it. The label is cut off if it doesn't fit.
+ * Other methods that only apply to buttons, such as what happens when a
+ * user clicks the button, would be implemented directly on 'Button' and
+ * wouldn't be part of 'Draw'. */
+pub struct Button {
+    pub width: u32,
+    pub height: u32,
+    pub label: String,
+}
+
+impl Draw for Button {
+    fn draw(&self) -> String {
+        let inner = self.width.saturating_sub(2) as usize;
+        let height = self.height.max(3) as usize;
+        let label: String = self.label.chars().take(inner).collect();
+
+        let border = format!("+{}+", "-".repeat(inner));
+        let blank = format!("|{}|", " ".repeat(inner));
+        let labeled = format!("|{label:^inner$}|");
+
+        let mut rows = vec![border.clone()];
+        let label_row = (height - 2) / 2;
+        for row in 0..height - 2 {
+            if row == label_row {
+                rows.push(labeled.clone());
+            } else {
+                rows.push(blank.clone());
+            }
+        }
+        rows.push(border);
+        rows.join("\n")
+    }
+}
+
+/* A 'SelectBox' lists its options one per line with a marker next to the
+ * selected one. 'height' limits how many options are visible at once. */
+pub struct SelectBox {
+    pub width: u32,
+    pub height: u32,
+    pub options: Vec<String>,
+    pub selected: usize,
+}
+
+impl Draw for SelectBox {
+    fn draw(&self) -> String {
+        let width = self.width as usize;
+        self.options
+            .iter()
+            .enumerate()
+            .take(self.height as usize)
+            .map(|(i, option)| {
+                let marker = if i == self.selected { '>' } else { ' ' };
+                let option: String = option.chars().take(width).collect();
+                format!("{marker} [{option:<width$}]")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/* Trait Objects and Dispatch */
+/* Code using generics with trait bounds is 'monomorphized': the compiler
+ * generates nongeneric implementations of functions and methods for each
+ * concrete type that we use in place of a generic type parameter, which
+ * lets it know at compile time which method to call ('static dispatch').
+ * With trait objects, Rust must use 'dynamic dispatch' instead: the
+ * compiler emits code that at runtime figures out which method to call
+ * through the pointers inside the trait object. This lookup has a runtime
+ * cost and prevents inlining, but buys us the flexibility of 'Screen'.
+ *
+ * 'draw_all' is the static counterpart of 'Screen::run' for comparison:
+ * all of its items must be the same type. */
+pub fn draw_all<T: Draw>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(|item| item.draw())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests;
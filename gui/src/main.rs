@@ -0,0 +1,50 @@
+use gui::{Button, Draw, Screen, SelectBox};
+
+/* A user of the library can implement 'Draw' on their own types. The
+ * 'gui' crate never heard of 'TextField', but 'Screen' can still draw it. */
+struct TextField {
+    width: u32,
+    placeholder: String,
+}
+
+impl Draw for TextField {
+    fn draw(&self) -> String {
+        let width = self.width as usize;
+        format!("[{:<width$}]", self.placeholder)
+    }
+}
+
+fn main() {
+    let screen = Screen {
+        components: vec![
+            Box::new(SelectBox {
+                width: 10,
+                height: 3,
+                options: vec![
+                    String::from("Yes"),
+                    String::from("Maybe"),
+                    String::from("No"),
+                ],
+                selected: 0,
+            }),
+            Box::new(TextField {
+                width: 12,
+                placeholder: String::from("Your name"),
+            }),
+            Box::new(Button {
+                width: 12,
+                height: 3,
+                label: String::from("OK"),
+            }),
+        ],
+    };
+
+    println!("{}", screen.run());
+    /* When uncommented, the below code fails to compile because 'String'
+     * doesn't implement the 'Draw' trait. Trait objects let the compiler
+     * check that every value in 'components' implements 'Draw' without
+     * knowing the concrete types. */
+    // let screen = Screen {
+    //     components: vec![Box::new(String::from("Hi"))],
+    // };
+}
@@ -0,0 +1,70 @@
+use crate::*;
+
+struct Label(&'static str);
+
+impl Draw for Label {
+    fn draw(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+#[test]
+fn test_button_draw() {
+    let button = Button {
+        width: 8,
+        height: 3,
+        label: String::from("OK"),
+    };
+
+    assert_eq!(button.draw(), "+------+\n|  OK  |\n+------+");
+}
+
+#[test]
+fn test_button_truncates_label() {
+    let button = Button {
+        width: 5,
+        height: 3,
+        label: String::from("Cancel"),
+    };
+
+    assert_eq!(button.draw(), "+---+\n|Can|\n+---+");
+}
+
+#[test]
+fn test_select_box_draw() {
+    let select = SelectBox {
+        width: 5,
+        height: 2,
+        options: vec![
+            String::from("Yes"),
+            String::from("Maybe"),
+            String::from("No"),
+        ],
+        selected: 1,
+    };
+
+    assert_eq!(select.draw(), "  [Yes  ]\n> [Maybe]");
+}
+
+#[test]
+fn test_screen_runs_mixed_components() {
+    let mut screen = Screen::new();
+    screen.add(Box::new(Label("title")));
+    screen.add(Box::new(Button {
+        width: 4,
+        height: 3,
+        label: String::from("Go"),
+    }));
+
+    assert_eq!(screen.run(), "title\n\n+--+\n|Go|\n+--+");
+}
+
+#[test]
+fn test_draw_all_matches_screen() {
+    let labels = [Label("a"), Label("b")];
+    let screen = Screen {
+        components: vec![Box::new(Label("a")), Box::new(Label("b"))],
+    };
+
+    assert_eq!(draw_all(&labels), screen.run());
+}
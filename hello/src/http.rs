@@ -0,0 +1,166 @@
+/* Reading the Request */
+/* HTTP is a text-based protocol, and a request takes this format:
+ *
+ * Method Request-URI HTTP-Version CRLF
+ * headers CRLF
+ * message-body
+ *
+ * The first line is the 'request line' that holds information about what
+ * the client is requesting. The method ('GET', 'POST', ...) says how the
+ * client is making the request, the request URI says which resource is
+ * being asked for, and the version is the HTTP version the client uses.
+ * After the request line, each remaining line up to an empty line is a
+ * header. GET requests have no body. */
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub version: String,
+    pub headers: Vec<(String, String)>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    Empty,
+    MalformedRequestLine(String),
+    MalformedHeader(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "empty request"),
+            ParseError::MalformedRequestLine(line) => {
+                write!(f, "malformed request line: {line:?}")
+            }
+            ParseError::MalformedHeader(line) => write!(f, "malformed header: {line:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Request {
+    /* The browser signals the end of an HTTP request by sending two newline
+     * characters in a row, so the caller collects lines until it gets an
+     * empty one and passes them here. */
+    pub fn parse<I, S>(lines: I) -> Result<Request, ParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut lines = lines.into_iter();
+
+        let request_line = match lines.next() {
+            Some(line) => line.as_ref().to_string(),
+            None => return Err(ParseError::Empty),
+        };
+
+        let mut parts = request_line.split_whitespace();
+        let (method, path, version) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(method), Some(path), Some(version)) if parts.next().is_none() => {
+                (method.to_string(), path.to_string(), version.to_string())
+            }
+            _ => return Err(ParseError::MalformedRequestLine(request_line)),
+        };
+
+        let mut headers = Vec::new();
+        for line in lines {
+            let line = line.as_ref();
+            if line.is_empty() {
+                break;
+            }
+            match line.split_once(':') {
+                Some((name, value)) => {
+                    headers.push((name.trim().to_string(), value.trim().to_string()))
+                }
+                None => return Err(ParseError::MalformedHeader(line.to_string())),
+            }
+        }
+
+        Ok(Request {
+            method,
+            path,
+            version,
+            headers,
+        })
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/* Writing a Response */
+/* Responses have the following format:
+ *
+ * HTTP-Version Status-Code Reason-Phrase CRLF
+ * headers CRLF
+ * message-body
+ *
+ * To ensure a valid response we add the 'Content-Length' header, which is
+ * set to the size of our response body. */
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status_line: &'static str,
+    pub body: String,
+}
+
+pub const OK: &str = "HTTP/1.1 200 OK";
+pub const NOT_FOUND: &str = "HTTP/1.1 404 NOT FOUND";
+
+impl Response {
+    pub fn new(status_line: &'static str, body: String) -> Response {
+        Response { status_line, body }
+    }
+
+    pub fn to_http_string(&self) -> String {
+        format!(
+            "{}\r\nContent-Length: {}\r\n\r\n{}",
+            self.status_line,
+            self.body.len(),
+            self.body
+        )
+    }
+}
+
+/* Validating the Request and Selectively Responding */
+/* Only a couple of routes exist; anything else gets the 404 page. '/sleep'
+ * is the slow request used to show why a single-threaded server falls
+ * over. Returning the status line and filename (rather than a 'Response')
+ * keeps the routing decision separate from reading files off disk. */
+#[derive(Debug, PartialEq)]
+pub enum Route {
+    Hello,
+    Sleep,
+    NotFound,
+}
+
+pub fn route(request: &Request) -> Route {
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/") => Route::Hello,
+        ("GET", "/sleep") => Route::Sleep,
+        _ => Route::NotFound,
+    }
+}
+
+impl Route {
+    pub fn status_line(&self) -> &'static str {
+        match self {
+            Route::Hello | Route::Sleep => OK,
+            Route::NotFound => NOT_FOUND,
+        }
+    }
+
+    pub fn filename(&self) -> &'static str {
+        match self {
+            Route::Hello | Route::Sleep => "hello.html",
+            Route::NotFound => "404.html",
+        }
+    }
+}
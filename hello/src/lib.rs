@@ -0,0 +1,168 @@
+/* FINAL PROJECT: Building a Multithreaded Web Server */
+/* The server in 'main.rs' starts out handling one request at a time. If a
+ * request takes a long time to process (the '/sleep' route simulates this),
+ * every request that arrives after it has to wait. To fix that we give the
+ * server a 'thread pool': a group of spawned threads that are waiting and
+ * ready to handle a task. When the program receives a new task, it assigns
+ * one of the threads in the pool to the task, and that thread will process
+ * it. The remaining threads are available to handle any other tasks that
+ * come in while the first thread is processing.
+ *
+ * Limiting the pool to a fixed number of threads protects us from Denial of
+ * Service attacks; if we spawned a new thread for every request, someone
+ * making ten million requests could use up all of our server's resources. */
+use std::fmt;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+pub mod http;
+
+/* The pool hands work to its threads through a channel. The 'ThreadPool'
+ * holds on to the sending side, and each 'Worker' holds on to the receiving
+ * side behind an 'Arc<Mutex<T>>' so that the threads can share ownership of
+ * the single receiver and take turns pulling jobs off of it. */
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    sender: Option<mpsc::Sender<Job>>,
+}
+
+/* A 'Job' is a type alias for a trait object that holds the type of closure
+ * that 'execute' receives. 'FnOnce' because each job runs exactly once,
+ * 'Send' to transfer the closure from one thread to another, and 'static'
+ * because we don't know how long the thread will take to execute. */
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/* Returned from 'ThreadPool::build' when asked to create a pool with zero
+ * threads, which would never be able to run anything. */
+#[derive(Debug, PartialEq)]
+pub struct PoolCreationError;
+
+impl fmt::Display for PoolCreationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a thread pool needs at least one thread")
+    }
+}
+
+impl std::error::Error for PoolCreationError {}
+
+impl ThreadPool {
+    /* Create a new ThreadPool.
+     *
+     * The size is the number of threads in the pool.
+     *
+     * The 'new' function will panic if the size is zero. Creating a pool
+     * with no threads doesn't make sense, and this is documented so that a
+     * caller knows about the panic condition. 'build' is the alternative
+     * for callers who would rather handle the error themselves. */
+    pub fn new(size: usize) -> ThreadPool {
+        assert!(size > 0);
+
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        /* 'with_capacity' preallocates space in the vector, which is slightly
+         * more efficient than 'Vec::new' because we know up front that we
+         * need to store 'size' elements. */
+        let mut workers = Vec::with_capacity(size);
+
+        for id in 0..size {
+            workers.push(Worker::new(id, Arc::clone(&receiver)));
+        }
+
+        ThreadPool {
+            workers,
+            sender: Some(sender),
+        }
+    }
+
+    pub fn build(size: usize) -> Result<ThreadPool, PoolCreationError> {
+        if size == 0 {
+            return Err(PoolCreationError);
+        }
+        Ok(ThreadPool::new(size))
+    }
+
+    /* We want the same interface as 'thread::spawn': take a closure and
+     * hand it off to be run. The difference is that we don't create a new
+     * thread; the closure is sent down the channel to whichever idle worker
+     * grabs the lock first. */
+    pub fn execute<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let job = Box::new(f);
+
+        self.sender.as_ref().unwrap().send(job).unwrap();
+    }
+
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+}
+
+/* Graceful Shutdown and Cleanup */
+/* When the pool is dropped, our threads should all join to make sure they
+ * finish their work. Dropping 'sender' first closes the channel, which
+ * signals that no more messages will be sent. All the calls to 'recv' that
+ * the workers do in their infinite loops will then return an error, and
+ * each worker breaks out of its loop. Only then do we join the threads;
+ * without dropping the sender first, 'join' would wait forever on workers
+ * that are still blocked waiting for jobs.
+ *
+ * We wrap each worker's 'JoinHandle' in an 'Option' so that we can 'take'
+ * ownership of it out of '&mut self' - 'join' consumes its argument. */
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        drop(self.sender.take());
+
+        for worker in &mut self.workers {
+            println!("Shutting down worker {}", worker.id);
+
+            if let Some(thread) = worker.thread.take() {
+                thread.join().unwrap();
+            }
+        }
+    }
+}
+
+/* A 'Worker' picks up code that needs to be run and runs the code in its
+ * own thread. The standard library's 'thread::spawn' expects to get code
+ * the thread should run as soon as it's created, but our threads need to
+ * wait for code that we'll send later, so each worker loops forever asking
+ * the receiver for its next job. */
+struct Worker {
+    id: usize,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl Worker {
+    fn new(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Job>>>) -> Worker {
+        let thread = thread::spawn(move || loop {
+            /* The lock is released as soon as this 'let' statement ends,
+             * because the temporary 'MutexGuard' is dropped at the end of
+             * the statement. Using 'while let' here instead would hold the
+             * lock for the duration of the job and block the other workers. */
+            let message = receiver.lock().unwrap().recv();
+
+            match message {
+                Ok(job) => {
+                    println!("Worker {id} got a job; executing.");
+
+                    job();
+                }
+                Err(_) => {
+                    println!("Worker {id} disconnected; shutting down.");
+                    break;
+                }
+            }
+        });
+
+        Worker {
+            id,
+            thread: Some(thread),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
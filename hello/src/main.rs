@@ -0,0 +1,89 @@
+use hello::http::{route, Request, Response, Route};
+use hello::ThreadPool;
+use std::{
+    fs,
+    io::{prelude::*, BufReader},
+    net::{TcpListener, TcpStream},
+    thread,
+    time::Duration,
+};
+
+/* Building a Single-Threaded Web Server */
+/* The two main protocols involved in web servers are the 'Hypertext
+ * Transfer Protocol' (HTTP) and the 'Transmission Control Protocol' (TCP).
+ * Both are 'request-response' protocols, meaning a client initiates
+ * requests and a server listens to the requests and provides a response
+ * to the client. TCP is the lower-level protocol that describes how
+ * information gets from one server to another; HTTP builds on top of TCP
+ * by defining the contents of the requests and responses.
+ *
+ * 'TcpListener::bind' works like 'new' in that it returns a new instance,
+ * and is called 'bind' because connecting to a port to listen to is known
+ * as "binding to a port". Port 7878 is "rust" typed on a telephone. */
+fn main() {
+    let listener = TcpListener::bind("127.0.0.1:7878").unwrap();
+    let pool = ThreadPool::new(4);
+
+    /* The 'incoming' method on 'TcpListener' returns an iterator that gives
+     * us a sequence of streams. A single 'stream' represents an open
+     * connection between the client and the server. We iterate over
+     * 'connection attempts' here rather than connections, so each one is a
+     * 'Result' that may fail (for example, if the OS limits open
+     * connections).
+     *
+     * Only the first two requests are accepted before the server shuts
+     * down, so that running the program shows the graceful shutdown in the
+     * 'Drop' implementation of 'ThreadPool'. A real server would loop
+     * forever. */
+    for stream in listener.incoming().take(2) {
+        let stream = stream.unwrap();
+
+        pool.execute(|| {
+            handle_connection(stream);
+        });
+    }
+
+    println!("Shutting down.");
+}
+/* Before the thread pool existed, the body of the loop was simply
+ * 'handle_connection(stream);'. That version handles connections one at a
+ * time: visiting '/sleep' in one browser tab and '/' in another shows the
+ * second request waiting the full five seconds for the first to finish.
+ * Handing each connection to 'pool.execute' lets the other workers pick up
+ * new connections while one of them is sleeping.
+ *
+ * Spawning a thread per connection ('thread::spawn(|| handle_connection(
+ * stream))') would also fix the slow request, but without any limit on the
+ * number of threads created. */
+
+fn handle_connection(mut stream: TcpStream) {
+    /* 'BufReader' adds buffering by managing calls to the
+     * 'std::io::Read' trait methods for us. 'lines' splits the stream of
+     * data whenever it sees a newline byte, and we stop at the first empty
+     * line, which marks the end of the request headers. */
+    let buf_reader = BufReader::new(&stream);
+    let request_lines = buf_reader
+        .lines()
+        .map(|result| result.unwrap())
+        .take_while(|line| !line.is_empty());
+
+    let request = match Request::parse(request_lines) {
+        Ok(request) => request,
+        Err(e) => {
+            println!("Ignoring bad request: {e}");
+            return;
+        }
+    };
+
+    let route = route(&request);
+    if route == Route::Sleep {
+        thread::sleep(Duration::from_secs(5));
+    }
+
+    let contents = fs::read_to_string(route.filename()).unwrap();
+    let response = Response::new(route.status_line(), contents);
+
+    stream
+        .write_all(response.to_http_string().as_bytes())
+        .unwrap();
+}
@@ -0,0 +1,75 @@
+use crate::http::{route, ParseError, Request, Response, Route, NOT_FOUND, OK};
+use crate::{PoolCreationError, ThreadPool};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn test_parse_request() {
+    let request = Request::parse([
+        "GET /sleep HTTP/1.1",
+        "Host: 127.0.0.1:7878",
+        "User-Agent: curl/8.0",
+        "",
+    ])
+    .unwrap();
+
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.path, "/sleep");
+    assert_eq!(request.version, "HTTP/1.1");
+    assert_eq!(request.header("host"), Some("127.0.0.1:7878"));
+    assert_eq!(route(&request), Route::Sleep);
+}
+
+#[test]
+fn test_parse_rejects_bad_requests() {
+    assert_eq!(Request::parse(Vec::<&str>::new()), Err(ParseError::Empty));
+    assert!(matches!(
+        Request::parse(["GET /"]),
+        Err(ParseError::MalformedRequestLine(_))
+    ));
+    assert!(matches!(
+        Request::parse(["GET / HTTP/1.1", "no colon here"]),
+        Err(ParseError::MalformedHeader(_))
+    ));
+}
+
+#[test]
+fn test_routes() {
+    let unknown = Request::parse(["GET /nope HTTP/1.1"]).unwrap();
+    assert_eq!(route(&unknown).status_line(), NOT_FOUND);
+    assert_eq!(route(&unknown).filename(), "404.html");
+
+    let post = Request::parse(["POST / HTTP/1.1"]).unwrap();
+    assert_eq!(route(&post), Route::NotFound);
+}
+
+#[test]
+fn test_response_has_content_length() {
+    let response = Response::new(OK, String::from("hi"));
+    assert_eq!(
+        response.to_http_string(),
+        "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nhi"
+    );
+}
+
+#[test]
+fn test_pool_runs_every_job_before_drop_returns() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let pool = ThreadPool::new(3);
+    assert_eq!(pool.size(), 3);
+
+    for _ in 0..10 {
+        let counter = Arc::clone(&counter);
+        pool.execute(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+    }
+    drop(pool);
+
+    assert_eq!(counter.load(Ordering::SeqCst), 10);
+}
+
+#[test]
+fn test_build_rejects_zero_threads() {
+    assert_eq!(ThreadPool::build(0).err(), Some(PoolCreationError));
+}
@@ -0,0 +1,162 @@
+/* VALIDATING REFERENCES WITH LIFETIMES */
+/* Lifetimes are another kind of generic that we've already been using.
+ * Rather than ensuring that a type has the behavior we want, lifetimes
+ * ensure that references are valid as long as we need them to be.
+ *
+ * Every reference in Rust has a 'lifetime', which is the scope for which
+ * that reference is valid. Most of the time, lifetimes are implicit and
+ * inferred, just like most of the time, types are inferred. We must
+ * annotate lifetimes when the lifetimes of references could be related in
+ * a few different ways. The main aim of lifetimes is to prevent 'dangling
+ * references', which cause a program to reference data other than the
+ * data it's intended to reference. */
+use std::fmt::Display;
+
+/* Generic Lifetimes in Functions */
+/* 'longest' returns whichever of two string slices is longer. Without the
+ * annotation, the compiler can't tell whether the returned reference
+ * refers to 'x' or 'y' - and neither can we, since it depends on the
+ * values at runtime.
+ *
+ * The signature now tells Rust that for some lifetime ''a', the function
+ * takes two parameters, both of which are string slices that live at least
+ * as long as lifetime ''a', and that the string slice returned will also
+ * live at least as long as ''a'. In practice, the concrete lifetime that
+ * is substituted for ''a' is the smaller of the lifetimes of 'x' and 'y'. */
+pub fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+/* If we only ever return the first parameter, 'y' doesn't need a lifetime
+ * tied to the return value at all. The lifetime parameter for the return
+ * type needs to match the lifetime parameter for one of the parameters. */
+pub fn first<'a>(x: &'a str, _y: &str) -> &'a str {
+    x
+}
+
+/* Lifetime Annotations in Struct Definitions */
+/* Structs can hold references, but then we need a lifetime annotation on
+ * every reference in the struct's definition. This annotation means an
+ * instance of 'ImportantExcerpt' can't outlive the reference it holds in
+ * its 'part' field. */
+#[derive(Debug)]
+pub struct ImportantExcerpt<'a> {
+    pub part: &'a str,
+}
+
+/* Lifetime Elision */
+/* Three rules let the compiler figure out the lifetimes of references
+ * when there aren't explicit annotations:
+ *
+ *  1. The compiler assigns a different lifetime parameter to each
+ *     parameter that's a reference.
+ *  2. If there is exactly one input lifetime parameter, that lifetime is
+ *     assigned to all output lifetime parameters.
+ *  3. If there are multiple input lifetime parameters, but one of them is
+ *     '&self' or '&mut self' because this is a method, the lifetime of
+ *     'self' is assigned to all output lifetime parameters.
+ *
+ * 'first_word' is covered by the second rule, so it needs no
+ * annotations. */
+pub fn first_word(s: &str) -> &str {
+    s.split(' ').next().unwrap_or("")
+}
+
+/* Lifetime Annotations in Method Definitions */
+/* Lifetime names for struct fields always need to be declared after the
+ * 'impl' keyword and then used after the struct's name, because those
+ * lifetimes are part of the struct's type. In 'announce_and_return_part',
+ * the third elision rule applies: the returned '&str' gets the lifetime of
+ * '&self', so no annotations are needed. */
+impl<'a> ImportantExcerpt<'a> {
+    /* Takes the first sentence of 'text', which is everything up to the
+     * first period (or the whole text if there isn't one). */
+    pub fn from_text(text: &'a str) -> ImportantExcerpt<'a> {
+        let part = text.split('.').next().unwrap_or(text);
+        ImportantExcerpt { part }
+    }
+
+    pub fn level(&self) -> i32 {
+        3
+    }
+
+    pub fn announce_and_return_part(&self, announcement: &str) -> &str {
+        println!("Attention please: {announcement}");
+        self.part
+    }
+
+    /* Returning ''a' rather than the elided lifetime of '&self' lets the
+     * result outlive this particular 'ImportantExcerpt', as long as the
+     * underlying text is still around. */
+    pub fn part(&self) -> &'a str {
+        self.part
+    }
+}
+
+/* The Static Lifetime */
+/* ''static' denotes that the affected reference can live for the entire
+ * duration of the program. All string literals have the ''static'
+ * lifetime, because their text is stored directly in the program's
+ * binary. */
+pub fn greeting() -> &'static str {
+    "I have a static lifetime."
+}
+
+/* Generic Type Parameters, Trait Bounds, and Lifetimes Together */
+/* Because lifetimes are a type of generic, the declarations of the
+ * lifetime parameter ''a' and the generic type parameter 'T' go in the
+ * same list inside the angle brackets after the function name. */
+pub fn longest_with_an_announcement<'a, T>(x: &'a str, y: &'a str, ann: T) -> &'a str
+where
+    T: Display,
+{
+    println!("Announcement! {ann}");
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+/* The same idea applied to a slice of any type that can be compared:
+ * the returned reference borrows from 'items', so it carries the slice's
+ * lifetime. Returning 'None' for an empty slice avoids the panic that the
+ * 'largest' function in the generics chapter would hit. The second elision
+ * rule would fill these annotations in for us; they're written out here so
+ * the relationship is visible. */
+#[allow(clippy::needless_lifetimes)]
+pub fn largest_ref<'a, T: PartialOrd>(items: &'a [T]) -> Option<&'a T> {
+    let mut iter = items.iter();
+    let mut largest = iter.next()?;
+    for item in iter {
+        if item > largest {
+            largest = item;
+        }
+    }
+    Some(largest)
+}
+
+/* A struct that holds a reference to a value of any type implementing
+ * 'Display', and a method combining all three: a lifetime on the struct,
+ * a generic type, and a trait bound. */
+pub struct Labeled<'a, T: Display> {
+    pub label: &'a str,
+    pub value: T,
+}
+
+impl<'a, T: Display> Labeled<'a, T> {
+    pub fn render(&self) -> String {
+        format!("{}: {}", self.label, self.value)
+    }
+
+    pub fn label(&self) -> &'a str {
+        self.label
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,54 @@
+use lifetimes::{
+    greeting, largest_ref, longest, longest_with_an_announcement, ImportantExcerpt, Labeled,
+};
+
+fn main() {
+    /* Preventing Dangling References with Lifetimes */
+    /* When uncommented, the below code fails to compile because 'x'
+     * doesn't live long enough: 'r' would refer to memory that was
+     * deallocated when 'x' went out of scope. The 'borrow checker' compares
+     * scopes to determine whether all borrows are valid. */
+    // let r;
+    // {
+    //     let x = 5;
+    //     r = &x;
+    // }
+    // println!("r: {}", r);
+
+    let string1 = String::from("abcd");
+    let string2 = "xyz";
+
+    let result = longest(string1.as_str(), string2);
+    println!("The longest string is {result}");
+
+    /* 'string2' lives in an inner scope here, so 'result' is only valid
+     * until the end of that inner scope. Moving the 'println!' outside of
+     * the inner scope would not compile, even though 'string1' happens to
+     * be the longer string: the lifetime of the returned reference is the
+     * smaller of the lifetimes passed in. */
+    {
+        let string2 = String::from("a much longer string");
+        let result = longest(string1.as_str(), string2.as_str());
+        println!("The longest string is {result}");
+    }
+
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let i = ImportantExcerpt::from_text(&novel);
+    println!("{:?} has level {}", i, i.level());
+    i.announce_and_return_part("an excerpt follows");
+
+    println!("{}", greeting());
+
+    println!(
+        "{}",
+        longest_with_an_announcement("hello", "hi", "Today is someone's birthday!")
+    );
+
+    println!("Largest: {:?}", largest_ref(&['y', 'm', 'a', 'q']));
+
+    let labeled = Labeled {
+        label: "answer",
+        value: 42,
+    };
+    println!("{}", labeled.render());
+}
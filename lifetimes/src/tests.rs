@@ -0,0 +1,51 @@
+use crate::*;
+
+#[test]
+fn test_longest() {
+    assert_eq!(longest("abcd", "xyz"), "abcd");
+    assert_eq!(longest("ab", "xyz"), "xyz");
+    assert_eq!(first("ab", "xyz"), "ab");
+}
+
+#[test]
+fn test_longest_across_scopes() {
+    let string1 = String::from("long string is long");
+    let result;
+    {
+        let string2 = String::from("xyz");
+        result = longest(string1.as_str(), string2.as_str()).to_string();
+    }
+    assert_eq!(result, "long string is long");
+}
+
+#[test]
+fn test_excerpt_outlives_struct() {
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let part = {
+        let excerpt = ImportantExcerpt::from_text(&novel);
+        assert_eq!(excerpt.level(), 3);
+        assert_eq!(excerpt.announce_and_return_part("hi"), "Call me Ishmael");
+        excerpt.part()
+    };
+    assert_eq!(part, "Call me Ishmael");
+}
+
+#[test]
+fn test_first_word() {
+    assert_eq!(first_word("hello world"), "hello");
+    assert_eq!(first_word(""), "");
+}
+
+#[test]
+fn test_generics_bounds_and_lifetimes() {
+    assert_eq!(longest_with_an_announcement("a", "bb", 42), "bb");
+    assert_eq!(largest_ref(&[3, 7, 2]), Some(&7));
+    assert_eq!(largest_ref::<i32>(&[]), None);
+
+    let labeled = Labeled {
+        label: "answer",
+        value: 42,
+    };
+    assert_eq!(labeled.render(), "answer: 42");
+    assert_eq!(labeled.label(), "answer");
+}
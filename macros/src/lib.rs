@@ -0,0 +1,185 @@
+/* MACROS */
+/* The term 'macro' refers to a family of features in Rust: 'declarative'
+ * macros with 'macro_rules!' and three kinds of 'procedural' macros
+ * (custom '#[derive]' macros, attribute-like macros, and function-like
+ * macros).
+ *
+ * Fundamentally, macros are a way of writing code that writes other code,
+ * which is known as 'metaprogramming'. Macros can take a variable number
+ * of parameters: 'println!("hello")' and 'println!("hello {}", name)' both
+ * work, while functions must declare the number and type of their
+ * parameters. Macros are also expanded before the compiler interprets the
+ * meaning of the code, so a macro can, for example, implement a trait on a
+ * given type - a function can't, because it gets called at runtime.
+ *
+ * Procedural macros need their own crate with a special crate type, so the
+ * builder below is written as a declarative macro over struct-like input
+ * instead of as a real '#[derive(Builder)]'. It gets surprisingly far. */
+
+/* Declarative Macros with macro_rules! for General Metaprogramming */
+/* Declarative macros allow us to write something similar to a 'match'
+ * expression: the macro compares the literal Rust source code passed to it
+ * against patterns, and when a pattern matches, the code associated with
+ * it replaces the code passed to the macro.
+ *
+ * '$( ... ),*' captures a repeated pattern separated by commas, and '$x'
+ * inside it is bound to each matched expression in turn. '$(,)?' accepts
+ * an optional trailing comma. '#[macro_export]' makes the macro available
+ * whenever the crate is brought into scope. */
+
+/// Creates a `Vec` containing the arguments, like the standard `vec!`.
+///
+/// ```
+/// use macros::my_vec;
+///
+/// let v = my_vec![1, 2, 3];
+/// assert_eq!(v, vec![1, 2, 3]);
+///
+/// let empty: Vec<i32> = my_vec![];
+/// assert!(empty.is_empty());
+///
+/// let zeros = my_vec![0; 4];
+/// assert_eq!(zeros, [0, 0, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! my_vec {
+    () => {
+        ::std::vec::Vec::new()
+    };
+    ( $elem:expr; $n:expr ) => {
+        ::std::vec::from_elem($elem, $n)
+    };
+    ( $( $x:expr ),+ $(,)? ) => {
+        <[_]>::into_vec(::std::boxed::Box::new([ $( $x ),+ ]))
+    };
+}
+/* The book's version of this macro expands 'my_vec![1, 2, 3]' into:
+ *
+ * {
+ *     let mut temp_vec = Vec::new();
+ *     temp_vec.push(1);
+ *     temp_vec.push(2);
+ *     temp_vec.push(3);
+ *     temp_vec
+ * }
+ *
+ * Our last arm instead does what the standard library's 'vec!' does: it
+ * builds a boxed array of all the elements and turns it into a 'Vec', which
+ * allocates once at exactly the right size instead of growing as it goes.
+ *
+ * Paths inside the macro start with '::std' so that the expansion still
+ * works if the caller has their own item named 'std' or 'Vec' in scope. */
+
+/// Creates a `HashMap` from `key => value` pairs.
+///
+/// ```
+/// use macros::hashmap;
+///
+/// let scores = hashmap! {
+///     "Blue" => 10,
+///     "Yellow" => 50,
+/// };
+/// assert_eq!(scores["Yellow"], 50);
+/// assert_eq!(scores.len(), 2);
+/// ```
+#[macro_export]
+macro_rules! hashmap {
+    () => {
+        ::std::collections::HashMap::new()
+    };
+    ( $( $key:expr => $value:expr ),+ $(,)? ) => {
+        {
+            let mut map = ::std::collections::HashMap::new();
+            $(
+                map.insert($key, $value);
+            )+
+            map
+        }
+    };
+}
+
+/* A Builder Written as a Declarative Macro */
+/* 'builder!' takes something that looks like a struct definition plus the
+ * name to give its builder, and emits three things: the struct itself, a
+ * builder struct holding an 'Option' of every field, and a 'build' method
+ * that returns an error naming the first field that was never set.
+ *
+ * Fragment specifiers tell the macro what kind of syntax to expect: 'ident'
+ * for names, 'ty' for types, 'vis' for an optional 'pub', and 'meta' for
+ * the contents of attributes such as 'derive(Debug)'. */
+
+/// Defines a struct together with a builder for it.
+///
+/// ```
+/// use macros::builder;
+///
+/// builder! {
+///     #[derive(Debug, PartialEq)]
+///     pub struct Command => CommandBuilder {
+///         executable: String,
+///         args: Vec<String>,
+///         current_dir: String,
+///     }
+/// }
+///
+/// let command = Command::builder()
+///     .executable("cargo")
+///     .args(vec![String::from("build")])
+///     .current_dir("..")
+///     .build()
+///     .unwrap();
+/// assert_eq!(command.executable, "cargo");
+///
+/// let missing = Command::builder().executable("cargo").build();
+/// assert_eq!(missing.unwrap_err(), "missing field `args`");
+/// ```
+#[macro_export]
+macro_rules! builder {
+    (
+        $( #[$meta:meta] )*
+        $vis:vis struct $name:ident => $builder:ident {
+            $( $field:ident : $ty:ty ),* $(,)?
+        }
+    ) => {
+        $( #[$meta] )*
+        $vis struct $name {
+            $( pub $field: $ty, )*
+        }
+
+        #[derive(Default)]
+        $vis struct $builder {
+            $( $field: ::std::option::Option<$ty>, )*
+        }
+
+        impl $name {
+            pub fn builder() -> $builder {
+                <$builder as ::std::default::Default>::default()
+            }
+        }
+
+        impl $builder {
+            $(
+                pub fn $field(mut self, value: impl ::std::convert::Into<$ty>) -> Self {
+                    self.$field = ::std::option::Option::Some(value.into());
+                    self
+                }
+            )*
+
+            pub fn build(self) -> ::std::result::Result<$name, ::std::string::String> {
+                ::std::result::Result::Ok($name {
+                    $(
+                        $field: self.$field.ok_or_else(|| {
+                            ::std::format!("missing field `{}`", ::std::stringify!($field))
+                        })?,
+                    )*
+                })
+            }
+        }
+    };
+}
+/* 'stringify!' turns the field's identifier into a string literal at
+ * compile time, so the error message names the exact field. Setting a field
+ * twice keeps the last value, the same as assigning twice would. */
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,31 @@
+use macros::{builder, hashmap, my_vec};
+
+builder! {
+    #[derive(Debug)]
+    struct Request => RequestBuilder {
+        method: String,
+        url: String,
+        retries: u8,
+    }
+}
+
+fn main() {
+    let v = my_vec![1, 2, 3];
+    println!("my_vec![1, 2, 3] = {v:?}");
+
+    let scores = hashmap! {
+        String::from("Blue") => 10,
+        String::from("Yellow") => 50,
+    };
+    println!("Yellow scored {}", scores["Yellow"]);
+
+    let request = Request::builder()
+        .method("GET")
+        .url("https://doc.rust-lang.org/book/")
+        .retries(3)
+        .build();
+    println!("{request:?}");
+
+    let incomplete = Request::builder().method("GET").build();
+    println!("{incomplete:?}");
+}
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+#[test]
+fn test_my_vec() {
+    let empty: Vec<u8> = my_vec![];
+    assert!(empty.is_empty());
+    assert_eq!(my_vec![1, 2, 3,], vec![1, 2, 3]);
+    assert_eq!(my_vec![String::from("a"); 2], vec!["a", "a"]);
+}
+
+#[test]
+fn test_hashmap() {
+    let empty: HashMap<&str, i32> = hashmap!();
+    assert!(empty.is_empty());
+
+    let map = hashmap! { "one" => 1, "two" => 2 };
+    assert_eq!(map.get("one"), Some(&1));
+    assert_eq!(map.get("two"), Some(&2));
+    assert_eq!(map.len(), 2);
+
+    // Later entries overwrite earlier ones, as with 'insert'
+    let map = hashmap! { 'a' => 1, 'a' => 2 };
+    assert_eq!(map[&'a'], 2);
+}
+
+builder! {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Pizza => PizzaBuilder {
+        size: u32,
+        topping: String,
+    }
+}
+
+#[test]
+fn test_builder_builds_when_complete() {
+    let pizza = Pizza::builder()
+        .topping("mushroom")
+        .size(12u32)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        pizza,
+        Pizza {
+            size: 12,
+            topping: String::from("mushroom"),
+        }
+    );
+}
+
+#[test]
+fn test_builder_reports_missing_field() {
+    assert_eq!(
+        Pizza::builder().size(12u32).build(),
+        Err(String::from("missing field `topping`"))
+    );
+    assert_eq!(
+        Pizza::builder().build(),
+        Err(String::from("missing field `size`"))
+    );
+}
+
+#[test]
+fn test_builder_last_set_wins() {
+    let pizza = Pizza::builder()
+        .size(10u32)
+        .size(14u32)
+        .topping("olive")
+        .build()
+        .unwrap();
+    assert_eq!(pizza.size, 14);
+}
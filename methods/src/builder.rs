@@ -0,0 +1,77 @@
+/* Building Rectangles */
+/* 'try_new' takes the two sides and nothing else. A builder names each
+ * setting as it's given, so 'RectangleBuilder::new().width(30).height(50)'
+ * can't have its sides swapped by accident, and it has room for more than
+ * the sides:
+ *
+ * - 'square' sets both sides at once, and 'golden' sets the height to go
+ *   with a width in the golden ratio, about 1.618 to 1, rounded to the
+ *   nearest whole side.
+ * - 'max_side' and 'max_area' are limits the rectangle has to keep to.
+ *   Without them, the only limit is the one every rectangle has,
+ *   'MAX_SIDE'.
+ *
+ * Nothing is checked until 'build', which returns the first problem it
+ * finds as a 'BuildError'. Setting a side twice keeps the second one. */
+use crate::error::BuildError;
+use crate::rectangle::Rectangle;
+
+const GOLDEN_RATIO: f64 = 1.618_033_988_749_895;
+
+#[derive(Debug, Clone, Default)]
+pub struct RectangleBuilder {
+    width: Option<u32>,
+    height: Option<u32>,
+    max_side: Option<u32>,
+    max_area: Option<u64>,
+}
+
+impl RectangleBuilder {
+    pub fn new() -> RectangleBuilder {
+        RectangleBuilder::default()
+    }
+
+    pub fn width(mut self, width: u32) -> RectangleBuilder {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: u32) -> RectangleBuilder {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn square(self, side: u32) -> RectangleBuilder {
+        self.width(side).height(side)
+    }
+
+    pub fn golden(self, width: u32) -> RectangleBuilder {
+        let height = (f64::from(width) / GOLDEN_RATIO).round() as u32;
+        self.width(width).height(height)
+    }
+
+    pub fn max_side(mut self, max_side: u32) -> RectangleBuilder {
+        self.max_side = Some(max_side);
+        self
+    }
+
+    pub fn max_area(mut self, max_area: u64) -> RectangleBuilder {
+        self.max_area = Some(max_area);
+        self
+    }
+
+    pub fn build(&self) -> Result<Rectangle, BuildError> {
+        let width = self.width.ok_or(BuildError::MissingWidth)?;
+        let height = self.height.ok_or(BuildError::MissingHeight)?;
+        let rect = match self.max_side {
+            Some(max) => Rectangle::try_new_with_max(width, height, max)?,
+            None => Rectangle::try_new(width, height)?,
+        };
+
+        let area = u64::from(rect.area());
+        match self.max_area {
+            Some(max) if area > max => Err(BuildError::AreaTooLarge { area, max }),
+            _ => Ok(rect),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+/* Dimension Errors */
+/* A side of a 'Rectangle' is a 'u32', so scaling or growing one can ask
+ * for a side that doesn't fit. Plain '*' and '+' would panic on that in a
+ * debug build and quietly wrap around in a release build, so the methods
+ * that change a size use 'checked_mul' and 'checked_add' instead and
+ * return this error, saying which side was too big. The same error covers
+ * a side that fits in a 'u32' but is over the rectangle's limit (see
+ * rectangle.rs), and a side of zero, which no rectangle may have. */
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DimensionError {
+    Zero,
+    WidthOverflow,
+    HeightOverflow,
+}
+
+impl fmt::Display for DimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DimensionError::Zero => write!(f, "a rectangle's sides must be greater than zero"),
+            DimensionError::WidthOverflow => write!(f, "the width would be too large"),
+            DimensionError::HeightOverflow => write!(f, "the height would be too large"),
+        }
+    }
+}
+
+impl std::error::Error for DimensionError {}
+
+/* Parse Errors */
+/* Reading a rectangle from text, like "30x50", can go wrong before there's
+ * a size to check (there aren't two numbers, or one of them isn't a
+ * number), or after (the numbers aren't a valid size). The second kind
+ * wraps the 'DimensionError' that 'try_new' gave, so the message can say
+ * which. */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRectError {
+    Format(String),
+    InvalidNumber(String),
+    Dimension(DimensionError),
+}
+
+impl fmt::Display for ParseRectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseRectError::Format(spec) => write!(
+                f,
+                "'{spec}' isn't a rectangle; expected a width and a height like '30x50' or '30 50'"
+            ),
+            ParseRectError::InvalidNumber(side) => {
+                write!(f, "'{side}' isn't a side length; expected a whole number")
+            }
+            ParseRectError::Dimension(e) => write!(f, "not a valid rectangle: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseRectError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseRectError::Dimension(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DimensionError> for ParseRectError {
+    fn from(e: DimensionError) -> ParseRectError {
+        ParseRectError::Dimension(e)
+    }
+}
+
+/* Build Errors */
+/* What 'RectangleBuilder::build' can find wrong: a side that was never
+ * set, an area over the builder's 'max_area', or a side 'try_new' turned
+ * away. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError {
+    MissingWidth,
+    MissingHeight,
+    AreaTooLarge { area: u64, max: u64 },
+    Dimension(DimensionError),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::MissingWidth => write!(f, "the rectangle needs a width"),
+            BuildError::MissingHeight => write!(f, "the rectangle needs a height"),
+            BuildError::AreaTooLarge { area, max } => {
+                write!(f, "an area of {area} is over the limit of {max}")
+            }
+            BuildError::Dimension(e) => write!(f, "not a valid rectangle: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BuildError::Dimension(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DimensionError> for BuildError {
+    fn from(e: DimensionError) -> BuildError {
+        BuildError::Dimension(e)
+    }
+}
@@ -1,91 +1,20 @@
-mod tests;
+mod builder;
+mod error;
+mod rectangle;
+mod units;
 
-#[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
-}
-
-/* Instead of creating a completely separate function to calculate the
- * area of a rectangle, we can implement a method on the 'Rectangle'
- * type to do the same thing.
- * Methods are similar to functions in that they are declared with the
- * 'fn' keyword and a name, they can have parameters and a return value,
- * and they contains some code that's run when the method is called from
- * somewhere else. Unlike functions, methods are defined within the
- * context of a struct (or an enum or a trait object), and their first
- * parameter is always 'self', which represents the instance of the
- * struct the method is being called on. */
-/* Implement a method on a struct using the 'impl' keyword and the
- * struct's name. */
-impl Rectangle {
-    //      v Note that self is borrowed in the area() method
-    fn area(&self) -> u32 {
-        self.width * self.height
-    }
-    /* As mentioned above, to define the area function in the context of
-     * the 'Rectangle' struct, we use 'impl' to start an implementation
-     * block. Everything within this 'impl' block will be associated with
-     * the 'Rectangle' type. Then we move the 'area' function within the
-     * 'impl' curly brackets and change the first (and only in
-     * this case) parameter to be 'self' in the signature and everywhere
-     * within the method body. As opposed to passing the Rectangle
-     * instance to a function, method syntax allows us to call the
-     * 'area' method on our 'Rectangle' instance. Calling a method on an
-     * instance of a type uses dot notation. (e.g., rect1.area()) */
-
-    /* Methods can work with any amount of parameters after 'self'
-     * parameter, and those parameters work just like parameters in
-     * functions. */
-    // Define a method that returns if one rectangle fits entirely inside another
-    fn can_hold(&self, other_rect: &Rectangle) -> bool {
-        self.width > other_rect.width && self.height > other_rect.height
-    }
-
-    /* All functions defined within an 'impl' block are called
-     * 'associated functions' because they're associated with the type
-     * named after the 'impl'. We can define associated functions that
-     * don't have 'self' as their first parameter(and thus, are not
-     * methods) because they don't need an instance of the type to work
-     * with. An example of such an associated function is 'String::from'
-     * function that's defined on the 'String' type.
-     *
-     * Associated functions that aren't methods are often used for
-     * constructors that will return a new instance of the struct.
-     * These are often called 'new', but 'new' isn't a special name and
-     * isn't built into the language. For example, we could choose to
-     * provide an associated function named 'square' that would have
-     * one dimension parameter and use that as both width and height,
-     * thus making it easier to create a square 'Rectangle' rather than
-     * having to specify the same value twice. */
-    fn square(size: u32) -> Self {
-        //                   ^^ This function returns a 'Rectangle' instance
-        // v This appearance of 'Self' is a constructor for 'Rectangle'
-        Self {
-            width: size,
-            height: size,
-        }
-    }
-}
-
-/* MULTIPLE 'impl' BLOCKS */
-/* Each struct is allowed to have multiple 'impl' blocks. */
-impl Rectangle {
-    /* Methods can take ownership of, borrow immutably, or borrow mutably
-     * the 'self' parameter just as they can any other parameter. */
-    // We can also add a method that shares a name with a struct field.
-    fn width(&self) -> bool {
-        self.width > 0
-    }
-}
-/* There is no reason to separate these methods into multiple 'impl' 
- * blocks here, but this is valid syntax. */
+use builder::RectangleBuilder;
+use rectangle::{largest_rect, sort_by_area, Dimension, FitStrategy, Rect, Rectangle, Rounding};
+use units::{Millimeters, Pixels};
 
+/* Every rectangle below has sides we know are fine, so a failed 'build'
+ * would be a bug in this file, and 'expect' is the right response. */
 fn main() {
-    let rect1 = Rectangle {
-        width: 30,
-        height: 50,
-    };
+    let rect1 = RectangleBuilder::new()
+        .width(30)
+        .height(50)
+        .build()
+        .expect("a valid rectangle");
 
     println!(
         "The area of the rectangle is {} square pixels.",
@@ -94,21 +23,193 @@ fn main() {
     dbg!(&rect1); // If the 'area' method took ownership of 'self', instead of borrowing, this line would cause a compiler error
 
     if rect1.width() {
-        println!("rect1 has a nonzero width: {}", rect1.width);
+        println!("rect1 has a nonzero width: {}", rect1.dimensions().0);
     }
 
-    let rect2 = Rectangle {
-        width: 10,
-        height: 40,
-    };
-    let rect3 = Rectangle {
-        width: 60,
-        height: 45,
-    };
+    let rect2 = RectangleBuilder::new()
+        .width(10)
+        .height(40)
+        .build()
+        .expect("a valid rectangle");
+    let rect3 = RectangleBuilder::new()
+        .width(60)
+        .height(45)
+        .build()
+        .expect("a valid rectangle");
 
     println!("Can rect1 hold rect2?: {}", rect1.can_hold(&rect2));
     println!("Can rect1 hold rect3?: {}", rect1.can_hold(&rect3));
+    println!("Is rect3 bigger than rect1?: {}", rect3 > rect1);
+
+    let door = RectangleBuilder::new()
+        .width(90)
+        .height(200)
+        .build()
+        .expect("a valid rectangle");
+    let table = RectangleBuilder::new()
+        .width(150)
+        .height(80)
+        .build()
+        .expect("a valid rectangle");
+    match door.fit_strategy(&table) {
+        FitStrategy::FitsAsIs => println!("The table goes straight through the door."),
+        FitStrategy::FitsRotated => println!("The table fits through the door on its side."),
+        FitStrategy::DoesNotFit => println!("The table won't fit through the door."),
+    }
+    println!(
+        "Can rect1 hold rect3 turned sideways?: {}",
+        rect1.can_hold_rotated(&rect3)
+    );
 
-    let square = Rectangle::square(4);
+    let square = RectangleBuilder::new()
+        .square(4)
+        .build()
+        .expect("a valid square");
     dbg!(square);
+
+    println!("rect1 is {rect1}, which looks like this:");
+    print!("{}", rect1.render_ascii(20));
+
+    match Rectangle::try_new(0, 50) {
+        Ok(rect) => println!("Made {rect:?}"),
+        Err(e) => println!("Can't make a 0 by 50 rectangle: {e}"),
+    }
+    match Rectangle::try_new_with_max(1920, 1200, 1024) {
+        Ok(rect) => println!("Made {rect:?}"),
+        Err(e) => println!("Can't make a 1920 by 1200 rectangle: {e}"),
+    }
+
+    println!("The perimeter of rect1 is {} pixels.", rect1.perimeter());
+    match rect1.scale(3) {
+        Ok(bigger) => println!("rect1 scaled by 3 is {bigger:?}"),
+        Err(e) => println!("Can't scale rect1: {e}"),
+    }
+    let mut rect4 = RectangleBuilder::new()
+        .square(u32::MAX_SIDE - 1)
+        .build()
+        .expect("a valid square");
+    if let Err(e) = rect4.grow(2, 0) {
+        println!("Can't grow rect4: {e}");
+    }
+    rect4.grow(1, 1).expect("one more pixel fits");
+    dbg!(rect4);
+
+    let square = |size| {
+        RectangleBuilder::new()
+            .square(size)
+            .build()
+            .expect("a valid square")
+    };
+    let rect7 = square(4) + square(6);
+    println!("Two squares added: {rect7:?}");
+    println!("Is that square(5) * 2?: {}", rect7 == square(5) * 2);
+
+    let mut rects = vec![
+        RectangleBuilder::new()
+            .width(30)
+            .height(50)
+            .build()
+            .expect("a valid rectangle"),
+        RectangleBuilder::new()
+            .width(10)
+            .height(40)
+            .build()
+            .expect("a valid rectangle"),
+        RectangleBuilder::new()
+            .width(60)
+            .height(45)
+            .build()
+            .expect("a valid rectangle"),
+    ];
+    if let Some(largest) = largest_rect(&rects) {
+        println!("The largest rectangle is {largest}.");
+    }
+    sort_by_area(&mut rects);
+    let sorted: Vec<String> = rects.iter().map(Rectangle::to_string).collect();
+    println!("From smallest to largest: {}", sorted.join(", "));
+
+    let rect8 = Rectangle::try_from((1024_i64, 768_i64)).expect("a valid rectangle");
+    let (width, height) = rect8.into();
+    println!("rect8 is {width} by {height}.");
+    let pair: Result<Rectangle, _> = (-4_i64, 3_i64).try_into();
+    println!("Is (-4, 3) a rectangle?: {}", pair.is_ok());
+
+    let rect5 = Rect::try_new(2.5, 4.0).expect("a valid rectangle");
+    let rect6: Rect<i64> = Rect::square(3).expect("a valid square");
+    println!("rect5 is {} square pixels.", rect5.area());
+    println!("Can rect6 hold itself?: {}", rect6.can_hold(&rect6));
+    println!(
+        "Can there be a -3 by -3 square?: {}",
+        Rect::<i64>::square(-3).is_ok()
+    );
+
+    /* Sides with units (see units.rs). */
+    let page = Rect::try_new(Millimeters(210), Millimeters(297)).expect("a valid rectangle");
+    let margin = Millimeters(20);
+    let (width, height) = page.dimensions();
+    let printable =
+        Rect::try_new(width - margin * 2, height - margin * 2).expect("a valid rectangle");
+    println!("An A4 page is {page}, {printable} inside the margins.");
+    match page.to_pixels(96) {
+        Ok(screen) => println!("At 96 dpi, the page is {screen}."),
+        Err(e) => println!("Can't show the page at 96 dpi: {e}"),
+    }
+    if let Some(back) = Pixels(794).to_millimeters(96) {
+        let inches = f64::from(u32::from(back)) / 25.4;
+        println!("794 px at 96 dpi is {back}, about {inches:.1} inches.");
+    }
+
+    /* Rectangles from text, the way a command line or a file has them. */
+    for spec in ["30x50", "1024 768", "30 by 50", "0x50"] {
+        match spec.parse::<Rectangle>() {
+            Ok(rect) => println!("'{spec}' is {rect}, written '{}'.", rect.to_spec_string()),
+            Err(e) => println!("Can't read '{spec}': {e}"),
+        }
+    }
+
+    /* Limits, and other shapes, from the builder (see builder.rs). */
+    let golden = RectangleBuilder::new().golden(89).build();
+    println!("A golden rectangle 89 wide: {golden:?}");
+    let too_big = RectangleBuilder::new()
+        .width(300)
+        .height(400)
+        .max_area(100_000)
+        .build();
+    if let Err(e) = too_big {
+        println!("Can't build a 300 by 400 rectangle: {e}");
+    }
+    match RectangleBuilder::new().width(30).max_side(1000).build() {
+        Ok(rect) => println!("Built {rect}"),
+        Err(e) => println!("Can't build a rectangle with only a width: {e}"),
+    }
+
+    /* Thumbnails keep the picture's shape. */
+    let photo = RectangleBuilder::new()
+        .width(4000)
+        .height(3000)
+        .build()
+        .expect("a valid rectangle");
+    let thumbnail_box = RectangleBuilder::new()
+        .width(160)
+        .height(160)
+        .build()
+        .expect("a valid rectangle");
+    println!("The photo's aspect ratio is {:.3}.", photo.aspect_ratio());
+    match photo.fit_within(&thumbnail_box, Rounding::Nearest) {
+        Ok(thumbnail) => println!("Its thumbnail is {thumbnail}."),
+        Err(e) => println!("No thumbnail: {e}"),
+    }
+    for rounding in [Rounding::Down, Rounding::Up] {
+        match photo.resize_to_width(1366, rounding) {
+            Ok(resized) => println!("1366 wide, rounding {rounding:?}: {resized}"),
+            Err(e) => println!("Can't resize: {e}"),
+        }
+    }
 }
+
+#[cfg(test)]
+mod testing;
+/* The tests are the book's, and it writes 'assert_eq!(.., true)'. */
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests;
@@ -0,0 +1,488 @@
+/* The Rectangle Type */
+/* 'Rectangle' and its methods live in their own module so that its fields
+ * can be private to it (see "Valid Rectangles" below). */
+use crate::error::{DimensionError, ParseRectError};
+use std::cmp::Ordering;
+use std::fmt;
+use std::num::NonZeroU16;
+use std::ops::{Add, Mul};
+use std::str::FromStr;
+
+/* Generic Rectangles */
+/* The sides don't have to be 'u32': 'Rect<T>' works with any 'Dimension'
+ * type 'T', one that can be copied, multiplied, and compared, which covers
+ * 'u32', 'i64', and 'f64'. 'Rectangle' is the 'u32' version the rest of
+ * this file was written for. Methods that only make sense for whole,
+ * unsigned sides (like the overflow checks in 'scale' and 'grow') are in
+ * 'impl Rectangle' blocks; the ones that work for every 'T' are in
+ * 'impl<T> Rect<T>'. */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rect<T> {
+    width: T,
+    height: T,
+}
+
+pub type Rectangle = Rect<u32>;
+
+/* Valid Rectangles */
+/* The fields are private, so code outside this module can't write
+ * 'Rectangle { width: 0, height: 50 }': the only ways to get a rectangle
+ * are 'try_new' and the other functions here, and all of them check that
+ * both sides are greater than zero and no bigger than 'MAX_SIDE'.
+ * 'try_new_with_max' lets a caller ask for a smaller limit than that, say
+ * for a window that has to fit on a screen; it only applies to that one
+ * call.
+ *
+ * 'MAX_SIDE' is picked so that 'area' can't overflow: the square of the
+ * largest side still fits in the type. Negative sides, and for 'f64' a
+ * 'NaN' or infinite one, aren't greater than zero or aren't at most
+ * 'MAX_SIDE', so they're turned away by the same two checks. */
+pub trait Dimension: Copy + PartialOrd + Mul<Output = Self> {
+    const ZERO: Self;
+    const MAX_SIDE: Self;
+}
+
+/* 'From<(NonZeroU16, NonZeroU16)>' under "Conversions" relies on this. */
+impl Dimension for u32 {
+    const ZERO: u32 = 0;
+    const MAX_SIDE: u32 = 65_535;
+}
+
+impl Dimension for i64 {
+    const ZERO: i64 = 0;
+    const MAX_SIDE: i64 = 3_037_000_499;
+}
+
+impl Dimension for f64 {
+    const ZERO: f64 = 0.0;
+    const MAX_SIDE: f64 = 1.0e154;
+}
+
+fn check_side<T: Dimension>(
+    side: T,
+    max: T,
+    too_large: DimensionError,
+) -> Result<T, DimensionError> {
+    let positive = side > T::ZERO;
+    let fits = side <= max && side <= T::MAX_SIDE;
+    if !positive {
+        return Err(DimensionError::Zero);
+    }
+    if !fits {
+        return Err(too_large);
+    }
+    Ok(side)
+}
+
+impl<T: Dimension> Rect<T> {
+    pub fn try_new(width: T, height: T) -> Result<Rect<T>, DimensionError> {
+        Rect::try_new_with_max(width, height, T::MAX_SIDE)
+    }
+
+    pub fn try_new_with_max(width: T, height: T, max: T) -> Result<Rect<T>, DimensionError> {
+        Ok(Rect {
+            width: check_side(width, max, DimensionError::WidthOverflow)?,
+            height: check_side(height, max, DimensionError::HeightOverflow)?,
+        })
+    }
+
+    /* The sides as a '(width, height)' pair, since the fields themselves
+     * are private. */
+    pub fn dimensions(&self) -> (T, T) {
+        (self.width, self.height)
+    }
+}
+
+/* Instead of creating a completely separate function to calculate the
+ * area of a rectangle, we can implement a method on the 'Rectangle'
+ * type to do the same thing.
+ * Methods are similar to functions in that they are declared with the
+ * 'fn' keyword and a name, they can have parameters and a return value,
+ * and they contains some code that's run when the method is called from
+ * somewhere else. Unlike functions, methods are defined within the
+ * context of a struct (or an enum or a trait object), and their first
+ * parameter is always 'self', which represents the instance of the
+ * struct the method is being called on. */
+/* Implement a method on a struct using the 'impl' keyword and the
+ * struct's name. */
+impl<T: Dimension> Rect<T> {
+    //      v Note that self is borrowed in the area() method
+    pub fn area(&self) -> T {
+        self.width * self.height
+    }
+    /* As mentioned above, to define the area function in the context of
+     * the 'Rectangle' struct, we use 'impl' to start an implementation
+     * block. Everything within this 'impl' block will be associated with
+     * the 'Rectangle' type. Then we move the 'area' function within the
+     * 'impl' curly brackets and change the first (and only in
+     * this case) parameter to be 'self' in the signature and everywhere
+     * within the method body. As opposed to passing the Rectangle
+     * instance to a function, method syntax allows us to call the
+     * 'area' method on our 'Rectangle' instance. Calling a method on an
+     * instance of a type uses dot notation. (e.g., rect1.area()) */
+
+    /* Methods can work with any amount of parameters after 'self'
+     * parameter, and those parameters work just like parameters in
+     * functions. */
+    // Define a method that returns if one rectangle fits entirely inside another
+    pub fn can_hold(&self, other_rect: &Rect<T>) -> bool {
+        self.width > other_rect.width && self.height > other_rect.height
+    }
+
+    /* All functions defined within an 'impl' block are called
+     * 'associated functions' because they're associated with the type
+     * named after the 'impl'. We can define associated functions that
+     * don't have 'self' as their first parameter(and thus, are not
+     * methods) because they don't need an instance of the type to work
+     * with. An example of such an associated function is 'String::from'
+     * function that's defined on the 'String' type.
+     *
+     * Associated functions that aren't methods are often used for
+     * constructors that will return a new instance of the struct.
+     * These are often called 'new', but 'new' isn't a special name and
+     * isn't built into the language. For example, we could choose to
+     * provide an associated function named 'square' that would have
+     * one dimension parameter and use that as both width and height,
+     * thus making it easier to create a square 'Rectangle' rather than
+     * having to specify the same value twice.
+     *
+     * Like 'try_new', 'square' turns away a side that's zero or too big,
+     * so it returns a 'Result' too. */
+    pub fn square(size: T) -> Result<Self, DimensionError> {
+        //                   ^^ This function returns a 'Rectangle' instance
+        Self::try_new(size, size)
+    }
+}
+
+/* MULTIPLE 'impl' BLOCKS */
+/* Each struct is allowed to have multiple 'impl' blocks. */
+impl Rectangle {
+    /* Methods can take ownership of, borrow immutably, or borrow mutably
+     * the 'self' parameter just as they can any other parameter. */
+    // We can also add a method that shares a name with a struct field.
+    pub fn width(&self) -> bool {
+        self.width > 0
+    }
+}
+/* There is no reason to separate these methods into multiple 'impl'
+ * blocks here, but this is valid syntax. */
+
+/* Fitting One Rectangle in Another */
+/* 'can_hold' only tries 'other' the way it's facing. A rectangle that's
+ * too tall might still fit on its side, so 'fit_strategy' tries both ways
+ * and says which one works, preferring no rotation when both do. Fitting
+ * means the same as in 'can_hold': strictly smaller on both sides. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FitStrategy {
+    FitsAsIs,
+    FitsRotated,
+    DoesNotFit,
+}
+
+impl<T: Dimension> Rect<T> {
+    pub fn fit_strategy(&self, other: &Rect<T>) -> FitStrategy {
+        let rotated = Rect {
+            width: other.height,
+            height: other.width,
+        };
+        if self.can_hold(other) {
+            FitStrategy::FitsAsIs
+        } else if self.can_hold(&rotated) {
+            FitStrategy::FitsRotated
+        } else {
+            FitStrategy::DoesNotFit
+        }
+    }
+
+    pub fn can_hold_rotated(&self, other: &Rect<T>) -> bool {
+        self.fit_strategy(other) != FitStrategy::DoesNotFit
+    }
+}
+
+/* Changing a Rectangle */
+/* 'scale' borrows 'self' and returns a new, bigger 'Rectangle', leaving
+ * the original alone, while 'grow' takes '&mut self' and changes the
+ * rectangle it's called on. Either one can ask for a side bigger than
+ * 'MAX_SIDE' (or one too big for a 'u32' at all), and scaling by zero
+ * would leave no rectangle, so both return a 'Result' (see error.rs); when
+ * 'grow' fails, the rectangle keeps the size it had. */
+impl Rectangle {
+    /* Twice the sum of two 'u32's always fits in a 'u64', so there's
+     * nothing to check here. */
+    pub fn perimeter(&self) -> u64 {
+        2 * (u64::from(self.width) + u64::from(self.height))
+    }
+
+    pub fn scale(&self, factor: u32) -> Result<Rectangle, DimensionError> {
+        let width = self.width.checked_mul(factor);
+        let height = self.height.checked_mul(factor);
+        match (width, height) {
+            (None, _) => Err(DimensionError::WidthOverflow),
+            (_, None) => Err(DimensionError::HeightOverflow),
+            (Some(width), Some(height)) => Rectangle::try_new(width, height),
+        }
+    }
+
+    pub fn grow(&mut self, dw: u32, dh: u32) -> Result<(), DimensionError> {
+        let width = self.width.checked_add(dw);
+        let height = self.height.checked_add(dh);
+        *self = match (width, height) {
+            (None, _) => return Err(DimensionError::WidthOverflow),
+            (_, None) => return Err(DimensionError::HeightOverflow),
+            (Some(width), Some(height)) => Rectangle::try_new(width, height)?,
+        };
+        Ok(())
+    }
+}
+
+/* Rectangle Arithmetic */
+/* Adding two rectangles adds their widths and their heights, and
+ * multiplying one by a number scales it, so 'rect1 + rect2' is the same
+ * as 'rect1.grow' by 'rect2's sides and 'rect * 2' is 'rect.scale(2)'.
+ * An operator has nowhere to put a 'Result', though, so when those would
+ * fail (a side too big, or multiplying by zero) these panic, the way '+'
+ * on a plain 'u32' does on overflow in a debug build (they panic in a
+ * release build too, instead of wrapping around). Code that can't rule
+ * that out should call 'grow' or 'scale' and handle the error. */
+impl Add for Rectangle {
+    type Output = Rectangle;
+
+    fn add(mut self, other: Rectangle) -> Rectangle {
+        match self.grow(other.width, other.height) {
+            Ok(()) => self,
+            Err(e) => panic!("rectangle addition failed: {e}"),
+        }
+    }
+}
+
+impl Mul<u32> for Rectangle {
+    type Output = Rectangle;
+
+    fn mul(self, factor: u32) -> Rectangle {
+        self.scale(factor)
+            .unwrap_or_else(|e| panic!("rectangle scaling failed: {e}"))
+    }
+}
+
+/* Conversions */
+/* A parser or a config file hands over a width and a height as a pair,
+ * so a pair converts straight into a 'Rectangle', and a 'Rectangle' back
+ * into a pair.
+ *
+ * Turning a '(u32, u32)' into a 'Rectangle' would ideally be a 'From', but
+ * 'From' can't fail, and '(0, 50)' isn't a rectangle; it's a 'TryFrom'
+ * like the one for '(i64, i64)' instead, with the same checks as
+ * 'try_new'. An 'i64' that doesn't fit in a 'u32' at all is reported the
+ * way one over 'MAX_SIDE' is, and a negative one the way zero is. Going
+ * the other way can't fail, so that one is a 'From'.
+ *
+ * A pair that can't be zero or too large does get a 'From': 'MAX_SIDE'
+ * for 'u32' is 'u16::MAX', so every '(NonZeroU16, NonZeroU16)' is a
+ * rectangle already. */
+impl TryFrom<(u32, u32)> for Rectangle {
+    type Error = DimensionError;
+
+    fn try_from((width, height): (u32, u32)) -> Result<Rectangle, DimensionError> {
+        Rectangle::try_new(width, height)
+    }
+}
+
+impl From<(NonZeroU16, NonZeroU16)> for Rectangle {
+    fn from((width, height): (NonZeroU16, NonZeroU16)) -> Rectangle {
+        Rect {
+            width: u32::from(width.get()),
+            height: u32::from(height.get()),
+        }
+    }
+}
+
+impl TryFrom<(i64, i64)> for Rectangle {
+    type Error = DimensionError;
+
+    fn try_from((width, height): (i64, i64)) -> Result<Rectangle, DimensionError> {
+        let side = |value: i64, too_large| match u32::try_from(value) {
+            Ok(value) => Ok(value),
+            Err(_) if value <= 0 => Err(DimensionError::Zero),
+            Err(_) => Err(too_large),
+        };
+        Rectangle::try_new(
+            side(width, DimensionError::WidthOverflow)?,
+            side(height, DimensionError::HeightOverflow)?,
+        )
+    }
+}
+
+impl From<Rectangle> for (u32, u32) {
+    fn from(rect: Rectangle) -> (u32, u32) {
+        rect.dimensions()
+    }
+}
+
+/* Showing a Rectangle */
+/* '{:?}' shows the struct the way it's written in code; 'Display' is for
+ * people, so '{}' prints just the sides, as in "30 by 50".
+ *
+ * 'render_ascii' goes one step further and draws the rectangle in '#'s.
+ * A big rectangle wouldn't fit in a terminal one character per pixel, so
+ * the drawing is shrunk to at most 'max_cols' columns, with the rows
+ * shrunk by the same amount to keep its shape. A character cell is about
+ * twice as tall as it is wide, so there's half a row for each column,
+ * which makes a square look square. Neither side goes below one
+ * character, however thin the rectangle, unless 'max_cols' is zero, which
+ * leaves no room to draw anything. */
+impl<T: fmt::Display> fmt::Display for Rect<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} by {}", self.width, self.height)
+    }
+}
+
+impl Rectangle {
+    pub fn render_ascii(&self, max_cols: u32) -> String {
+        if max_cols == 0 {
+            return String::new();
+        }
+        let cols = self.width.min(max_cols);
+        let shrink = f64::from(cols) / f64::from(self.width);
+        let rows = (f64::from(self.height) * shrink / 2.0).round().max(1.0) as usize;
+
+        let line = "#".repeat(cols as usize);
+        let mut drawing = String::new();
+        for _ in 0..rows {
+            drawing.push_str(&line);
+            drawing.push('\n');
+        }
+        drawing
+    }
+}
+
+/* Comparing Rectangles */
+/* One rectangle is bigger than another if it has more area. Two with the
+ * same area are put in order by width, narrower first, so that 'Ord' is a
+ * total order: with the area and the width the same, so is the height,
+ * and the two are equal by 'PartialEq' too, as 'Ord' requires. */
+impl Ord for Rectangle {
+    fn cmp(&self, other: &Rectangle) -> Ordering {
+        (self.area(), self.width).cmp(&(other.area(), other.width))
+    }
+}
+
+impl PartialOrd for Rectangle {
+    fn partial_cmp(&self, other: &Rectangle) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/* 'None' for no rectangles at all. */
+pub fn largest_rect(rects: &[Rectangle]) -> Option<&Rectangle> {
+    rects.iter().max()
+}
+
+/* Smallest first. This takes a slice rather than a '&mut Vec', which a
+ * '&mut Vec' turns into on its own, so it can sort part of a list too. */
+pub fn sort_by_area(rects: &mut [Rectangle]) {
+    rects.sort();
+}
+
+/* Resizing */
+/* Making a thumbnail of an image means changing its size without
+ * stretching it: the ratio of width to height has to stay the same.
+ * 'resize_to_width' picks the new width and works out the height to go
+ * with it; 'fit_within' finds the largest size that fits inside 'bounds'
+ * (touching its edges is fine, unlike 'can_hold'), growing the rectangle
+ * if it's smaller.
+ *
+ * The side that's worked out is rarely a whole number, so the caller
+ * says which way to round it with 'Rounding'. Rounding down a very thin
+ * rectangle's side can leave nothing of it, and a new side can be too
+ * big, so both return a 'Result'. Rounding up in 'fit_within' never pokes
+ * out of 'bounds': the exact size fits, and 'bounds' has whole sides. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    #[default]
+    Nearest,
+    Down,
+    Up,
+}
+
+/* 'side * numerator / denominator', rounded. None of these can overflow a
+ * 'u64', with each number at most a 'u32'. */
+fn scale_side(side: u32, numerator: u32, denominator: u32, rounding: Rounding) -> u64 {
+    let product = u64::from(side) * u64::from(numerator);
+    let denominator = u64::from(denominator);
+    let extra = match rounding {
+        Rounding::Nearest => denominator / 2,
+        Rounding::Down => 0,
+        Rounding::Up => denominator - 1,
+    };
+    (product + extra) / denominator
+}
+
+impl Rectangle {
+    pub fn aspect_ratio(&self) -> f64 {
+        f64::from(self.width) / f64::from(self.height)
+    }
+
+    pub fn resize_to_width(
+        &self,
+        width: u32,
+        rounding: Rounding,
+    ) -> Result<Rectangle, DimensionError> {
+        let height = scale_side(self.height, width, self.width, rounding);
+        let height = u32::try_from(height).map_err(|_| DimensionError::HeightOverflow)?;
+        Rectangle::try_new(width, height)
+    }
+
+    pub fn fit_within(
+        &self,
+        bounds: &Rectangle,
+        rounding: Rounding,
+    ) -> Result<Rectangle, DimensionError> {
+        /* Whichever side has less room to grow decides the size:
+         * 'width / height >= bounds.width / bounds.height', multiplied
+         * out to stay in whole numbers. */
+        let wide = u64::from(self.width) * u64::from(bounds.height)
+            >= u64::from(self.height) * u64::from(bounds.width);
+        if wide {
+            self.resize_to_width(bounds.width, rounding)
+        } else {
+            let width = scale_side(self.width, bounds.height, self.height, rounding);
+            let width = u32::try_from(width).map_err(|_| DimensionError::WidthOverflow)?;
+            Rectangle::try_new(width, bounds.height)
+        }
+    }
+}
+
+/* Reading a Rectangle */
+/* A rectangle on the command line or in a file is written as its width
+ * and height, either with an 'x' between them, as in "30x50", or with
+ * spaces, as in "30 50". Spaces around the numbers don't matter, so
+ * "30 x 50" works too. 'to_spec_string' writes the first form, which
+ * 'parse' reads back as the same rectangle. */
+impl FromStr for Rectangle {
+    type Err = ParseRectError;
+
+    fn from_str(spec: &str) -> Result<Rectangle, ParseRectError> {
+        let format_error = || ParseRectError::Format(spec.to_string());
+        let sides: Vec<&str> = match spec.split_once(['x', 'X']) {
+            Some((width, height)) => vec![width.trim(), height.trim()],
+            None => spec.split_whitespace().collect(),
+        };
+        let [width, height] = sides[..] else {
+            return Err(format_error());
+        };
+        if width.is_empty() || height.is_empty() {
+            return Err(format_error());
+        }
+        let number = |side: &str| {
+            side.parse::<u32>()
+                .map_err(|_| ParseRectError::InvalidNumber(side.to_string()))
+        };
+        Ok(Rectangle::try_new(number(width)?, number(height)?)?)
+    }
+}
+
+impl Rectangle {
+    pub fn to_spec_string(&self) -> String {
+        format!("{}x{}", self.width, self.height)
+    }
+}
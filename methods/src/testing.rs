@@ -0,0 +1,46 @@
+/* Random Rectangles for Tests */
+/* The property tests in tests.rs need rectangles by the hundred. 'Gen'
+ * (from test_support) makes them from a seeded 'Rng', so a run that fails
+ * can be replayed with the 'TEST_SEED' it prints, and shrinks a failing
+ * rectangle one side at a time towards a 1 by 1 square, so the rectangle
+ * in the failure message is as small as it can be.
+ *
+ * 'max_side' keeps the sides in '1..=max_side'; a property about 'scale',
+ * say, can ask for rectangles small enough to scale. */
+use crate::rectangle::Rectangle;
+use test_support::gen::{self, Gen, Ints};
+use test_support::Rng;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Rectangles {
+    sides: (Ints, Ints),
+}
+
+pub fn rectangles(max_side: u32) -> Rectangles {
+    let side = gen::ints(1, i64::from(max_side));
+    Rectangles {
+        sides: (side, side),
+    }
+}
+
+fn from_sides((width, height): (i64, i64)) -> Rectangle {
+    Rectangle::try_new(width as u32, height as u32).expect("sides from 'rectangles' are valid")
+}
+
+impl Gen for Rectangles {
+    type Value = Rectangle;
+
+    fn generate(&self, rng: &mut Rng) -> Rectangle {
+        from_sides(self.sides.generate(rng))
+    }
+
+    fn shrink(&self, rect: &Rectangle) -> Vec<Rectangle> {
+        let (width, height) = rect.dimensions();
+        let sides = (i64::from(width), i64::from(height));
+        self.sides
+            .shrink(&sides)
+            .into_iter()
+            .map(from_sides)
+            .collect()
+    }
+}
@@ -2,39 +2,683 @@
 mod tests {
     use crate::Rectangle;
 
+    use crate::builder::RectangleBuilder;
+    use crate::error::{BuildError, DimensionError, ParseRectError};
+    use crate::rectangle::{FitStrategy, Rounding};
+    use crate::testing::rectangles;
+    use crate::units::{Millimeters, Pixels};
+    use crate::*;
+    use std::num::NonZeroU16;
+    use test_support::gen::{self, assert_property};
+
     #[test]
     fn test_area() {
-        let rectangle = Rectangle {
-            width: 30,
-            height: 50,
-        };
+        let rectangle = Rectangle::try_new(30, 50).unwrap();
         assert_eq!(rectangle.area(), 1500);
     }
 
     #[test]
     fn test_width() {
-        let rect = Rectangle {
-            width: 10,
-            height: 20,
-        };
+        let rect = Rectangle::try_new(10, 20).unwrap();
         assert_eq!(rect.width(), true);
     }
 
     #[test]
     fn test_can_hold() {
-        let rect1 = Rectangle {
-            width: 30,
-            height: 50,
-        };
-        let rect2 = Rectangle {
-            width: 10,
-            height: 40,
-        };
-        let rect3 = Rectangle {
-            width: 60,
-            height: 45,
-        };
+        let rect1 = Rectangle::try_new(30, 50).unwrap();
+        let rect2 = Rectangle::try_new(10, 40).unwrap();
+        let rect3 = Rectangle::try_new(60, 45).unwrap();
         assert_eq!(rect1.can_hold(&rect2), true);
         assert_eq!(rect1.can_hold(&rect3), false);
     }
+
+    fn rect(width: u32, height: u32) -> Rectangle {
+        RectangleBuilder::new()
+            .width(width)
+            .height(height)
+            .build()
+            .unwrap()
+    }
+
+    fn square(size: u32) -> Rectangle {
+        RectangleBuilder::new().square(size).build().unwrap()
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(rect(30, 50).dimensions(), (30, 50));
+        assert_eq!(Rectangle::try_new(0, 50), Err(DimensionError::Zero));
+        assert_eq!(Rectangle::try_new(30, 0), Err(DimensionError::Zero));
+        assert_eq!(
+            Rectangle::try_new(u32::MAX_SIDE + 1, 50),
+            Err(DimensionError::WidthOverflow)
+        );
+        assert_eq!(
+            Rectangle::try_new(30, u32::MAX),
+            Err(DimensionError::HeightOverflow)
+        );
+        assert!(Rectangle::try_new(u32::MAX_SIDE, u32::MAX_SIDE).is_ok());
+        assert_eq!(square(u32::MAX_SIDE).area(), 65_535 * 65_535);
+    }
+
+    #[test]
+    fn test_try_new_with_max() {
+        assert!(Rectangle::try_new_with_max(1024, 768, 1024).is_ok());
+        assert_eq!(
+            Rectangle::try_new_with_max(1920, 1200, 1024),
+            Err(DimensionError::WidthOverflow)
+        );
+        assert_eq!(
+            Rectangle::try_new_with_max(0, 1200, 1024),
+            Err(DimensionError::Zero)
+        );
+        /* A caller's limit can only be tighter than 'MAX_SIDE', not looser. */
+        assert_eq!(
+            Rectangle::try_new_with_max(100_000, 1, u32::MAX),
+            Err(DimensionError::WidthOverflow)
+        );
+    }
+
+    #[test]
+    fn test_square() {
+        assert_eq!(square(4).dimensions(), (4, 4));
+        assert_eq!(Rectangle::square(0), Err(DimensionError::Zero));
+        assert_eq!(Rect::<i64>::square(-3), Err(DimensionError::Zero));
+    }
+
+    #[test]
+    fn test_perimeter() {
+        let rect = rect(30, 50);
+        assert_eq!(rect.perimeter(), 160);
+
+        let huge = square(u32::MAX_SIDE);
+        assert_eq!(huge.perimeter(), 4 * u64::from(u32::MAX_SIDE));
+    }
+
+    #[test]
+    fn test_scale() {
+        let rect = rect(30, 50);
+        let scaled = rect.scale(2).unwrap();
+        assert_eq!(scaled.dimensions(), (60, 100));
+        assert_eq!(rect.scale(0), Err(DimensionError::Zero));
+
+        let wide = Rectangle::try_new(u32::MAX_SIDE / 2 + 1, 1).unwrap();
+        assert_eq!(wide.scale(2).unwrap_err(), DimensionError::WidthOverflow);
+        let tall = Rectangle::try_new(1, u32::MAX_SIDE).unwrap();
+        assert_eq!(tall.scale(2).unwrap_err(), DimensionError::HeightOverflow);
+        /* Overflowing the 'u32' itself is the same error. */
+        assert_eq!(
+            tall.scale(u32::MAX).unwrap_err(),
+            DimensionError::HeightOverflow
+        );
+    }
+
+    #[test]
+    fn test_grow() {
+        let mut rect = rect(30, 50);
+        rect.grow(5, 10).unwrap();
+        assert_eq!(rect.dimensions(), (35, 60));
+
+        /* A failed 'grow' leaves both sides as they were, even the one that
+         * would have fit. */
+        let mut tall = Rectangle::try_new(1, u32::MAX_SIDE).unwrap();
+        assert_eq!(tall.grow(1, 1), Err(DimensionError::HeightOverflow));
+        assert_eq!(tall.dimensions(), (1, u32::MAX_SIDE));
+        assert_eq!(tall.grow(u32::MAX, 0), Err(DimensionError::WidthOverflow));
+        assert_eq!(tall.dimensions(), (1, u32::MAX_SIDE));
+    }
+
+    #[test]
+    fn test_generic_area() {
+        let float = Rect::try_new(2.5, 4.0).unwrap();
+        assert_eq!(float.area(), 10.0);
+
+        let big = Rect::<i64>::try_new(3_000_000_000, 2).unwrap();
+        assert_eq!(big.area(), 6_000_000_000);
+        assert_eq!(Rect::<i64>::square(3).unwrap().area(), 9);
+        assert_eq!(
+            Rect::<i64>::square(i64::MAX_SIDE).unwrap().area(),
+            i64::MAX_SIDE * i64::MAX_SIDE
+        );
+    }
+
+    #[test]
+    fn test_generic_try_new() {
+        assert_eq!(Rect::try_new(-1.0, 4.0), Err(DimensionError::Zero));
+        assert_eq!(Rect::try_new(f64::NAN, 4.0), Err(DimensionError::Zero));
+        assert_eq!(
+            Rect::try_new(1.0, f64::INFINITY),
+            Err(DimensionError::HeightOverflow)
+        );
+        assert!(Rect::square(f64::MAX_SIDE).unwrap().area().is_finite());
+        assert_eq!(
+            Rect::<i64>::try_new(i64::MAX_SIDE + 1, 1),
+            Err(DimensionError::WidthOverflow)
+        );
+    }
+
+    #[test]
+    fn test_generic_can_hold() {
+        let outer = Rect::try_new(3.0, 2.0).unwrap();
+        let inner = Rect::try_new(2.9, 1.5).unwrap();
+        assert!(outer.can_hold(&inner));
+        assert!(!inner.can_hold(&outer));
+        assert!(!outer.can_hold(&outer));
+
+        let wide = Rect::<i64>::try_new(10, 1).unwrap();
+        assert!(Rect::<i64>::square(11).unwrap().can_hold(&wide));
+    }
+
+    #[test]
+    fn test_add() {
+        let sum = rect(30, 50) + rect(10, 40);
+        assert_eq!(sum, rect(40, 90));
+        assert_eq!(
+            rect(u32::MAX_SIDE - 1, 1) + square(1),
+            rect(u32::MAX_SIDE, 2)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "rectangle addition failed: the height")]
+    fn test_add_overflow() {
+        let tall = rect(1, u32::MAX_SIDE);
+        let _ = tall + square(1);
+    }
+
+    #[test]
+    fn test_mul() {
+        assert_eq!(rect(30, 50) * 2, rect(60, 100));
+        assert_eq!(square(7) * 3, square(21));
+        assert_eq!(square(u32::MAX_SIDE) * 1, square(u32::MAX_SIDE));
+    }
+
+    #[test]
+    #[should_panic(expected = "rectangle scaling failed: the width")]
+    fn test_mul_overflow() {
+        let _ = square(u32::MAX_SIDE / 2 + 1) * 2;
+    }
+
+    #[test]
+    #[should_panic(expected = "rectangle scaling failed: a rectangle's sides")]
+    fn test_mul_by_zero() {
+        let factor = 0;
+        let _ = square(7) * factor;
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(square(3), square(3));
+        assert_ne!(rect(2, 3), rect(3, 2));
+        assert_eq!(Rect::square(0.5), Rect::square(0.5));
+    }
+
+    #[test]
+    fn test_from_pairs() {
+        assert_eq!(Rectangle::try_from((30_u32, 50_u32)), Ok(rect(30, 50)));
+        assert_eq!(
+            Rectangle::try_from((0_u32, 50_u32)),
+            Err(DimensionError::Zero)
+        );
+        assert_eq!(Rectangle::try_from((30_i64, 50_i64)), Ok(rect(30, 50)));
+        assert_eq!(
+            Rectangle::try_from((-30_i64, 50_i64)),
+            Err(DimensionError::Zero)
+        );
+        assert_eq!(
+            Rectangle::try_from((30_i64, i64::from(u32::MAX) + 1)),
+            Err(DimensionError::HeightOverflow)
+        );
+        assert_eq!(
+            Rectangle::try_from((i64::from(u32::MAX_SIDE) + 1, 50_i64)),
+            Err(DimensionError::WidthOverflow)
+        );
+
+        let side = |n| NonZeroU16::new(n).unwrap();
+        assert_eq!(Rectangle::from((side(30), side(50))), rect(30, 50));
+        assert_eq!(
+            Rectangle::from((side(u16::MAX), side(1))).dimensions(),
+            (u32::MAX_SIDE, 1)
+        );
+    }
+
+    #[test]
+    fn test_into_pair() {
+        let pair: (u32, u32) = rect(30, 50).into();
+        assert_eq!(pair, (30, 50));
+        assert_eq!(
+            Rectangle::try_from(<(u32, u32)>::from(rect(7, 9))),
+            Ok(rect(7, 9))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(rect(30, 50).to_string(), "30 by 50");
+        assert_eq!(Rect::try_new(2.5, 4.0).unwrap().to_string(), "2.5 by 4");
+    }
+
+    #[test]
+    fn test_render_ascii() {
+        /* Small enough to draw at full width, with half as many rows. */
+        assert_eq!(rect(4, 4).render_ascii(10), "####\n####\n");
+        /* 60 columns shrink to 20, so 30 rows become 10, then 5 for the
+         * character shape. */
+        let drawing = rect(60, 30).render_ascii(20);
+        let lines: Vec<&str> = drawing.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines.iter().all(|line| *line == "#".repeat(20)));
+        /* A very wide rectangle still gets one row. */
+        assert_eq!(rect(u32::MAX_SIDE, 1).render_ascii(3), "###\n");
+        assert_eq!(rect(30, 50).render_ascii(0), "");
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(rect(60, 45) > rect(30, 50));
+        assert!(rect(10, 40) < rect(30, 50));
+        /* Same area: the narrower one comes first. */
+        assert!(rect(20, 30) < rect(30, 20));
+        assert_eq!(rect(6, 4).cmp(&rect(6, 4)), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn test_largest_rect() {
+        assert_eq!(largest_rect(&[]), None);
+        let rects = [rect(30, 50), rect(60, 45), rect(10, 40)];
+        assert_eq!(largest_rect(&rects), Some(&rect(60, 45)));
+        let ties = [rect(30, 20), rect(20, 30)];
+        assert_eq!(largest_rect(&ties), Some(&rect(30, 20)));
+    }
+
+    #[test]
+    fn test_sort_by_area() {
+        let mut rects = vec![rect(60, 45), rect(30, 20), rect(10, 40), rect(20, 30)];
+        sort_by_area(&mut rects);
+        assert_eq!(
+            rects,
+            [rect(10, 40), rect(20, 30), rect(30, 20), rect(60, 45)]
+        );
+        assert!(rects
+            .windows(2)
+            .all(|pair| pair[0].area() <= pair[1].area()));
+    }
+
+    #[test]
+    fn test_fit_strategy() {
+        let door = rect(90, 200);
+        assert_eq!(door.fit_strategy(&rect(80, 150)), FitStrategy::FitsAsIs);
+        assert_eq!(door.fit_strategy(&rect(150, 80)), FitStrategy::FitsRotated);
+        assert_eq!(door.fit_strategy(&rect(100, 100)), FitStrategy::DoesNotFit);
+        /* Same as 'can_hold': touching the edges doesn't count. */
+        assert_eq!(door.fit_strategy(&rect(200, 80)), FitStrategy::DoesNotFit);
+        assert_eq!(door.fit_strategy(&rect(90, 200)), FitStrategy::DoesNotFit);
+
+        let floats = Rect::try_new(2.0, 5.0).unwrap();
+        assert_eq!(
+            floats.fit_strategy(&Rect::try_new(4.5, 1.5).unwrap()),
+            FitStrategy::FitsRotated
+        );
+    }
+
+    #[test]
+    fn test_can_hold_rotated() {
+        let rect1 = rect(30, 50);
+        assert!(rect1.can_hold_rotated(&rect(10, 40)));
+        assert!(rect1.can_hold_rotated(&rect(40, 10)));
+        assert!(!rect1.can_hold(&rect(40, 10)));
+        assert!(!rect1.can_hold_rotated(&rect(60, 45)));
+        /* Anything 'can_hold' accepts, 'can_hold_rotated' accepts too. */
+        for other in [rect(1, 1), rect(29, 49), rect(20, 20)] {
+            assert!(!rect1.can_hold(&other) || rect1.can_hold_rotated(&other));
+        }
+    }
+
+    #[test]
+    fn test_unit_arithmetic() {
+        assert_eq!(Millimeters(30) + Millimeters(12), Millimeters(42));
+        assert_eq!(Millimeters(30) - Millimeters(12), Millimeters(18));
+        assert_eq!(Pixels(30) * 3, Pixels(90));
+        assert_eq!(Millimeters(12) - Millimeters(30), Millimeters(0));
+        assert_eq!(Pixels(u32::MAX) + Pixels(1), Pixels(u32::MAX));
+        assert_eq!(Pixels(u32::MAX) * Pixels(2), Pixels(u32::MAX));
+        assert_eq!(Millimeters(u32::MAX) * 2, Millimeters(u32::MAX));
+        assert_eq!(u32::from(Pixels(7)), 7);
+        assert_eq!(Millimeters(210).to_string(), "210 mm");
+        assert_eq!(Pixels(794).to_string(), "794 px");
+    }
+
+    #[test]
+    fn test_unit_conversions() {
+        /* 25.4 mm is an inch. */
+        assert_eq!(Millimeters(254).to_pixels(96), Some(Pixels(960)));
+        assert_eq!(Millimeters(210).to_pixels(96), Some(Pixels(794)));
+        assert_eq!(Pixels(794).to_millimeters(96), Some(Millimeters(210)));
+        assert_eq!(Pixels(96).to_millimeters(0), None);
+        assert_eq!(Millimeters(u32::MAX).to_pixels(300), None);
+    }
+
+    #[test]
+    fn test_unit_rectangles() {
+        let page = Rect::try_new(Millimeters(210), Millimeters(297)).unwrap();
+        assert_eq!(page.to_string(), "210 mm by 297 mm");
+        let card = Rect::try_new(Millimeters(85), Millimeters(54)).unwrap();
+        assert!(page.can_hold(&card));
+        assert_eq!(card.area(), Millimeters(85 * 54));
+
+        let screen = page.to_pixels(96).unwrap();
+        assert_eq!(screen.dimensions(), (Pixels(794), Pixels(1123)));
+        assert_eq!(
+            Rect::square(Millimeters(1)).unwrap().to_pixels(1),
+            Err(DimensionError::Zero)
+        );
+        assert_eq!(
+            Rect::square(Millimeters(u32::MAX_SIDE))
+                .unwrap()
+                .to_pixels(96),
+            Err(DimensionError::WidthOverflow)
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("30x50".parse(), Ok(rect(30, 50)));
+        assert_eq!("30X50".parse(), Ok(rect(30, 50)));
+        assert_eq!(" 30 x 50 ".parse(), Ok(rect(30, 50)));
+        assert_eq!("30 50".parse(), Ok(rect(30, 50)));
+        assert_eq!("30\t 50\n".parse(), Ok(rect(30, 50)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let format = |spec: &str| Err(ParseRectError::Format(spec.to_string()));
+        assert_eq!("".parse::<Rectangle>(), format(""));
+        assert_eq!("30".parse::<Rectangle>(), format("30"));
+        assert_eq!("30 50 70".parse::<Rectangle>(), format("30 50 70"));
+        assert_eq!("x50".parse::<Rectangle>(), format("x50"));
+        assert_eq!("30x".parse::<Rectangle>(), format("30x"));
+
+        let number = |side: &str| Err(ParseRectError::InvalidNumber(side.to_string()));
+        assert_eq!("30 by 50".parse::<Rectangle>(), format("30 by 50"));
+        assert_eq!("thirty x 50".parse::<Rectangle>(), number("thirty"));
+        assert_eq!("30x50x70".parse::<Rectangle>(), number("50x70"));
+        assert_eq!("-30 50".parse::<Rectangle>(), number("-30"));
+
+        assert_eq!(
+            "0x50".parse::<Rectangle>(),
+            Err(ParseRectError::Dimension(DimensionError::Zero))
+        );
+        assert_eq!(
+            "30x99999".parse::<Rectangle>(),
+            Err(ParseRectError::Dimension(DimensionError::HeightOverflow))
+        );
+        let message = "30".parse::<Rectangle>().unwrap_err().to_string();
+        assert!(message.contains("'30x50'"));
+    }
+
+    #[test]
+    fn test_spec_round_trip() {
+        for rect in [rect(30, 50), square(1), square(u32::MAX_SIDE)] {
+            let spec = rect.to_spec_string();
+            assert_eq!(spec.parse(), Ok(rect));
+        }
+        assert_eq!(rect(30, 50).to_spec_string(), "30x50");
+    }
+
+    #[test]
+    fn test_aspect_ratio() {
+        assert_eq!(rect(4000, 3000).aspect_ratio(), 4.0 / 3.0);
+        assert_eq!(square(7).aspect_ratio(), 1.0);
+    }
+
+    #[test]
+    fn test_resize_to_width() {
+        let photo = rect(4000, 3000);
+        assert_eq!(
+            photo.resize_to_width(400, Rounding::Nearest),
+            Ok(rect(400, 300))
+        );
+        assert_eq!(
+            photo.resize_to_width(8000, Rounding::Down),
+            Ok(rect(8000, 6000))
+        );
+        /* 1366 * 3 / 4 = 1024.5 */
+        assert_eq!(
+            photo.resize_to_width(1366, Rounding::Down),
+            Ok(rect(1366, 1024))
+        );
+        assert_eq!(
+            photo.resize_to_width(1366, Rounding::Up),
+            Ok(rect(1366, 1025))
+        );
+        assert_eq!(
+            photo.resize_to_width(1366, Rounding::Nearest),
+            Ok(rect(1366, 1025))
+        );
+        /* 1365 * 3 / 4 = 1023.75 */
+        assert_eq!(
+            photo.resize_to_width(1365, Rounding::Nearest),
+            Ok(rect(1365, 1024))
+        );
+
+        let strip = rect(1000, 1);
+        assert_eq!(
+            strip.resize_to_width(10, Rounding::Down),
+            Err(DimensionError::Zero)
+        );
+        assert_eq!(strip.resize_to_width(10, Rounding::Up), Ok(rect(10, 1)));
+        assert_eq!(
+            rect(1, 1000).resize_to_width(100, Rounding::Nearest),
+            Err(DimensionError::HeightOverflow)
+        );
+        assert_eq!(
+            photo.resize_to_width(0, Rounding::Up),
+            Err(DimensionError::Zero)
+        );
+    }
+
+    #[test]
+    fn test_fit_within() {
+        let bounds = square(160);
+        assert_eq!(
+            rect(4000, 3000).fit_within(&bounds, Rounding::Nearest),
+            Ok(rect(160, 120))
+        );
+        assert_eq!(
+            rect(3000, 4000).fit_within(&bounds, Rounding::Nearest),
+            Ok(rect(120, 160))
+        );
+        /* Smaller rectangles grow to fit. */
+        assert_eq!(
+            rect(2, 1).fit_within(&bounds, Rounding::Nearest),
+            Ok(rect(160, 80))
+        );
+        assert_eq!(
+            square(3).fit_within(&rect(10, 20), Rounding::Down),
+            Ok(square(10))
+        );
+
+        /* 100 * 160 / 300 = 53.3 */
+        let tall = rect(100, 300);
+        assert_eq!(tall.fit_within(&bounds, Rounding::Down), Ok(rect(53, 160)));
+        assert_eq!(tall.fit_within(&bounds, Rounding::Up), Ok(rect(54, 160)));
+
+        /* Whatever the rounding, the result stays inside the bounds. */
+        for (width, height) in [(7, 3), (3, 7), (1, 1), (999, 1000), (65_535, 2)] {
+            for rounding in [Rounding::Nearest, Rounding::Down, Rounding::Up] {
+                if let Ok(fitted) = rect(width, height).fit_within(&rect(100, 90), rounding) {
+                    let (w, h) = fitted.dimensions();
+                    assert!(w <= 100 && h <= 90, "{fitted} with {rounding:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_builder() {
+        let built = RectangleBuilder::new()
+            .width(30)
+            .height(50)
+            .max_area(10_000)
+            .build();
+        assert_eq!(built, Ok(rect(30, 50)));
+        assert_eq!(
+            RectangleBuilder::new().square(4).build(),
+            Rectangle::square(4).map_err(BuildError::from)
+        );
+        /* The last setting wins. */
+        let builder = RectangleBuilder::new().square(4).height(9);
+        assert_eq!(builder.build(), Ok(rect(4, 9)));
+        /* 'build' borrows the builder, so one builder can make many. */
+        assert_eq!(builder.build(), builder.build());
+    }
+
+    #[test]
+    fn test_builder_golden() {
+        assert_eq!(RectangleBuilder::new().golden(89).build(), Ok(rect(89, 55)));
+        assert_eq!(RectangleBuilder::new().golden(1).build(), Ok(square(1)));
+        assert_eq!(
+            RectangleBuilder::new().golden(1000).build(),
+            Ok(rect(1000, 618))
+        );
+        assert_eq!(
+            RectangleBuilder::new().golden(0).build(),
+            Err(BuildError::Dimension(DimensionError::Zero))
+        );
+    }
+
+    #[test]
+    fn test_builder_errors() {
+        assert_eq!(
+            RectangleBuilder::new().height(50).build(),
+            Err(BuildError::MissingWidth)
+        );
+        assert_eq!(
+            RectangleBuilder::new().width(30).build(),
+            Err(BuildError::MissingHeight)
+        );
+        assert_eq!(
+            RectangleBuilder::new()
+                .width(300)
+                .height(400)
+                .max_area(100_000)
+                .build(),
+            Err(BuildError::AreaTooLarge {
+                area: 120_000,
+                max: 100_000
+            })
+        );
+        assert!(RectangleBuilder::new()
+            .square(100)
+            .max_area(10_000)
+            .build()
+            .is_ok());
+        assert_eq!(
+            RectangleBuilder::new()
+                .width(1920)
+                .height(1080)
+                .max_side(1024)
+                .build(),
+            Err(BuildError::Dimension(DimensionError::WidthOverflow))
+        );
+        assert_eq!(
+            RectangleBuilder::new().width(0).height(1).build(),
+            Err(BuildError::Dimension(DimensionError::Zero))
+        );
+    }
+
+    /* Properties */
+    /* The same checks as above, over random rectangles (see testing.rs). */
+    #[test]
+    fn test_property_can_hold_means_bigger() {
+        let pairs = (rectangles(100), rectangles(100));
+        assert_property(&pairs, |(a, b)| !a.can_hold(b) || a.area() > b.area());
+    }
+
+    #[test]
+    fn test_property_scale_by_two() {
+        /* Small enough that doubling them stays under 'MAX_SIDE'. */
+        assert_property(&rectangles(u32::MAX_SIDE / 2), |rect| {
+            rect.scale(2).map(|scaled| scaled.area()) == Ok(rect.area() * 4)
+        });
+        /* Any bigger and 'scale' says which side didn't fit. */
+        assert_property(&rectangles(u32::MAX_SIDE), |rect| {
+            let (width, height) = rect.dimensions();
+            match rect.scale(2) {
+                Ok(_) => width <= u32::MAX_SIDE / 2 && height <= u32::MAX_SIDE / 2,
+                Err(DimensionError::WidthOverflow) => width > u32::MAX_SIDE / 2,
+                Err(DimensionError::HeightOverflow) => height > u32::MAX_SIDE / 2,
+                Err(DimensionError::Zero) => false,
+            }
+        });
+    }
+
+    #[test]
+    fn test_property_grow_keeps_or_fails() {
+        let cases = (
+            rectangles(u32::MAX_SIDE),
+            (gen::ints(0, 1000), gen::ints(0, 1000)),
+        );
+        assert_property(&cases, |(rect, (dw, dh))| {
+            let (width, height) = rect.dimensions();
+            let (dw, dh) = (*dw as u32, *dh as u32);
+            let mut grown = rect.clone();
+            match grown.grow(dw, dh) {
+                Ok(()) => grown.dimensions() == (width + dw, height + dh),
+                Err(_) => {
+                    grown == *rect && (width + dw > u32::MAX_SIDE || height + dh > u32::MAX_SIDE)
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn test_property_rotation() {
+        let pairs = (rectangles(50), rectangles(50));
+        assert_property(&pairs, |(a, b)| {
+            let (width, height) = b.dimensions();
+            let turned = rect(height, width);
+            a.can_hold_rotated(b) == (a.can_hold(b) || a.can_hold(&turned))
+        });
+    }
+
+    #[test]
+    fn test_property_order_follows_area() {
+        let pairs = (rectangles(100), rectangles(100));
+        assert_property(&pairs, |(a, b)| {
+            a.cmp(b) == b.cmp(a).reverse() && (a < b) <= (a.area() <= b.area())
+        });
+        assert_property(&gen::vecs(rectangles(100), 20), |rects| {
+            let mut sorted = rects.clone();
+            sort_by_area(&mut sorted);
+            let largest = largest_rect(rects);
+            sorted.windows(2).all(|pair| pair[0] <= pair[1]) && largest == sorted.last()
+        });
+    }
+
+    #[test]
+    fn test_property_spec_round_trip() {
+        assert_property(&rectangles(u32::MAX_SIDE), |rect| {
+            rect.to_spec_string().parse() == Ok(rect.clone())
+        });
+    }
+
+    #[test]
+    fn test_property_fit_within_bounds() {
+        let pairs = (rectangles(2000), rectangles(2000));
+        assert_property(&pairs, |(rect, bounds)| {
+            [Rounding::Nearest, Rounding::Down, Rounding::Up]
+                .into_iter()
+                .all(|rounding| match rect.fit_within(bounds, rounding) {
+                    Ok(fitted) => {
+                        let (width, height) = fitted.dimensions();
+                        let (max_width, max_height) = bounds.dimensions();
+                        width <= max_width
+                            && height <= max_height
+                            && (width == max_width || height == max_height)
+                    }
+                    Err(e) => e == DimensionError::Zero && rounding != Rounding::Up,
+                })
+        });
+    }
 }
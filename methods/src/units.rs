@@ -0,0 +1,124 @@
+/* Units */
+/* A plain 'u32' side could be in pixels, millimeters, or anything else,
+ * and nothing stops a rectangle measured in one from being compared with
+ * one measured in another. Wrapping the number in a newtype, a tuple
+ * struct with one field, gives each unit a type of its own. Both are
+ * 'Dimension's, so 'Rect<Millimeters>' and 'Rect<Pixels>' get all of
+ * 'Rect's methods, but 'can_hold' between the two doesn't compile: to
+ * compare them, one has to be converted first, which needs the screen's
+ * resolution in dots per inch.
+ *
+ * Only the arithmetic that makes sense within a unit is implemented:
+ * adding and subtracting lengths, and scaling one by a plain number.
+ * Multiplying two lengths is only there because 'area' needs it; strictly
+ * speaking, the result is in square millimeters (or pixels). All of it
+ * saturates instead of overflowing: a length stops at zero going down and
+ * at 'u32::MAX' going up, where a plain 'u32' would panic in a debug
+ * build and wrap around in a release one. */
+use crate::error::DimensionError;
+use crate::rectangle::{Dimension, Rect};
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Millimeters(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Pixels(pub u32);
+
+/* An inch is exactly 25.4 mm, kept as tenths of a millimeter so the
+ * conversions stay in whole numbers. They round to the nearest unit, and
+ * a result too big for a 'u32' is 'None'. */
+const TENTHS_OF_MM_PER_INCH: u64 = 254;
+
+fn convert(value: u32, multiply: u64, divide: u64) -> Option<u32> {
+    let value = u64::from(value) * multiply;
+    u32::try_from((value + divide / 2) / divide).ok()
+}
+
+impl Millimeters {
+    pub fn to_pixels(self, dpi: u32) -> Option<Pixels> {
+        convert(self.0, u64::from(dpi) * 10, TENTHS_OF_MM_PER_INCH).map(Pixels)
+    }
+}
+
+impl Pixels {
+    /* 'None' for a 'dpi' of zero too, since there's no such screen. */
+    pub fn to_millimeters(self, dpi: u32) -> Option<Millimeters> {
+        if dpi == 0 {
+            return None;
+        }
+        convert(self.0, TENTHS_OF_MM_PER_INCH, u64::from(dpi) * 10).map(Millimeters)
+    }
+}
+
+/* The same seven impls for both units, so a macro writes them. */
+macro_rules! unit {
+    ($unit:ident, $suffix:literal) => {
+        impl Dimension for $unit {
+            const ZERO: $unit = $unit(0);
+            const MAX_SIDE: $unit = $unit(u32::MAX_SIDE);
+        }
+
+        impl Add for $unit {
+            type Output = $unit;
+
+            fn add(self, other: $unit) -> $unit {
+                $unit(self.0.saturating_add(other.0))
+            }
+        }
+
+        impl Sub for $unit {
+            type Output = $unit;
+
+            fn sub(self, other: $unit) -> $unit {
+                $unit(self.0.saturating_sub(other.0))
+            }
+        }
+
+        impl Mul for $unit {
+            type Output = $unit;
+
+            fn mul(self, other: $unit) -> $unit {
+                $unit(self.0.saturating_mul(other.0))
+            }
+        }
+
+        impl Mul<u32> for $unit {
+            type Output = $unit;
+
+            fn mul(self, factor: u32) -> $unit {
+                $unit(self.0.saturating_mul(factor))
+            }
+        }
+
+        impl From<$unit> for u32 {
+            fn from(value: $unit) -> u32 {
+                value.0
+            }
+        }
+
+        impl fmt::Display for $unit {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{} {}", self.0, $suffix)
+            }
+        }
+    };
+}
+
+unit!(Millimeters, "mm");
+unit!(Pixels, "px");
+
+/* A whole rectangle, converted a side at a time. A side that rounds down
+ * to nothing, or grows past 'MAX_SIDE', is the same error 'try_new' would
+ * give. */
+impl Rect<Millimeters> {
+    pub fn to_pixels(&self, dpi: u32) -> Result<Rect<Pixels>, DimensionError> {
+        let (width, height) = self.dimensions();
+        let width = width.to_pixels(dpi).ok_or(DimensionError::WidthOverflow)?;
+        let height = height
+            .to_pixels(dpi)
+            .ok_or(DimensionError::HeightOverflow)?;
+        Rect::try_new(width, height)
+    }
+}
@@ -0,0 +1,232 @@
+/* PATTERNS AND MATCHING */
+/* 'Patterns' are a special syntax in Rust for matching against the
+ * structure of types, both complex and simple. A pattern consists of some
+ * combination of literals; destructured arrays, enums, structs, or tuples;
+ * variables; wildcards; and placeholders.
+ *
+ * Rather than a list of isolated 'match' statements, this crate routes
+ * input events - key presses, mouse clicks, window resizes, and chat
+ * messages - to the action an application should take. Each arm of
+ * 'route' uses a different piece of pattern syntax, and the comments above
+ * the arms name the feature being shown. */
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Modifiers {
+    pub ctrl: bool,
+    pub shift: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Key { key: char, modifiers: Modifiers },
+    Click { button: MouseButton, at: Point },
+    Scroll(i32),
+    Resize { width: u32, height: u32 },
+    Message { channel: u32, sender: String, body: String },
+    Quit,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    Exit,
+    Save,
+    SelectAll,
+    Type(char),
+    TypeUpper(char),
+    Focus(Point),
+    ContextMenu(Point),
+    Paste,
+    ScrollBy(i32),
+    ScrollToEdge,
+    Relayout { width: u32, height: u32 },
+    TooSmall,
+    Notify { sender: String },
+    Log(String),
+    Ignore,
+}
+
+/* Screens narrower or shorter than this can't be laid out. */
+pub const MIN_WIDTH: u32 = 20;
+pub const MIN_HEIGHT: u32 = 5;
+
+/* The top row of the window is the title bar; clicks there are ignored. */
+pub const TITLE_BAR_Y: i32 = 0;
+
+pub fn route(event: &Event) -> Action {
+    match event {
+        /* Matching Literals and Destructuring Nested Structs and Enums */
+        /* Patterns can reach through several levels at once: the 'Key'
+         * variant, its 'modifiers' field, and the 'ctrl' field inside
+         * that. Fields we list with a literal must match exactly. */
+        Event::Key {
+            key: 'q',
+            modifiers: Modifiers { ctrl: true, .. },
+        } => Action::Exit,
+
+        /* Multiple Patterns */
+        /* The '|' syntax is the pattern 'or' operator. Here either of two
+         * keys triggers a save, as long as control is held. */
+        Event::Key {
+            key: 's' | 'S',
+            modifiers: Modifiers { ctrl: true, .. },
+        } => Action::Save,
+
+        Event::Key {
+            key: 'a',
+            modifiers:
+                Modifiers {
+                    ctrl: true,
+                    shift: false,
+                },
+        } => Action::SelectAll,
+
+        /* @ Bindings */
+        /* The 'at' operator '@' lets us create a variable that holds a
+         * value at the same time as we're testing that value for a pattern
+         * match - here, that the key is a lowercase ASCII letter. */
+        Event::Key {
+            key: letter @ 'a'..='z',
+            modifiers: Modifiers { ctrl: false, shift },
+        } => {
+            if *shift {
+                Action::TypeUpper(letter.to_ascii_uppercase())
+            } else {
+                Action::Type(*letter)
+            }
+        }
+
+        /* Ignoring Values with _ */
+        /* Any other key press, with any modifiers, does nothing. */
+        Event::Key { .. } => Action::Ignore,
+
+        /* Extra Conditionals with Match Guards */
+        /* A 'match guard' is an additional 'if' condition, specified after
+         * the pattern in a 'match' arm, that must also match for that arm
+         * to be chosen. Guards can express ideas patterns alone can't, like
+         * comparing a field to a constant. */
+        Event::Click {
+            at: Point { y, .. },
+            ..
+        } if *y == TITLE_BAR_Y => Action::Ignore,
+
+        Event::Click {
+            button: MouseButton::Left,
+            at,
+        } => Action::Focus(*at),
+
+        Event::Click {
+            button: MouseButton::Right,
+            at,
+        } => Action::ContextMenu(*at),
+
+        Event::Click {
+            button: MouseButton::Middle,
+            ..
+        } => Action::Paste,
+
+        /* Matching Ranges of Values with ..= */
+        /* Huge scroll deltas (in either direction) jump to the edge
+         * instead of scrolling by the amount. '..=-100' and '100..' are
+         * open-ended range patterns. */
+        Event::Scroll(..=-100 | 100..) => Action::ScrollToEdge,
+        Event::Scroll(0) => Action::Ignore,
+        Event::Scroll(delta) => Action::ScrollBy(*delta),
+
+        /* A guard can mention several bound variables at once. The '||'
+         * inside the guard is the ordinary boolean 'or', not the pattern
+         * '|' from above. */
+        Event::Resize { width, height } if *width < MIN_WIDTH || *height < MIN_HEIGHT => {
+            Action::TooSmall
+        }
+        Event::Resize { width, height } => Action::Relayout {
+            width: *width,
+            height: *height,
+        },
+
+        /* @ bindings also work with ranges of numbers. Channel 0 is the
+         * system channel; its messages go to the log rather than to the
+         * user. Channels 1 through 9 are direct messages, which notify.
+         * 'body' isn't needed in the second arm so it's ignored with '_'. */
+        Event::Message {
+            channel: 0,
+            body,
+            ..
+        } => Action::Log(body.clone()),
+        Event::Message {
+            channel: 1..=9,
+            sender,
+            body: _,
+        } => Action::Notify {
+            sender: sender.clone(),
+        },
+        Event::Message {
+            channel: id @ 10..,
+            body,
+            ..
+        } => Action::Log(format!("#{id}: {body}")),
+
+        Event::Quit => Action::Exit,
+    }
+}
+
+/* Destructuring Structs and Tuples */
+/* Patterns can mix and nest structs and tuples inside one another. This
+ * 'let' pulls the x and y of the first point out of a tuple of points in
+ * one statement, ignoring the second point entirely. */
+pub fn first_x_y(points: (Point, Point)) -> (i32, i32) {
+    let (Point { x, y }, _) = points;
+    (x, y)
+}
+
+/* Destructuring a Point against fixed axes: matching a literal in one
+ * field while binding the other, with the last arm catching everything. */
+pub fn describe_point(p: Point) -> String {
+    match p {
+        Point { x: 0, y: 0 } => String::from("at the origin"),
+        Point { x, y: 0 } => format!("on the x axis at {x}"),
+        Point { x: 0, y } => format!("on the y axis at {y}"),
+        Point { x, y } => format!("on neither axis: ({x}, {y})"),
+    }
+}
+
+/* Ignoring Remaining Parts of a Value with .. */
+/* In a slice pattern, '..' matches any number of elements, so we can pick
+ * out the first and last events of a batch however long the batch is. */
+pub fn first_and_last(events: &[Event]) -> Option<(&Event, &Event)> {
+    match events {
+        [] => None,
+        [only] => Some((only, only)),
+        [first, .., last] => Some((first, last)),
+    }
+}
+
+/* 'if let' takes a pattern too. Routing a batch stops at the first 'Exit'
+ * action, which ends the application. */
+pub fn route_all(events: &[Event]) -> Vec<Action> {
+    let mut actions = Vec::new();
+    for event in events {
+        let action = route(event);
+        if let Action::Exit = action {
+            actions.push(action);
+            break;
+        }
+        actions.push(action);
+    }
+    actions
+}
+
+#[cfg(test)]
+mod tests;
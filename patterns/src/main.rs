@@ -0,0 +1,57 @@
+use patterns::{describe_point, route_all, Event, Modifiers, MouseButton, Point};
+
+fn main() {
+    let events = vec![
+        Event::Key {
+            key: 'h',
+            modifiers: Modifiers {
+                ctrl: false,
+                shift: true,
+            },
+        },
+        Event::Click {
+            button: MouseButton::Right,
+            at: Point { x: 12, y: 3 },
+        },
+        Event::Scroll(-500),
+        Event::Resize {
+            width: 10,
+            height: 10,
+        },
+        Event::Message {
+            channel: 3,
+            sender: String::from("ferris"),
+            body: String::from("lunch?"),
+        },
+        Event::Key {
+            key: 'q',
+            modifiers: Modifiers {
+                ctrl: true,
+                shift: false,
+            },
+        },
+        Event::Scroll(1),
+    ];
+
+    /* 'for' loops take a pattern too: '(event, action)' destructures each
+     * tuple produced by 'zip'. The last event is never routed because the
+     * 'Ctrl+q' before it exits. */
+    for (event, action) in events.iter().zip(route_all(&events)) {
+        println!("{event:?}\n    => {action:?}");
+    }
+
+    let p = Point { x: 0, y: 7 };
+    println!("{:?} is {}", p, describe_point(p));
+
+    /* Refutability */
+    /* Patterns come in two forms: refutable and irrefutable. 'let' only
+     * accepts irrefutable patterns, which will match for any possible value
+     * passed. When uncommented, the below line doesn't compile, because
+     * 'Some(x)' doesn't cover the 'None' case. 'let...else' accepts a
+     * refutable pattern as long as the 'else' branch diverges. */
+    // let Some(x) = events.first();
+    let Some(first) = events.first() else {
+        return;
+    };
+    println!("First event: {first:?}");
+}
@@ -0,0 +1,103 @@
+use crate::*;
+
+fn key(key: char, ctrl: bool, shift: bool) -> Event {
+    Event::Key {
+        key,
+        modifiers: Modifiers { ctrl, shift },
+    }
+}
+
+#[test]
+fn test_key_routing() {
+    assert_eq!(route(&key('q', true, true)), Action::Exit);
+    assert_eq!(route(&key('S', true, true)), Action::Save);
+    assert_eq!(route(&key('a', true, false)), Action::SelectAll);
+    assert_eq!(route(&key('a', true, true)), Action::Ignore);
+    assert_eq!(route(&key('x', false, false)), Action::Type('x'));
+    assert_eq!(route(&key('x', false, true)), Action::TypeUpper('X'));
+    assert_eq!(route(&key('1', false, false)), Action::Ignore);
+}
+
+#[test]
+fn test_click_routing() {
+    let at = Point { x: 3, y: 4 };
+    let click = |button| Event::Click { button, at };
+
+    assert_eq!(route(&click(MouseButton::Left)), Action::Focus(at));
+    assert_eq!(route(&click(MouseButton::Right)), Action::ContextMenu(at));
+    assert_eq!(route(&click(MouseButton::Middle)), Action::Paste);
+
+    let title = Event::Click {
+        button: MouseButton::Left,
+        at: Point { x: 3, y: 0 },
+    };
+    assert_eq!(route(&title), Action::Ignore);
+}
+
+#[test]
+fn test_scroll_and_resize_routing() {
+    assert_eq!(route(&Event::Scroll(-250)), Action::ScrollToEdge);
+    assert_eq!(route(&Event::Scroll(100)), Action::ScrollToEdge);
+    assert_eq!(route(&Event::Scroll(0)), Action::Ignore);
+    assert_eq!(route(&Event::Scroll(-3)), Action::ScrollBy(-3));
+
+    let small = Event::Resize {
+        width: 10,
+        height: 40,
+    };
+    assert_eq!(route(&small), Action::TooSmall);
+    let ok = Event::Resize {
+        width: 80,
+        height: 24,
+    };
+    assert_eq!(
+        route(&ok),
+        Action::Relayout {
+            width: 80,
+            height: 24
+        }
+    );
+}
+
+#[test]
+fn test_message_routing() {
+    let message = |channel| Event::Message {
+        channel,
+        sender: String::from("ferris"),
+        body: String::from("hi"),
+    };
+
+    assert_eq!(route(&message(0)), Action::Log(String::from("hi")));
+    assert_eq!(
+        route(&message(7)),
+        Action::Notify {
+            sender: String::from("ferris")
+        }
+    );
+    assert_eq!(route(&message(42)), Action::Log(String::from("#42: hi")));
+}
+
+#[test]
+fn test_destructuring_helpers() {
+    let a = Point { x: 1, y: 2 };
+    let b = Point { x: 0, y: 7 };
+    assert_eq!(first_x_y((a, b)), (1, 2));
+    assert_eq!(describe_point(b), "on the y axis at 7");
+    assert_eq!(describe_point(Point { x: 0, y: 0 }), "at the origin");
+
+    let events = [Event::Scroll(1), Event::Quit, Event::Scroll(2)];
+    assert_eq!(
+        first_and_last(&events),
+        Some((&Event::Scroll(1), &Event::Scroll(2)))
+    );
+    assert_eq!(first_and_last(&[]), None);
+}
+
+#[test]
+fn test_route_all_stops_at_exit() {
+    let events = [Event::Scroll(1), Event::Quit, Event::Scroll(2)];
+    assert_eq!(
+        route_all(&events),
+        vec![Action::ScrollBy(1), Action::Exit]
+    );
+}
@@ -0,0 +1,101 @@
+/* A Collection of Shapes */
+/* 'ShapeCollection' owns any mix of shapes as 'Box<dyn Shape>'s, the trait
+ * objects from shape.rs, and answers questions about all of them at once.
+ * The answers come from iterators: 'total_area' is a 'sum', 'largest' a
+ * 'max_by', and 'filter_min_area' a 'filter' that hands back the shapes
+ * lazily, without copying them into a new list.
+ *
+ * Looping over a collection with 'for' works the same way as over a
+ * 'Vec': '&collection' gives '&dyn Shape's through 'Iter', an iterator of
+ * our own, and 'collection' itself gives up the boxes. */
+use crate::shape::Shape;
+use std::slice;
+
+#[derive(Default)]
+pub struct ShapeCollection {
+    shapes: Vec<Box<dyn Shape>>,
+}
+
+impl ShapeCollection {
+    pub fn new() -> ShapeCollection {
+        ShapeCollection::default()
+    }
+
+    pub fn push(&mut self, shape: impl Shape + 'static) {
+        self.shapes.push(Box::new(shape));
+    }
+
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    /* The demo in main.rs never has an empty collection, but anything
+     * with a 'len' should have this too. */
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.shapes.iter(),
+        }
+    }
+
+    pub fn total_area(&self) -> f64 {
+        self.iter().map(|shape| shape.area()).sum()
+    }
+
+    /* 'f64' isn't 'Ord', so 'max_by_key' won't take an area; 'total_cmp'
+     * gives the areas an order anyway. With a tie, the later shape wins. */
+    pub fn largest(&self) -> Option<&dyn Shape> {
+        self.iter().max_by(|a, b| a.area().total_cmp(&b.area()))
+    }
+
+    /* The shapes with an area of at least 'min_area'. */
+    pub fn filter_min_area(&self, min_area: f64) -> impl Iterator<Item = &dyn Shape> {
+        self.iter().filter(move |shape| shape.area() >= min_area)
+    }
+}
+
+pub struct Iter<'a> {
+    inner: slice::Iter<'a, Box<dyn Shape>>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a dyn Shape;
+
+    fn next(&mut self) -> Option<&'a dyn Shape> {
+        self.inner.next().map(|shape| shape.as_ref())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> IntoIterator for &'a ShapeCollection {
+    type Item = &'a dyn Shape;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl IntoIterator for ShapeCollection {
+    type Item = Box<dyn Shape>;
+    type IntoIter = std::vec::IntoIter<Box<dyn Shape>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.shapes.into_iter()
+    }
+}
+
+impl FromIterator<Box<dyn Shape>> for ShapeCollection {
+    fn from_iter<I: IntoIterator<Item = Box<dyn Shape>>>(iter: I) -> ShapeCollection {
+        ShapeCollection {
+            shapes: iter.into_iter().collect(),
+        }
+    }
+}
@@ -1,3 +1,13 @@
+mod collection;
+mod placed;
+mod quadtree;
+mod shape;
+
+use collection::ShapeCollection;
+use placed::{PlacedRectangle, Point};
+use quadtree::Quadtree;
+use shape::{describe, Circle, Shape, Triangle};
+
 #[derive(Debug)] // enable the use of debug print on this struct - ':?'
 struct Rectangle {
     width: u32,
@@ -38,8 +48,83 @@ fn main() {
     /* Ownership of rect2 is taken by 'dbg!' macro but then ownership
      * is returned to the place where it was called, then being used
      * as the argument to the string literal formatting. */
+
+    /* Two rectangles with a place on the screen (see placed.rs). */
+    let player = PlacedRectangle::new(
+        0,
+        0,
+        Rectangle {
+            width: 10,
+            height: 20,
+        },
+    );
+    let wall = PlacedRectangle::new(
+        25,
+        -5,
+        Rectangle {
+            width: 5,
+            height: 40,
+        },
+    );
+    println!(
+        "Is (5, 5) on the player?: {}",
+        player.contains_point(Point { x: 5, y: 5 })
+    );
+    println!(
+        "Has the player hit the wall?: {}",
+        player.collides_with(&wall)
+    );
+    println!("The wall is {} away.", player.distance_to(&wall));
+
+    /* A whole row of bricks, and which of them the player is touching
+     * (see quadtree.rs). */
+    let mut bricks = Quadtree::new();
+    for i in 0..50 {
+        let brick = Rectangle {
+            width: 8,
+            height: 4,
+        };
+        bricks.insert(PlacedRectangle::new(i * 10, 18, brick));
+    }
+    let touching = bricks.query(&player);
+    println!(
+        "The player is touching {} of the {} bricks.",
+        touching.len(),
+        bricks.len()
+    );
+
+    /* The same rectangle, this time as one of several shapes (see
+     * shape.rs). */
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(rect1),
+        Box::new(Circle::new(10.0).expect("a valid radius")),
+        Box::new(Triangle::new(3.0, 4.0, 5.0).expect("valid sides")),
+    ];
+    for shape in &shapes {
+        println!("{}", describe(shape.as_ref()));
+    }
+
+    /* All of them together (see collection.rs). */
+    let mut collection: ShapeCollection = shapes.into_iter().collect();
+    collection.push(Circle::new(1.0).expect("a valid radius"));
+    println!(
+        "{} shapes with a total area of {:.2}",
+        collection.len(),
+        collection.total_area()
+    );
+    if let Some(largest) = collection.largest() {
+        println!("The largest is {}", describe(largest));
+    }
+    for shape in collection.filter_min_area(100.0) {
+        println!("At least 100: {}", shape.name());
+    }
+    let names: Vec<&str> = collection.into_iter().map(|shape| shape.name()).collect();
+    println!("Shapes: {}", names.join(", "));
 }
 
 fn area(rectangle: &Rectangle) -> u32 {
     rectangle.width * rectangle.height
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,78 @@
+/* Rectangles in Place */
+/* A 'Rectangle' only has a size. To lay rectangles out on a screen, or to
+ * move them around a game, each one also needs a position: the 'Point'
+ * where its top-left corner is. 'x' grows to the right and 'y' grows
+ * down, the way screen coordinates do.
+ *
+ * A placed rectangle covers the points from its origin up to, but not
+ * including, its origin plus its size, so a 10 by 10 rectangle at (0, 0)
+ * and another at (10, 0) sit side by side without overlapping. The sums
+ * are done in 'i64', where an 'i32' coordinate plus a 'u32' side always
+ * fits. */
+use crate::Rectangle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Debug)]
+pub struct PlacedRectangle {
+    pub origin: Point,
+    pub rect: Rectangle,
+}
+
+impl PlacedRectangle {
+    pub fn new(x: i32, y: i32, rect: Rectangle) -> PlacedRectangle {
+        PlacedRectangle {
+            origin: Point { x, y },
+            rect,
+        }
+    }
+
+    /* The edges, as the sums described above. 'right' and 'bottom' are
+     * just past the rectangle. */
+    pub fn left(&self) -> i64 {
+        i64::from(self.origin.x)
+    }
+
+    pub fn right(&self) -> i64 {
+        self.left() + i64::from(self.rect.width)
+    }
+
+    pub fn top(&self) -> i64 {
+        i64::from(self.origin.y)
+    }
+
+    pub fn bottom(&self) -> i64 {
+        self.top() + i64::from(self.rect.height)
+    }
+
+    pub fn contains_point(&self, point: Point) -> bool {
+        let (x, y) = (i64::from(point.x), i64::from(point.y));
+        (self.left()..self.right()).contains(&x) && (self.top()..self.bottom()).contains(&y)
+    }
+
+    /* Two rectangles collide when they overlap; touching along an edge
+     * isn't enough. */
+    pub fn collides_with(&self, other: &PlacedRectangle) -> bool {
+        self.left() < other.right()
+            && other.left() < self.right()
+            && self.top() < other.bottom()
+            && other.top() < self.bottom()
+    }
+
+    /* The length of the shortest gap between the two rectangles: zero when
+     * they collide or touch, otherwise how far apart they are, straight
+     * across or diagonally from corner to corner. */
+    pub fn distance_to(&self, other: &PlacedRectangle) -> f64 {
+        let dx = (other.left() - self.right())
+            .max(self.left() - other.right())
+            .max(0);
+        let dy = (other.top() - self.bottom())
+            .max(self.top() - other.bottom())
+            .max(0);
+        (dx as f64).hypot(dy as f64)
+    }
+}
@@ -0,0 +1,221 @@
+/* A Quadtree */
+/* Finding the rectangles that overlap a region by checking every one of
+ * them gets slow once there are thousands. A quadtree splits the plane
+ * into four quarters, each quarter into four more, and so on, and files
+ * each rectangle in the smallest quarter it fits in. A query then only
+ * looks in the quarters that touch its region and skips the rest.
+ *
+ * A quarter is only split once it holds more than 'CAPACITY' rectangles,
+ * so a sparse tree stays shallow. A rectangle that lies across the line
+ * between two quarters can't go into either, so it stays with their
+ * parent. The tree starts out just under 2^33 units across, so after
+ * 'MAX_DEPTH' (33) splits a quarter is down to about one unit, and
+ * splitting further couldn't separate anything: the last ones keep
+ * everything they are given. Anything that small is rectangles stacked
+ * on top of each other, which no tree can tell apart.
+ *
+ * The tree covers every position a 'PlacedRectangle' can have, so there's
+ * no outside to worry about. The rectangles themselves are kept in a
+ * 'Vec' in the order they were inserted, and the tree holds their
+ * indexes, which is how 'query' can return them in that order too. */
+use crate::placed::PlacedRectangle;
+
+const CAPACITY: usize = 8;
+/* The furthest a placed rectangle's edges can be from the origin. */
+const MIN: i64 = i32::MIN as i64;
+const MAX: i64 = i32::MAX as i64 + u32::MAX as i64;
+const MAX_DEPTH: u32 = (MAX - MIN).ilog2() + 1;
+
+/* Bounds in 'i64', like the ones 'PlacedRectangle' works out. Unlike a
+ * placed rectangle, these include their right and bottom edges: a
+ * rectangle with no width sitting on the line between two quarters
+ * belongs in one of them, and a query that only touches a quarter's edge
+ * still looks inside. Looking in one quarter too many is harmless, since
+ * every rectangle found is checked with 'collides_with' anyway. */
+#[derive(Debug, Clone, Copy)]
+struct Bounds {
+    left: i64,
+    top: i64,
+    right: i64,
+    bottom: i64,
+}
+
+impl Bounds {
+    fn of(rect: &PlacedRectangle) -> Bounds {
+        Bounds {
+            left: rect.left(),
+            top: rect.top(),
+            right: rect.right(),
+            bottom: rect.bottom(),
+        }
+    }
+
+    fn everywhere() -> Bounds {
+        Bounds {
+            left: MIN,
+            top: MIN,
+            right: MAX,
+            bottom: MAX,
+        }
+    }
+
+    fn contains(&self, other: &Bounds) -> bool {
+        self.left <= other.left
+            && other.right <= self.right
+            && self.top <= other.top
+            && other.bottom <= self.bottom
+    }
+
+    fn touches(&self, other: &Bounds) -> bool {
+        self.left <= other.right
+            && other.left <= self.right
+            && self.top <= other.bottom
+            && other.top <= self.bottom
+    }
+
+    fn quarters(&self) -> [Bounds; 4] {
+        let x = self.left + (self.right - self.left) / 2;
+        let y = self.top + (self.bottom - self.top) / 2;
+        [
+            Bounds {
+                right: x,
+                bottom: y,
+                ..*self
+            },
+            Bounds {
+                left: x,
+                bottom: y,
+                ..*self
+            },
+            Bounds {
+                right: x,
+                top: y,
+                ..*self
+            },
+            Bounds {
+                left: x,
+                top: y,
+                ..*self
+            },
+        ]
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    bounds: Bounds,
+    items: Vec<usize>,
+    quarters: Option<Box<[Node; 4]>>,
+}
+
+impl Node {
+    fn new(bounds: Bounds) -> Node {
+        Node {
+            bounds,
+            items: Vec::new(),
+            quarters: None,
+        }
+    }
+
+    fn insert(&mut self, index: usize, rects: &[PlacedRectangle], depth: u32) {
+        let bounds = Bounds::of(&rects[index]);
+        if let Some(quarters) = self.quarters.as_mut() {
+            if let Some(quarter) = quarters.iter_mut().find(|q| q.bounds.contains(&bounds)) {
+                quarter.insert(index, rects, depth + 1);
+                return;
+            }
+        }
+        self.items.push(index);
+        if self.quarters.is_none() && self.items.len() > CAPACITY && depth < MAX_DEPTH {
+            self.split(rects, depth);
+        }
+    }
+
+    /* Moves down every rectangle that fits in one of the new quarters. */
+    fn split(&mut self, rects: &[PlacedRectangle], depth: u32) {
+        self.quarters = Some(Box::new(self.bounds.quarters().map(Node::new)));
+        for index in std::mem::take(&mut self.items) {
+            self.insert(index, rects, depth);
+        }
+    }
+
+    fn query(&self, region: &PlacedRectangle, rects: &[PlacedRectangle], found: &mut Vec<usize>) {
+        if !self.bounds.touches(&Bounds::of(region)) {
+            return;
+        }
+        found.extend(
+            self.items
+                .iter()
+                .filter(|&&index| rects[index].collides_with(region)),
+        );
+        for quarter in self.quarters.iter().flat_map(|quarters| quarters.iter()) {
+            quarter.query(region, rects, found);
+        }
+    }
+
+    #[cfg(test)]
+    fn candidates(&self, region: &Bounds) -> usize {
+        if !self.bounds.touches(region) {
+            return 0;
+        }
+        let below = self.quarters.iter().flat_map(|quarters| quarters.iter());
+        self.items.len()
+            + below
+                .map(|quarter| quarter.candidates(region))
+                .sum::<usize>()
+    }
+}
+
+#[derive(Debug)]
+pub struct Quadtree {
+    rects: Vec<PlacedRectangle>,
+    root: Node,
+}
+
+impl Default for Quadtree {
+    fn default() -> Quadtree {
+        Quadtree::new()
+    }
+}
+
+impl Quadtree {
+    pub fn new() -> Quadtree {
+        Quadtree {
+            rects: Vec::new(),
+            root: Node::new(Bounds::everywhere()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.rects.len()
+    }
+
+    /* The demo in main.rs never has an empty tree, but anything with a
+     * 'len' should have this too. */
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    pub fn insert(&mut self, rect: PlacedRectangle) {
+        self.rects.push(rect);
+        self.root.insert(self.rects.len() - 1, &self.rects, 0);
+    }
+
+    /* Every rectangle that 'collides_with' the region, in the order they
+     * were inserted. */
+    pub fn query(&self, region: &PlacedRectangle) -> Vec<&PlacedRectangle> {
+        let mut found = Vec::new();
+        self.root.query(region, &self.rects, &mut found);
+        found.sort_unstable();
+        found.into_iter().map(|index| &self.rects[index]).collect()
+    }
+
+    /* How many rectangles 'query' checks with 'collides_with' on the way,
+     * so the tests can tell a tree that skips most of them from one that
+     * only gives the right answers. */
+    #[cfg(test)]
+    pub fn candidates(&self, region: &PlacedRectangle) -> usize {
+        self.root.candidates(&Bounds::of(region))
+    }
+}
@@ -0,0 +1,102 @@
+/* Shapes */
+/* 'Rectangle' is one kind of shape among many. The 'Shape' trait is what
+ * they all have in common: an area, a perimeter, and a name to print. The
+ * measurements are 'f64' rather than the rectangle's 'u32', since a
+ * circle's area is never a whole number.
+ *
+ * 'describe' takes any shape as a '&dyn Shape', a trait object, so the
+ * same function prints a rectangle, a circle, or a triangle, and a list
+ * of different shapes can be a 'Vec<Box<dyn Shape>>'. */
+use crate::Rectangle;
+use std::f64::consts::PI;
+
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn perimeter(&self) -> f64;
+    fn name(&self) -> &'static str;
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        f64::from(self.width) * f64::from(self.height)
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (f64::from(self.width) + f64::from(self.height))
+    }
+
+    fn name(&self) -> &'static str {
+        "rectangle"
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    radius: f64,
+}
+
+impl Circle {
+    /* A radius has to be a real, non-negative length. */
+    pub fn new(radius: f64) -> Option<Circle> {
+        (radius.is_finite() && radius >= 0.0).then_some(Circle { radius })
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+
+    fn name(&self) -> &'static str {
+        "circle"
+    }
+}
+
+/* A triangle given by the lengths of its three sides. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle {
+    sides: [f64; 3],
+}
+
+impl Triangle {
+    /* Not every three lengths make a triangle: each side has to be shorter
+     * than the other two put together. Three sides that only just reach
+     * (like 1, 2, and 3) lie flat, with no area, and are allowed. */
+    pub fn new(a: f64, b: f64, c: f64) -> Option<Triangle> {
+        let sides = [a, b, c];
+        let lengths = sides.iter().all(|side| side.is_finite() && *side >= 0.0);
+        let closes = a <= b + c && b <= a + c && c <= a + b;
+        (lengths && closes).then_some(Triangle { sides })
+    }
+}
+
+impl Shape for Triangle {
+    /* Heron's formula, from the sides alone. Rounding can leave the
+     * product a hair below zero for a flat triangle, hence the 'max'. */
+    fn area(&self) -> f64 {
+        let [a, b, c] = self.sides;
+        let s = self.perimeter() / 2.0;
+        (s * (s - a) * (s - b) * (s - c)).max(0.0).sqrt()
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.sides.iter().sum()
+    }
+
+    fn name(&self) -> &'static str {
+        "triangle"
+    }
+}
+
+pub fn describe(shape: &dyn Shape) -> String {
+    format!(
+        "a {} with an area of {:.2} and a perimeter of {:.2}",
+        shape.name(),
+        shape.area(),
+        shape.perimeter()
+    )
+}
@@ -0,0 +1,276 @@
+use crate::collection::ShapeCollection;
+use crate::placed::{PlacedRectangle, Point};
+use crate::quadtree::Quadtree;
+use crate::shape::{describe, Circle, Shape, Triangle};
+use crate::*;
+use test_support::Rng;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn test_rectangle_shape() {
+    let rect = Rectangle {
+        width: 30,
+        height: 50,
+    };
+    assert_eq!(Shape::area(&rect), f64::from(area(&rect)));
+    assert_eq!(rect.perimeter(), 160.0);
+    assert_eq!(rect.name(), "rectangle");
+}
+
+#[test]
+fn test_circle() {
+    let circle = Circle::new(2.0).unwrap();
+    assert!(close(circle.area(), 4.0 * std::f64::consts::PI));
+    assert!(close(circle.perimeter(), 4.0 * std::f64::consts::PI));
+    assert!(Circle::new(0.0).is_some());
+    assert!(Circle::new(-1.0).is_none());
+    assert!(Circle::new(f64::NAN).is_none());
+}
+
+#[test]
+fn test_triangle() {
+    let triangle = Triangle::new(3.0, 4.0, 5.0).unwrap();
+    assert!(close(triangle.area(), 6.0));
+    assert_eq!(triangle.perimeter(), 12.0);
+
+    let flat = Triangle::new(1.0, 2.0, 3.0).unwrap();
+    assert_eq!(flat.area(), 0.0);
+    assert!(Triangle::new(1.0, 2.0, 4.0).is_none());
+    assert!(Triangle::new(-3.0, 4.0, 5.0).is_none());
+    assert!(Triangle::new(f64::INFINITY, 4.0, 5.0).is_none());
+}
+
+#[test]
+fn test_describe() {
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Rectangle {
+            width: 2,
+            height: 3,
+        }),
+        Box::new(Circle::new(1.0).unwrap()),
+        Box::new(Triangle::new(3.0, 4.0, 5.0).unwrap()),
+    ];
+    let descriptions: Vec<String> = shapes.iter().map(|s| describe(s.as_ref())).collect();
+    assert_eq!(
+        descriptions,
+        [
+            "a rectangle with an area of 6.00 and a perimeter of 10.00",
+            "a circle with an area of 3.14 and a perimeter of 6.28",
+            "a triangle with an area of 6.00 and a perimeter of 12.00",
+        ]
+    );
+}
+
+fn collection() -> ShapeCollection {
+    let mut shapes = ShapeCollection::new();
+    shapes.push(Rectangle {
+        width: 2,
+        height: 3,
+    });
+    shapes.push(Circle::new(1.0).unwrap());
+    shapes.push(Triangle::new(3.0, 4.0, 5.0).unwrap());
+    shapes
+}
+
+#[test]
+fn test_collection_totals() {
+    let shapes = collection();
+    assert_eq!(shapes.len(), 3);
+    assert!(close(shapes.total_area(), 12.0 + std::f64::consts::PI));
+    /* The rectangle and the triangle tie at 6; the later one wins. */
+    assert_eq!(shapes.largest().map(|shape| shape.name()), Some("triangle"));
+
+    let empty = ShapeCollection::new();
+    assert!(empty.is_empty());
+    assert_eq!(empty.total_area(), 0.0);
+    assert!(empty.largest().is_none());
+}
+
+#[test]
+fn test_collection_filter() {
+    let shapes = collection();
+    let big: Vec<&str> = shapes.filter_min_area(6.0).map(|s| s.name()).collect();
+    assert_eq!(big, ["rectangle", "triangle"]);
+    assert_eq!(shapes.filter_min_area(0.0).count(), 3);
+    assert_eq!(shapes.filter_min_area(100.0).count(), 0);
+}
+
+#[test]
+fn test_collection_iterators() {
+    let shapes = collection();
+    let mut names = Vec::new();
+    for shape in &shapes {
+        names.push(shape.name());
+    }
+    assert_eq!(names, ["rectangle", "circle", "triangle"]);
+    assert_eq!(shapes.iter().size_hint(), (3, Some(3)));
+
+    let again: ShapeCollection = shapes.into_iter().rev().collect();
+    let names: Vec<&str> = again.iter().map(|s| s.name()).collect();
+    assert_eq!(names, ["triangle", "circle", "rectangle"]);
+}
+
+fn placed(x: i32, y: i32, width: u32, height: u32) -> PlacedRectangle {
+    PlacedRectangle::new(x, y, Rectangle { width, height })
+}
+
+#[test]
+fn test_contains_point() {
+    let rect = placed(10, 20, 30, 50);
+    assert!(rect.contains_point(Point { x: 10, y: 20 }));
+    assert!(rect.contains_point(Point { x: 39, y: 69 }));
+    assert!(!rect.contains_point(Point { x: 40, y: 20 }));
+    assert!(!rect.contains_point(Point { x: 10, y: 70 }));
+    assert!(!rect.contains_point(Point { x: 9, y: 30 }));
+
+    let far = placed(i32::MAX, i32::MAX, u32::MAX, u32::MAX);
+    assert!(far.contains_point(Point {
+        x: i32::MAX,
+        y: i32::MAX
+    }));
+}
+
+#[test]
+fn test_collides_with() {
+    let a = placed(0, 0, 10, 10);
+    assert!(a.collides_with(&placed(5, 5, 10, 10)));
+    assert!(a.collides_with(&placed(-5, -5, 30, 30)));
+    assert!(a.collides_with(&a));
+    /* Edges touching, and corners touching, aren't collisions. */
+    assert!(!a.collides_with(&placed(10, 0, 10, 10)));
+    assert!(!a.collides_with(&placed(10, 10, 10, 10)));
+    assert!(!a.collides_with(&placed(0, -10, 10, 10)));
+
+    let b = placed(3, -4, 2, 30);
+    assert_eq!(a.collides_with(&b), b.collides_with(&a));
+}
+
+#[test]
+fn test_distance_to() {
+    let a = placed(0, 0, 10, 10);
+    assert_eq!(a.distance_to(&placed(5, 5, 10, 10)), 0.0);
+    assert_eq!(a.distance_to(&placed(10, 0, 10, 10)), 0.0);
+    assert_eq!(a.distance_to(&placed(15, 0, 10, 10)), 5.0);
+    assert_eq!(a.distance_to(&placed(0, -30, 10, 10)), 20.0);
+    /* Diagonally, corner to corner. */
+    assert_eq!(a.distance_to(&placed(13, 14, 1, 1)), 5.0);
+    assert_eq!(placed(13, 14, 1, 1).distance_to(&a), 5.0);
+}
+
+/* A random rectangle as '(x, y, width, height)', so the same one can be
+ * placed in both a 'Vec' and a quadtree. */
+type Spec = (i32, i32, u32, u32);
+
+fn random_spec(rng: &mut Rng, size: i64) -> Spec {
+    (
+        rng.between(-1000, 1000) as i32,
+        rng.between(-1000, 1000) as i32,
+        rng.between(0, size) as u32,
+        rng.between(0, size) as u32,
+    )
+}
+
+fn place((x, y, width, height): Spec) -> PlacedRectangle {
+    placed(x, y, width, height)
+}
+
+fn spec(rect: &PlacedRectangle) -> Spec {
+    (
+        rect.origin.x,
+        rect.origin.y,
+        rect.rect.width,
+        rect.rect.height,
+    )
+}
+
+#[test]
+fn test_quadtree_small() {
+    let mut tree = Quadtree::new();
+    assert!(tree.is_empty());
+    assert!(tree.query(&placed(0, 0, 100, 100)).is_empty());
+
+    tree.insert(placed(0, 0, 10, 10));
+    tree.insert(placed(20, 0, 10, 10));
+    tree.insert(placed(-50, -50, 200, 5));
+    assert_eq!(tree.len(), 3);
+
+    let found = tree.query(&placed(5, 5, 10, 10));
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].origin, Point { x: 0, y: 0 });
+    /* Touching isn't overlapping. */
+    assert!(tree.query(&placed(10, 0, 10, 10)).is_empty());
+    assert_eq!(tree.query(&placed(-100, -100, 1000, 1000)).len(), 3);
+}
+
+/* Compared with checking every rectangle, the slow way. */
+#[test]
+fn test_quadtree_matches_brute_force() {
+    let mut rng = Rng::from_env();
+    for size in [5, 50, 500] {
+        let specs: Vec<Spec> = (0..300).map(|_| random_spec(&mut rng, size)).collect();
+        let rects: Vec<PlacedRectangle> = specs.iter().copied().map(place).collect();
+        let mut tree = Quadtree::new();
+        for &spec in &specs {
+            tree.insert(place(spec));
+        }
+        assert_eq!(tree.len(), rects.len());
+
+        for _ in 0..100 {
+            let region = place(random_spec(&mut rng, 400));
+            let expected: Vec<Spec> = rects
+                .iter()
+                .filter(|rect| rect.collides_with(&region))
+                .map(spec)
+                .collect();
+            let found: Vec<Spec> = tree.query(&region).into_iter().map(spec).collect();
+            assert_eq!(found, expected, "query {region:?}, seed {}", rng.seed());
+        }
+    }
+}
+
+/* A query in one corner of a grid of small rectangles shouldn't have to
+ * look at the whole grid, wherever the grid is. Getting the right answer
+ * isn't enough for that: a tree that kept everything in one leaf would
+ * pass the tests above. */
+#[test]
+fn test_quadtree_skips_far_rectangles() {
+    for (x, y) in [(0, 0), (-1000, -1000), (1_000_000, -5_000_000)] {
+        let mut tree = Quadtree::new();
+        for row in 0..32 {
+            for column in 0..32 {
+                tree.insert(placed(x + column * 10, y + row * 10, 8, 8));
+            }
+        }
+        let corner = placed(x, y, 15, 15);
+        assert_eq!(tree.query(&corner).len(), 4);
+        let candidates = tree.candidates(&corner);
+        /* Rectangles lying across a quarter's middle lines stay higher
+         * up and are always looked at, but that's a few rows and columns,
+         * not all 1024. */
+        assert!(candidates < 200, "{candidates} candidates at ({x}, {y})");
+    }
+}
+
+#[test]
+fn test_quadtree_far_apart_and_stacked() {
+    let mut tree = Quadtree::new();
+    tree.insert(placed(i32::MIN, i32::MIN, 1, 1));
+    tree.insert(placed(i32::MAX, i32::MAX, u32::MAX, u32::MAX));
+    tree.insert(placed(i32::MIN, i32::MAX, u32::MAX, 1));
+    /* Many copies of the same rectangle can't be split apart; the tree
+     * has to stop splitting and keep them together. */
+    for _ in 0..100 {
+        tree.insert(placed(3, 3, 1, 1));
+    }
+    assert_eq!(tree.query(&placed(3, 3, 1, 1)).len(), 100);
+    assert_eq!(tree.query(&placed(i32::MIN, i32::MIN, 2, 2)).len(), 1);
+    assert_eq!(tree.query(&placed(i32::MAX - 1, i32::MAX, 2, 1)).len(), 2);
+    assert_eq!(
+        tree.query(&placed(i32::MIN, i32::MIN, u32::MAX, u32::MAX))
+            .len(),
+        101
+    );
+}
@@ -0,0 +1,145 @@
+/* Inventory */
+/* The kitchen keeps count of how much of each ingredient it has on hand,
+ * in whatever unit suits it: eggs by the egg, flour by the cup. Each
+ * ingredient also has a level at or below which it's running low and
+ * should be ordered again; the low-stock report lists those.
+ *
+ * What a dish uses up is its recipe, filed under the dish's name on the
+ * menu. A dish without a recipe, like a cup of coffee, isn't tracked and
+ * can always be made. */
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutOfStock {
+    pub ingredient: String,
+    pub needed: u64,
+    pub on_hand: u32,
+}
+
+impl fmt::Display for OutOfStock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "out of {}: {} needed, {} on hand",
+            self.ingredient, self.needed, self.on_hand
+        )
+    }
+}
+
+impl std::error::Error for OutOfStock {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Stock {
+    on_hand: u32,
+    low_at: u32,
+}
+
+/* A 'BTreeMap' for the stock, so the report comes out in alphabetical
+ * order. */
+#[derive(Debug, Clone, Default)]
+pub struct Inventory {
+    stock: BTreeMap<String, Stock>,
+    recipes: HashMap<String, Vec<(String, u32)>>,
+}
+
+impl Inventory {
+    pub fn new() -> Inventory {
+        Inventory::default()
+    }
+
+    /* Starts keeping track of an ingredient, or changes its low level if
+     * it's already tracked. */
+    pub fn track(&mut self, ingredient: &str, low_at: u32) {
+        self.stock
+            .entry(String::from(ingredient))
+            .or_insert(Stock { on_hand: 0, low_at })
+            .low_at = low_at;
+    }
+
+    /* Restocking an ingredient that isn't tracked yet tracks it, with no
+     * low level. */
+    pub fn restock(&mut self, ingredient: &str, amount: u32) {
+        let stock = self.stock.entry(String::from(ingredient)).or_insert(Stock {
+            on_hand: 0,
+            low_at: 0,
+        });
+        stock.on_hand = stock.on_hand.saturating_add(amount);
+    }
+
+    pub fn on_hand(&self, ingredient: &str) -> u32 {
+        self.stock.get(ingredient).map_or(0, |stock| stock.on_hand)
+    }
+
+    pub fn consume(&mut self, ingredient: &str, amount: u32) -> Result<(), OutOfStock> {
+        self.check(ingredient, u64::from(amount))?;
+        if let Some(stock) = self.stock.get_mut(ingredient) {
+            stock.on_hand -= amount;
+        }
+        Ok(())
+    }
+
+    pub fn add_recipe(&mut self, dish: &str, ingredients: &[(&str, u32)]) {
+        let ingredients = ingredients
+            .iter()
+            .map(|&(name, amount)| (String::from(name), amount))
+            .collect();
+        self.recipes.insert(String::from(dish), ingredients);
+    }
+
+    /* Uses up what 'quantity' of each dish needs, all together: if
+     * there's not enough of any one ingredient, nothing is used and the
+     * first ingredient that ran short is the error. */
+    pub fn consume_dishes<'a>(
+        &mut self,
+        dishes: impl IntoIterator<Item = (&'a str, u32)>,
+    ) -> Result<(), OutOfStock> {
+        let mut needed: BTreeMap<&str, u64> = BTreeMap::new();
+        for (dish, quantity) in dishes {
+            for (ingredient, amount) in self.recipes.get(dish).into_iter().flatten() {
+                *needed.entry(ingredient).or_insert(0) += u64::from(*amount) * u64::from(quantity);
+            }
+        }
+        for (&ingredient, &amount) in &needed {
+            self.check(ingredient, amount)?;
+        }
+        for (ingredient, amount) in needed {
+            if let Some(stock) = self.stock.get_mut(ingredient) {
+                /* 'check' made sure this fits in what's on hand. */
+                stock.on_hand -= amount as u32;
+            }
+        }
+        Ok(())
+    }
+
+    fn check(&self, ingredient: &str, needed: u64) -> Result<(), OutOfStock> {
+        let on_hand = self.on_hand(ingredient);
+        if needed > u64::from(on_hand) {
+            return Err(OutOfStock {
+                ingredient: String::from(ingredient),
+                needed,
+                on_hand,
+            });
+        }
+        Ok(())
+    }
+
+    /* The ingredients at or below their low level, with how much of each
+     * is left. */
+    pub fn low_stock(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.stock
+            .iter()
+            .filter(|(_, stock)| stock.on_hand <= stock.low_at)
+            .map(|(ingredient, stock)| (ingredient.as_str(), stock.on_hand))
+    }
+
+    /* One line per low ingredient, like "eggs: 3 left (low at 12)". */
+    pub fn low_stock_report(&self) -> String {
+        self.low_stock()
+            .map(|(ingredient, on_hand)| {
+                let low_at = self.stock[ingredient].low_at;
+                format!("{ingredient}: {on_hand} left (low at {low_at})\n")
+            })
+            .collect()
+    }
+}
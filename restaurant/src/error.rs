@@ -0,0 +1,99 @@
+/* Restaurant Errors */
+/* Each part of the restaurant has its own error for what can go wrong
+ * there: an 'OrderError' when an order is changed after it's closed, a
+ * 'PaymentError' when a payment doesn't go through, and so on. Something
+ * that does a bit of everything, like 'eat_at_restaurant', would have to
+ * return all of them, so they're gathered up here into one
+ * 'RestaurantError'. The 'From' impls let '?' turn each module's error
+ * into this one.
+ *
+ * A few are common enough to get a variant of their own; the rest are
+ * wrapped, and 'source' hands back the original. */
+use crate::inventory::OutOfStock;
+use crate::orders::OrderError;
+use crate::reservations::ReservationError;
+use crate::serving::payment::PaymentError;
+use crate::staff::PermissionDenied;
+use crate::tickets::TicketError;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RestaurantError {
+    NoTablesAvailable,
+    ItemNotOnMenu(String),
+    ReservationNotFound,
+    PaymentDeclined(PaymentError),
+    OutOfStock(OutOfStock),
+    Order(OrderError),
+    Ticket(TicketError),
+    PermissionDenied(PermissionDenied),
+}
+
+impl fmt::Display for RestaurantError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RestaurantError::NoTablesAvailable => write!(f, "there are no tables available"),
+            RestaurantError::ItemNotOnMenu(name) => write!(f, "'{name}' isn't on the menu"),
+            RestaurantError::ReservationNotFound => write!(f, "there's no such reservation"),
+            RestaurantError::PaymentDeclined(e) => write!(f, "the payment was declined: {e}"),
+            RestaurantError::OutOfStock(e) => write!(f, "the kitchen can't make that: {e}"),
+            RestaurantError::Order(e) => write!(f, "the order can't be changed: {e}"),
+            RestaurantError::Ticket(e) => write!(f, "the kitchen ticket can't move on: {e}"),
+            RestaurantError::PermissionDenied(e) => write!(f, "permission denied: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for RestaurantError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RestaurantError::PaymentDeclined(e) => Some(e),
+            RestaurantError::OutOfStock(e) => Some(e),
+            RestaurantError::Order(e) => Some(e),
+            RestaurantError::Ticket(e) => Some(e),
+            RestaurantError::PermissionDenied(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<PaymentError> for RestaurantError {
+    fn from(e: PaymentError) -> RestaurantError {
+        RestaurantError::PaymentDeclined(e)
+    }
+}
+
+impl From<OutOfStock> for RestaurantError {
+    fn from(e: OutOfStock) -> RestaurantError {
+        RestaurantError::OutOfStock(e)
+    }
+}
+
+impl From<OrderError> for RestaurantError {
+    fn from(e: OrderError) -> RestaurantError {
+        RestaurantError::Order(e)
+    }
+}
+
+impl From<TicketError> for RestaurantError {
+    fn from(e: TicketError) -> RestaurantError {
+        RestaurantError::Ticket(e)
+    }
+}
+
+impl From<PermissionDenied> for RestaurantError {
+    fn from(e: PermissionDenied) -> RestaurantError {
+        RestaurantError::PermissionDenied(e)
+    }
+}
+
+/* Both of these have a variant above already, so there's nothing to
+ * wrap. */
+impl From<ReservationError> for RestaurantError {
+    fn from(e: ReservationError) -> RestaurantError {
+        match e {
+            ReservationError::NoTableAvailable => RestaurantError::NoTablesAvailable,
+            ReservationError::NotFound => RestaurantError::ReservationNotFound,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+/* Serving */
+/* The inline 'front_of_house' module in 'lib.rs' declares 'serving'
+ * without a body, so its definition lives here, in a directory named
+ * after its parent. Serving has grown parts of its own, which live one
+ * level further down, in 'serving/'. */
+pub mod payment;
+
+use crate::error::RestaurantError;
+use crate::menu::Menu;
+use crate::orders::Order;
+use crate::tickets::{TicketQueue, TicketState};
+
+/* Writes down what the table asked for, looking it up on the menu by
+ * name so the order gets the menu's price, and sends the kitchen a
+ * ticket for it. Returns the ticket's number. */
+pub fn take_order(
+    order: &mut Order,
+    menu: &Menu,
+    tickets: &mut TicketQueue,
+    name: &str,
+    quantity: u32,
+) -> Result<u32, RestaurantError> {
+    let item = menu
+        .get(name)
+        .ok_or_else(|| RestaurantError::ItemNotOnMenu(String::from(name)))?;
+    order.add(item, quantity)?;
+    Ok(tickets.push(order.id(), order.table(), name, quantity))
+}
+
+/* Takes a dish back off the order, and off the kitchen's tickets with it,
+ * so it isn't cooked anyway. Once the kitchen has started on it, it's too
+ * late, and neither the order nor the tickets change. */
+pub fn remove_from_order(
+    order: &mut Order,
+    tickets: &mut TicketQueue,
+    name: &str,
+    quantity: u32,
+) -> Result<(), RestaurantError> {
+    let on_order = |order: &Order| {
+        let line = order.lines().iter().find(|line| line.item.name == name);
+        line.map_or(0, |line| line.quantity)
+    };
+    let mut amended = order.clone();
+    amended.remove(name, quantity)?;
+    tickets.take_back(order.id(), name, on_order(order) - on_order(&amended))?;
+    *order = amended;
+    Ok(())
+}
+
+/* Cancels the order and tells the kitchen, so nothing more is cooked or
+ * brought out for it. 'Order::cancel' on its own only changes the order;
+ * its tickets would still be cooked and served. */
+pub fn cancel_order(order: &mut Order, tickets: &mut TicketQueue) -> Result<(), RestaurantError> {
+    order.cancel()?;
+    tickets.cancel_order(order.id());
+    Ok(())
+}
+
+/* Brings a dish the kitchen has marked ready out to its table. */
+pub fn serve_order(tickets: &mut TicketQueue, number: u32) -> Result<(), RestaurantError> {
+    tickets.advance(number, TicketState::Ready)?;
+    Ok(())
+}
@@ -0,0 +1,138 @@
+/* Payment */
+/* Once an order has been served, the table pays. There
+ * are three ways to do that:
+ *
+ * - 'Cash', which is always enough: the server brings change.
+ * - 'Card', known by the last four digits printed on it.
+ * - 'GiftCard', which carries a balance, in cents, and can only pay for
+ *   an order that costs no more than that.
+ *
+ * Only a server or a manager can take a payment. One that goes through
+ * gives back a 'Receipt', which shows the order line by line when it's
+ * printed. */
+use crate::error::RestaurantError;
+use crate::menu::format_price;
+use crate::orders::{Order, OrderLine, OrderStatus};
+use crate::staff::{Duty, Staff};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentMethod {
+    Cash,
+    Card { last4: u16 },
+    GiftCard { balance: u64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentError {
+    NothingToPay,
+    InvalidCard,
+    InsufficientBalance { balance: u64, due: u64 },
+}
+
+impl fmt::Display for PaymentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PaymentError::NothingToPay => write!(f, "there's nothing on the order to pay for"),
+            PaymentError::InvalidCard => write!(f, "a card number ends in four digits"),
+            PaymentError::InsufficientBalance { balance, due } => write!(
+                f,
+                "the gift card has {} on it, but {} is due",
+                format_price(*balance),
+                format_price(*due)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PaymentError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Receipt {
+    pub table: u32,
+    pub lines: Vec<OrderLine>,
+    pub total_cents: u64,
+    pub method: PaymentMethod,
+}
+
+impl Receipt {
+    /* What's left on a gift card once it's paid. 'take_payment' never
+     * makes a receipt for more than the card holds, but the fields are
+     * public, so one built by hand might; that card has nothing left. */
+    pub fn remaining_balance(&self) -> Option<u64> {
+        match self.method {
+            PaymentMethod::GiftCard { balance } => Some(balance.saturating_sub(self.total_cents)),
+            _ => None,
+        }
+    }
+}
+
+/* A payment that goes through marks the order paid, so it can't be paid
+ * for twice. */
+pub fn take_payment(
+    staff: &Staff,
+    order: &mut Order,
+    method: PaymentMethod,
+) -> Result<Receipt, RestaurantError> {
+    staff.check(Duty::TakePayment)?;
+    order.check_transition(OrderStatus::Paid)?;
+    let receipt = charge(order, method)?;
+    order.mark_paid()?;
+    Ok(receipt)
+}
+
+fn charge(order: &Order, method: PaymentMethod) -> Result<Receipt, PaymentError> {
+    let due = order.total_cents();
+    if due == 0 {
+        return Err(PaymentError::NothingToPay);
+    }
+    match method {
+        PaymentMethod::Card { last4 } if last4 > 9999 => return Err(PaymentError::InvalidCard),
+        PaymentMethod::GiftCard { balance } if balance < due => {
+            return Err(PaymentError::InsufficientBalance { balance, due })
+        }
+        _ => {}
+    }
+    Ok(Receipt {
+        table: order.table(),
+        lines: order.lines().to_vec(),
+        total_cents: due,
+        method,
+    })
+}
+
+/* The items on the left and the prices lined up on the right:
+ *
+ *     Table 4
+ *     2 x pancakes            $17.00
+ *     3 x coffee               $8.25
+ *     Total                   $25.25
+ *     Paid by card ending in 1234
+ */
+const WIDTH: usize = 30;
+
+impl fmt::Display for Receipt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Table {}", self.table)?;
+        for line in &self.lines {
+            let item = format!("{} x {}", line.quantity, line.item.name);
+            let price = format_price(line.total_cents());
+            writeln!(
+                f,
+                "{item} {price:>width$}",
+                width = WIDTH.saturating_sub(item.len() + 1)
+            )?;
+        }
+        let total = format_price(self.total_cents);
+        writeln!(f, "Total {total:>width$}", width = WIDTH - 6)?;
+        match self.method {
+            PaymentMethod::Cash => write!(f, "Paid in cash"),
+            PaymentMethod::Card { last4 } => write!(f, "Paid by card ending in {last4:04}"),
+            PaymentMethod::GiftCard { .. } => write!(
+                f,
+                "Paid by gift card, {} left on it",
+                format_price(self.remaining_balance().unwrap_or(0))
+            ),
+        }
+    }
+}
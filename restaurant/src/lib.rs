@@ -1,3 +1,23 @@
+/* Beyond the modules below, which are kept as the chapter wrote them,
+ * the restaurant has working parts of its own, each in its own file. */
+pub mod error;
+pub mod menu;
+pub mod orders;
+pub mod prelude;
+pub mod reservations;
+pub mod seating;
+pub mod staff;
+pub mod tickets;
+
+/* 'front_of_house' itself stays private, as the chapter has it, so the
+ * parts of it meant for other crates are re-exported here. */
+pub use crate::back_of_house::{
+    cook_next, cook_order, fix_incorrect_order, inventory, mark_cooked,
+};
+pub use crate::error::RestaurantError;
+pub use crate::front_of_house::hosting;
+pub use crate::front_of_house::serving;
+
 #[allow(dead_code)]
 mod _front_of_house {
     mod hosting {
@@ -61,19 +81,43 @@ mod _front_of_house {
      * to expose the contents of a module with other code, we must mark 
      * each item we wish to make public with the 'pub' keyword. */
     pub mod hosting {
+        use crate::error::RestaurantError;
+        use crate::seating::{FloorPlan, Party, Waitlist};
+
         /* Marking the 'add_to_waitlist' function as public allows our 
          * 'eat_at_restaurant' function below to call it using its 
          * path. */
-        pub fn add_to_waitlist() {}
+        pub fn add_to_waitlist(waitlist: &mut Waitlist, name: &str, size: u32) {
+            waitlist.add(Party::new(name, size));
+        }
+
+        /* Seats the first party on the waitlist that a free table can
+         * take, and returns the table's number. A party too big for any
+         * free table doesn't hold up the smaller ones behind it. */
+        pub fn seat_at_table(
+            waitlist: &mut Waitlist,
+            floor: &mut FloorPlan,
+        ) -> Result<u32, RestaurantError> {
+            waitlist
+                .take_first(|party| floor.best_table_for(party).is_some())
+                .and_then(|party| floor.seat(&party))
+                .ok_or(RestaurantError::NoTablesAvailable)
+        }
     }
+
+    /* 'serving' has grown too big to keep here, so its body is in
+     * 'front_of_house/serving.rs'. */
+    pub mod serving;
 }
 
 pub fn _eat_at_restaurant() {
+    let mut waitlist = seating::Waitlist::new();
+
     // Absolute path
-    crate::front_of_house::hosting::add_to_waitlist();
+    crate::front_of_house::hosting::add_to_waitlist(&mut waitlist, "Ferris", 2);
 
     // Relative path
-    front_of_house::hosting::add_to_waitlist();
+    front_of_house::hosting::add_to_waitlist(&mut waitlist, "Corro", 4);
     /* The path begins with 'front_of_house' here because our function is 
      * defined in the same module as 'front_of_house'. Therefore, our 
      * function and the front_of_house module are siblings. 
@@ -104,27 +148,275 @@ mod _back_of_house {
  * make each field public or not on a case-by-case basis. */
 #[allow(dead_code)]
 mod back_of_house {
+    use crate::error::RestaurantError;
+    use crate::menu::{Menu, Season};
+    use crate::orders::{Order, OrderStatus};
+    use crate::staff::{Duty, Staff};
+    use crate::tickets::{TicketError, TicketQueue, TicketState};
+    use std::fmt;
+
+    /* The kitchen's stock is in 'back_of_house/inventory.rs'. */
+    pub mod inventory;
+    use inventory::Inventory;
+
     pub struct Breakfast {
         pub toast: String,
+        pub sides: Vec<String>,
+        pub drink: Option<String>,
+        pub dietary: Dietary,
         seasonal_fruit: String,
     }
 
+    /* What the kitchen needs to know about the person eating. */
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct Dietary {
+        pub vegetarian: bool,
+        pub vegan: bool,
+        pub gluten_free: bool,
+    }
+
     impl Breakfast {
         pub fn summer(toast: &str) -> Breakfast {
-            Breakfast {
+            Breakfast::in_season(toast, &Menu::house(), Season::Summer)
+                .expect("the house menu has fruit all year")
+        }
+
+        pub fn in_season(toast: &str, menu: &Menu, season: Season) -> Option<Breakfast> {
+            BreakfastBuilder::new(toast).build(menu, season)
+        }
+
+        /* Customers can't choose the fruit, but they can see what they
+         * got. */
+        pub fn seasonal_fruit(&self) -> &str {
+            &self.seasonal_fruit
+        }
+    }
+
+    /* "Rye toast with peaches, bacon, and coffee (gluten free)" */
+    impl fmt::Display for Breakfast {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let mut with = vec![self.seasonal_fruit.as_str()];
+            with.extend(self.sides.iter().map(String::as_str));
+            with.extend(self.drink.as_deref());
+            write!(f, "{} toast with ", self.toast)?;
+            match with.split_last() {
+                Some((last, [])) => write!(f, "{last}")?,
+                Some((last, [first])) => write!(f, "{first} and {last}")?,
+                Some((last, rest)) => write!(f, "{}, and {last}", rest.join(", "))?,
+                None => {}
+            }
+
+            let flags = [
+                (self.dietary.vegetarian, "vegetarian"),
+                (self.dietary.vegan, "vegan"),
+                (self.dietary.gluten_free, "gluten free"),
+            ];
+            let flags: Vec<&str> = flags
+                .iter()
+                .filter(|(on, _)| *on)
+                .map(|(_, name)| *name)
+                .collect();
+            if !flags.is_empty() {
+                write!(f, " ({})", flags.join(", "))?;
+            }
+            Ok(())
+        }
+    }
+
+    /* The customer says what they'd like with their breakfast, but there's
+     * nothing here for the fruit: 'build' leaves that to the chef, who
+     * picks it from the menu the same way 'in_season' does. Vegan implies
+     * vegetarian, so asking for one sets both. */
+    #[derive(Debug, Clone, Default)]
+    pub struct BreakfastBuilder {
+        toast: String,
+        sides: Vec<String>,
+        drink: Option<String>,
+        dietary: Dietary,
+    }
+
+    impl BreakfastBuilder {
+        pub fn new(toast: &str) -> BreakfastBuilder {
+            BreakfastBuilder {
                 toast: String::from(toast),
-                seasonal_fruit: String::from("peaches"),
+                ..BreakfastBuilder::default()
             }
         }
+
+        pub fn side(mut self, side: &str) -> BreakfastBuilder {
+            self.sides.push(String::from(side));
+            self
+        }
+
+        pub fn drink(mut self, drink: &str) -> BreakfastBuilder {
+            self.drink = Some(String::from(drink));
+            self
+        }
+
+        pub fn vegetarian(mut self) -> BreakfastBuilder {
+            self.dietary.vegetarian = true;
+            self
+        }
+
+        pub fn vegan(mut self) -> BreakfastBuilder {
+            self.dietary.vegan = true;
+            self.vegetarian()
+        }
+
+        pub fn gluten_free(mut self) -> BreakfastBuilder {
+            self.dietary.gluten_free = true;
+            self
+        }
+
+        /* 'None' if the menu has no fruit at all for this season. */
+        pub fn build(self, menu: &Menu, season: Season) -> Option<Breakfast> {
+            let fruit = menu.fruit_for(season)?;
+            Some(Breakfast {
+                toast: self.toast,
+                sides: self.sides,
+                drink: self.drink,
+                dietary: self.dietary,
+                seasonal_fruit: fruit.name.clone(),
+            })
+        }
+    }
+
+    /* Unlike the 'cook_order' in '_back_of_house' above, this one starts
+     * every ticket of the order's that the kitchen hasn't started yet,
+     * and then marks the order as cooking. It refuses to start if
+     * anything would run out or the order isn't waiting to be cooked.
+     * Tickets 'cook_next' has already started are left alone, so an
+     * order is never cooked twice, whichever way it got to the stove. */
+    pub fn cook_order(
+        order: &mut Order,
+        tickets: &mut TicketQueue,
+        inventory: &mut Inventory,
+    ) -> Result<(), RestaurantError> {
+        order.check_transition(OrderStatus::Cooking)?;
+        let waiting: Vec<u32> = tickets
+            .outstanding()
+            .filter(|ticket| ticket.order == order.id() && ticket.state == TicketState::Received)
+            .map(|ticket| ticket.number)
+            .collect();
+        start_tickets(tickets, inventory, &waiting)?;
+        order.start_cooking()?;
+        Ok(())
+    }
+
+    /* Starts cooking the oldest ticket that hasn't been started, if there
+     * is one, and returns its number. A ticket the kitchen doesn't have
+     * the ingredients for stays where it is. */
+    pub fn cook_next(
+        tickets: &mut TicketQueue,
+        inventory: &mut Inventory,
+    ) -> Result<Option<u32>, RestaurantError> {
+        let Some(ticket) = tickets.oldest(TicketState::Received) else {
+            return Ok(None);
+        };
+        let number = ticket.number;
+        start_tickets(tickets, inventory, &[number])?;
+        Ok(Some(number))
+    }
+
+    /* The one place a ticket's ingredients are used up: as it goes from
+     * received to cooking, which happens to each ticket only once. All
+     * the tickets start, or none do and nothing is used. */
+    fn start_tickets(
+        tickets: &mut TicketQueue,
+        inventory: &mut Inventory,
+        numbers: &[u32],
+    ) -> Result<(), RestaurantError> {
+        let dishes = numbers
+            .iter()
+            .filter_map(|&number| tickets.get(number))
+            .map(|ticket| (ticket.dish.as_str(), ticket.quantity));
+        inventory.consume_dishes(dishes)?;
+        for &number in numbers {
+            tickets.advance(number, TicketState::Received)?;
+        }
+        Ok(())
+    }
+
+    pub fn mark_cooked(tickets: &mut TicketQueue, number: u32) -> Result<(), RestaurantError> {
+        tickets.advance(number, TicketState::Cooking)?;
+        Ok(())
+    }
+
+    /* Unlike the one in '_back_of_house', this 'fix_incorrect_order'
+     * needs a chef or a manager. A dish that went out wrong is cooked
+     * again from scratch, on a ticket of its own that starts out cooking,
+     * and is then served like any other; the new ticket's number is
+     * returned. */
+    pub fn fix_incorrect_order(
+        staff: &Staff,
+        tickets: &mut TicketQueue,
+        number: u32,
+        inventory: &mut Inventory,
+    ) -> Result<u32, RestaurantError> {
+        staff.check(Duty::FixOrder)?;
+        let ticket = tickets.get(number).ok_or(TicketError::NotFound)?;
+        if ticket.state != TicketState::Delivered {
+            return Err(TicketError::WrongState {
+                expected: TicketState::Delivered,
+                found: ticket.state,
+            }
+            .into());
+        }
+        let (order, table) = (ticket.order, ticket.table);
+        let (dish, quantity) = (ticket.dish.clone(), ticket.quantity);
+        inventory.consume_dishes([(dish.as_str(), quantity)])?;
+        let remade = tickets.push(order, table, &dish, quantity);
+        tickets.advance(remade, TicketState::Received)?;
+        Ok(remade)
     }
 }
 
-pub fn eat_at_restaurant() {
+pub fn eat_at_restaurant() -> Result<(), RestaurantError> {
     // Order a breakfast in the summer with Rye toast
     let mut meal = back_of_house::Breakfast::summer("Rye");
     // Change our mind about what bread we'd like
     meal.toast = String::from("Wheat");
     println!("I'd like {} toast please", meal.toast);
+
+    /* A breakfast the way we like it. We choose everything but the
+     * fruit, which is still up to the chef. */
+    let menu = menu::Menu::house();
+    let custom = back_of_house::BreakfastBuilder::new("Sourdough")
+        .side("hash browns")
+        .drink("orange juice")
+        .vegetarian()
+        .build(&menu, menu::Season::Summer);
+    if let Some(custom) = custom {
+        println!("I'd like {custom}, please");
+    }
+
+    /* The rest of the visit, from getting a table to paying. Any step
+     * can go wrong, and '?' hands what went wrong back to the caller as
+     * a 'RestaurantError'. */
+    let mut floor = seating::FloorPlan::new();
+    floor.add_table(1, 2);
+    let mut waitlist = seating::Waitlist::new();
+    hosting::add_to_waitlist(&mut waitlist, "Ferris", 1);
+    let table = hosting::seat_at_table(&mut waitlist, &mut floor)?;
+
+    let mut order = orders::Order::open(table);
+    let mut tickets = tickets::TicketQueue::new();
+    serving::take_order(&mut order, &menu, &mut tickets, "pancakes", 1)?;
+    serving::take_order(&mut order, &menu, &mut tickets, "coffee", 1)?;
+
+    let mut inventory = inventory::Inventory::new();
+    cook_order(&mut order, &mut tickets, &mut inventory)?;
+    while let Some(number) = tickets.oldest(tickets::TicketState::Cooking).map(|t| t.number) {
+        mark_cooked(&mut tickets, number)?;
+        serving::serve_order(&mut tickets, number)?;
+    }
+    order.serve()?;
+
+    let server = staff::Staff::new("Corro", staff::Role::Server);
+    let cash = serving::payment::PaymentMethod::Cash;
+    let receipt = serving::payment::take_payment(&server, &mut order, cash)?;
+    println!("{receipt}");
+    Ok(())
 }
 /* The above 'back_of_house' module and 'eat_at_restaurant' function 
  * model a case in a restaurant where the customer can pick the type of 
@@ -147,13 +439,105 @@ pub fn eat_at_restaurant() {
  * In contrast, if we make an enum public, all of its variants are then 
  * public. We only need the 'pub' keyword before the 'enum' keyword. */
 mod another_back_of_house {
+    use std::fmt;
+
+    /* The variants can carry data, like any enum's, and that data is
+     * public along with them. */
+    #[derive(Debug, Clone, PartialEq, Eq)]
     pub enum Appetizer {
-        Soup,
-        Salad,
+        Soup {
+            of_the_day: String,
+            vegetarian: bool,
+        },
+        Salad {
+            with_chicken: bool,
+        },
+        Wings {
+            count: u32,
+        },
+    }
+
+    impl Appetizer {
+        /* In cents, like the prices on the menu. */
+        pub fn price(&self) -> u32 {
+            match self {
+                Appetizer::Soup { .. } => 600,
+                Appetizer::Salad { with_chicken } => 550 + if *with_chicken { 300 } else { 0 },
+                Appetizer::Wings { count } => count.saturating_mul(150),
+            }
+        }
+
+        pub fn is_vegetarian(&self) -> bool {
+            match self {
+                Appetizer::Soup { vegetarian, .. } => *vegetarian,
+                Appetizer::Salad { with_chicken } => !with_chicken,
+                Appetizer::Wings { .. } => false,
+            }
+        }
+    }
+
+    impl fmt::Display for Appetizer {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Appetizer::Soup { of_the_day, .. } => write!(f, "{of_the_day} soup"),
+                Appetizer::Salad { with_chicken: true } => write!(f, "chicken salad"),
+                Appetizer::Salad { .. } => write!(f, "salad"),
+                Appetizer::Wings { count } => write!(f, "{count} wings"),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Weekday {
+        Monday,
+        Tuesday,
+        Wednesday,
+        Thursday,
+        Friday,
+        Saturday,
+        Sunday,
+    }
+
+    /* There's a different soup every day, and wings only at the
+     * weekend. */
+    pub fn todays_appetizers(today: Weekday) -> Vec<Appetizer> {
+        let (soup, vegetarian) = match today {
+            Weekday::Monday => ("tomato", true),
+            Weekday::Tuesday => ("chicken noodle", false),
+            Weekday::Wednesday => ("minestrone", true),
+            Weekday::Thursday => ("clam chowder", false),
+            Weekday::Friday => ("lentil", true),
+            Weekday::Saturday => ("French onion", false),
+            Weekday::Sunday => ("pea and ham", false),
+        };
+        let mut appetizers = vec![
+            Appetizer::Soup {
+                of_the_day: String::from(soup),
+                vegetarian,
+            },
+            Appetizer::Salad {
+                with_chicken: false,
+            },
+            Appetizer::Salad { with_chicken: true },
+        ];
+        if matches!(today, Weekday::Saturday | Weekday::Sunday) {
+            appetizers.push(Appetizer::Wings { count: 6 });
+        }
+        appetizers
     }
 }
 
+pub use crate::another_back_of_house::{todays_appetizers, Appetizer, Weekday};
+
 pub fn eat_again() {
-    let _order1 = another_back_of_house::Appetizer::Soup;
-    let _order2 = another_back_of_house::Appetizer::Salad;
-}
\ No newline at end of file
+    let _order1 = another_back_of_house::Appetizer::Soup {
+        of_the_day: String::from("tomato"),
+        vegetarian: true,
+    };
+    let _order2 = another_back_of_house::Appetizer::Salad {
+        with_chicken: false,
+    };
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,137 @@
+/* The Menu */
+/* Every 'MenuItem' has a price and a 'Category', the part of the menu it's
+ * listed under. Some items are only served in one 'Season': those have
+ * 'seasonal' set, and the rest, with 'None', are served all year.
+ *
+ * 'Menu::house' is the menu the restaurant opens with. It's also where the
+ * chef looks to decide which fruit comes with a breakfast. */
+use std::fmt::Write;
+
+/* A price in cents written the way a menu shows it, like "$8.50", with
+ * commas between the thousands of dollars. */
+pub fn format_price(cents: u64) -> String {
+    let dollars = (cents / 100).to_string();
+    let mut price = String::from("$");
+    for (i, digit) in dollars.chars().enumerate() {
+        if i > 0 && (dollars.len() - i).is_multiple_of(3) {
+            price.push(',');
+        }
+        price.push(digit);
+    }
+    write!(price, ".{:02}", cents % 100).unwrap();
+    price
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Breakfast,
+    Fruit,
+    Appetizer,
+    Main,
+    Drink,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MenuItem {
+    pub name: String,
+    pub price_cents: u32,
+    pub category: Category,
+    pub seasonal: Option<Season>,
+}
+
+impl MenuItem {
+    pub fn new(name: &str, price_cents: u32, category: Category) -> MenuItem {
+        MenuItem {
+            name: String::from(name),
+            price_cents,
+            category,
+            seasonal: None,
+        }
+    }
+
+    pub fn only_in(mut self, season: Season) -> MenuItem {
+        self.seasonal = Some(season);
+        self
+    }
+
+    pub fn is_available_on(&self, season: Season) -> bool {
+        self.seasonal.is_none_or(|only| only == season)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Menu {
+    items: Vec<MenuItem>,
+}
+
+impl Menu {
+    pub fn new() -> Menu {
+        Menu::default()
+    }
+
+    pub fn house() -> Menu {
+        let mut menu = Menu::new();
+        menu.add(MenuItem::new("pancakes", 850, Category::Breakfast));
+        menu.add(MenuItem::new("eggs on toast", 725, Category::Breakfast));
+        menu.add(MenuItem::new("strawberries", 325, Category::Fruit).only_in(Season::Spring));
+        menu.add(MenuItem::new("peaches", 300, Category::Fruit).only_in(Season::Summer));
+        menu.add(MenuItem::new("apples", 250, Category::Fruit).only_in(Season::Autumn));
+        menu.add(MenuItem::new("oranges", 275, Category::Fruit).only_in(Season::Winter));
+        menu.add(MenuItem::new("bananas", 200, Category::Fruit));
+        menu.add(MenuItem::new("soup", 600, Category::Appetizer));
+        menu.add(MenuItem::new("salad", 550, Category::Appetizer));
+        menu.add(MenuItem::new("roast chicken", 1650, Category::Main));
+        menu.add(MenuItem::new("coffee", 275, Category::Drink));
+        menu.add(MenuItem::new("orange juice", 350, Category::Drink));
+        menu
+    }
+
+    /* Adding an item with the same name as one already on the menu
+     * replaces it, so a price can be changed by adding the item again. */
+    pub fn add(&mut self, item: MenuItem) {
+        match self.items.iter_mut().find(|old| old.name == item.name) {
+            Some(old) => *old = item,
+            None => self.items.push(item),
+        }
+    }
+
+    pub fn items(&self) -> &[MenuItem] {
+        &self.items
+    }
+
+    pub fn get(&self, name: &str) -> Option<&MenuItem> {
+        self.items.iter().find(|item| item.name == name)
+    }
+
+    pub fn in_category(&self, category: Category) -> impl Iterator<Item = &MenuItem> {
+        self.items
+            .iter()
+            .filter(move |item| item.category == category)
+    }
+
+    pub fn available_on(&self, season: Season) -> impl Iterator<Item = &MenuItem> {
+        self.items
+            .iter()
+            .filter(move |item| item.is_available_on(season))
+    }
+
+    /* The fruit the chef puts on a breakfast: one that's only around this
+     * season if there is one, since that's what's best right now, and
+     * otherwise one that's served all year. */
+    pub fn fruit_for(&self, season: Season) -> Option<&MenuItem> {
+        self.in_category(Category::Fruit)
+            .find(|item| item.seasonal == Some(season))
+            .or_else(|| {
+                self.in_category(Category::Fruit)
+                    .find(|item| item.seasonal.is_none())
+            })
+    }
+}
@@ -0,0 +1,238 @@
+/* Orders */
+/* The modules in 'lib.rs' only have empty functions, which is enough to
+ * show how paths work but not enough to run a restaurant. This module
+ * gives the front of house something to write down: an 'Order' is a list
+ * of 'OrderLine's, each one a 'MenuItem' and how many of it the table
+ * wants, with the item as it's listed in the 'menu'.
+ *
+ * Prices are kept in cents, as whole numbers, so that adding them up never
+ * runs into the rounding that floating-point numbers have. A single price
+ * fits in a 'u32'; totals are 'u64', since a big enough order of a pricey
+ * enough item could go past what a 'u32' holds.
+ *
+ * An order moves through its 'OrderStatus' in one direction:
+ *
+ *     Placed -> Cooking -> Served -> Paid
+ *
+ * and can be 'Cancelled' any time before it's served. Only a placed order
+ * can be amended; once the kitchen starts on it, what's on it is settled.
+ * Each move has a method of its own, and a move that isn't on the chart
+ * above, like serving a cancelled order, is an error that leaves the
+ * order as it was. Asking for the total along the way gives the running
+ * total.
+ *
+ * Every order gets a number of its own when it's opened, which its
+ * kitchen tickets carry too. A table can have more than one order over an
+ * evening, so the table number alone can't say which tickets go with
+ * which. */
+use crate::menu::MenuItem;
+use std::fmt;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderLine {
+    pub item: MenuItem,
+    pub quantity: u32,
+}
+
+impl OrderLine {
+    pub fn total_cents(&self) -> u64 {
+        u64::from(self.item.price_cents) * u64::from(self.quantity)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderStatus {
+    Placed,
+    Cooking,
+    Served,
+    Paid,
+    Cancelled,
+}
+
+impl OrderStatus {
+    pub fn can_become(self, next: OrderStatus) -> bool {
+        use OrderStatus::*;
+        matches!(
+            (self, next),
+            (Placed, Cooking)
+                | (Cooking, Served)
+                | (Served, Paid)
+                | (Placed, Cancelled)
+                | (Cooking, Cancelled)
+        )
+    }
+}
+
+impl fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status = match self {
+            OrderStatus::Placed => "placed",
+            OrderStatus::Cooking => "cooking",
+            OrderStatus::Served => "served",
+            OrderStatus::Paid => "paid",
+            OrderStatus::Cancelled => "cancelled",
+        };
+        write!(f, "{status}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderError {
+    Locked(OrderStatus),
+    InvalidTransition { from: OrderStatus, to: OrderStatus },
+    NotOnOrder,
+    ZeroQuantity,
+    TooMany,
+}
+
+impl fmt::Display for OrderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OrderError::Locked(status) => {
+                write!(f, "the order is already {status} and can't be changed")
+            }
+            OrderError::InvalidTransition { from, to } => {
+                write!(f, "an order that's {from} can't be {to}")
+            }
+            OrderError::NotOnOrder => write!(f, "that item isn't on the order"),
+            OrderError::ZeroQuantity => write!(f, "a quantity has to be at least one"),
+            OrderError::TooMany => write!(f, "that's more than one line can hold"),
+        }
+    }
+}
+
+impl std::error::Error for OrderError {}
+
+/* The fields are private so that the status can only move the way the
+ * chart says, and the lines can only change while the order is placed:
+ * every change goes through a method that checks first. */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Order {
+    id: u32,
+    table: u32,
+    lines: Vec<OrderLine>,
+    status: OrderStatus,
+}
+
+impl Order {
+    pub fn open(table: u32) -> Order {
+        Order {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            table,
+            lines: Vec::new(),
+            status: OrderStatus::Placed,
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn table(&self) -> u32 {
+        self.table
+    }
+
+    pub fn lines(&self) -> &[OrderLine] {
+        &self.lines
+    }
+
+    pub fn status(&self) -> OrderStatus {
+        self.status
+    }
+
+    /* Ordering more of something that's already on the order adds to its
+     * line, rather than starting a second one. The item has to match
+     * exactly, price and all, so a lunch and a dinner portion of the same
+     * dish get lines of their own. A line that would go past 'u32::MAX'
+     * is left as it was. */
+    pub fn add(&mut self, item: &MenuItem, quantity: u32) -> Result<(), OrderError> {
+        self.check_placed()?;
+        if quantity == 0 {
+            return Err(OrderError::ZeroQuantity);
+        }
+        match self.lines.iter_mut().find(|line| line.item == *item) {
+            Some(line) => {
+                line.quantity = line
+                    .quantity
+                    .checked_add(quantity)
+                    .ok_or(OrderError::TooMany)?
+            }
+            None => self.lines.push(OrderLine {
+                item: item.clone(),
+                quantity,
+            }),
+        }
+        Ok(())
+    }
+
+    /* Takes off up to 'quantity' of the item with this name, and the whole
+     * line once none are left. Removing more than were ordered just
+     * removes them all. */
+    pub fn remove(&mut self, name: &str, quantity: u32) -> Result<(), OrderError> {
+        self.check_placed()?;
+        if quantity == 0 {
+            return Err(OrderError::ZeroQuantity);
+        }
+        let index = self
+            .lines
+            .iter()
+            .position(|line| line.item.name == name)
+            .ok_or(OrderError::NotOnOrder)?;
+        let line = &mut self.lines[index];
+        if line.quantity > quantity {
+            line.quantity -= quantity;
+        } else {
+            self.lines.remove(index);
+        }
+        Ok(())
+    }
+
+    pub fn total_cents(&self) -> u64 {
+        self.lines.iter().map(OrderLine::total_cents).sum()
+    }
+
+    pub fn start_cooking(&mut self) -> Result<(), OrderError> {
+        self.transition(OrderStatus::Cooking)
+    }
+
+    pub fn serve(&mut self) -> Result<(), OrderError> {
+        self.transition(OrderStatus::Served)
+    }
+
+    pub fn mark_paid(&mut self) -> Result<(), OrderError> {
+        self.transition(OrderStatus::Paid)
+    }
+
+    pub fn cancel(&mut self) -> Result<(), OrderError> {
+        self.transition(OrderStatus::Cancelled)
+    }
+
+    /* Whether the order could make a move, without making it, for when
+     * something else has to be done in between. */
+    pub fn check_transition(&self, next: OrderStatus) -> Result<(), OrderError> {
+        if self.status.can_become(next) {
+            Ok(())
+        } else {
+            Err(OrderError::InvalidTransition {
+                from: self.status,
+                to: next,
+            })
+        }
+    }
+
+    fn transition(&mut self, next: OrderStatus) -> Result<(), OrderError> {
+        self.check_transition(next)?;
+        self.status = next;
+        Ok(())
+    }
+
+    fn check_placed(&self) -> Result<(), OrderError> {
+        match self.status {
+            OrderStatus::Placed => Ok(()),
+            status => Err(OrderError::Locked(status)),
+        }
+    }
+}
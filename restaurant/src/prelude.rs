@@ -0,0 +1,24 @@
+/* The Prelude */
+/* The restaurant's types are spread over a dozen modules, some of them
+ * reached through 're-exports' because 'front_of_house' is private.
+ * Another crate that wants to seat a party, take an order, and be paid
+ * for it shouldn't have to know where each piece lives, so the ones it
+ * needs are gathered here, and
+ *
+ *     use restaurant::prelude::*;
+ *
+ * brings them all into scope at once, the way the standard library's
+ * prelude brings in 'Vec' and 'String' without a 'use'. Anything more
+ * specialised is still there at its full path. */
+pub use crate::error::RestaurantError;
+pub use crate::hosting::{add_to_waitlist, seat_at_table};
+pub use crate::inventory::{Inventory, OutOfStock};
+pub use crate::menu::{format_price, Category, Menu, MenuItem, Season};
+pub use crate::orders::{Order, OrderError, OrderStatus};
+pub use crate::reservations::{ReservationBook, ReservationError, TimeSlot};
+pub use crate::seating::{FloorPlan, Party, Waitlist};
+pub use crate::serving::payment::{take_payment, PaymentError, PaymentMethod, Receipt};
+pub use crate::serving::{cancel_order, remove_from_order, serve_order, take_order};
+pub use crate::staff::{PermissionDenied, Role, Staff};
+pub use crate::tickets::{TicketError, TicketQueue};
+pub use crate::{cook_next, cook_order, fix_incorrect_order, mark_cooked};
@@ -0,0 +1,143 @@
+/* Reservations */
+/* A party can book a table ahead of time for a 'TimeSlot' later in the
+ * day. Every booking holds its table for 'SITTING_MINUTES', so two
+ * bookings for the same table conflict when they start less than that far
+ * apart. Tables come from the 'FloorPlan', but only their sizes matter
+ * here: whether someone is sitting at a table right now says nothing
+ * about who'll be there this evening.
+ *
+ * Each booking gets a number, which is what a party gives when they call
+ * to cancel. */
+use crate::seating::{FloorPlan, Party};
+use std::fmt;
+
+pub const SITTING_MINUTES: u16 = 90;
+
+/* A time of day, to the minute. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TimeSlot {
+    minutes: u16,
+}
+
+impl TimeSlot {
+    pub fn at(hour: u16, minute: u16) -> Option<TimeSlot> {
+        if hour < 24 && minute < 60 {
+            Some(TimeSlot {
+                minutes: hour * 60 + minute,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn overlaps(&self, other: &TimeSlot) -> bool {
+        self.minutes.abs_diff(other.minutes) < SITTING_MINUTES
+    }
+}
+
+impl fmt::Display for TimeSlot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.minutes / 60, self.minutes % 60)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reservation {
+    pub number: u32,
+    pub party: Party,
+    pub slot: TimeSlot,
+    pub table: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReservationError {
+    NoTableAvailable,
+    NotFound,
+}
+
+impl fmt::Display for ReservationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReservationError::NoTableAvailable => {
+                write!(f, "no table is free for that party at that time")
+            }
+            ReservationError::NotFound => write!(f, "there's no such reservation"),
+        }
+    }
+}
+
+impl std::error::Error for ReservationError {}
+
+#[derive(Debug, Clone, Default)]
+pub struct ReservationBook {
+    reservations: Vec<Reservation>,
+    last_number: u32,
+}
+
+impl ReservationBook {
+    pub fn new() -> ReservationBook {
+        ReservationBook::default()
+    }
+
+    pub fn get(&self, number: u32) -> Option<&Reservation> {
+        self.reservations.iter().find(|r| r.number == number)
+    }
+
+    pub fn is_free(&self, table: u32, slot: TimeSlot) -> bool {
+        !self
+            .reservations
+            .iter()
+            .any(|r| r.table == table && r.slot.overlaps(&slot))
+    }
+
+    /* Books the smallest table the party fits at that isn't already
+     * booked around that time, the way 'FloorPlan::seat' picks one, and
+     * returns the booking's number. */
+    pub fn book(
+        &mut self,
+        floor: &FloorPlan,
+        party: Party,
+        slot: TimeSlot,
+    ) -> Result<u32, ReservationError> {
+        let table = floor
+            .tables()
+            .iter()
+            .filter(|table| table.capacity >= party.size && self.is_free(table.number, slot))
+            .min_by_key(|table| (table.capacity, table.number))
+            .ok_or(ReservationError::NoTableAvailable)?;
+        self.last_number += 1;
+        self.reservations.push(Reservation {
+            number: self.last_number,
+            party,
+            slot,
+            table: table.number,
+        });
+        Ok(self.last_number)
+    }
+
+    pub fn cancel(&mut self, number: u32) -> Result<Reservation, ReservationError> {
+        let index = self
+            .reservations
+            .iter()
+            .position(|r| r.number == number)
+            .ok_or(ReservationError::NotFound)?;
+        Ok(self.reservations.remove(index))
+    }
+
+    /* The day's bookings, earliest first, one per line:
+     *
+     *     18:00  table 2  Ferris, party of 2
+     */
+    pub fn schedule(&self) -> String {
+        let mut day: Vec<&Reservation> = self.reservations.iter().collect();
+        day.sort_by_key(|r| (r.slot, r.table));
+        day.iter()
+            .map(|r| {
+                format!(
+                    "{}  table {}  {}, party of {}\n",
+                    r.slot, r.table, r.party.name, r.party.size
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,153 @@
+/* Seating */
+/* A 'FloorPlan' is the restaurant's tables, each with a number, the most
+ * people it can seat, and whether anyone is sitting at it. Parties who
+ * arrive when there's no table for them wait on the 'Waitlist', in the
+ * order they came in.
+ *
+ * These are the types; 'hosting' in 'lib.rs' has the functions the host
+ * uses to put parties on the waitlist and seat them. */
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Party {
+    pub name: String,
+    pub size: u32,
+}
+
+impl Party {
+    pub fn new(name: &str, size: u32) -> Party {
+        Party {
+            name: String::from(name),
+            size,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Waitlist {
+    parties: VecDeque<Party>,
+}
+
+impl Waitlist {
+    pub fn new() -> Waitlist {
+        Waitlist::default()
+    }
+
+    pub fn add(&mut self, party: Party) {
+        self.parties.push_back(party);
+    }
+
+    pub fn len(&self) -> usize {
+        self.parties.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parties.is_empty()
+    }
+
+    /* The parties in the order they'll be seated. */
+    pub fn parties(&self) -> impl Iterator<Item = &Party> {
+        self.parties.iter()
+    }
+
+    /* A party that gets tired of waiting. */
+    pub fn remove(&mut self, name: &str) -> Option<Party> {
+        let index = self.parties.iter().position(|party| party.name == name)?;
+        self.parties.remove(index)
+    }
+
+    /* Takes out the first party that 'fits' says can be seated. */
+    pub(crate) fn take_first(&mut self, fits: impl Fn(&Party) -> bool) -> Option<Party> {
+        let index = self.parties.iter().position(fits)?;
+        self.parties.remove(index)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableStatus {
+    Free,
+    Occupied { party: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub number: u32,
+    pub capacity: u32,
+    pub status: TableStatus,
+}
+
+impl Table {
+    pub fn is_free(&self) -> bool {
+        self.status == TableStatus::Free
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FloorPlan {
+    tables: Vec<Table>,
+}
+
+impl FloorPlan {
+    pub fn new() -> FloorPlan {
+        FloorPlan::default()
+    }
+
+    /* Adding a table with a number that's already taken replaces the old
+     * table, as if it had been swapped for a different size. */
+    pub fn add_table(&mut self, number: u32, capacity: u32) {
+        let table = Table {
+            number,
+            capacity,
+            status: TableStatus::Free,
+        };
+        match self.tables.iter_mut().find(|old| old.number == number) {
+            Some(old) => *old = table,
+            None => self.tables.push(table),
+        }
+    }
+
+    pub fn tables(&self) -> &[Table] {
+        &self.tables
+    }
+
+    pub fn table(&self, number: u32) -> Option<&Table> {
+        self.tables.iter().find(|table| table.number == number)
+    }
+
+    pub fn free_tables(&self) -> impl Iterator<Item = &Table> {
+        self.tables.iter().filter(|table| table.is_free())
+    }
+
+    /* The table a party would be given: the smallest free one they fit
+     * at, so that big tables are kept for big parties. Between tables of
+     * the same size, the lowest number. */
+    pub fn best_table_for(&self, party: &Party) -> Option<u32> {
+        self.free_tables()
+            .filter(|table| table.capacity >= party.size)
+            .min_by_key(|table| (table.capacity, table.number))
+            .map(|table| table.number)
+    }
+
+    pub fn seat(&mut self, party: &Party) -> Option<u32> {
+        let number = self.best_table_for(party)?;
+        let table = self
+            .tables
+            .iter_mut()
+            .find(|table| table.number == number)?;
+        table.status = TableStatus::Occupied {
+            party: party.name.clone(),
+        };
+        Some(number)
+    }
+
+    /* The party at the table leaves. 'false' if there's no such table. */
+    pub fn clear(&mut self, number: u32) -> bool {
+        match self.tables.iter_mut().find(|table| table.number == number) {
+            Some(table) => {
+                table.status = TableStatus::Free;
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,97 @@
+/* Staff */
+/* Everyone who works at the restaurant has a 'Role', and some duties are
+ * only for some roles: servers take payment, chefs fix a dish that went
+ * out wrong, and a manager can step in to do either. The functions that
+ * carry out those duties take the 'Staff' member doing it, and check
+ * before doing anything. */
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Host,
+    Server,
+    Chef,
+    Manager,
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let role = match self {
+            Role::Host => "host",
+            Role::Server => "server",
+            Role::Chef => "chef",
+            Role::Manager => "manager",
+        };
+        write!(f, "{role}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Duty {
+    TakePayment,
+    FixOrder,
+}
+
+impl fmt::Display for Duty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Duty::TakePayment => write!(f, "take payments"),
+            Duty::FixOrder => write!(f, "fix orders"),
+        }
+    }
+}
+
+impl Role {
+    pub fn can(self, duty: Duty) -> bool {
+        match duty {
+            Duty::TakePayment => matches!(self, Role::Server | Role::Manager),
+            Duty::FixOrder => matches!(self, Role::Chef | Role::Manager),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionDenied {
+    pub name: String,
+    pub role: Role,
+    pub duty: Duty,
+}
+
+impl fmt::Display for PermissionDenied {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} is a {} and can't {}",
+            self.name, self.role, self.duty
+        )
+    }
+}
+
+impl std::error::Error for PermissionDenied {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Staff {
+    pub name: String,
+    pub role: Role,
+}
+
+impl Staff {
+    pub fn new(name: &str, role: Role) -> Staff {
+        Staff {
+            name: String::from(name),
+            role,
+        }
+    }
+
+    pub fn check(&self, duty: Duty) -> Result<(), PermissionDenied> {
+        if self.role.can(duty) {
+            Ok(())
+        } else {
+            Err(PermissionDenied {
+                name: self.name.clone(),
+                role: self.role,
+                duty,
+            })
+        }
+    }
+}
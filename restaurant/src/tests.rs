@@ -0,0 +1,952 @@
+use crate::back_of_house::{Breakfast, BreakfastBuilder, Dietary};
+use crate::inventory::{Inventory, OutOfStock};
+use crate::menu::{format_price, Category, Menu, MenuItem, Season};
+use crate::orders::{Order, OrderError, OrderStatus};
+use crate::reservations::{ReservationBook, ReservationError, TimeSlot};
+use crate::seating::{FloorPlan, Party, TableStatus, Waitlist};
+use crate::serving::payment::{take_payment, PaymentError, PaymentMethod, Receipt};
+use crate::staff::{Duty, PermissionDenied, Role, Staff};
+use crate::tickets::{TicketError, TicketQueue, TicketState};
+use crate::*;
+use std::error::Error;
+
+fn pancakes() -> MenuItem {
+    MenuItem::new("pancakes", 850, Category::Breakfast)
+}
+
+fn coffee() -> MenuItem {
+    MenuItem::new("coffee", 275, Category::Drink)
+}
+
+#[test]
+fn test_order_running_total() {
+    let mut order = Order::open(4);
+    assert_eq!(order.table(), 4);
+    assert_eq!(order.total_cents(), 0);
+
+    order.add(&pancakes(), 2).unwrap();
+    assert_eq!(order.total_cents(), 1700);
+    order.add(&coffee(), 1).unwrap();
+    order.add(&coffee(), 2).unwrap();
+    assert_eq!(order.lines().len(), 2);
+    assert_eq!(order.lines()[1].quantity, 3);
+    assert_eq!(order.total_cents(), 1700 + 825);
+}
+
+#[test]
+fn test_order_amend() {
+    let mut order = Order::open(1);
+    order.add(&pancakes(), 3).unwrap();
+    order.add(&coffee(), 1).unwrap();
+
+    order.remove("pancakes", 1).unwrap();
+    assert_eq!(order.lines()[0].quantity, 2);
+    /* Removing more than were ordered takes the whole line off. */
+    order.remove("coffee", 5).unwrap();
+    assert_eq!(order.lines().len(), 1);
+    assert_eq!(order.remove("coffee", 1), Err(OrderError::NotOnOrder));
+    assert_eq!(order.add(&coffee(), 0), Err(OrderError::ZeroQuantity));
+    assert_eq!(order.remove("pancakes", 0), Err(OrderError::ZeroQuantity));
+    /* A line that would overflow is refused and left alone. */
+    order.add(&coffee(), u32::MAX - 1).unwrap();
+    assert_eq!(order.add(&coffee(), 2), Err(OrderError::TooMany));
+    assert_eq!(order.lines()[1].quantity, u32::MAX - 1);
+}
+
+#[test]
+fn test_order_lifecycle() {
+    let mut order = Order::open(2);
+    assert_eq!(order.status(), OrderStatus::Placed);
+    order.add(&pancakes(), 1).unwrap();
+    order.start_cooking().unwrap();
+    assert_eq!(
+        order.add(&coffee(), 1),
+        Err(OrderError::Locked(OrderStatus::Cooking))
+    );
+    assert_eq!(
+        order.remove("pancakes", 1),
+        Err(OrderError::Locked(OrderStatus::Cooking))
+    );
+    assert_eq!(
+        order.mark_paid(),
+        Err(OrderError::InvalidTransition {
+            from: OrderStatus::Cooking,
+            to: OrderStatus::Paid
+        })
+    );
+    assert_eq!(order.status(), OrderStatus::Cooking);
+    order.serve().unwrap();
+    assert_eq!(
+        order.cancel().unwrap_err().to_string(),
+        "an order that's served can't be cancelled"
+    );
+    order.mark_paid().unwrap();
+    assert_eq!(order.status(), OrderStatus::Paid);
+    assert_eq!(order.total_cents(), 850);
+}
+
+#[test]
+fn test_cancelled_order_goes_nowhere() {
+    let mut order = Order::open(3);
+    order.add(&coffee(), 1).unwrap();
+    order.cancel().unwrap();
+    for next in [
+        OrderStatus::Placed,
+        OrderStatus::Cooking,
+        OrderStatus::Served,
+        OrderStatus::Paid,
+        OrderStatus::Cancelled,
+    ] {
+        assert!(!OrderStatus::Cancelled.can_become(next));
+        assert!(order.check_transition(next).is_err());
+    }
+    assert!(order.serve().is_err());
+    assert_eq!(
+        order.add(&coffee(), 1),
+        Err(OrderError::Locked(OrderStatus::Cancelled))
+    );
+
+    let mut cooking = Order::open(4);
+    cooking.start_cooking().unwrap();
+    cooking.cancel().unwrap();
+    assert_eq!(cooking.status(), OrderStatus::Cancelled);
+}
+
+#[test]
+fn test_order_total_is_wide() {
+    let mut order = Order::open(3);
+    order
+        .add(
+            &MenuItem::new("caviar", u32::MAX, Category::Appetizer),
+            u32::MAX,
+        )
+        .unwrap();
+    assert_eq!(
+        order.total_cents(),
+        u64::from(u32::MAX) * u64::from(u32::MAX)
+    );
+}
+
+fn names<'a>(items: impl Iterator<Item = &'a MenuItem>) -> Vec<&'a str> {
+    items.map(|item| item.name.as_str()).collect()
+}
+
+#[test]
+fn test_menu_lookups() {
+    let menu = Menu::house();
+    assert_eq!(menu.get("soup").map(|item| item.price_cents), Some(600));
+    assert!(menu.get("lobster").is_none());
+    assert_eq!(
+        names(menu.in_category(Category::Drink)),
+        ["coffee", "orange juice"]
+    );
+    assert_eq!(
+        names(menu.in_category(Category::Breakfast)),
+        ["pancakes", "eggs on toast"]
+    );
+}
+
+#[test]
+fn test_menu_add_replaces() {
+    let mut menu = Menu::new();
+    menu.add(coffee());
+    menu.add(MenuItem::new("coffee", 300, Category::Drink));
+    assert_eq!(menu.items().len(), 1);
+    assert_eq!(menu.items()[0].price_cents, 300);
+}
+
+#[test]
+fn test_menu_available_on() {
+    let menu = Menu::house();
+    let fruit: Vec<&str> = menu
+        .available_on(Season::Autumn)
+        .filter(|item| item.category == Category::Fruit)
+        .map(|item| item.name.as_str())
+        .collect();
+    assert_eq!(fruit, ["apples", "bananas"]);
+    assert_eq!(
+        menu.available_on(Season::Winter).count(),
+        menu.items().len() - 3
+    );
+}
+
+#[test]
+fn test_breakfast_fruit() {
+    let meal = Breakfast::summer("Rye");
+    assert_eq!(meal.toast, "Rye");
+    assert_eq!(meal.seasonal_fruit(), "peaches");
+
+    let menu = Menu::house();
+    let meal = Breakfast::in_season("Wheat", &menu, Season::Spring).unwrap();
+    assert_eq!(meal.seasonal_fruit(), "strawberries");
+
+    /* With nothing in season, the chef falls back on the year-round fruit,
+     * and with no fruit at all there's no breakfast. */
+    let mut menu = Menu::new();
+    menu.add(MenuItem::new("peaches", 300, Category::Fruit).only_in(Season::Summer));
+    assert!(Breakfast::in_season("Rye", &menu, Season::Winter).is_none());
+    menu.add(MenuItem::new("bananas", 200, Category::Fruit));
+    let meal = Breakfast::in_season("Rye", &menu, Season::Winter).unwrap();
+    assert_eq!(meal.seasonal_fruit(), "bananas");
+    let meal = Breakfast::in_season("Rye", &menu, Season::Summer).unwrap();
+    assert_eq!(meal.seasonal_fruit(), "peaches");
+}
+
+#[test]
+fn test_format_price() {
+    assert_eq!(format_price(0), "$0.00");
+    assert_eq!(format_price(5), "$0.05");
+    assert_eq!(format_price(850), "$8.50");
+    assert_eq!(format_price(123_456_789), "$1,234,567.89");
+    assert_eq!(format_price(100_000), "$1,000.00");
+}
+
+fn pay(order: &mut Order, method: PaymentMethod) -> Result<Receipt, RestaurantError> {
+    take_payment(&Staff::new("Corro", Role::Server), order, method)
+}
+
+fn declined(e: PaymentError) -> Result<Receipt, RestaurantError> {
+    Err(RestaurantError::PaymentDeclined(e))
+}
+
+fn served_order() -> Order {
+    let mut order = Order::open(4);
+    order.add(&pancakes(), 2).unwrap();
+    order.add(&coffee(), 3).unwrap();
+    order.start_cooking().unwrap();
+    order.serve().unwrap();
+    order
+}
+
+#[test]
+fn test_take_payment() {
+    let mut order = served_order();
+    let receipt = pay(&mut order, PaymentMethod::Card { last4: 42 }).unwrap();
+    assert_eq!(receipt.total_cents, 2525);
+    assert_eq!(receipt.lines.len(), 2);
+    assert_eq!(receipt.remaining_balance(), None);
+    assert_eq!(
+        receipt.to_string(),
+        "Table 4\n\
+         2 x pancakes            $17.00\n\
+         3 x coffee               $8.25\n\
+         Total                   $25.25\n\
+         Paid by card ending in 0042"
+    );
+    assert_eq!(order.status(), OrderStatus::Paid);
+    /* Once is enough. */
+    assert_eq!(
+        pay(&mut order, PaymentMethod::Cash),
+        Err(RestaurantError::Order(OrderError::InvalidTransition {
+            from: OrderStatus::Paid,
+            to: OrderStatus::Paid
+        }))
+    );
+
+    let receipt = pay(&mut served_order(), PaymentMethod::Cash).unwrap();
+    assert!(receipt.to_string().ends_with("\nPaid in cash"));
+}
+
+#[test]
+fn test_gift_card_payment() {
+    let gift_card = |balance| PaymentMethod::GiftCard { balance };
+    let receipt = pay(&mut served_order(), gift_card(3000)).unwrap();
+    assert_eq!(receipt.remaining_balance(), Some(475));
+    assert!(receipt
+        .to_string()
+        .ends_with("\nPaid by gift card, $4.75 left on it"));
+
+    let exact = pay(&mut served_order(), gift_card(2525)).unwrap();
+    assert_eq!(exact.remaining_balance(), Some(0));
+    let overdrawn = Receipt {
+        total_cents: 3001,
+        ..receipt
+    };
+    assert_eq!(overdrawn.remaining_balance(), Some(0));
+
+    /* A payment that doesn't go through leaves the order unpaid. */
+    let mut order = served_order();
+    assert_eq!(
+        pay(&mut order, gift_card(2524)),
+        declined(PaymentError::InsufficientBalance {
+            balance: 2524,
+            due: 2525
+        })
+    );
+    assert_eq!(order.status(), OrderStatus::Served);
+}
+
+#[test]
+fn test_payment_errors() {
+    let mut order = Order::open(1);
+    assert_eq!(
+        pay(&mut order, PaymentMethod::Cash),
+        Err(RestaurantError::Order(OrderError::InvalidTransition {
+            from: OrderStatus::Placed,
+            to: OrderStatus::Paid
+        }))
+    );
+    order.start_cooking().unwrap();
+    order.serve().unwrap();
+    assert_eq!(
+        pay(&mut order, PaymentMethod::Cash),
+        declined(PaymentError::NothingToPay)
+    );
+    assert_eq!(
+        pay(&mut served_order(), PaymentMethod::Card { last4: 10_000 }),
+        declined(PaymentError::InvalidCard)
+    );
+}
+
+fn floor() -> FloorPlan {
+    let mut floor = FloorPlan::new();
+    floor.add_table(1, 4);
+    floor.add_table(2, 2);
+    floor.add_table(3, 6);
+    floor.add_table(4, 2);
+    floor
+}
+
+#[test]
+fn test_floor_plan_seats_smallest_table() {
+    let mut floor = floor();
+    assert_eq!(floor.seat(&Party::new("Ferris", 2)), Some(2));
+    assert_eq!(floor.seat(&Party::new("Corro", 1)), Some(4));
+    assert_eq!(floor.seat(&Party::new("Rustacean", 2)), Some(1));
+    assert_eq!(floor.seat(&Party::new("Crab", 7)), None);
+    assert_eq!(
+        floor.table(2).map(|table| &table.status),
+        Some(&TableStatus::Occupied {
+            party: String::from("Ferris")
+        })
+    );
+    assert_eq!(floor.free_tables().count(), 1);
+
+    assert!(floor.clear(2));
+    assert!(!floor.clear(9));
+    assert!(floor.table(2).unwrap().is_free());
+}
+
+#[test]
+fn test_floor_plan_replaces_table() {
+    let mut floor = floor();
+    floor.add_table(2, 8);
+    assert_eq!(floor.tables().len(), 4);
+    assert_eq!(floor.table(2).map(|table| table.capacity), Some(8));
+}
+
+#[test]
+fn test_waitlist() {
+    let mut waitlist = Waitlist::new();
+    hosting::add_to_waitlist(&mut waitlist, "Ferris", 2);
+    hosting::add_to_waitlist(&mut waitlist, "Corro", 3);
+    hosting::add_to_waitlist(&mut waitlist, "Crab", 1);
+    assert_eq!(waitlist.len(), 3);
+
+    assert_eq!(waitlist.remove("Corro"), Some(Party::new("Corro", 3)));
+    assert_eq!(waitlist.remove("Corro"), None);
+    let names: Vec<&str> = waitlist.parties().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["Ferris", "Crab"]);
+}
+
+#[test]
+fn test_seat_at_table() {
+    let mut floor = FloorPlan::new();
+    floor.add_table(1, 2);
+    floor.add_table(2, 4);
+    let mut waitlist = Waitlist::new();
+    hosting::add_to_waitlist(&mut waitlist, "Big", 6);
+    hosting::add_to_waitlist(&mut waitlist, "Ferris", 4);
+    hosting::add_to_waitlist(&mut waitlist, "Corro", 2);
+    hosting::add_to_waitlist(&mut waitlist, "Crab", 2);
+
+    /* The party of six can't be seated, so the parties behind it go first. */
+    assert_eq!(hosting::seat_at_table(&mut waitlist, &mut floor), Ok(2));
+    assert_eq!(hosting::seat_at_table(&mut waitlist, &mut floor), Ok(1));
+    assert_eq!(
+        hosting::seat_at_table(&mut waitlist, &mut floor),
+        Err(RestaurantError::NoTablesAvailable)
+    );
+    let names: Vec<&str> = waitlist.parties().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["Big", "Crab"]);
+
+    floor.clear(1);
+    assert_eq!(hosting::seat_at_table(&mut waitlist, &mut floor), Ok(1));
+    assert_eq!(waitlist.len(), 1);
+}
+
+fn kitchen() -> Inventory {
+    let mut inventory = Inventory::new();
+    inventory.track("eggs", 6);
+    inventory.track("flour", 4);
+    inventory.restock("eggs", 12);
+    inventory.restock("flour", 10);
+    inventory.add_recipe("pancakes", &[("eggs", 2), ("flour", 3)]);
+    inventory
+}
+
+#[test]
+fn test_inventory_consume_and_restock() {
+    let mut inventory = kitchen();
+    inventory.consume("eggs", 5).unwrap();
+    assert_eq!(inventory.on_hand("eggs"), 7);
+    assert_eq!(
+        inventory.consume("eggs", 8),
+        Err(OutOfStock {
+            ingredient: String::from("eggs"),
+            needed: 8,
+            on_hand: 7
+        })
+    );
+    assert_eq!(inventory.on_hand("eggs"), 7);
+    assert!(inventory.consume("saffron", 1).is_err());
+
+    inventory.restock("saffron", 2);
+    assert_eq!(inventory.on_hand("saffron"), 2);
+    inventory.restock("saffron", u32::MAX);
+    assert_eq!(inventory.on_hand("saffron"), u32::MAX);
+}
+
+#[test]
+fn test_low_stock_report() {
+    let mut inventory = kitchen();
+    assert_eq!(inventory.low_stock().count(), 0);
+    inventory.consume("flour", 6).unwrap();
+    inventory.consume("eggs", 5).unwrap();
+    assert_eq!(inventory.low_stock_report(), "flour: 4 left (low at 4)\n");
+    inventory.consume("eggs", 3).unwrap();
+    assert_eq!(
+        inventory.low_stock_report(),
+        "eggs: 4 left (low at 6)\nflour: 4 left (low at 4)\n"
+    );
+    inventory.track("flour", 2);
+    assert_eq!(inventory.low_stock().collect::<Vec<_>>(), [("eggs", 4)]);
+}
+
+#[test]
+fn test_cook_order() {
+    let menu = Menu::house();
+    let mut inventory = kitchen();
+    let mut tickets = TicketQueue::new();
+    let mut order = Order::open(1);
+    let pancakes = serving::take_order(&mut order, &menu, &mut tickets, "pancakes", 2).unwrap();
+    let coffee = serving::take_order(&mut order, &menu, &mut tickets, "coffee", 1).unwrap();
+    cook_order(&mut order, &mut tickets, &mut inventory).unwrap();
+    assert_eq!(order.status(), OrderStatus::Cooking);
+    assert_eq!(inventory.on_hand("eggs"), 8);
+    assert_eq!(inventory.on_hand("flour"), 4);
+    for number in [pancakes, coffee] {
+        assert_eq!(
+            tickets.get(number).map(|t| t.state),
+            Some(TicketState::Cooking)
+        );
+    }
+    /* An order is only cooked once. */
+    assert!(matches!(
+        cook_order(&mut order, &mut tickets, &mut inventory),
+        Err(RestaurantError::Order(OrderError::InvalidTransition { .. }))
+    ));
+
+    /* Two more pancakes would need six cups of flour; nothing is used
+     * when the order can't be made, and it stays placed. */
+    let mut again = Order::open(2);
+    let more = serving::take_order(&mut again, &menu, &mut tickets, "pancakes", 2).unwrap();
+    let error = cook_order(&mut again, &mut tickets, &mut inventory).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "the kitchen can't make that: out of flour: 6 needed, 4 on hand"
+    );
+    assert_eq!(inventory.on_hand("eggs"), 8);
+    assert_eq!(again.status(), OrderStatus::Placed);
+    assert_eq!(
+        tickets.get(more).map(|t| t.state),
+        Some(TicketState::Received)
+    );
+
+    again.cancel().unwrap();
+    assert!(cook_order(&mut again, &mut tickets, &mut inventory).is_err());
+}
+
+#[test]
+fn test_order_cooked_both_ways_uses_stock_once() {
+    let menu = Menu::house();
+    let mut inventory = kitchen();
+    let mut tickets = TicketQueue::new();
+    let mut order = Order::open(3);
+    let pancakes = serving::take_order(&mut order, &menu, &mut tickets, "pancakes", 1).unwrap();
+    let more = serving::take_order(&mut order, &menu, &mut tickets, "pancakes", 1).unwrap();
+
+    /* The kitchen gets to the first ticket before the order is cooked;
+     * 'cook_order' then only starts the one that's left. */
+    assert_eq!(cook_next(&mut tickets, &mut inventory), Ok(Some(pancakes)));
+    assert_eq!(inventory.on_hand("flour"), 7);
+    cook_order(&mut order, &mut tickets, &mut inventory).unwrap();
+    assert_eq!(inventory.on_hand("flour"), 4);
+    assert_eq!(inventory.on_hand("eggs"), 8);
+    assert_eq!(
+        tickets.get(more).map(|t| t.state),
+        Some(TicketState::Cooking)
+    );
+    assert_eq!(cook_next(&mut tickets, &mut inventory), Ok(None));
+    assert_eq!(inventory.on_hand("flour"), 4);
+}
+
+#[test]
+fn test_orders_at_one_table_keep_their_own_tickets() {
+    let menu = Menu::house();
+    let mut inventory = kitchen();
+    let mut tickets = TicketQueue::new();
+    let mut first = Order::open(4);
+    let mut second = Order::open(4);
+    assert_ne!(first.id(), second.id());
+    let pancakes = serving::take_order(&mut first, &menu, &mut tickets, "pancakes", 1).unwrap();
+    let coffee = serving::take_order(&mut second, &menu, &mut tickets, "coffee", 1).unwrap();
+
+    /* Cooking the second order leaves the first one's pancakes alone. */
+    cook_order(&mut second, &mut tickets, &mut inventory).unwrap();
+    assert_eq!(inventory.on_hand("flour"), 10);
+    assert_eq!(
+        tickets.get(pancakes).map(|t| t.state),
+        Some(TicketState::Received)
+    );
+    assert_eq!(
+        tickets.get(coffee).map(|t| (t.order, t.state)),
+        Some((second.id(), TicketState::Cooking))
+    );
+}
+
+#[test]
+fn test_cancel_order_stops_the_kitchen() {
+    let menu = Menu::house();
+    let mut inventory = kitchen();
+    let mut tickets = TicketQueue::new();
+    let mut order = Order::open(7);
+    let cooking = serving::take_order(&mut order, &menu, &mut tickets, "pancakes", 1).unwrap();
+    let waiting = serving::take_order(&mut order, &menu, &mut tickets, "pancakes", 2).unwrap();
+    cook_next(&mut tickets, &mut inventory).unwrap();
+
+    serving::cancel_order(&mut order, &mut tickets).unwrap();
+    assert_eq!(order.status(), OrderStatus::Cancelled);
+    assert_eq!(cook_next(&mut tickets, &mut inventory), Ok(None));
+    assert_eq!(inventory.on_hand("flour"), 7);
+    for number in [cooking, waiting] {
+        assert_eq!(
+            tickets.get(number).map(|t| t.state),
+            Some(TicketState::Cancelled)
+        );
+    }
+    assert!(mark_cooked(&mut tickets, cooking).is_err());
+    assert!(serving::serve_order(&mut tickets, cooking).is_err());
+    /* A cancelled order can't be cancelled again. */
+    assert!(serving::cancel_order(&mut order, &mut tickets).is_err());
+}
+
+#[test]
+fn test_remove_from_order_takes_back_tickets() {
+    let menu = Menu::house();
+    let mut inventory = kitchen();
+    let mut tickets = TicketQueue::new();
+    let mut order = Order::open(6);
+    let number = serving::take_order(&mut order, &menu, &mut tickets, "pancakes", 3).unwrap();
+
+    serving::remove_from_order(&mut order, &mut tickets, "pancakes", 1).unwrap();
+    assert_eq!(order.lines()[0].quantity, 2);
+    assert_eq!(tickets.get(number).map(|t| t.quantity), Some(2));
+    serving::remove_from_order(&mut order, &mut tickets, "pancakes", 5).unwrap();
+    assert!(order.lines().is_empty());
+    assert_eq!(
+        tickets.get(number).map(|t| t.state),
+        Some(TicketState::Cancelled)
+    );
+    assert_eq!(tickets.outstanding().count(), 0);
+    assert_eq!(cook_next(&mut tickets, &mut inventory), Ok(None));
+    assert_eq!(inventory.on_hand("flour"), 10);
+
+    /* Too late once the kitchen has started; nothing changes. */
+    serving::take_order(&mut order, &menu, &mut tickets, "pancakes", 1).unwrap();
+    cook_next(&mut tickets, &mut inventory).unwrap();
+    assert_eq!(
+        serving::remove_from_order(&mut order, &mut tickets, "pancakes", 1),
+        Err(RestaurantError::Ticket(TicketError::AlreadyStarted))
+    );
+    assert_eq!(order.lines()[0].quantity, 1);
+    assert_eq!(
+        serving::remove_from_order(&mut order, &mut tickets, "soup", 1),
+        Err(RestaurantError::Order(OrderError::NotOnOrder))
+    );
+}
+
+fn at(hour: u16, minute: u16) -> TimeSlot {
+    TimeSlot::at(hour, minute).unwrap()
+}
+
+#[test]
+fn test_time_slot() {
+    assert_eq!(at(9, 5).to_string(), "09:05");
+    assert_eq!(at(23, 59).to_string(), "23:59");
+    assert!(TimeSlot::at(24, 0).is_none());
+    assert!(TimeSlot::at(12, 60).is_none());
+    assert!(at(18, 0).overlaps(&at(19, 29)));
+    assert!(at(19, 29).overlaps(&at(18, 0)));
+    assert!(!at(18, 0).overlaps(&at(19, 30)));
+}
+
+#[test]
+fn test_book_reservations() {
+    let floor = floor();
+    let mut book = ReservationBook::new();
+    let first = book
+        .book(&floor, Party::new("Ferris", 2), at(18, 0))
+        .unwrap();
+    assert_eq!(book.get(first).map(|r| r.table), Some(2));
+    let second = book
+        .book(&floor, Party::new("Corro", 2), at(18, 30))
+        .unwrap();
+    assert_eq!(book.get(second).map(|r| r.table), Some(4));
+    /* Both small tables are taken, so the next party of two gets a bigger
+     * one; once the first sitting is over, a small one is free again. */
+    let third = book.book(&floor, Party::new("Crab", 2), at(19, 0)).unwrap();
+    assert_eq!(book.get(third).map(|r| r.table), Some(1));
+    let fourth = book
+        .book(&floor, Party::new("Late", 1), at(19, 30))
+        .unwrap();
+    assert_eq!(book.get(fourth).map(|r| r.table), Some(2));
+
+    assert_eq!(
+        book.book(&floor, Party::new("Huge", 7), at(12, 0)),
+        Err(ReservationError::NoTableAvailable)
+    );
+    assert!(!book.is_free(1, at(20, 0)));
+    assert!(book.is_free(1, at(20, 30)));
+}
+
+#[test]
+fn test_cancel_reservation() {
+    let floor = floor();
+    let mut book = ReservationBook::new();
+    let number = book.book(&floor, Party::new("Big", 6), at(20, 0)).unwrap();
+    assert_eq!(
+        book.book(&floor, Party::new("Bigger", 5), at(20, 0)),
+        Err(ReservationError::NoTableAvailable)
+    );
+    let cancelled = book.cancel(number).unwrap();
+    assert_eq!(cancelled.party.name, "Big");
+    assert_eq!(book.cancel(number), Err(ReservationError::NotFound));
+    let again = book
+        .book(&floor, Party::new("Bigger", 5), at(20, 0))
+        .unwrap();
+    assert_ne!(again, number);
+}
+
+#[test]
+fn test_schedule() {
+    let floor = floor();
+    let mut book = ReservationBook::new();
+    book.book(&floor, Party::new("Corro", 4), at(19, 0))
+        .unwrap();
+    book.book(&floor, Party::new("Ferris", 2), at(18, 0))
+        .unwrap();
+    book.book(&floor, Party::new("Crab", 2), at(18, 0)).unwrap();
+    assert_eq!(
+        book.schedule(),
+        "18:00  table 2  Ferris, party of 2\n\
+         18:00  table 4  Crab, party of 2\n\
+         19:00  table 1  Corro, party of 4\n"
+    );
+    assert_eq!(ReservationBook::new().schedule(), "");
+}
+
+#[test]
+fn test_take_order_from_menu() {
+    let menu = Menu::house();
+    let mut order = Order::open(1);
+    let mut tickets = TicketQueue::new();
+    let number = serving::take_order(&mut order, &menu, &mut tickets, "soup", 2).unwrap();
+    assert_eq!(order.total_cents(), 1200);
+    assert_eq!(
+        tickets.get(number).map(|ticket| ticket.to_string()),
+        Some(String::from("#1 table 1: 2 x soup (received)"))
+    );
+    assert_eq!(
+        serving::take_order(&mut order, &menu, &mut tickets, "lobster", 1),
+        Err(RestaurantError::ItemNotOnMenu(String::from("lobster")))
+    );
+    order.start_cooking().unwrap();
+    assert_eq!(
+        serving::take_order(&mut order, &menu, &mut tickets, "soup", 1),
+        Err(RestaurantError::Order(OrderError::Locked(
+            OrderStatus::Cooking
+        )))
+    );
+    assert_eq!(tickets.outstanding().count(), 1);
+}
+
+#[test]
+fn test_ticket_states() {
+    let mut tickets = TicketQueue::new();
+    let number = tickets.push(1, 3, "salad", 1);
+    assert_eq!(
+        tickets.advance(number, TicketState::Cooking),
+        Err(TicketError::WrongState {
+            expected: TicketState::Cooking,
+            found: TicketState::Received
+        })
+    );
+    assert_eq!(
+        tickets.advance(number, TicketState::Received),
+        Ok(TicketState::Cooking)
+    );
+    assert_eq!(
+        tickets.advance(number, TicketState::Cooking),
+        Ok(TicketState::Ready)
+    );
+    assert_eq!(
+        tickets.advance(number, TicketState::Ready),
+        Ok(TicketState::Delivered)
+    );
+    assert!(tickets.advance(number, TicketState::Delivered).is_err());
+    assert_eq!(
+        tickets.advance(99, TicketState::Received),
+        Err(TicketError::NotFound)
+    );
+    assert_eq!(tickets.outstanding().count(), 0);
+    assert!(tickets.get(number).is_some());
+}
+
+#[test]
+fn test_kitchen_works_through_tickets() {
+    let menu = Menu::house();
+    let mut inventory = kitchen();
+    let mut tickets = TicketQueue::new();
+    let mut first = Order::open(1);
+    let mut second = Order::open(2);
+    let pancakes = serving::take_order(&mut first, &menu, &mut tickets, "pancakes", 2).unwrap();
+    let coffee = serving::take_order(&mut second, &menu, &mut tickets, "coffee", 1).unwrap();
+    let more = serving::take_order(&mut second, &menu, &mut tickets, "pancakes", 3).unwrap();
+
+    assert_eq!(cook_next(&mut tickets, &mut inventory), Ok(Some(pancakes)));
+    assert_eq!(inventory.on_hand("flour"), 4);
+    /* The dish that's cooking can't be served until it's marked cooked. */
+    assert!(serving::serve_order(&mut tickets, pancakes).is_err());
+    mark_cooked(&mut tickets, pancakes).unwrap();
+    serving::serve_order(&mut tickets, pancakes).unwrap();
+
+    assert_eq!(cook_next(&mut tickets, &mut inventory), Ok(Some(coffee)));
+    /* Three more pancakes need nine cups of flour, and there are four. */
+    assert!(matches!(
+        cook_next(&mut tickets, &mut inventory),
+        Err(RestaurantError::OutOfStock(_))
+    ));
+    assert_eq!(
+        tickets.get(more).map(|t| t.state),
+        Some(TicketState::Received)
+    );
+    inventory.restock("flour", 5);
+    assert_eq!(cook_next(&mut tickets, &mut inventory), Ok(Some(more)));
+    assert_eq!(cook_next(&mut tickets, &mut inventory), Ok(None));
+
+    let outstanding: Vec<String> = tickets.outstanding().map(|t| t.to_string()).collect();
+    assert_eq!(
+        outstanding,
+        [
+            "#2 table 2: 1 x coffee (cooking)",
+            "#3 table 2: 3 x pancakes (cooking)"
+        ]
+    );
+}
+
+#[test]
+fn test_restaurant_error_conversions() {
+    assert_eq!(
+        RestaurantError::from(ReservationError::NoTableAvailable),
+        RestaurantError::NoTablesAvailable
+    );
+    assert_eq!(
+        RestaurantError::from(ReservationError::NotFound),
+        RestaurantError::ReservationNotFound
+    );
+
+    let declined = RestaurantError::from(PaymentError::InvalidCard);
+    assert_eq!(
+        declined.to_string(),
+        "the payment was declined: a card number ends in four digits"
+    );
+    assert!(declined.source().is_some());
+    assert!(RestaurantError::NoTablesAvailable.source().is_none());
+    assert_eq!(
+        RestaurantError::ItemNotOnMenu(String::from("lobster")).to_string(),
+        "'lobster' isn't on the menu"
+    );
+}
+
+#[test]
+fn test_eat_at_restaurant() {
+    assert_eq!(eat_at_restaurant(), Ok(()));
+}
+
+#[test]
+fn test_breakfast_builder() {
+    let menu = Menu::house();
+    let meal = BreakfastBuilder::new("Sourdough")
+        .side("bacon")
+        .side("beans")
+        .drink("coffee")
+        .gluten_free()
+        .build(&menu, Season::Winter)
+        .unwrap();
+    assert_eq!(meal.sides, ["bacon", "beans"]);
+    assert_eq!(meal.drink.as_deref(), Some("coffee"));
+    assert_eq!(meal.seasonal_fruit(), "oranges");
+    assert_eq!(
+        meal.to_string(),
+        "Sourdough toast with oranges, bacon, beans, and coffee (gluten free)"
+    );
+
+    let plain = BreakfastBuilder::new("Rye")
+        .build(&menu, Season::Summer)
+        .unwrap();
+    assert_eq!(plain.dietary, Dietary::default());
+    assert_eq!(plain.to_string(), "Rye toast with peaches");
+    let with_drink = BreakfastBuilder::new("Rye")
+        .drink("tea")
+        .build(&menu, Season::Summer);
+    assert_eq!(
+        with_drink.unwrap().to_string(),
+        "Rye toast with peaches and tea"
+    );
+    assert!(BreakfastBuilder::new("Rye")
+        .build(&Menu::new(), Season::Summer)
+        .is_none());
+}
+
+#[test]
+fn test_breakfast_vegan_is_vegetarian() {
+    let meal = BreakfastBuilder::new("Wheat")
+        .vegan()
+        .build(&Menu::house(), Season::Spring)
+        .unwrap();
+    assert_eq!(
+        meal.dietary,
+        Dietary {
+            vegetarian: true,
+            vegan: true,
+            gluten_free: false
+        }
+    );
+    assert_eq!(
+        meal.to_string(),
+        "Wheat toast with strawberries (vegetarian, vegan)"
+    );
+}
+
+#[test]
+fn test_appetizers() {
+    let soup = Appetizer::Soup {
+        of_the_day: String::from("tomato"),
+        vegetarian: true,
+    };
+    assert_eq!(soup.to_string(), "tomato soup");
+    assert_eq!(soup.price(), 600);
+    assert!(soup.is_vegetarian());
+
+    let salad = Appetizer::Salad { with_chicken: true };
+    assert_eq!(salad.to_string(), "chicken salad");
+    assert_eq!(salad.price(), 850);
+    assert!(!salad.is_vegetarian());
+
+    let wings = Appetizer::Wings { count: 10 };
+    assert_eq!(wings.to_string(), "10 wings");
+    assert_eq!(wings.price(), 1500);
+    assert!(!wings.is_vegetarian());
+    assert_eq!(Appetizer::Wings { count: u32::MAX }.price(), u32::MAX);
+}
+
+#[test]
+fn test_todays_appetizers() {
+    let monday: Vec<String> = todays_appetizers(Weekday::Monday)
+        .iter()
+        .map(|a| a.to_string())
+        .collect();
+    assert_eq!(monday, ["tomato soup", "salad", "chicken salad"]);
+
+    let sunday = todays_appetizers(Weekday::Sunday);
+    assert_eq!(sunday.len(), 4);
+    assert_eq!(sunday[0].to_string(), "pea and ham soup");
+    let vegetarian: Vec<String> = sunday
+        .iter()
+        .filter(|a| a.is_vegetarian())
+        .map(|a| a.to_string())
+        .collect();
+    assert_eq!(vegetarian, ["salad"]);
+}
+
+#[test]
+fn test_roles() {
+    assert!(Role::Server.can(Duty::TakePayment));
+    assert!(Role::Manager.can(Duty::TakePayment));
+    assert!(!Role::Host.can(Duty::TakePayment));
+    assert!(!Role::Chef.can(Duty::TakePayment));
+    assert!(Role::Chef.can(Duty::FixOrder));
+    assert!(Role::Manager.can(Duty::FixOrder));
+    assert!(!Role::Server.can(Duty::FixOrder));
+    assert!(!Role::Host.can(Duty::FixOrder));
+}
+
+#[test]
+fn test_take_payment_needs_a_server() {
+    let mut order = served_order();
+    let host = Staff::new("Ferris", Role::Host);
+    let denied = take_payment(&host, &mut order, PaymentMethod::Cash).unwrap_err();
+    assert_eq!(
+        denied,
+        RestaurantError::PermissionDenied(PermissionDenied {
+            name: String::from("Ferris"),
+            role: Role::Host,
+            duty: Duty::TakePayment
+        })
+    );
+    assert_eq!(
+        denied.to_string(),
+        "permission denied: Ferris is a host and can't take payments"
+    );
+    assert_eq!(order.status(), OrderStatus::Served);
+    let manager = Staff::new("Boss", Role::Manager);
+    assert!(take_payment(&manager, &mut order, PaymentMethod::Cash).is_ok());
+}
+
+#[test]
+fn test_fix_incorrect_order() {
+    let menu = Menu::house();
+    let mut inventory = kitchen();
+    let mut tickets = TicketQueue::new();
+    let mut order = Order::open(5);
+    let number = serving::take_order(&mut order, &menu, &mut tickets, "pancakes", 1).unwrap();
+    let chef = Staff::new("Remy", Role::Chef);
+
+    /* Nothing can be fixed before it's been served. */
+    assert!(matches!(
+        fix_incorrect_order(&chef, &mut tickets, number, &mut inventory),
+        Err(RestaurantError::Ticket(TicketError::WrongState { .. }))
+    ));
+    cook_next(&mut tickets, &mut inventory).unwrap();
+    mark_cooked(&mut tickets, number).unwrap();
+    serving::serve_order(&mut tickets, number).unwrap();
+
+    let server = Staff::new("Corro", Role::Server);
+    assert!(matches!(
+        fix_incorrect_order(&server, &mut tickets, number, &mut inventory),
+        Err(RestaurantError::PermissionDenied(_))
+    ));
+    assert_eq!(inventory.on_hand("eggs"), 10);
+
+    let remade = fix_incorrect_order(&chef, &mut tickets, number, &mut inventory).unwrap();
+    assert_eq!(inventory.on_hand("eggs"), 8);
+    assert_eq!(
+        tickets.get(remade).map(|t| t.to_string()),
+        Some(String::from("#2 table 5: 1 x pancakes (cooking)"))
+    );
+    assert_eq!(
+        fix_incorrect_order(&chef, &mut tickets, 99, &mut inventory),
+        Err(RestaurantError::Ticket(TicketError::NotFound))
+    );
+}
@@ -0,0 +1,202 @@
+/* Kitchen Tickets */
+/* When a server takes an order, the kitchen gets a ticket for it: which
+ * order and table, what dish, and how many. Tickets go through the same four states
+ * in the same order, and each part of the restaurant moves them along one
+ * step:
+ *
+ *     Received -> Cooking -> Ready -> Delivered
+ *
+ * 'serving::take_order' puts a new ticket in the queue, 'back_of_house'
+ * starts cooking the oldest one it hasn't started and marks it ready when
+ * it's done, and 'serving::serve_order' delivers it. A ticket can't skip a
+ * step or go back one.
+ *
+ * A dish taken off the order before the kitchen starts on it doesn't get
+ * cooked: 'take_back' makes its ticket smaller, and a ticket with nothing
+ * left on it is 'Cancelled', which is as far as it goes. Cancelling the
+ * whole order cancels every ticket of its that hasn't been delivered.
+ *
+ * Delivered and cancelled tickets stay in the queue, so a ticket's number
+ * always finds it, but they're no longer outstanding. */
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TicketState {
+    Received,
+    Cooking,
+    Ready,
+    Delivered,
+    Cancelled,
+}
+
+impl TicketState {
+    fn next(self) -> Option<TicketState> {
+        match self {
+            TicketState::Received => Some(TicketState::Cooking),
+            TicketState::Cooking => Some(TicketState::Ready),
+            TicketState::Ready => Some(TicketState::Delivered),
+            TicketState::Delivered | TicketState::Cancelled => None,
+        }
+    }
+}
+
+impl fmt::Display for TicketState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = match self {
+            TicketState::Received => "received",
+            TicketState::Cooking => "cooking",
+            TicketState::Ready => "ready",
+            TicketState::Delivered => "delivered",
+            TicketState::Cancelled => "cancelled",
+        };
+        write!(f, "{state}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ticket {
+    pub number: u32,
+    pub order: u32,
+    pub table: u32,
+    pub dish: String,
+    pub quantity: u32,
+    pub state: TicketState,
+}
+
+/* "#3 table 4: 2 x pancakes (cooking)" */
+impl fmt::Display for Ticket {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "#{} table {}: {} x {} ({})",
+            self.number, self.table, self.quantity, self.dish, self.state
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TicketError {
+    NotFound,
+    AlreadyStarted,
+    WrongState {
+        expected: TicketState,
+        found: TicketState,
+    },
+}
+
+impl fmt::Display for TicketError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TicketError::NotFound => write!(f, "there's no ticket with that number"),
+            TicketError::AlreadyStarted => write!(f, "the kitchen has already started on it"),
+            TicketError::WrongState { expected, found } => {
+                write!(f, "the ticket should be {expected}, but it's {found}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TicketError {}
+
+#[derive(Debug, Clone, Default)]
+pub struct TicketQueue {
+    tickets: Vec<Ticket>,
+    last_number: u32,
+}
+
+impl TicketQueue {
+    pub fn new() -> TicketQueue {
+        TicketQueue::default()
+    }
+
+    /* Returns the new ticket's number. */
+    pub fn push(&mut self, order: u32, table: u32, dish: &str, quantity: u32) -> u32 {
+        self.last_number += 1;
+        self.tickets.push(Ticket {
+            number: self.last_number,
+            order,
+            table,
+            dish: String::from(dish),
+            quantity,
+            state: TicketState::Received,
+        });
+        self.last_number
+    }
+
+    pub fn get(&self, number: u32) -> Option<&Ticket> {
+        self.tickets.iter().find(|ticket| ticket.number == number)
+    }
+
+    /* The oldest ticket in this state, if any is. */
+    pub fn oldest(&self, state: TicketState) -> Option<&Ticket> {
+        self.tickets.iter().find(|ticket| ticket.state == state)
+    }
+
+    /* Moves a ticket on to the state after 'from', as long as 'from' is
+     * the state it's in. */
+    pub fn advance(&mut self, number: u32, from: TicketState) -> Result<TicketState, TicketError> {
+        let ticket = self
+            .tickets
+            .iter_mut()
+            .find(|ticket| ticket.number == number)
+            .ok_or(TicketError::NotFound)?;
+        match ticket.state.next() {
+            Some(next) if ticket.state == from => {
+                ticket.state = next;
+                Ok(next)
+            }
+            _ => Err(TicketError::WrongState {
+                expected: from,
+                found: ticket.state,
+            }),
+        }
+    }
+
+    /* Takes 'quantity' of a dish back off an order's tickets that haven't
+     * been started, newest first. If the kitchen has already started on
+     * some of it, nothing changes. */
+    pub fn take_back(&mut self, order: u32, dish: &str, quantity: u32) -> Result<(), TicketError> {
+        let waiting = |ticket: &&mut Ticket| {
+            ticket.order == order && ticket.dish == dish && ticket.state == TicketState::Received
+        };
+        let on_hold: u64 = self
+            .tickets
+            .iter_mut()
+            .filter(waiting)
+            .map(|ticket| u64::from(ticket.quantity))
+            .sum();
+        if on_hold < u64::from(quantity) {
+            return Err(TicketError::AlreadyStarted);
+        }
+        let mut left = quantity;
+        for ticket in self.tickets.iter_mut().rev().filter(waiting) {
+            let taken = left.min(ticket.quantity);
+            ticket.quantity -= taken;
+            if ticket.quantity == 0 {
+                ticket.state = TicketState::Cancelled;
+            }
+            left -= taken;
+        }
+        Ok(())
+    }
+
+    /* Stops the kitchen on everything for 'order' it hasn't delivered. */
+    pub fn cancel_order(&mut self, order: u32) {
+        for ticket in &mut self.tickets {
+            if ticket.order == order && ticket.state != TicketState::Delivered {
+                ticket.state = TicketState::Cancelled;
+            }
+        }
+    }
+
+    /* Every ticket that hasn't been delivered or cancelled, oldest
+     * first. */
+    pub fn outstanding(&self) -> impl Iterator<Item = &Ticket> {
+        self.tickets.iter().filter(|ticket| {
+            !matches!(
+                ticket.state,
+                TicketState::Delivered | TicketState::Cancelled
+            )
+        })
+    }
+}
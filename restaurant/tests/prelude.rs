@@ -0,0 +1,59 @@
+/* A whole visit, from the waitlist to the receipt, with nothing but the
+ * prelude, the way another crate would use the restaurant. */
+use restaurant::prelude::*;
+
+#[test]
+fn test_visit_through_the_prelude() -> Result<(), RestaurantError> {
+    let menu = Menu::house();
+    let mut floor = FloorPlan::new();
+    floor.add_table(7, 4);
+    let mut waitlist = Waitlist::new();
+    add_to_waitlist(&mut waitlist, "Ferris", 3);
+    let table = seat_at_table(&mut waitlist, &mut floor)?;
+    assert_eq!(table, 7);
+
+    let mut order = Order::open(table);
+    let mut tickets = TicketQueue::new();
+    let soup = take_order(&mut order, &menu, &mut tickets, "soup", 3)?;
+    let coffee = take_order(&mut order, &menu, &mut tickets, "coffee", 2)?;
+    assert_eq!(format_price(order.total_cents()), "$23.50");
+
+    let mut inventory = Inventory::new();
+    cook_order(&mut order, &mut tickets, &mut inventory)?;
+    assert_eq!(cook_next(&mut tickets, &mut inventory)?, None);
+    for number in [soup, coffee] {
+        mark_cooked(&mut tickets, number)?;
+        serve_order(&mut tickets, number)?;
+    }
+    /* The soup was cold; the chef makes it again. */
+    let chef = Staff::new("Remy", Role::Chef);
+    let remade = fix_incorrect_order(&chef, &mut tickets, soup, &mut inventory)?;
+    mark_cooked(&mut tickets, remade)?;
+    serve_order(&mut tickets, remade)?;
+    order.serve()?;
+
+    let server = Staff::new("Corro", Role::Server);
+    let receipt = take_payment(&server, &mut order, PaymentMethod::Card { last4: 1234 })?;
+    assert!(receipt.to_string().ends_with("Paid by card ending in 1234"));
+    assert_eq!(order.status(), OrderStatus::Paid);
+    Ok(())
+}
+
+#[test]
+fn test_prelude_errors() {
+    let menu = Menu::house();
+    let mut order = Order::open(1);
+    let error = take_order(&mut order, &menu, &mut TicketQueue::new(), "lobster", 1);
+    assert_eq!(
+        error,
+        Err(RestaurantError::ItemNotOnMenu(String::from("lobster")))
+    );
+
+    let mut book = ReservationBook::new();
+    let slot = TimeSlot::at(19, 0).unwrap();
+    let error = book.book(&FloorPlan::new(), Party::new("Ferris", 2), slot);
+    assert_eq!(
+        error.map_err(RestaurantError::from),
+        Err(RestaurantError::NoTablesAvailable)
+    );
+}
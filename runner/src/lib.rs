@@ -0,0 +1,225 @@
+/* EXAMPLE RUNNER */
+/* Each chapter of the book lives in its own crate at the top level of the
+ * repository. The runner finds them by looking for directories that
+ * contain a 'Cargo.toml', reads a one-line description out of each
+ * manifest, and can hand off to 'cargo run' for any crate that has a
+ * binary to run.
+ *
+ * Descriptions come from the standard 'description' key in the
+ * '[package]' table, so no crate has to depend on the runner. The
+ * 'Describe' trait is the runner's side of that convention: anything that
+ * can produce a name and a one-line summary can be listed. */
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+
+pub trait Describe {
+    fn name(&self) -> &str;
+    fn description(&self) -> &str;
+
+    /* Lines up descriptions in a column 'width' characters from the start
+     * of the name. */
+    fn describe(&self, width: usize) -> String {
+        format!("{:<width$} {}", self.name(), self.description())
+    }
+}
+
+/* The few fields of a 'Cargo.toml' the runner cares about. This is not a
+ * TOML parser: it reads 'key = "value"' lines inside '[package]' and the
+ * 'members' list of a '[workspace]' table, which is all our manifests
+ * use. */
+#[derive(Debug, Default, PartialEq)]
+pub struct Manifest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub workspace_members: Vec<String>,
+}
+
+impl Manifest {
+    pub fn parse(text: &str) -> Manifest {
+        let mut manifest = Manifest::default();
+        let mut section = String::new();
+        let mut in_members = false;
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.starts_with('[') {
+                section = line.trim_matches(|c| c == '[' || c == ']').to_string();
+                in_members = false;
+                continue;
+            }
+
+            if in_members {
+                manifest.workspace_members.extend(quoted_strings(line));
+                if line.contains(']') {
+                    in_members = false;
+                }
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let (key, value) = (key.trim(), value.trim());
+            match (section.as_str(), key) {
+                ("package", "name") => manifest.name = quoted_strings(value).into_iter().next(),
+                ("package", "description") => {
+                    manifest.description = quoted_strings(value).into_iter().next()
+                }
+                ("workspace", "members") => {
+                    manifest.workspace_members.extend(quoted_strings(value));
+                    in_members = !value.contains(']');
+                }
+                _ => {}
+            }
+        }
+        manifest
+    }
+}
+
+fn quoted_strings(text: &str) -> Vec<String> {
+    text.split('"')
+        .skip(1)
+        .step_by(2)
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/* A crate found in the repository. 'has_binary' is true when there's a
+ * 'src/main.rs' for 'cargo run' to build. */
+#[derive(Debug, PartialEq)]
+pub struct Member {
+    pub name: String,
+    pub description: String,
+    pub path: PathBuf,
+    pub has_binary: bool,
+}
+
+impl Describe for Member {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+pub const NO_DESCRIPTION: &str = "(no description)";
+
+impl Member {
+    pub fn load(path: &Path) -> io::Result<Member> {
+        let manifest = Manifest::parse(&fs::read_to_string(path.join("Cargo.toml"))?);
+        let dir_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Ok(Member {
+            name: manifest.name.unwrap_or(dir_name),
+            description: manifest
+                .description
+                .unwrap_or_else(|| String::from(NO_DESCRIPTION)),
+            path: path.to_path_buf(),
+            has_binary: path.join("src").join("main.rs").is_file(),
+        })
+    }
+}
+
+/* Every directory directly under 'root' with a 'Cargo.toml' is a member,
+ * except for virtual workspaces - manifests with a '[workspace]' but no
+ * '[package]' - whose members are listed in their place. The result is
+ * sorted by name so the listing is stable. */
+pub fn discover(root: &Path) -> io::Result<Vec<Member>> {
+    let mut members = Vec::new();
+
+    for entry in fs::read_dir(root)? {
+        let path = entry?.path();
+        let manifest_path = path.join("Cargo.toml");
+        if !manifest_path.is_file() {
+            continue;
+        }
+
+        let manifest = Manifest::parse(&fs::read_to_string(&manifest_path)?);
+        if manifest.name.is_none() && !manifest.workspace_members.is_empty() {
+            for member in &manifest.workspace_members {
+                members.push(Member::load(&path.join(member))?);
+            }
+        } else {
+            members.push(Member::load(&path)?);
+        }
+    }
+
+    members.sort_by_key(|m| m.name.to_lowercase());
+    Ok(members)
+}
+
+pub fn find<'a>(members: &'a [Member], name: &str) -> Option<&'a Member> {
+    members
+        .iter()
+        .find(|m| m.name == name)
+        .or_else(|| members.iter().find(|m| m.name.eq_ignore_ascii_case(name)))
+}
+
+pub fn listing(members: &[Member]) -> String {
+    let width = members.iter().map(|m| m.name.len()).max().unwrap_or(0);
+    members
+        .iter()
+        .map(|m| {
+            let marker = if m.has_binary { ' ' } else { '*' };
+            format!("{marker} {}", m.describe(width))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[derive(Debug)]
+pub enum RunError {
+    UnknownExample(String),
+    NoBinary(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunError::UnknownExample(name) => write!(f, "no example named '{name}'"),
+            RunError::NoBinary(name) => {
+                write!(f, "'{name}' is a library crate with nothing to run")
+            }
+            RunError::Io(e) => write!(f, "failed to start cargo: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl From<io::Error> for RunError {
+    fn from(e: io::Error) -> RunError {
+        RunError::Io(e)
+    }
+}
+
+/* Runs the member's demo entry point - its 'main' - through cargo, passing
+ * any extra arguments along. The child inherits stdin and stdout, so the
+ * interactive examples like the guessing game work as usual. */
+pub fn run(member: &Member, args: &[String]) -> Result<ExitStatus, RunError> {
+    if !member.has_binary {
+        return Err(RunError::NoBinary(member.name.clone()));
+    }
+
+    let status = Command::new("cargo")
+        .arg("run")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(member.path.join("Cargo.toml"))
+        .arg("--")
+        .args(args)
+        .status()?;
+    Ok(status)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,49 @@
+use runner::{discover, find, listing, run, RunError};
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "\
+usage: runner [list]
+       runner run <example> [args...]";
+
+fn main() {
+    /* The runner lives one level below the repository root, next to the
+     * crates it lists. 'CARGO_MANIFEST_DIR' is filled in at compile time
+     * with the directory holding the runner's own 'Cargo.toml'. */
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner should live inside the repository")
+        .to_path_buf();
+
+    let members = match discover(&root) {
+        Ok(members) => members,
+        Err(e) => {
+            eprintln!("Could not read {}: {e}", root.display());
+            process::exit(1);
+        }
+    };
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None | Some("list") => {
+            println!("{}", listing(&members));
+            println!("\n* library crate, nothing to run");
+        }
+        Some("run") if args.len() >= 2 => {
+            let result = find(&members, &args[1])
+                .ok_or_else(|| RunError::UnknownExample(args[1].clone()))
+                .and_then(|member| run(member, &args[2..]));
+            match result {
+                Ok(status) => process::exit(status.code().unwrap_or(1)),
+                Err(e) => {
+                    eprintln!("{e}");
+                    process::exit(1);
+                }
+            }
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+    }
+}
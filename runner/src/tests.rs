@@ -0,0 +1,82 @@
+use crate::*;
+use std::fs;
+use std::path::PathBuf;
+use test_support::TempDir;
+
+fn add_crate(root: &TempDir, dir: &str, manifest: &str, binary: bool) {
+    root.write(format!("{dir}/Cargo.toml"), manifest).unwrap();
+    let main = if binary { "main.rs" } else { "lib.rs" };
+    root.write(format!("{dir}/src/{main}"), "").unwrap();
+}
+
+#[test]
+fn test_parse_manifest() {
+    let manifest = Manifest::parse(
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\ndescription = \"A demo\"\n\n[dependencies]\nrand = \"0.8.5\"\n",
+    );
+    assert_eq!(manifest.name.as_deref(), Some("demo"));
+    assert_eq!(manifest.description.as_deref(), Some("A demo"));
+    assert!(manifest.workspace_members.is_empty());
+}
+
+#[test]
+fn test_parse_workspace_members() {
+    let manifest =
+        Manifest::parse("[workspace]\nmembers = [\n    \"adder\",\n    \"add_one\",\n]\n");
+    assert_eq!(manifest.name, None);
+    assert_eq!(manifest.workspace_members, vec!["adder", "add_one"]);
+
+    let inline = Manifest::parse("[workspace]\nmembers = [\"a\", \"b\"]\n");
+    assert_eq!(inline.workspace_members, vec!["a", "b"]);
+}
+
+#[test]
+fn test_discover() {
+    let scratch = TempDir::new("runner-discover").unwrap();
+    add_crate(
+        &scratch,
+        "zeta",
+        "[package]\nname = \"zeta\"\ndescription = \"Last\"\n",
+        true,
+    );
+    add_crate(&scratch, "alpha", "[package]\nname = \"alpha\"\n", false);
+    add_crate(
+        &scratch,
+        "ws",
+        "[workspace]\nmembers = [\"inner\"]\n",
+        false,
+    );
+    add_crate(
+        &scratch,
+        "ws/inner",
+        "[package]\nname = \"inner\"\ndescription = \"Nested\"\n",
+        true,
+    );
+    fs::create_dir_all(scratch.path().join("not_a_crate")).unwrap();
+
+    let members = discover(scratch.path()).unwrap();
+    let names: Vec<&str> = members.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, vec!["alpha", "inner", "zeta"]);
+
+    let alpha = find(&members, "alpha").unwrap();
+    assert_eq!(alpha.description, NO_DESCRIPTION);
+    assert!(!alpha.has_binary);
+    assert!(matches!(run(alpha, &[]), Err(RunError::NoBinary(_))));
+
+    assert_eq!(
+        listing(&members),
+        "* alpha (no description)\n  inner Nested\n  zeta  Last"
+    );
+}
+
+#[test]
+fn test_find_is_case_insensitive_fallback() {
+    let members = vec![Member {
+        name: String::from("Strings"),
+        description: String::new(),
+        path: PathBuf::new(),
+        has_binary: true,
+    }];
+    assert!(find(&members, "strings").is_some());
+    assert!(find(&members, "vectors").is_none());
+}
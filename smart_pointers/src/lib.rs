@@ -0,0 +1,235 @@
+/* SMART POINTERS */
+/* A 'pointer' is a general concept for a variable that contains an address
+ * in memory. The most common kind of pointer in Rust is a reference, which
+ * borrows the value it points to and has no overhead. 'Smart pointers', on
+ * the other hand, are data structures that act like a pointer but also have
+ * additional metadata and capabilities. While references only borrow data,
+ * in many cases smart pointers 'own' the data they point to. 'String' and
+ * 'Vec<T>' are both smart pointers.
+ *
+ * Smart pointers are usually implemented using structs that implement the
+ * 'Deref' and 'Drop' traits. 'Deref' allows an instance of the smart
+ * pointer struct to behave like a reference, and 'Drop' lets us customize
+ * the code that's run when an instance goes out of scope. */
+use std::cell::RefCell;
+use std::ops::Deref;
+use std::rc::Rc;
+
+/* Using Box<T> to Point to Data on the Heap */
+/* A 'cons list' is a data structure that comes from Lisp: each item
+ * contains the value of the current item and the next item, and the last
+ * item contains only a value called 'Nil' without a next item. Rust needs
+ * to know how much space a type takes up at compile time, and a recursive
+ * type could in theory nest infinitely. Because a 'Box<T>' is a pointer,
+ * Rust always knows how much space it needs: a pointer's size doesn't
+ * change based on the amount of data it's pointing to. */
+#[derive(Debug)]
+pub enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+use crate::List::{Cons, Nil};
+
+impl List {
+    pub fn from_slice(values: &[i32]) -> List {
+        values
+            .iter()
+            .rev()
+            .fold(Nil, |next, &value| Cons(value, Box::new(next)))
+    }
+
+    pub fn sum(&self) -> i32 {
+        match self {
+            Cons(value, next) => value + next.sum(),
+            Nil => 0,
+        }
+    }
+}
+
+/* Treating Smart Pointers Like Regular References with Deref */
+/* 'MyBox<T>' is a tuple struct with one element of type 'T'. Without the
+ * 'Deref' trait, the compiler can only dereference '&' references.
+ * Implementing 'deref' to return a reference to the inner value means that
+ * '*y' on a 'MyBox' is run as '*(y.deref())' behind the scenes. */
+pub struct MyBox<T>(T);
+
+impl<T> MyBox<T> {
+    pub fn new(x: T) -> MyBox<T> {
+        MyBox(x)
+    }
+}
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/* Implicit Deref Coercions with Functions and Methods */
+/* 'Deref coercion' converts a reference to a type that implements 'Deref'
+ * into a reference to another type. Calling 'hello(&m)' with a
+ * 'MyBox<String>' works because Rust turns '&MyBox<String>' into '&String'
+ * by calling 'deref', and the standard library's 'Deref' on 'String' then
+ * turns '&String' into '&str'. */
+pub fn hello(name: &str) -> String {
+    format!("Hello, {name}!")
+}
+
+/* Running Code on Cleanup with the Drop Trait */
+/* 'Drop' lets us customize what happens when a value is about to go out of
+ * scope. Variables are dropped in the reverse order of their creation. We
+ * aren't allowed to call 'Drop::drop' ourselves; to clean a value up early
+ * we call 'std::mem::drop' instead, which takes ownership of the value. */
+pub struct CustomSmartPointer {
+    pub data: String,
+}
+
+impl Drop for CustomSmartPointer {
+    fn drop(&mut self) {
+        println!("Dropping CustomSmartPointer with data `{}`!", self.data);
+    }
+}
+
+/* Rc<T>, the Reference Counted Smart Pointer */
+/* In the majority of cases, ownership is clear. There are cases, however,
+ * when a single value might have multiple owners - in a graph, multiple
+ * edges might point to the same node. 'Rc<T>' keeps track of the number of
+ * references to a value to determine whether or not the value is still in
+ * use. 'Rc::clone' only increments the reference count; it doesn't make a
+ * deep copy of the data. */
+#[derive(Debug)]
+pub enum RcList {
+    Cons(i32, Rc<RcList>),
+    Nil,
+}
+
+/* Builds the book's shared list - 'b' and 'c' both point into 'a' - and
+ * returns the strong count of 'a' at each step: after creating 'a', after
+ * 'b', after 'c', and after 'c' goes out of scope. */
+pub fn rc_counts() -> Vec<usize> {
+    let mut counts = Vec::new();
+
+    let a = Rc::new(RcList::Cons(
+        5,
+        Rc::new(RcList::Cons(10, Rc::new(RcList::Nil))),
+    ));
+    counts.push(Rc::strong_count(&a));
+
+    let _b = RcList::Cons(3, Rc::clone(&a));
+    counts.push(Rc::strong_count(&a));
+
+    {
+        let _c = RcList::Cons(4, Rc::clone(&a));
+        counts.push(Rc::strong_count(&a));
+    }
+
+    counts.push(Rc::strong_count(&a));
+    counts
+}
+
+/* RefCell<T> and the Interior Mutability Pattern */
+/* 'Interior mutability' is a design pattern that allows us to mutate data
+ * even when there are immutable references to that data. With references
+ * and 'Box<T>', the borrowing rules' invariants are enforced at compile
+ * time. With 'RefCell<T>', these invariants are enforced at runtime; if we
+ * break them we get a panic instead of a compiler error.
+ *
+ * The library below tracks a value against a maximum value and sends
+ * messages based on how close to the maximum the current value is. It
+ * could be used to track a user's quota for the number of API calls
+ * they're allowed to make. The library only provides the tracking; the
+ * application using it provides the mechanism for sending the messages
+ * through the 'Messenger' trait. */
+pub trait Messenger {
+    fn send(&self, msg: &str);
+}
+
+pub struct LimitTracker<'a, T: Messenger> {
+    messenger: &'a T,
+    value: usize,
+    max: usize,
+}
+
+impl<'a, T> LimitTracker<'a, T>
+where
+    T: Messenger,
+{
+    pub fn new(messenger: &'a T, max: usize) -> LimitTracker<'a, T> {
+        LimitTracker {
+            messenger,
+            value: 0,
+            max,
+        }
+    }
+
+    pub fn set_value(&mut self, value: usize) {
+        self.value = value;
+
+        let percentage_of_max = self.value as f64 / self.max as f64;
+
+        if percentage_of_max >= 1.0 {
+            self.messenger.send("Error: You are over your quota!");
+        } else if percentage_of_max >= 0.9 {
+            self.messenger
+                .send("Urgent warning: You've used up over 90% of your quota!");
+        } else if percentage_of_max >= 0.75 {
+            self.messenger
+                .send("Warning: You've used up over 75% of your quota!");
+        }
+    }
+}
+
+/* A mock object that records the messages it's told to send so tests can
+ * inspect them. 'Messenger::send' only takes '&self', so to record anything
+ * we keep the messages in a 'RefCell' and borrow it mutably at runtime. */
+pub struct MockMessenger {
+    pub sent_messages: RefCell<Vec<String>>,
+}
+
+impl MockMessenger {
+    pub fn new() -> MockMessenger {
+        MockMessenger {
+            sent_messages: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl Default for MockMessenger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Messenger for MockMessenger {
+    fn send(&self, message: &str) {
+        self.sent_messages.borrow_mut().push(String::from(message));
+    }
+}
+
+/* Having Multiple Owners of Mutable Data by Combining Rc<T> and RefCell<T> */
+/* An 'Rc<T>' that holds a 'RefCell<T>' gives us a value that can have
+ * multiple owners and that we can mutate. Changing the shared value through
+ * any one owner is visible through all of the others. */
+#[derive(Debug)]
+pub enum SharedList {
+    Cons(Rc<RefCell<i32>>, Rc<SharedList>),
+    Nil,
+}
+
+impl SharedList {
+    pub fn values(&self) -> Vec<i32> {
+        let mut values = Vec::new();
+        let mut current = self;
+        while let SharedList::Cons(value, next) = current {
+            values.push(*value.borrow());
+            current = next;
+        }
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests;
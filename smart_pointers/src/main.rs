@@ -0,0 +1,61 @@
+use smart_pointers::{
+    hello, rc_counts, CustomSmartPointer, LimitTracker, List, Messenger, MyBox, SharedList,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/* A 'Messenger' for the demo that just prints what it's asked to send. */
+struct ConsoleMessenger;
+
+impl Messenger for ConsoleMessenger {
+    fn send(&self, msg: &str) {
+        println!("[quota] {msg}");
+    }
+}
+
+fn main() {
+    /* The simplest use of a box: storing an 'i32' on the heap. When 'b'
+     * goes out of scope it will be deallocated, both the box (stored on the
+     * stack) and the data it points to (stored on the heap). */
+    let b = Box::new(5);
+    println!("b = {b}");
+
+    let list = List::from_slice(&[1, 2, 3]);
+    println!("{list:?} sums to {}", list.sum());
+
+    let m = MyBox::new(String::from("Rust"));
+    println!("{}", hello(&m));
+    /* Without deref coercion we'd have to write 'hello(&(*m)[..])': '*m'
+     * dereferences the 'MyBox<String>' into a 'String', and '&' and '[..]'
+     * take a string slice of the whole 'String'. */
+
+    let c = CustomSmartPointer {
+        data: String::from("my stuff"),
+    };
+    let d = CustomSmartPointer {
+        data: String::from("other stuff"),
+    };
+    println!("CustomSmartPointers created.");
+    drop(c);
+    println!("CustomSmartPointer dropped before the end of main.");
+    /* 'd' is dropped automatically at the end of 'main', after everything
+     * else has printed. */
+
+    println!("Rc::strong_count of the shared list: {:?}", rc_counts());
+
+    let messenger = ConsoleMessenger;
+    let mut tracker = LimitTracker::new(&messenger, 100);
+    tracker.set_value(76);
+    tracker.set_value(91);
+
+    let value = Rc::new(RefCell::new(5));
+    let a = Rc::new(SharedList::Cons(
+        Rc::clone(&value),
+        Rc::new(SharedList::Nil),
+    ));
+    let shared = SharedList::Cons(Rc::new(RefCell::new(3)), Rc::clone(&a));
+    *value.borrow_mut() += 10;
+    println!("shared list after mutation = {:?}", shared.values());
+
+    println!("Last use of d: {}", d.data);
+}
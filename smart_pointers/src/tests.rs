@@ -0,0 +1,70 @@
+use crate::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn test_cons_list() {
+    let list = List::from_slice(&[1, 2, 3]);
+    assert_eq!(list.sum(), 6);
+    assert_eq!(format!("{list:?}"), "Cons(1, Cons(2, Cons(3, Nil)))");
+}
+
+#[test]
+fn test_deref() {
+    let x = 5;
+    let y = MyBox::new(x);
+
+    assert_eq!(5, x);
+    assert_eq!(5, *y);
+
+    let m = MyBox::new(String::from("Rust"));
+    assert_eq!(hello(&m), "Hello, Rust!");
+}
+
+#[test]
+fn test_rc_counts() {
+    assert_eq!(rc_counts(), vec![1, 2, 3, 2]);
+}
+
+#[test]
+fn it_sends_an_over_75_percent_warning_message() {
+    let mock_messenger = MockMessenger::new();
+    let mut limit_tracker = LimitTracker::new(&mock_messenger, 100);
+
+    limit_tracker.set_value(80);
+
+    assert_eq!(mock_messenger.sent_messages.borrow().len(), 1);
+}
+
+#[test]
+fn it_sends_escalating_messages() {
+    let mock_messenger = MockMessenger::new();
+    let mut limit_tracker = LimitTracker::new(&mock_messenger, 100);
+
+    limit_tracker.set_value(10);
+    limit_tracker.set_value(95);
+    limit_tracker.set_value(100);
+
+    assert_eq!(
+        *mock_messenger.sent_messages.borrow(),
+        vec![
+            "Urgent warning: You've used up over 90% of your quota!",
+            "Error: You are over your quota!",
+        ]
+    );
+}
+
+#[test]
+fn test_shared_list_mutation() {
+    let value = Rc::new(RefCell::new(5));
+    let a = Rc::new(SharedList::Cons(
+        Rc::clone(&value),
+        Rc::new(SharedList::Nil),
+    ));
+    let b = SharedList::Cons(Rc::new(RefCell::new(3)), Rc::clone(&a));
+
+    *value.borrow_mut() += 10;
+
+    assert_eq!(a.values(), vec![15]);
+    assert_eq!(b.values(), vec![3, 15]);
+}
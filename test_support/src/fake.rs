@@ -0,0 +1,80 @@
+/* Fake Standard Input and Output */
+/* Code that reads from stdin and prints to stdout is hard to test as is.
+ * The usual fix is to write it against the 'BufRead' and 'Write' traits
+ * instead, so that 'main' can pass in 'io::stdin().lock()' and
+ * 'io::stdout()' while a test passes in these: an 'Input' that replays
+ * lines written ahead of time, and an 'Output' that keeps everything
+ * written to it so the test can check it afterwards. */
+use std::cell::RefCell;
+use std::io::{self, BufRead, Cursor, Read, Write};
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Default)]
+pub struct Input {
+    cursor: Cursor<Vec<u8>>,
+}
+
+impl Input {
+    /* Each line gets its own trailing newline, the way it would if someone
+     * typed it and pressed enter. */
+    pub fn from_lines(lines: &[&str]) -> Input {
+        let mut text = String::new();
+        for line in lines {
+            text.push_str(line);
+            text.push('\n');
+        }
+        Input {
+            cursor: Cursor::new(text.into_bytes()),
+        }
+    }
+}
+
+impl Read for Input {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.cursor.read(buf)
+    }
+}
+
+impl BufRead for Input {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.cursor.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.cursor.consume(amt)
+    }
+}
+
+/* The code under test usually takes its writer by value or by '&mut', so
+ * the test would lose access to it. Cloning an 'Output' gives a second
+ * handle to the same buffer (an 'Rc<RefCell<...>>', as in the smart
+ * pointers chapter): hand one to the code and read from the other. */
+#[derive(Debug, Clone, Default)]
+pub struct Output {
+    buffer: Rc<RefCell<Vec<u8>>>,
+}
+
+impl Output {
+    pub fn new() -> Output {
+        Output::default()
+    }
+
+    pub fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.buffer.borrow()).into_owned()
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.contents().lines().map(String::from).collect()
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,282 @@
+/* Property Testing */
+/* An example-based test checks the handful of inputs we thought of. A
+ * property-based test states something that should hold for every input
+ * ("'Guess::new' accepts a number exactly when it's between 1 and 100"),
+ * then checks it against hundreds of random ones. When an input breaks the
+ * property, the harness 'shrinks' it: it keeps trying simpler versions of
+ * the input (smaller numbers, shorter strings, fewer elements) and keeps
+ * any that still fail, so the failure we get to read is as small as
+ * possible instead of whatever random noise happened to trip it.
+ *
+ * A 'Gen' knows how to make random values and how to propose simpler
+ * versions of a value. 'check' ties the two together; 'assert_property' is
+ * the version to call from a '#[test]', which panics with the smallest
+ * failing input and the seed that found it. */
+use std::fmt::Debug;
+
+use crate::Rng;
+
+pub trait Gen {
+    type Value: Clone + Debug;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /* Candidates that are simpler than 'value', simplest first. An empty
+     * list means 'value' can't be simplified any further. */
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value>;
+}
+
+/* Integers */
+/* Integers shrink towards whichever value in their range is closest to
+ * zero: first straight to it, then halfway there, then one step. */
+#[derive(Debug, Clone, Copy)]
+pub struct Ints {
+    low: i64,
+    high: i64,
+}
+
+pub fn ints(low: i64, high: i64) -> Ints {
+    assert!(low <= high, "empty range {low}..={high}");
+    Ints { low, high }
+}
+
+impl Ints {
+    fn target(&self) -> i64 {
+        0.clamp(self.low, self.high)
+    }
+}
+
+impl Gen for Ints {
+    type Value = i64;
+
+    /* Uniformly random numbers would almost never hit the edges of a wide
+     * range, which is where the bugs usually are, so a quarter of the time
+     * we pick one of the edges (or zero) on purpose. */
+    fn generate(&self, rng: &mut Rng) -> i64 {
+        if rng.below(4) == 0 {
+            *rng.choose(&[self.low, self.high, self.target()])
+        } else {
+            rng.between(self.low, self.high)
+        }
+    }
+
+    fn shrink(&self, &value: &i64) -> Vec<i64> {
+        let target = self.target();
+        if value == target {
+            return Vec::new();
+        }
+        let halfway = target + (value - target) / 2;
+        let step = if value > target { value - 1 } else { value + 1 };
+
+        let mut candidates = vec![target];
+        for candidate in [halfway, step] {
+            if !candidates.contains(&candidate) && candidate != value {
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
+/* Strings */
+/* Strings are built from the characters in 'alphabet'. They shrink by
+ * dropping characters (the whole back half first, then one at a time) and
+ * then by replacing characters with the first one in the alphabet. */
+#[derive(Debug, Clone)]
+pub struct Strings {
+    alphabet: Vec<char>,
+    max_len: usize,
+}
+
+pub fn strings(alphabet: &str, max_len: usize) -> Strings {
+    let alphabet: Vec<char> = alphabet.chars().collect();
+    assert!(
+        !alphabet.is_empty(),
+        "the alphabet needs at least one character"
+    );
+    Strings { alphabet, max_len }
+}
+
+impl Gen for Strings {
+    type Value = String;
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let len = rng.between(0, self.max_len as i64) as usize;
+        (0..len).map(|_| *rng.choose(&self.alphabet)).collect()
+    }
+
+    fn shrink(&self, value: &String) -> Vec<String> {
+        let chars: Vec<char> = value.chars().collect();
+        let simplest = self.alphabet[0];
+        let mut candidates = Vec::new();
+
+        if chars.len() > 1 {
+            candidates.push(chars[..chars.len() / 2].iter().collect());
+        }
+        for i in 0..chars.len() {
+            let mut shorter = chars.clone();
+            shorter.remove(i);
+            candidates.push(shorter.into_iter().collect());
+        }
+        for i in 0..chars.len() {
+            if chars[i] != simplest {
+                let mut simpler = chars.clone();
+                simpler[i] = simplest;
+                candidates.push(simpler.into_iter().collect());
+            }
+        }
+        candidates
+    }
+}
+
+/* Choices */
+/* One of a fixed list of values. Earlier entries count as simpler, so put
+ * the most ordinary value first. */
+#[derive(Debug, Clone)]
+pub struct OneOf<T> {
+    choices: Vec<T>,
+}
+
+pub fn one_of<T: Clone + Debug + PartialEq>(choices: &[T]) -> OneOf<T> {
+    assert!(!choices.is_empty(), "nothing to choose from");
+    OneOf {
+        choices: choices.to_vec(),
+    }
+}
+
+impl<T: Clone + Debug + PartialEq> Gen for OneOf<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        rng.choose(&self.choices).clone()
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        self.choices
+            .iter()
+            .take_while(|&choice| choice != value)
+            .cloned()
+            .collect()
+    }
+}
+
+/* Vectors */
+/* Vectors shrink by dropping elements, like strings, and then by shrinking
+ * each element with its own generator. */
+#[derive(Debug, Clone)]
+pub struct Vecs<G> {
+    element: G,
+    max_len: usize,
+}
+
+pub fn vecs<G: Gen>(element: G, max_len: usize) -> Vecs<G> {
+    Vecs { element, max_len }
+}
+
+impl<G: Gen> Gen for Vecs<G> {
+    type Value = Vec<G::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Vec<G::Value> {
+        let len = rng.between(0, self.max_len as i64) as usize;
+        (0..len).map(|_| self.element.generate(rng)).collect()
+    }
+
+    fn shrink(&self, value: &Vec<G::Value>) -> Vec<Vec<G::Value>> {
+        let mut candidates = Vec::new();
+        if value.len() > 1 {
+            candidates.push(value[..value.len() / 2].to_vec());
+        }
+        for i in 0..value.len() {
+            let mut shorter = value.clone();
+            shorter.remove(i);
+            candidates.push(shorter);
+        }
+        for (i, element) in value.iter().enumerate() {
+            for simpler in self.element.shrink(element) {
+                let mut candidate = value.clone();
+                candidate[i] = simpler;
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
+/* Pairs */
+/* A tuple of two generators generates pairs, shrinking one side at a
+ * time. Nest them for more: '(a, (b, c))'. */
+impl<A: Gen, B: Gen> Gen for (A, B) {
+    type Value = (A::Value, B::Value);
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        (self.0.generate(rng), self.1.generate(rng))
+    }
+
+    fn shrink(&self, (a, b): &Self::Value) -> Vec<Self::Value> {
+        let firsts = self.0.shrink(a).into_iter().map(|a| (a, b.clone()));
+        let seconds = self.1.shrink(b).into_iter().map(|b| (a.clone(), b));
+        firsts.chain(seconds).collect()
+    }
+}
+
+/* Running a Property */
+/* How many shrinking steps to take at most. Every candidate list is
+ * finite and every accepted candidate is simpler than the last, so
+ * shrinking always ends, but a long string can take a while to get
+ * there. */
+const MAX_SHRINKS: u32 = 1000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure<T> {
+    pub seed: u64,
+    pub original: T,
+    pub shrunk: T,
+    pub shrinks: u32,
+}
+
+pub fn check<G: Gen>(
+    gen: &G,
+    rng: &mut Rng,
+    cases: u32,
+    property: impl Fn(&G::Value) -> bool,
+) -> Result<(), Failure<G::Value>> {
+    for _ in 0..cases {
+        let original = gen.generate(rng);
+        if property(&original) {
+            continue;
+        }
+
+        let mut shrunk = original.clone();
+        let mut shrinks = 0;
+        'shrinking: while shrinks < MAX_SHRINKS {
+            for candidate in gen.shrink(&shrunk) {
+                if !property(&candidate) {
+                    shrunk = candidate;
+                    shrinks += 1;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+
+        return Err(Failure {
+            seed: rng.seed(),
+            original,
+            shrunk,
+            shrinks,
+        });
+    }
+    Ok(())
+}
+
+/* Runs 256 cases seeded from 'TEST_SEED' (or the clock), and panics with
+ * the smallest failing input it found. */
+pub fn assert_property<G: Gen>(gen: &G, property: impl Fn(&G::Value) -> bool) {
+    let mut rng = Rng::from_env();
+    if let Err(failure) = check(gen, &mut rng, 256, property) {
+        panic!(
+            "property failed for {:?} (shrunk from {:?} in {} steps); rerun with TEST_SEED={}",
+            failure.shrunk, failure.original, failure.shrinks, failure.seed
+        );
+    }
+}
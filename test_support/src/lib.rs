@@ -0,0 +1,167 @@
+/* TEST SUPPORT */
+/* The scaffolding that kept getting copied from one crate's 'tests.rs'
+ * into the next: random data to test with, stand-ins for stdin and stdout,
+ * files that clean up after themselves, and a small property-testing
+ * harness (the 'gen' module). Other crates pull this in as
+ * a dev-dependency, which means it's only compiled for their tests and
+ * examples and never ends up in the crates themselves:
+ *
+ * [dev-dependencies]
+ * test_support = { path = "../test_support" }
+ *
+ * Because this crate can't depend on the crates it's testing (that would
+ * be a cycle), the generators produce plain "fixture" structs with the
+ * same fields as the real types, and each test converts them into
+ * whatever type it needs. */
+pub mod fake;
+pub mod gen;
+pub mod temp;
+
+pub use fake::{Input, Output};
+pub use temp::{TempDir, TempFile};
+
+use std::env;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/* A Seeded Random Number Generator */
+/* Random inputs are only useful in tests if a failure can be reproduced,
+ * so instead of the 'rand' crate's thread-local generator we use a small
+ * generator (SplitMix64) that's entirely determined by its seed. The same
+ * seed always produces the same sequence of numbers. 'from_env' prints the
+ * seed it picked; cargo shows a test's output when it fails, and setting
+ * 'TEST_SEED' to that value replays the exact same inputs. */
+#[derive(Debug, Clone)]
+pub struct Rng {
+    seed: u64,
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { seed, state: seed }
+    }
+
+    pub fn from_env() -> Rng {
+        let seed = env::var("TEST_SEED")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_nanos() as u64)
+                    .unwrap_or(0)
+            });
+        println!("test_support: TEST_SEED={seed}");
+        Rng::new(seed)
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /* A number in 'low..=high'. The modulo makes some numbers very slightly
+     * more likely than others, which doesn't matter for test data. */
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "empty range {low}..={high}");
+        let span = (high as i128 - low as i128 + 1) as u128;
+        (low as i128 + (self.next_u64() as u128 % span) as i128) as i64
+    }
+
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "can't pick a number below 0");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /* A lowercase ASCII word of 'min_len..=max_len' letters. */
+    pub fn word(&mut self, min_len: usize, max_len: usize) -> String {
+        let len = self.between(min_len as i64, max_len as i64) as usize;
+        (0..len)
+            .map(|_| (b'a' + self.below(26) as u8) as char)
+            .collect()
+    }
+
+    pub fn sentence(&mut self, words: usize) -> String {
+        (0..words)
+            .map(|_| self.word(1, 8))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/* Fixtures */
+/* One generator per type that more than one crate's tests wanted: the
+ * 'User' from the structs chapter, the aggregator's 'Tweet', and the
+ * 'Rectangle' from the methods chapter. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserFixture {
+    pub active: bool,
+    pub username: String,
+    pub email: String,
+    pub sign_in_count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TweetFixture {
+    pub username: String,
+    pub content: String,
+    pub reply_to: Option<String>,
+    /* Whether someone else goes on to retweet this tweet. */
+    pub retweet: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RectangleFixture {
+    pub width: u32,
+    pub height: u32,
+}
+
+pub fn random_user(rng: &mut Rng) -> UserFixture {
+    let username = rng.word(3, 12);
+    UserFixture {
+        active: rng.bool(),
+        email: format!("{username}@example.com"),
+        username,
+        sign_in_count: rng.between(0, 10_000) as u64,
+    }
+}
+
+pub fn random_tweet(rng: &mut Rng) -> TweetFixture {
+    let words = rng.between(1, 20) as usize;
+    TweetFixture {
+        username: rng.word(3, 15),
+        content: rng.sentence(words),
+        reply_to: if rng.bool() {
+            Some(rng.word(3, 15))
+        } else {
+            None
+        },
+        retweet: rng.bool(),
+    }
+}
+
+/* Sides stay at or under 10,000 so that the area can't overflow a 'u32'. */
+pub fn random_rectangle(rng: &mut Rng) -> RectangleFixture {
+    RectangleFixture {
+        width: rng.between(0, 10_000) as u32,
+        height: rng.between(0, 10_000) as u32,
+    }
+}
+
+#[cfg(test)]
+mod tests;
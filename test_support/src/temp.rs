@@ -0,0 +1,88 @@
+/* Temporary Files and Directories */
+/* Both types create their path under the system temp directory and delete
+ * it again in 'Drop', so a test can't leave files behind even when an
+ * assertion fails partway through - unwinding still runs destructors.
+ * Names combine the process id with a counter, so tests running in
+ * parallel threads never share a path. */
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+fn unique_path(prefix: &str) -> PathBuf {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    env::temp_dir().join(format!("{prefix}-{}-{id}", process::id()))
+}
+
+#[derive(Debug)]
+pub struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    /* A path that nothing has been written to yet. */
+    pub fn new(prefix: &str) -> TempFile {
+        let path = unique_path(prefix);
+        let _ = fs::remove_file(&path);
+        TempFile { path }
+    }
+
+    pub fn with_contents(prefix: &str, contents: &str) -> io::Result<TempFile> {
+        let file = TempFile::new(prefix);
+        fs::write(&file.path, contents)?;
+        Ok(file)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        fs::read_to_string(&self.path)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[derive(Debug)]
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    pub fn new(prefix: &str) -> io::Result<TempDir> {
+        let path = unique_path(prefix);
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path)?;
+        Ok(TempDir { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /* Writes a file relative to the directory, creating any parent
+     * directories it needs, and returns the file's full path. */
+    pub fn write(&self, relative: impl AsRef<Path>, contents: &str) -> io::Result<PathBuf> {
+        let path = self.path.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, contents)?;
+        Ok(path)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::*;
+use std::io::{BufRead, Write};
+
+#[test]
+fn test_same_seed_same_sequence() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    for _ in 0..100 {
+        assert_eq!(a.next_u64(), b.next_u64());
+    }
+    assert_eq!(random_tweet(&mut a), random_tweet(&mut b));
+    assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+}
+
+#[test]
+fn test_ranges_are_respected() {
+    let mut rng = Rng::new(7);
+    for _ in 0..1000 {
+        let n = rng.between(-3, 3);
+        assert!((-3..=3).contains(&n));
+        assert!(rng.below(5) < 5);
+
+        let word = rng.word(2, 4);
+        assert!((2..=4).contains(&word.len()));
+        assert!(word.chars().all(|c| c.is_ascii_lowercase()));
+    }
+    assert_eq!(rng.between(i64::MIN, i64::MIN), i64::MIN);
+    let _ = rng.between(i64::MIN, i64::MAX);
+}
+
+#[test]
+fn test_fixtures_are_well_formed() {
+    let mut rng = Rng::new(3);
+    for _ in 0..100 {
+        let user = random_user(&mut rng);
+        assert_eq!(user.email, format!("{}@example.com", user.username));
+
+        let tweet = random_tweet(&mut rng);
+        assert!(!tweet.username.is_empty() && !tweet.content.is_empty());
+
+        let rect = random_rectangle(&mut rng);
+        assert!(rect.width.checked_mul(rect.height).is_some());
+    }
+}
+
+#[test]
+fn test_fake_io() {
+    let mut input = Input::from_lines(&["first", "second"]);
+    let mut line = String::new();
+    input.read_line(&mut line).unwrap();
+    assert_eq!(line, "first\n");
+    assert_eq!(input.lines().count(), 1);
+
+    let output = Output::new();
+    let mut writer = output.clone();
+    writeln!(writer, "hello").unwrap();
+    write!(writer, "world").unwrap();
+    assert_eq!(output.contents(), "hello\nworld");
+    assert_eq!(output.lines(), vec!["hello", "world"]);
+}
+
+#[test]
+fn test_temp_paths_are_removed() {
+    let file = TempFile::with_contents("test_support", "data").unwrap();
+    let file_path = file.path().to_path_buf();
+    assert_eq!(file.read().unwrap(), "data");
+
+    let dir = TempDir::new("test_support").unwrap();
+    let nested = dir.write("a/b.txt", "nested").unwrap();
+    assert!(nested.exists());
+    let dir_path = dir.path().to_path_buf();
+    assert_ne!(dir_path, file_path);
+
+    drop(file);
+    drop(dir);
+    assert!(!file_path.exists());
+    assert!(!dir_path.exists());
+}
+
+#[test]
+fn test_passing_property() {
+    let mut rng = Rng::new(11);
+    let result = gen::check(&gen::ints(-50, 50), &mut rng, 500, |&n| n.abs() <= 50);
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn test_failing_int_shrinks_to_boundary() {
+    let mut rng = Rng::new(5);
+    let failure = gen::check(&gen::ints(-1000, 1000), &mut rng, 500, |&n| n < 17).unwrap_err();
+    assert_eq!(failure.shrunk, 17);
+    assert_eq!(failure.seed, 5);
+    assert!(failure.original >= 17);
+}
+
+#[test]
+fn test_failing_string_shrinks_to_minimal() {
+    let mut rng = Rng::new(9);
+    let failure = gen::check(&gen::strings("abcx", 30), &mut rng, 500, |s| {
+        !s.contains('x')
+    })
+    .unwrap_err();
+    assert_eq!(failure.shrunk, "x");
+}
+
+#[test]
+fn test_failing_vec_and_pair_shrink() {
+    let mut rng = Rng::new(13);
+    let failure = gen::check(&gen::vecs(gen::ints(0, 100), 20), &mut rng, 500, |v| {
+        v.iter().sum::<i64>() < 100
+    })
+    .unwrap_err();
+    assert_eq!(failure.shrunk, vec![100]);
+
+    let pairs = (gen::one_of(&["a", "b", "c"]), gen::ints(0, 10));
+    let failure = gen::check(&pairs, &mut rng, 500, |&(s, n)| s != "c" || n < 3).unwrap_err();
+    assert_eq!(failure.shrunk, ("c", 3));
+}
@@ -0,0 +1,130 @@
+/* UNSAFE RUST */
+/* Rust has a second language hidden inside it that doesn't enforce the
+ * memory safety guarantees: it's called 'unsafe Rust'. Unsafe Rust exists
+ * because static analysis is conservative - it's better for the compiler
+ * to reject some valid programs than to accept some invalid ones - and
+ * because the underlying hardware is inherently unsafe.
+ *
+ * The 'unsafe' keyword gives us five abilities, called 'unsafe
+ * superpowers':
+ *
+ *  - Dereference a raw pointer
+ *  - Call an unsafe function or method
+ *  - Access or modify a mutable static variable
+ *  - Implement an unsafe trait
+ *  - Access fields of a 'union'
+ *
+ * 'unsafe' doesn't turn off the borrow checker; it only lets us use these
+ * five features, which the compiler doesn't check for memory safety. The
+ * goal of each function below is to keep the 'unsafe' code small and wrap
+ * it in a safe API, so that callers can't misuse it no matter what they
+ * pass in. */
+use std::slice;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/* Dereferencing a Raw Pointer */
+/* Raw pointers, '*const T' and '*mut T', are allowed to ignore the
+ * borrowing rules, aren't guaranteed to point to valid memory, are allowed
+ * to be null, and don't implement any automatic cleanup. We can create raw
+ * pointers in safe code; we just can't dereference them outside an unsafe
+ * block. Here both pointers come from a live local variable, so they are
+ * valid for the whole block. */
+pub fn raw_pointer_round_trip(mut num: i32) -> (i32, i32) {
+    let r1 = &raw const num;
+    let r2 = &raw mut num;
+
+    unsafe {
+        let before = *r1;
+        *r2 += 1;
+        (before, *r1)
+    }
+}
+
+/* Creating a Safe Abstraction over Unsafe Code */
+/* 'split_at_mut' takes one slice and makes it two by splitting the slice at
+ * the index given as an argument. It can't be written with only safe Rust:
+ * the borrow checker can't understand that we're borrowing different parts
+ * of the slice, only that we're borrowing from the same slice twice.
+ *
+ * The 'assert!' is what makes the function safe to call. Because 'mid' is
+ * at most 'len', both new slices lie inside the original one and don't
+ * overlap, so handing out two mutable references can't alias. */
+pub fn split_at_mut<T>(values: &mut [T], mid: usize) -> (&mut [T], &mut [T]) {
+    let len = values.len();
+    let ptr = values.as_mut_ptr();
+
+    assert!(mid <= len);
+
+    unsafe {
+        (
+            slice::from_raw_parts_mut(ptr, mid),
+            slice::from_raw_parts_mut(ptr.add(mid), len - mid),
+        )
+    }
+}
+
+/* Using extern Functions to Call External Code */
+/* The 'extern' keyword facilitates the creation and use of a 'Foreign
+ * Function Interface (FFI)'. The '"C"' part defines which 'application
+ * binary interface (ABI)' the external function uses. Functions declared
+ * within 'extern' blocks are always unsafe to call from Rust code, because
+ * other languages don't enforce Rust's rules and Rust can't check them.
+ *
+ * The C standard library's 'abs' has undefined behavior for the most
+ * negative 'int', whose absolute value doesn't fit in an 'int'. The safe
+ * wrapper refuses that one input instead of passing it along. */
+extern "C" {
+    fn abs(input: i32) -> i32;
+}
+
+pub fn c_abs(input: i32) -> Option<i32> {
+    if input == i32::MIN {
+        return None;
+    }
+    Some(unsafe { abs(input) })
+}
+
+/* Accessing or Modifying a Mutable Static Variable */
+/* Global variables are called 'static' variables in Rust. Accessing and
+ * modifying mutable static variables is unsafe, because with multiple
+ * threads accessing the same variable it's easy to get data races.
+ *
+ * The book's 'add_to_count' is itself an 'unsafe fn', pushing the problem
+ * onto every caller. Here the counter is only ever touched while holding a
+ * tiny spin lock built from an 'AtomicBool', which makes 'add_to_count' and
+ * 'count' safe to call from any thread. Where possible, prefer the
+ * concurrency types from the standard library (an 'AtomicU32' would do the
+ * whole job here); the lock exists to show what it takes to make the
+ * static sound. */
+static mut COUNTER: u32 = 0;
+static COUNTER_LOCK: AtomicBool = AtomicBool::new(false);
+
+fn with_counter<R>(f: impl FnOnce(&mut u32) -> R) -> R {
+    while COUNTER_LOCK
+        .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+        .is_err()
+    {
+        std::hint::spin_loop();
+    }
+
+    // SAFETY: the lock above guarantees no other thread holds this reference
+    let counter = &raw mut COUNTER;
+    let result = f(unsafe { &mut *counter });
+
+    COUNTER_LOCK.store(false, Ordering::Release);
+    result
+}
+
+pub fn add_to_count(inc: u32) -> u32 {
+    with_counter(|count| {
+        *count = count.wrapping_add(inc);
+        *count
+    })
+}
+
+pub fn count() -> u32 {
+    with_counter(|count| *count)
+}
+
+#[cfg(test)]
+mod tests;
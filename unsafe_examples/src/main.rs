@@ -0,0 +1,18 @@
+use unsafe_examples::{add_to_count, c_abs, count, raw_pointer_round_trip, split_at_mut};
+
+fn main() {
+    let (before, after) = raw_pointer_round_trip(5);
+    println!("Through raw pointers: {before} became {after}");
+
+    let mut v = vec![1, 2, 3, 4, 5, 6];
+    let (a, b) = split_at_mut(&mut v, 3);
+    a[0] = 10;
+    b[0] = 40;
+    println!("After writing through both halves: {v:?}");
+
+    println!("Absolute value of -3 according to C: {:?}", c_abs(-3));
+    println!("Absolute value of i32::MIN according to C: {:?}", c_abs(i32::MIN));
+
+    add_to_count(3);
+    println!("COUNTER: {}", count());
+}
@@ -0,0 +1,72 @@
+use crate::*;
+use std::thread;
+
+#[test]
+fn test_raw_pointer_round_trip() {
+    assert_eq!(raw_pointer_round_trip(5), (5, 6));
+}
+
+#[test]
+fn test_split_at_mut_halves_are_independent() {
+    let mut v = vec![1, 2, 3, 4, 5, 6];
+
+    let (a, b) = split_at_mut(&mut v, 3);
+    assert_eq!(a, &mut [1, 2, 3]);
+    assert_eq!(b, &mut [4, 5, 6]);
+
+    a[0] = 10;
+    b[0] = 40;
+    assert_eq!(v, vec![10, 2, 3, 40, 5, 6]);
+}
+
+#[test]
+fn test_split_at_mut_edges() {
+    let mut v = [1, 2];
+    let (a, b) = split_at_mut(&mut v, 0);
+    assert!(a.is_empty());
+    assert_eq!(b.len(), 2);
+
+    let (a, b) = split_at_mut(&mut v, 2);
+    assert_eq!(a.len(), 2);
+    assert!(b.is_empty());
+
+    let mut empty: [u8; 0] = [];
+    let (a, b) = split_at_mut(&mut empty, 0);
+    assert!(a.is_empty() && b.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_split_at_mut_rejects_out_of_bounds() {
+    let mut v = [1, 2, 3];
+    split_at_mut(&mut v, 4);
+}
+
+#[test]
+fn test_c_abs() {
+    assert_eq!(c_abs(-3), Some(3));
+    assert_eq!(c_abs(7), Some(7));
+    assert_eq!(c_abs(i32::MAX), Some(i32::MAX));
+    assert_eq!(c_abs(i32::MIN + 1), Some(i32::MAX));
+    assert_eq!(c_abs(i32::MIN), None);
+}
+
+#[test]
+fn test_counter_is_exact_across_threads() {
+    let before = count();
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            thread::spawn(|| {
+                for _ in 0..1000 {
+                    add_to_count(1);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(count() - before, 8000);
+}